@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::traits::ProjectiveCurve;
+
+use parking_lot::RwLock;
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The environment variable for the allowlist of CUDA devices, as a comma-separated list of device IDs.
+pub const DEVICE_ALLOWLIST_VARIABLE: &str = "SNARKVM_ICICLE_DEVICES";
+
+lazy_static::lazy_static! {
+    /// The devices used for GPU acceleration, which is initialized from the environment on first use.
+    static ref DEVICE_MANAGER: RwLock<DeviceManager> = RwLock::new(DeviceManager::from_env());
+}
+
+/// The counter used to assign independent (I)NTTs to the devices in turn.
+static NEXT_DEVICE: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the devices used for GPU acceleration.
+pub fn device_manager() -> DeviceManager {
+    DEVICE_MANAGER.read().clone()
+}
+
+/// Restricts GPU acceleration to the given devices, overriding the allowlist from the environment.
+pub fn set_device_allowlist(device_ids: &[usize]) -> Result<(), GPUError> {
+    *DEVICE_MANAGER.write() = DeviceManager::new(device_ids)?;
+    Ok(())
+}
+
+/// A set of CUDA devices, which partitions the MSMs and independent (I)NTTs of a proof across the devices.
+///
+/// Each device is driven from its own thread, with its own CUDA context and device memory,
+/// so the work on one device does not block the work on another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceManager {
+    /// The IDs of the devices, in the order work is assigned to them.
+    device_ids: Vec<usize>,
+}
+
+impl DeviceManager {
+    /// Initializes a device manager for the given device IDs.
+    pub fn new(device_ids: &[usize]) -> Result<Self, GPUError> {
+        let num_devices = num_devices();
+        let mut ids = Vec::with_capacity(device_ids.len());
+        for id in device_ids {
+            // Ensure the device exists.
+            if *id >= num_devices {
+                return Err(GPUError::Generic(format!("CUDA device {id} does not exist ({num_devices} found)")));
+            }
+            // Ensure the device is not repeated.
+            if !ids.contains(id) {
+                ids.push(*id);
+            }
+        }
+        Ok(Self { device_ids: ids })
+    }
+
+    /// Initializes a device manager for every device, or for the allowlist in the `SNARKVM_ICICLE_DEVICES` variable.
+    /// An invalid allowlist disables GPU acceleration.
+    pub fn from_env() -> Self {
+        match std::env::var(DEVICE_ALLOWLIST_VARIABLE) {
+            Ok(allowlist) => {
+                let device_ids =
+                    allowlist.split(',').map(|id| id.trim().parse::<usize>()).collect::<Result<Vec<_>, _>>();
+                match device_ids.map_err(|e| GPUError::Generic(e.to_string())).and_then(|ids| Self::new(&ids)) {
+                    Ok(manager) => manager,
+                    Err(error) => {
+                        tracing::warn!("Invalid {DEVICE_ALLOWLIST_VARIABLE} '{allowlist}', disabling ICICLE: {error}");
+                        Self { device_ids: vec![] }
+                    }
+                }
+            }
+            Err(_) => Self { device_ids: (0..num_devices()).collect() },
+        }
+    }
+
+    /// Returns the IDs of the devices.
+    pub fn device_ids(&self) -> &[usize] {
+        &self.device_ids
+    }
+
+    /// Returns the ID of the device for the next independent (I)NTT, assigning the devices in turn.
+    pub(crate) fn next_device_id(&self) -> Result<usize, GPUError> {
+        self.ensure_device()?;
+        let index = NEXT_DEVICE.fetch_add(1, Ordering::Relaxed) % self.device_ids.len();
+        Ok(self.device_ids[index])
+    }
+
+    /// Returns an error if there are no devices.
+    fn ensure_device(&self) -> Result<(), GPUError> {
+        match self.device_ids.is_empty() {
+            true => Err(GPUError::DeviceNotFound),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns the variable base MSM of the given bases and scalars,
+    /// where the bases and scalars are split evenly across the devices.
+    pub fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective, GPUError> {
+        self.ensure_device()?;
+
+        let size = bases.len().min(scalars.len());
+        let chunk_size = ((size + self.device_ids.len() - 1) / self.device_ids.len()).max(1);
+        // Compute the MSM of each chunk on its own device.
+        let results = std::thread::scope(|scope| {
+            let handles = bases[..size]
+                .chunks(chunk_size)
+                .zip(scalars[..size].chunks(chunk_size))
+                .zip(&self.device_ids)
+                .map(|((bases, scalars), id)| scope.spawn(move || msm_on_device(bases, scalars, *id)))
+                .collect::<Vec<_>>();
+            handles.into_iter().map(join).collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(results.into_iter().sum())
+    }
+
+    /// Computes the in-order (I)NTT of each of the given vectors in place,
+    /// where the vectors are assigned to the devices in turn. The INTT includes the division by the domain size.
+    /// On failure, some vectors may have been transformed and the others are left unchanged.
+    pub fn ntt_batch(&self, batch: &mut [&mut [Fr]], inverse: bool) -> Result<(), GPUError> {
+        self.ensure_device()?;
+
+        // Assign the vectors to the devices in turn.
+        let num_devices = self.device_ids.len();
+        let mut assignments = (0..num_devices).map(|_| Vec::new()).collect::<Vec<_>>();
+        for (i, values) in batch.iter_mut().enumerate() {
+            assignments[i % num_devices].push(&mut **values);
+        }
+        // Compute the (I)NTTs of each device on its own thread.
+        std::thread::scope(|scope| {
+            let handles = assignments
+                .into_iter()
+                .zip(&self.device_ids)
+                .map(|(assignment, id)| {
+                    scope.spawn(move || {
+                        assignment.into_iter().try_for_each(|values| ntt_on_device(values, inverse, *id))
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles.into_iter().map(join).collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(())
+    }
+}
+
+/// Returns the result of the given device thread.
+fn join<T>(handle: std::thread::ScopedJoinHandle<'_, Result<T, GPUError>>) -> Result<T, GPUError> {
+    handle.join().map_err(|_| GPUError::Generic("A device thread panicked".to_string()))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{
+        rand::{test_rng, Uniform},
+        BitIteratorBE,
+    };
+
+    #[test]
+    fn test_device_manager_new() {
+        let num_devices = num_devices();
+        // Ensure a nonexistent device is rejected.
+        assert!(DeviceManager::new(&[num_devices]).is_err());
+        // Ensure repeated devices are removed.
+        if num_devices > 0 {
+            assert_eq!(DeviceManager::new(&[0, 0]).unwrap().device_ids(), &[0]);
+        }
+        // Ensure an empty allowlist disables GPU acceleration.
+        let manager = DeviceManager::new(&[]).unwrap();
+        assert!(manager.msm(&[], &[]).is_err());
+        assert!(manager.ntt_batch(&mut [], false).is_err());
+    }
+
+    #[test]
+    fn test_device_manager_msm_and_ntt() {
+        let manager = DeviceManager::new(&(0..num_devices()).collect::<Vec<_>>()).unwrap();
+        if manager.device_ids().is_empty() {
+            return;
+        }
+        let mut rng = test_rng();
+
+        // Ensure the partitioned MSM matches the naive MSM.
+        let size = 1 << 12;
+        let bases = (0..size).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+        let scalars = (0..size).map(|_| Fr::rand(&mut rng).to_repr()).collect::<Vec<_>>();
+        let expected: G1Projective =
+            bases.iter().zip(&scalars).map(|(base, scalar)| base.mul_bits(BitIteratorBE::new(*scalar))).sum();
+        assert_eq!(expected, manager.msm(&bases, &scalars).unwrap());
+
+        // Ensure the batched (I)NTTs match the individual (I)NTTs.
+        let vectors = (0..5).map(|_| (0..1 << 12).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let mut candidates = vectors.clone();
+        manager.ntt_batch(&mut candidates.iter_mut().map(|v| v.as_mut_slice()).collect::<Vec<_>>(), false).unwrap();
+        for (vector, candidate) in vectors.iter().zip(&candidates) {
+            let mut expected = vector.clone();
+            ntt_on_device(&mut expected, false, 0).unwrap();
+            assert_eq!(&expected, candidate);
+        }
+        manager.ntt_batch(&mut candidates.iter_mut().map(|v| v.as_mut_slice()).collect::<Vec<_>>(), true).unwrap();
+        assert_eq!(vectors, candidates);
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! GPU acceleration for BLS12-377 through the ICICLE library.
+//!
+//! This crate is not a member of the snarkVM workspace, as the ICICLE bindings are only available from git.
+//! A prover enables the backend by calling `register` at startup, after which `VariableBase::msm` and the
+//! (I)FFTs of `EvaluationDomain` run on the GPU, when `snarkvm-algorithms` is built with the `gpu` feature.
+//!
+//! The ICICLE types store field elements as little-endian `u32` limbs in standard (non-Montgomery) form,
+//! and store projective points in homogeneous coordinates `(X : Y : Z)`, where `x = X / Z` and `y = Y / Z`.
+
+mod device;
+pub use device::*;
+
+mod msm;
+pub use msm::*;
+
+mod ntt;
+pub use ntt::*;
+
+use snarkvm_algorithms::gpu::{self, GpuBackend};
+use snarkvm_curves::{
+    bls12_377::{Fq, Fr, G1Affine, G1Projective},
+    traits::AffineCurve,
+};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{BigInteger256, BigInteger384};
+
+use anyhow::Result;
+use icicle_utils::curves::bls12_377::{
+    BaseField_BLS12_377, PointAffineNoInfinity_BLS12_377, Point_BLS12_377, ScalarField_BLS12_377,
+};
+use rust_gpu_tools::{Device, GPUError};
+
+/// Registers the ICICLE backend as the GPU backend of `snarkvm-algorithms`.
+pub fn register() -> Result<()> {
+    gpu::set_backend(IcicleBackend)
+}
+
+/// The GPU backend of ICICLE, which splits the work across the devices of the device manager.
+#[derive(Copy, Clone, Debug)]
+pub struct IcicleBackend;
+
+impl GpuBackend for IcicleBackend {
+    /// Returns the variable base MSM of the given bases and scalars, split across the devices of the device manager.
+    fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective> {
+        Ok(device_manager().msm(bases, scalars)?)
+    }
+
+    /// Computes the in-order (I)NTT of the given values in place, on the next device of the device manager,
+    /// so independent (I)NTTs computed concurrently are spread across the devices.
+    fn ntt(&self, values: &mut [Fr], inverse: bool) -> Result<()> {
+        let device_id = device_manager().next_device_id()?;
+        Ok(ntt_on_device(values, inverse, device_id)?)
+    }
+}
+
+/// Returns the number of CUDA devices available to ICICLE.
+pub fn num_devices() -> usize {
+    Device::all().iter().filter(|device| device.cuda_device().is_some()).count()
+}
+
+/// Splits the given `u64` limbs into little-endian `u32` limbs.
+fn to_u32_limbs(limbs: &[u64]) -> Vec<u32> {
+    limbs.iter().flat_map(|limb| [*limb as u32, (*limb >> 32) as u32]).collect()
+}
+
+/// Joins the given little-endian `u32` limbs into `u64` limbs.
+fn to_u64_limbs<const N: usize>(limbs: &[u32]) -> [u64; N] {
+    let mut output = [0u64; N];
+    for (output, limbs) in output.iter_mut().zip(limbs.chunks(2)) {
+        *output = limbs[0] as u64 | ((*limbs.get(1).unwrap_or(&0) as u64) << 32);
+    }
+    output
+}
+
+/// Returns the ICICLE scalar for the given scalar, in standard form.
+pub(crate) fn scalar_to_icicle(scalar: &BigInteger256) -> ScalarField_BLS12_377 {
+    ScalarField_BLS12_377::from_limbs(&to_u32_limbs(&scalar.0))
+}
+
+/// Returns the scalar for the given ICICLE scalar.
+pub(crate) fn scalar_from_icicle(scalar: &ScalarField_BLS12_377) -> Result<Fr, GPUError> {
+    Fr::from_repr(BigInteger256(to_u64_limbs(&scalar.limbs())))
+        .ok_or_else(|| GPUError::Generic("ICICLE returned a non-canonical scalar".to_string()))
+}
+
+/// Returns the ICICLE base field element for the given base field element.
+fn base_to_icicle(base: &Fq) -> BaseField_BLS12_377 {
+    BaseField_BLS12_377::from_limbs(&to_u32_limbs(&base.to_repr().0))
+}
+
+/// Returns the base field element for the given ICICLE base field element.
+fn base_from_icicle(base: &BaseField_BLS12_377) -> Result<Fq, GPUError> {
+    Fq::from_repr(BigInteger384(to_u64_limbs(&base.limbs())))
+        .ok_or_else(|| GPUError::Generic("ICICLE returned a non-canonical base field element".to_string()))
+}
+
+/// Returns the ICICLE affine point for the given affine point, which must not be the point at infinity.
+pub(crate) fn affine_to_icicle(point: &G1Affine) -> PointAffineNoInfinity_BLS12_377 {
+    debug_assert!(!point.is_zero());
+    PointAffineNoInfinity_BLS12_377 {
+        x: base_to_icicle(&point.to_x_coordinate()),
+        y: base_to_icicle(&point.to_y_coordinate()),
+    }
+}
+
+/// Returns the projective point for the given ICICLE projective point.
+pub(crate) fn projective_from_icicle(point: &Point_BLS12_377) -> Result<G1Projective, GPUError> {
+    let z = base_from_icicle(&point.z)?;
+    // Convert the homogeneous coordinates into affine coordinates.
+    let z_inverse = match z.inverse() {
+        Some(z_inverse) => z_inverse,
+        None => return Ok(G1Projective::zero()),
+    };
+    let x = base_from_icicle(&point.x)? * z_inverse;
+    let y = base_from_icicle(&point.y)? * z_inverse;
+    // Ensure the point is on the curve.
+    let point = G1Affine::new(x, y, false);
+    match point.is_on_curve() {
+        true => Ok(point.to_projective()),
+        false => Err(GPUError::Generic("ICICLE returned a point that is not on the curve".to_string())),
+    }
+}
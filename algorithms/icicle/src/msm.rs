@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::traits::ProjectiveCurve;
+use snarkvm_utilities::cfg_iter;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the variable base MSM of the given bases and scalars, computed on the given CUDA device.
+pub fn msm_on_device(
+    bases: &[G1Affine],
+    scalars: &[BigInteger256],
+    device_id: usize,
+) -> Result<G1Projective, GPUError> {
+    // Convert the inputs, skipping the points at infinity, as ICICLE does not represent them.
+    let (points, scalars): (Vec<_>, Vec<_>) = cfg_iter!(bases)
+        .zip(scalars)
+        .filter(|(base, _)| !base.is_zero())
+        .map(|(base, scalar)| (affine_to_icicle(base), scalar_to_icicle(scalar)))
+        .unzip();
+    if points.is_empty() {
+        return Ok(G1Projective::zero());
+    }
+
+    // Compute the MSM, catching a panic from the device bindings, so the caller can fall back to the CPU.
+    let result = std::panic::catch_unwind(|| icicle_utils::msm_bls12_377(&points, &scalars, device_id))
+        .map_err(|_| GPUError::Generic(format!("ICICLE MSM failed on device {device_id}")))?;
+    projective_from_icicle(&result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::gpu::MIN_GPU_MSM_SIZE;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::{
+        rand::{test_rng, Uniform},
+        BitIteratorBE,
+    };
+
+    #[test]
+    fn test_limbs() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let expected = Fr::rand(&mut rng);
+            assert_eq!(expected, scalar_from_icicle(&scalar_to_icicle(&expected.to_repr())).unwrap());
+
+            let expected = G1Projective::rand(&mut rng);
+            let candidate = expected.to_affine();
+            let point = Point_BLS12_377 {
+                x: base_to_icicle(&candidate.x),
+                y: base_to_icicle(&candidate.y),
+                z: base_to_icicle(&Fq::one()),
+            };
+            assert_eq!(expected, projective_from_icicle(&point).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_msm_icicle() {
+        if num_devices() == 0 {
+            return;
+        }
+        let mut rng = test_rng();
+        for size in [1, 10, MIN_GPU_MSM_SIZE] {
+            let mut bases = (0..size).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+            let scalars = (0..size).map(|_| Fr::rand(&mut rng).to_repr()).collect::<Vec<_>>();
+            // Include a point at infinity.
+            bases[0] = G1Affine::zero();
+
+            let expected: G1Projective =
+                bases.iter().zip(&scalars).map(|(base, scalar)| base.mul_bits(BitIteratorBE::new(*scalar))).sum();
+            let candidate = IcicleBackend.msm(&bases, &scalars).unwrap();
+            assert_eq!(expected, candidate, "MSM size: {size}");
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_algorithms::fft::domain::log2;
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut};
+
+use icicle_utils::{build_domain_bls12_377, ntt_inplace_batch_bls12_377};
+use parking_lot::Mutex;
+use rustacuda::{
+    context::{Context, ContextFlags, CurrentContext},
+    device::Device as CudaDevice,
+    error::CudaError,
+    memory::{CopyDestination, DeviceBuffer},
+    CudaFlags,
+};
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+lazy_static::lazy_static! {
+    /// The device memory of each CUDA device, which is reused across the (I)NTTs of a proof.
+    /// Each device has its own lock, so the devices are used concurrently.
+    static ref DEVICE_CACHES: Mutex<HashMap<usize, Arc<Mutex<DeviceCache>>>> = Mutex::new(HashMap::new());
+}
+
+/// The device memory of a CUDA device.
+struct DeviceCache {
+    /// The CUDA context of the device.
+    context: Context,
+    /// The twiddle factors, for each domain size and direction.
+    twiddles: HashMap<(u32, bool), DeviceBuffer<ScalarField_BLS12_377>>,
+    /// The input and output buffer, for each domain size.
+    buffers: HashMap<u32, DeviceBuffer<ScalarField_BLS12_377>>,
+}
+
+impl DeviceCache {
+    /// Initializes the CUDA context of the given device.
+    fn new(device_id: usize) -> Result<Self, GPUError> {
+        rustacuda::init(CudaFlags::empty()).map_err(cuda_error)?;
+        let device = CudaDevice::get_device(device_id as u32).map_err(cuda_error)?;
+        let context =
+            Context::create_and_push(ContextFlags::MAP_HOST | ContextFlags::SCHED_AUTO, device).map_err(cuda_error)?;
+        Ok(Self { context, twiddles: HashMap::new(), buffers: HashMap::new() })
+    }
+}
+
+/// Returns the GPU error for the given CUDA error.
+fn cuda_error(error: CudaError) -> GPUError {
+    GPUError::Generic(format!("CUDA error: {error}"))
+}
+
+/// Releases the device memory retained for the (I)NTTs, such as after a proof is complete.
+pub fn release_device_memory() {
+    DEVICE_CACHES.lock().clear();
+}
+
+/// Computes the in-order (I)NTT of the given values in place, on the given CUDA device.
+/// On failure, the values are left unchanged.
+pub fn ntt_on_device(values: &mut [Fr], inverse: bool, device_id: usize) -> Result<(), GPUError> {
+    if !values.len().is_power_of_two() {
+        return Err(GPUError::Generic(format!("The NTT size {} is not a power of two", values.len())));
+    }
+    let log_size = log2(values.len());
+
+    // Convert the values.
+    let mut scalars = cfg_iter!(values).map(|value| scalar_to_icicle(&value.to_repr())).collect::<Vec<_>>();
+
+    {
+        let cache = match DEVICE_CACHES.lock().entry(device_id) {
+            Entry::Occupied(entry) => entry.get().clone(),
+            Entry::Vacant(entry) => entry.insert(Arc::new(Mutex::new(DeviceCache::new(device_id)?))).clone(),
+        };
+        let mut cache = cache.lock();
+        let cache = &mut *cache;
+        CurrentContext::set_current(&cache.context).map_err(cuda_error)?;
+
+        // Retrieve the twiddle factors, computing them on the first use of the domain.
+        let twiddles = match cache.twiddles.entry((log_size, inverse)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let size = scalars.len();
+                let twiddles = std::panic::catch_unwind(|| build_domain_bls12_377(size, log_size as usize, inverse))
+                    .map_err(|_| GPUError::Generic(format!("ICICLE failed to build a domain on device {device_id}")))?;
+                entry.insert(twiddles)
+            }
+        };
+        // Copy the values into the device buffer, allocating it on the first use of the domain.
+        let buffer = match cache.buffers.entry(log_size) {
+            Entry::Occupied(entry) => {
+                let buffer = entry.into_mut();
+                buffer.copy_from(scalars.as_slice()).map_err(cuda_error)?;
+                buffer
+            }
+            Entry::Vacant(entry) => entry.insert(DeviceBuffer::from_slice(&scalars).map_err(cuda_error)?),
+        };
+
+        // Compute the (I)NTT, catching a panic from the device bindings, so the caller can fall back to the CPU.
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ntt_inplace_batch_bls12_377(buffer, twiddles, 1, inverse, device_id)
+        }))
+        .map_err(|_| GPUError::Generic(format!("ICICLE NTT failed on device {device_id}")))?;
+        buffer.copy_to(scalars.as_mut_slice()).map_err(cuda_error)?;
+    }
+
+    // Convert the results, only writing them back once they are all valid.
+    let results = cfg_iter!(scalars).map(scalar_from_icicle).collect::<Result<Vec<_>, _>>()?;
+    cfg_iter_mut!(values).zip(results).for_each(|(value, result)| *value = result);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+    use snarkvm_utilities::rand::{test_rng, Uniform};
+
+    use rand::Rng;
+
+    #[test]
+    fn test_ntt_icicle() {
+        if num_devices() == 0 {
+            return;
+        }
+        let mut rng = test_rng();
+        for log_size in [12, 14, 16] {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            // Repeat each size, to exercise the reuse of the device memory.
+            for _ in 0..2 {
+                let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+                let mut candidate = coeffs.clone();
+                IcicleBackend.ntt(&mut candidate, false).unwrap();
+
+                // Ensure the NTT is in order, by spot-checking the evaluations.
+                let polynomial = DensePolynomial::from_coefficients_slice(&coeffs);
+                let elements = domain.elements().collect::<Vec<_>>();
+                for _ in 0..8 {
+                    let i = rng.gen_range(0..domain.size());
+                    assert_eq!(polynomial.evaluate(elements[i]), candidate[i], "NTT size: 2^{log_size}");
+                }
+
+                IcicleBackend.ntt(&mut candidate, true).unwrap();
+                assert_eq!(coeffs, candidate, "INTT size: 2^{log_size}");
+            }
+        }
+        release_device_memory();
+    }
+}
@@ -27,10 +27,7 @@
 //! by performing an O(n log n) FFT over such a domain.
 
 use crate::{
-    cfg_chunks_mut,
-    cfg_into_iter,
-    cfg_iter,
-    cfg_iter_mut,
+    cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut,
     fft::{DomainCoeff, SparsePolynomial},
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
@@ -47,6 +44,12 @@ use rayon::prelude::*;
 #[cfg(not(feature = "parallel"))]
 use itertools::Itertools;
 
+#[cfg(feature = "gpu")]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "gpu")]
+static HAS_GPU_FAILED: AtomicBool = AtomicBool::new(false);
+
 /// Returns the ceiling of the base-2 logarithm of `x`.
 ///
 /// ```
@@ -149,7 +152,11 @@ impl<F: FftField> EvaluationDomain<F> {
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
         let size = num_coeffs.next_power_of_two();
-        if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY { Some(size) } else { None }
+        if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+            Some(size)
+        } else {
+            None
+        }
     }
 
     /// Return the size of `self`.
@@ -371,17 +378,27 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::II, false) {
+            return;
+        }
         let pc = self.precompute_fft();
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
     }
 
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::II, true) {
+            return;
+        }
         let pc = self.precompute_ifft();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
 
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::II, true) {
+            Self::distribute_powers(x_s, self.generator_inv);
+            return;
+        }
         let pc = self.precompute_ifft();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
         let coset_shift = self.generator_inv;
@@ -394,6 +411,9 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &FFTPrecomputation<F>,
     ) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::II, false) {
+            return;
+        }
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp)
     }
 
@@ -402,6 +422,9 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &FFTPrecomputation<F>,
     ) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::IO, false) {
+            return;
+        }
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::IO, pre_comp)
     }
 
@@ -410,6 +433,9 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::II, true) {
+            return;
+        }
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
@@ -419,6 +445,9 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::OI, true) {
+            return;
+        }
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::OI, pre_comp);
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
@@ -429,11 +458,59 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
+        if Self::gpu_fft_in_place(x_s, FFTOrder::II, true) {
+            Self::distribute_powers(x_s, self.generator_inv);
+            return;
+        }
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
         let coset_shift = self.generator_inv;
         Self::distribute_powers_and_mul_by_const(x_s, coset_shift, self.size_inv);
     }
 
+    /// Computes the (I)FFT of `x_s` on the GPU, in the given order,
+    /// and returns `false` if it must be computed on the CPU instead. The IFFT includes the division by the domain size.
+    #[cfg(feature = "gpu")]
+    fn gpu_fft_in_place<T: DomainCoeff<F>>(x_s: &mut [T], ord: FFTOrder, inverse: bool) -> bool {
+        use core::any::TypeId;
+        use snarkvm_curves::bls12_377::Fr;
+
+        if TypeId::of::<T>() != TypeId::of::<Fr>()
+            || x_s.len() < crate::gpu::MIN_GPU_NTT_SIZE
+            || crate::gpu::backend().is_none()
+            || HAS_GPU_FAILED.load(Ordering::SeqCst)
+        {
+            return false;
+        }
+
+        // The GPU computes the in-order (I)FFT, so the out-of-order input or output is bit-reversed on the CPU.
+        if ord == FFTOrder::OI {
+            derange(x_s);
+        }
+        match crate::gpu::ntt_gpu(x_s, inverse) {
+            Ok(()) => {
+                if ord == FFTOrder::IO {
+                    derange(x_s);
+                }
+                true
+            }
+            Err(error) => {
+                // Restore the input, as the values are unchanged on failure.
+                if ord == FFTOrder::OI {
+                    derange(x_s);
+                }
+                HAS_GPU_FAILED.store(true, Ordering::SeqCst);
+                tracing::warn!("The GPU backend failed ({error}), moving to the next FFT method");
+                false
+            }
+        }
+    }
+
+    #[cfg(not(feature = "gpu"))]
+    #[inline]
+    fn gpu_fft_in_place<T: DomainCoeff<F>>(_x_s: &mut [T], _ord: FFTOrder, _inverse: bool) -> bool {
+        false
+    }
+
     fn fft_helper_in_place_with_pc<T: DomainCoeff<F>>(
         &self,
         x_s: &mut [T],
@@ -42,6 +42,7 @@ pub trait DomainCoeff<F: FftField>:
     + core::ops::SubAssign
     + snarkvm_fields::Zero
     + core::ops::MulAssign<F>
+    + 'static
 {
 }
 
@@ -56,6 +57,7 @@ where
         + core::ops::SubAssign
         + core::ops::MulAssign<F>
         + core::ops::Add<Output = Self>
-        + core::ops::Sub<Output = Self>,
+        + core::ops::Sub<Output = Self>
+        + 'static,
 {
 }
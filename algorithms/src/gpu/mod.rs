@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The registration point for a GPU backend, which accelerates the BLS12-377 MSMs and (I)NTTs of the prover.
+//!
+//! The backends are not members of the workspace, as their device bindings are not published on crates.io;
+//! the ICICLE backend is in `algorithms/icicle`. A backend is registered once, at startup, and the MSMs and
+//! (I)FFTs run on the CPU if no backend is registered, or once the backend has failed.
+
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    traits::AffineCurve,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::BigInteger256;
+
+use anyhow::{anyhow, bail, Result};
+use once_cell::sync::OnceCell;
+
+use core::any::TypeId;
+
+/// The minimum number of bases for which the MSM is offloaded to the GPU,
+/// as the transfer cost dominates for smaller instances.
+pub const MIN_GPU_MSM_SIZE: usize = 1 << 10;
+
+/// The minimum domain size for which the (I)NTT is offloaded to the GPU,
+/// as the transfer cost dominates for smaller domains.
+pub const MIN_GPU_NTT_SIZE: usize = 1 << 12;
+
+/// The registered GPU backend.
+static BACKEND: OnceCell<Box<dyn GpuBackend>> = OnceCell::new();
+
+/// A GPU backend for the BLS12-377 MSMs and (I)NTTs of the prover.
+pub trait GpuBackend: Send + Sync + 'static {
+    /// Returns the variable base MSM of the given bases and scalars.
+    fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective>;
+
+    /// Computes the in-order (I)NTT of the given values in place. The INTT includes the division by the domain size.
+    /// On failure, the values must be left unchanged.
+    fn ntt(&self, values: &mut [Fr], inverse: bool) -> Result<()>;
+}
+
+/// Registers the given GPU backend. A backend can only be registered once.
+pub fn set_backend<B: GpuBackend>(backend: B) -> Result<()> {
+    BACKEND.set(Box::new(backend)).map_err(|_| anyhow!("A GPU backend is already registered"))
+}
+
+/// Returns the registered GPU backend, if one is registered.
+pub fn backend() -> Option<&'static dyn GpuBackend> {
+    BACKEND.get().map(|backend| &**backend)
+}
+
+/// Returns the variable base MSM of the given bases and scalars, computed by the registered GPU backend.
+#[allow(clippy::transmute_undefined_repr)]
+pub(crate) fn msm_gpu<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Result<G::Projective> {
+    let backend = match backend() {
+        Some(backend) => backend,
+        None => bail!("No GPU backend is registered"),
+    };
+    if TypeId::of::<G>() != TypeId::of::<G1Affine>() {
+        bail!("The GPU backend only supports BLS12-377 G1");
+    }
+    // Safety: `G` is `G1Affine`, and so the scalars are `BigInteger256`.
+    let bases: &[G1Affine] = unsafe { std::mem::transmute(bases) };
+    let scalars: &[BigInteger256] = unsafe { std::mem::transmute(scalars) };
+
+    let result = backend.msm(bases, scalars)?;
+    // Safety: `G::Projective` is `G1Projective`.
+    Ok(unsafe { std::mem::transmute_copy(&result) })
+}
+
+/// Computes the in-order (I)NTT of the given values in place, with the registered GPU backend.
+/// The INTT includes the division by the domain size.
+#[allow(clippy::transmute_undefined_repr)]
+pub(crate) fn ntt_gpu<T: 'static>(values: &mut [T], inverse: bool) -> Result<()> {
+    let backend = match backend() {
+        Some(backend) => backend,
+        None => bail!("No GPU backend is registered"),
+    };
+    if TypeId::of::<T>() != TypeId::of::<Fr>() {
+        bail!("The GPU backend only supports the BLS12-377 scalar field");
+    }
+    // Safety: `T` is `Fr`.
+    let values: &mut [Fr] = unsafe { std::mem::transmute(values) };
+    backend.ntt(values, inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msm::VariableBase;
+    use snarkvm_curves::traits::ProjectiveCurve;
+    use snarkvm_utilities::{
+        rand::{test_rng, Uniform},
+        BitIteratorBE,
+    };
+
+    /// A GPU backend which computes the MSM naively on the CPU, and does not support the (I)NTT.
+    struct NaiveBackend;
+
+    impl GpuBackend for NaiveBackend {
+        fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective> {
+            Ok(bases.iter().zip(scalars).map(|(base, scalar)| base.mul_bits(BitIteratorBE::new(*scalar))).sum())
+        }
+
+        fn ntt(&self, _values: &mut [Fr], _inverse: bool) -> Result<()> {
+            bail!("The naive backend does not support the (I)NTT")
+        }
+    }
+
+    #[test]
+    fn test_gpu_backend() {
+        let mut rng = test_rng();
+
+        // Ensure the backend can only be registered once.
+        set_backend(NaiveBackend).unwrap();
+        assert!(set_backend(NaiveBackend).is_err());
+
+        // Ensure the MSM is dispatched to the backend, and matches the CPU.
+        let bases = (0..MIN_GPU_MSM_SIZE).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+        let scalars = (0..MIN_GPU_MSM_SIZE).map(|_| Fr::rand(&mut rng).to_repr()).collect::<Vec<_>>();
+        let expected = msm_gpu(&bases, &scalars).unwrap();
+        assert_eq!(expected, VariableBase::msm(&bases, &scalars));
+        assert_eq!(expected.to_affine(), NaiveBackend.msm(&bases, &scalars).unwrap().to_affine());
+
+        // Ensure a failed (I)NTT leaves the values unchanged.
+        let values = (0..MIN_GPU_NTT_SIZE).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut candidate = values.clone();
+        assert!(ntt_gpu(&mut candidate, false).is_err());
+        assert_eq!(values, candidate);
+    }
+}
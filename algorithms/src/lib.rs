@@ -39,6 +39,9 @@ pub use errors::*;
 #[cfg(feature = "fft")]
 pub mod fft;
 
+#[cfg(feature = "gpu")]
+pub mod gpu;
+
 #[cfg(feature = "msm")]
 pub mod msm;
 
@@ -364,6 +364,15 @@ fn batched_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    msm_with_window(bases, scalars, super::window_size::<G>(scalars.len()))
+}
+
+/// Performs the MSM with the given bucket size `c`, or by double-and-add if there are few bases.
+pub(super) fn msm_with_window<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    c: usize,
+) -> G::Projective {
     if bases.len() < 15 {
         let num_bits = G::ScalarField::size_in_bits();
         let bigint_size = <G::ScalarField as PrimeField>::BigInteger::NUM_LIMBS * 64;
@@ -386,12 +395,6 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         debug_assert!(bits.iter_mut().all(|b| b.next().is_none()));
         sum
     } else {
-        // Determine the bucket size `c` (chosen empirically).
-        let c = match scalars.len() < 32 {
-            true => 1,
-            false => crate::msm::ln_without_floats(scalars.len()) + 2,
-        };
-
         let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
         // Each window is of size `c`.
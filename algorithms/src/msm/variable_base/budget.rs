@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::batched::BucketPosition;
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::PrimeField;
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The environment variable for the memory budget of an MSM, in megabytes.
+pub const MEMORY_BUDGET_VARIABLE: &str = "SNARKVM_MSM_MEMORY_BUDGET_MB";
+/// The minimum number of bases in a chunk, below which an MSM is not split further.
+pub const MIN_CHUNK_SIZE: usize = 1 << 10;
+
+/// The memory budget of an MSM in bytes, or `0` if it is not set.
+static MEMORY_BUDGET: AtomicUsize = AtomicUsize::new(0);
+
+lazy_static::lazy_static! {
+    /// The memory budget from the environment, in bytes, if it is set to a nonzero number of megabytes.
+    static ref MEMORY_BUDGET_OVERRIDE: Option<usize> = std::env::var(MEMORY_BUDGET_VARIABLE)
+        .ok()
+        .and_then(|budget| budget.trim().parse::<usize>().ok())
+        .filter(|budget| *budget > 0)
+        .map(|budget| budget.saturating_mul(1 << 20));
+}
+
+/// Sets the memory budget of an MSM in bytes, or removes the budget if `None`.
+///
+/// An MSM whose working memory would exceed the budget is performed in chunks that fit within it,
+/// at the cost of extra bucket reductions per chunk. The budget is best-effort: it bounds the
+/// buckets of each chunk, not the bases and scalars given by the caller.
+pub fn set_memory_budget(budget: Option<usize>) {
+    MEMORY_BUDGET.store(budget.unwrap_or(0), Ordering::SeqCst);
+}
+
+/// Returns the memory budget of an MSM in bytes, from `set_memory_budget` or else from the
+/// `SNARKVM_MSM_MEMORY_BUDGET_MB` environment variable, or `None` if there is no budget.
+pub fn memory_budget() -> Option<usize> {
+    match MEMORY_BUDGET.load(Ordering::SeqCst) {
+        0 => *MEMORY_BUDGET_OVERRIDE,
+        budget => Some(budget),
+    }
+}
+
+/// Returns the estimated working memory of an MSM of the given size, in bytes.
+///
+/// Each window concurrently holds a bucket position per base, the partial sums of the batched
+/// additions (up to 3/8 of the bases), and its buckets.
+pub fn estimate_memory<G: AffineCurve>(msm_size: usize) -> usize {
+    let window_size = super::window_size::<G>(msm_size);
+    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+    let num_windows = (num_bits + window_size - 1) / window_size;
+    let window_memory = msm_size * (core::mem::size_of::<BucketPosition>() + core::mem::size_of::<G>() * 3 / 8)
+        + (1 << window_size) * core::mem::size_of::<G>();
+    num_windows.min(super::window::num_threads()) * window_memory
+}
+
+/// Returns the chunk size of an MSM of the given size that fits within the memory budget,
+/// or `None` if there is no budget or the MSM already fits within it.
+pub fn chunk_size<G: AffineCurve>(msm_size: usize) -> Option<usize> {
+    let budget = memory_budget()?;
+    if msm_size <= MIN_CHUNK_SIZE || estimate_memory::<G>(msm_size) <= budget {
+        return None;
+    }
+    // Halve the chunk size until it fits within the budget.
+    let mut chunk_size = msm_size;
+    while chunk_size > MIN_CHUNK_SIZE && estimate_memory::<G>(chunk_size) > budget {
+        chunk_size /= 2;
+    }
+    Some(chunk_size.max(MIN_CHUNK_SIZE))
+}
@@ -17,6 +17,12 @@
 mod batched;
 mod standard;
 
+mod budget;
+pub use budget::*;
+
+mod window;
+pub use window::*;
+
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 mod cuda;
 
@@ -24,22 +30,45 @@ mod cuda;
 pub mod prefetch;
 
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
+
+use std::io::Read;
 
 use core::any::TypeId;
 
-#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+#[cfg(any(all(feature = "cuda", target_arch = "x86_64"), feature = "gpu"))]
 use core::sync::atomic::{AtomicBool, Ordering};
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 static HAS_CUDA_FAILED: AtomicBool = AtomicBool::new(false);
 
+#[cfg(feature = "gpu")]
+static HAS_GPU_FAILED: AtomicBool = AtomicBool::new(false);
+
 pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        // If the MSM exceeds the memory budget, perform it in chunks that fit within the budget.
+        if let Some(chunk_size) = budget::chunk_size::<G>(bases.len().min(scalars.len())) {
+            return bases.chunks(chunk_size).zip(scalars.chunks(chunk_size)).map(|(b, s)| Self::msm(b, s)).sum();
+        }
+
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            #[cfg(feature = "gpu")]
+            if bases.len() >= crate::gpu::MIN_GPU_MSM_SIZE
+                && crate::gpu::backend().is_some()
+                && !HAS_GPU_FAILED.load(Ordering::SeqCst)
+            {
+                match crate::gpu::msm_gpu(bases, scalars) {
+                    Ok(x) => return x,
+                    Err(error) => {
+                        HAS_GPU_FAILED.store(true, Ordering::SeqCst);
+                        tracing::warn!("The GPU backend failed ({error}), moving to the next MSM method");
+                    }
+                }
+            }
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             if !HAS_CUDA_FAILED.load(Ordering::SeqCst) {
                 match cuda::msm_cuda(bases, scalars) {
@@ -58,6 +87,32 @@ impl VariableBase {
         }
     }
 
+    /// Performs the MSM with bases read from the given reader, in chunks that fit within the memory budget,
+    /// so the bases are never held in memory at once. Reads one base for each scalar.
+    pub fn msm_from_reader<G: AffineCurve, R: Read>(
+        mut reader: R,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> std::io::Result<G::Projective> {
+        let chunk_size = budget::chunk_size::<G>(scalars.len()).unwrap_or(scalars.len()).max(1);
+
+        let mut result = G::Projective::zero();
+        for scalars in scalars.chunks(chunk_size) {
+            // Read the bases for this chunk.
+            let bases = (0..scalars.len()).map(|_| G::read_le(&mut reader)).collect::<std::io::Result<Vec<_>>>()?;
+            result += Self::msm(&bases, scalars);
+        }
+        Ok(result)
+    }
+
+    /// Calibrates the window sizes of the MSM on the given curve on this machine,
+    /// for each power of two up to `2^max_log_size`, and returns the `(log_size, window_size)` pairs.
+    pub fn calibrate<G: AffineCurve>(max_log_size: u32) -> Vec<(u32, usize)> {
+        match TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            true => window::calibrate::<G>(batched::msm_with_window, max_log_size),
+            false => window::calibrate::<G>(standard::msm_with_window, max_log_size),
+        }
+    }
+
     #[cfg(test)]
     fn msm_naive<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         use itertools::Itertools;
@@ -115,6 +170,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_with_window() {
+        use snarkvm_curves::ProjectiveCurve;
+        let mut rng = test_rng();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 100);
+        let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+
+        // Ensure every window size is correct, up to a modest number of buckets.
+        for c in 1..=16 {
+            let candidate = standard::msm_with_window(bases.as_slice(), scalars.as_slice(), c).to_affine();
+            assert_eq!(naive, candidate, "Window size: {c}");
+
+            let candidate = batched::msm_with_window(bases.as_slice(), scalars.as_slice(), c).to_affine();
+            assert_eq!(naive, candidate, "Window size: {c}");
+        }
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let window_sizes = VariableBase::calibrate::<G1Affine>(6);
+        assert_eq!(7, window_sizes.len());
+        for (log_size, window_size) in window_sizes {
+            assert!((1..=window::MAX_WINDOW_SIZE).contains(&window_size));
+            // Ensure the calibrated window size is used, unless it is overridden by the environment.
+            if std::env::var(window::WINDOW_SIZE_VARIABLE).is_err() {
+                assert_eq!(window_size, window::window_size::<G1Affine>(1 << log_size));
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_with_budget() {
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_utilities::ToBytes;
+
+        let mut rng = test_rng();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 5000);
+        let expected = VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+
+        // Ensure a tight budget splits the MSM into chunks, with the same result.
+        set_memory_budget(Some(1));
+        assert_eq!(Some(MIN_CHUNK_SIZE), chunk_size::<G1Affine>(bases.len()));
+        assert_eq!(expected, VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine());
+
+        // Ensure the MSM with bases streamed from a reader has the same result.
+        let bytes = bases.iter().map(|base| base.to_bytes_le().unwrap()).collect::<Vec<_>>().concat();
+        let candidate = VariableBase::msm_from_reader::<G1Affine, _>(&bytes[..], &scalars).unwrap();
+        assert_eq!(expected, candidate.to_affine());
+        set_memory_budget(None);
+
+        // Ensure a generous budget does not split the MSM.
+        set_memory_budget(Some(usize::MAX));
+        assert_eq!(None, chunk_size::<G1Affine>(bases.len()));
+        set_memory_budget(None);
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
@@ -78,12 +78,15 @@ fn standard_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-    // Determine the bucket size `c` (chosen empirically).
-    let c = match scalars.len() < 32 {
-        true => 1,
-        false => crate::msm::ln_without_floats(scalars.len()) + 2,
-    };
+    msm_with_window(bases, scalars, super::window_size::<G>(scalars.len()))
+}
 
+/// Performs the MSM with the given bucket size `c`.
+pub(super) fn msm_with_window<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    c: usize,
+) -> G::Projective {
     let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
     // Each window is of size `c`.
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::Uniform;
+
+use core::any::TypeId;
+use parking_lot::RwLock;
+use std::{collections::HashMap, time::Instant};
+
+/// The environment variable that overrides the window size of every variable base MSM.
+pub const WINDOW_SIZE_VARIABLE: &str = "SNARKVM_MSM_WINDOW_SIZE";
+/// The maximum window size, which bounds the number of buckets in each window.
+pub const MAX_WINDOW_SIZE: usize = 20;
+
+lazy_static::lazy_static! {
+    /// The window size from the environment, if it is set to a valid window size.
+    static ref WINDOW_SIZE_OVERRIDE: Option<usize> = std::env::var(WINDOW_SIZE_VARIABLE)
+        .ok()
+        .and_then(|window_size| window_size.trim().parse::<usize>().ok())
+        .filter(|window_size| (1..=MAX_WINDOW_SIZE).contains(window_size));
+    /// The calibrated window sizes, keyed by the curve and the base-2 logarithm of the MSM size.
+    static ref CALIBRATED_WINDOW_SIZES: RwLock<HashMap<(TypeId, u32), usize>> = Default::default();
+}
+
+/// Returns the window size for an MSM of the given size on the given curve.
+///
+/// The window size is, in order of precedence, the `SNARKVM_MSM_WINDOW_SIZE` environment variable,
+/// the calibrated window size for the nearest power of two, or the window size that minimizes
+/// the estimated cost of the MSM on the available threads.
+pub fn window_size<G: AffineCurve>(msm_size: usize) -> usize {
+    if let Some(window_size) = *WINDOW_SIZE_OVERRIDE {
+        return window_size;
+    }
+    let key = (TypeId::of::<G>(), crate::fft::domain::log2(msm_size));
+    if let Some(window_size) = CALIBRATED_WINDOW_SIZES.read().get(&key) {
+        return *window_size;
+    }
+    estimate_window_size(<G::ScalarField as PrimeField>::size_in_bits(), msm_size, num_threads())
+}
+
+/// Returns the window size that minimizes the estimated cost of an MSM
+/// with the given number of scalar bits and size, on the given number of threads.
+pub fn estimate_window_size(num_bits: usize, msm_size: usize, num_threads: usize) -> usize {
+    (1..=MAX_WINDOW_SIZE)
+        .min_by_key(|window_size| estimate_cost(num_bits, msm_size, num_threads.max(1), *window_size))
+        .unwrap_or(1)
+}
+
+/// Returns the estimated cost of an MSM with the given window size, in group operations.
+///
+/// Each window adds every base into one of `2^c` buckets, and then sums the buckets with two additions
+/// per bucket. The windows are processed concurrently, in rounds of `num_threads` windows,
+/// and the window sums are then combined with one doubling per bit. The total work is also charged,
+/// spread over the threads, as the threads share the memory bandwidth and may be running other work.
+fn estimate_cost(num_bits: usize, msm_size: usize, num_threads: usize, window_size: usize) -> usize {
+    let num_windows = (num_bits + window_size - 1) / window_size;
+    let num_rounds = (num_windows + num_threads - 1) / num_threads;
+    let window_cost = msm_size + (2 << window_size);
+    num_rounds * window_cost + num_windows * window_cost / num_threads + num_bits
+}
+
+/// Returns the number of threads available to an MSM.
+pub(super) fn num_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    return rayon::current_num_threads();
+    #[cfg(not(feature = "parallel"))]
+    return 1;
+}
+
+/// Calibrates the window sizes of the given MSM on this machine, for each power of two up to `2^max_log_size`.
+///
+/// For each size, the MSM is timed with the window sizes around the estimated window size,
+/// and the fastest window size is used for subsequent MSMs of that size on the curve.
+/// Returns the calibrated `(log_size, window_size)` pairs.
+pub fn calibrate<G: AffineCurve>(
+    msm: impl Fn(&[G], &[<G::ScalarField as PrimeField>::BigInteger], usize) -> G::Projective,
+    max_log_size: u32,
+) -> Vec<(u32, usize)> {
+    let rng = &mut rand::thread_rng();
+    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+
+    // Sample distinct bases, as consecutive multiples of the generator, and random scalars.
+    let max_size = 1usize << max_log_size;
+    let generator = G::prime_subgroup_generator().to_projective();
+    let bases = G::Projective::batch_normalization_into_affine(
+        core::iter::successors(Some(generator), |base| Some(*base + generator)).take(max_size).collect(),
+    );
+    let scalars = (0..max_size).map(|_| G::ScalarField::rand(rng).to_repr()).collect::<Vec<_>>();
+
+    let mut window_sizes = Vec::with_capacity(max_log_size as usize + 1);
+    for log_size in 0..=max_log_size {
+        let size = 1usize << log_size;
+        let (bases, scalars) = (&bases[..size], &scalars[..size]);
+
+        // Time the window sizes around the estimated window size.
+        let estimate = estimate_window_size(num_bits, size, num_threads());
+        let candidates = estimate.saturating_sub(2).max(1)..=(estimate + 2).min(MAX_WINDOW_SIZE);
+        let fastest = candidates
+            .min_by_key(|window_size| {
+                let timer = Instant::now();
+                msm(bases, scalars, *window_size);
+                timer.elapsed()
+            })
+            .unwrap_or(estimate);
+
+        CALIBRATED_WINDOW_SIZES.write().insert((TypeId::of::<G>(), log_size), fastest);
+        window_sizes.push((log_size, fastest));
+    }
+    window_sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_window_size() {
+        for num_threads in [1, 4, 16, 64] {
+            // Ensure the window size grows with the MSM size.
+            let mut previous = 1;
+            for log_size in 0..28 {
+                let window_size = estimate_window_size(253, 1 << log_size, num_threads);
+                assert!(window_size >= previous, "Window size decreased at 2^{log_size} on {num_threads} threads");
+                assert!((1..=MAX_WINDOW_SIZE).contains(&window_size));
+                previous = window_size;
+            }
+        }
+
+        // Ensure a single thread is close to the usual empirical choice for a large MSM.
+        assert!((14..=17).contains(&estimate_window_size(253, 1 << 20, 1)));
+        // Ensure many threads favor more, smaller windows.
+        assert!(estimate_window_size(253, 1 << 20, 64) < estimate_window_size(253, 1 << 20, 1));
+    }
+}
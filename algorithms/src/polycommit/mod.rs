@@ -45,5 +45,11 @@ pub use error::*;
 /// checker.
 pub mod optional_rng;
 
+/// An experimental vector commitment based on [\[KZG10\]][kzg], with constant-size multi-openings.
+///
+/// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+#[cfg(feature = "experimental")]
+pub mod vector_commitment;
+
 #[cfg(test)]
 pub mod test_templates;
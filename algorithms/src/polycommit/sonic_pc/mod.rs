@@ -408,6 +408,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        Ok(Self::batch_check_deferred(vk, commitments, query_set, values, proof, fs_rng)?.verify())
+    }
+
+    /// Performs `batch_check`, except for the final pairing check, which is returned instead,
+    /// so that it can be merged with the pairing checks of other proofs.
+    pub fn batch_check_deferred<'a>(
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -463,7 +479,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk);
+        let result = Self::pairing_check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk);
         end_timer!(batch_check_time);
         result
     }
@@ -557,6 +573,32 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::check_combinations_deferred(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Ok(check.verify())
+    }
+
+    /// Performs `check_combinations`, except for the final pairing check, which is returned instead,
+    /// so that it can be merged with the pairing checks of other proofs.
+    pub fn check_combinations_deferred<'a>(
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -612,7 +654,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_check_deferred(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -703,43 +745,88 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         end_timer!(acc_time);
     }
 
-    #[allow(clippy::type_complexity)]
-    fn check_elems(
+    fn pairing_check_elems(
         combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
         combined_witness: E::G1Projective,
         combined_adjusted_witness: E::G1Projective,
         vk: &VerifierKey<E>,
-    ) -> Result<bool, PCError> {
-        let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+    ) -> Result<PairingCheck<E>, PCError> {
+        let mut check = PairingCheck::new();
 
         for (degree_bound, comm) in combined_comms.into_iter() {
-            let shift_power = if let Some(degree_bound) = degree_bound {
-                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
+            let (shift_power, prepared_shift_power) = if let Some(degree_bound) = degree_bound {
+                let shift_power =
+                    vk.get_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+                let prepared_shift_power =
+                    vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+                (shift_power, prepared_shift_power)
             } else {
-                vk.vk.prepared_h.clone()
+                (vk.vk.h, vk.vk.prepared_h.clone())
             };
-
-            g1_projective_elems.push(comm);
-            g2_prepared_elems.push(shift_power);
+            check.add(comm, shift_power, &prepared_shift_power);
         }
+        check.add(-combined_adjusted_witness, vk.vk.h, &vk.vk.prepared_h);
+        check.add(-combined_witness, vk.vk.beta_h, &vk.vk.prepared_beta_h);
+        Ok(check)
+    }
+}
+
+/// A deferred pairing product check `prod_i e(g1_i, g2_i) == 1`, where the G1 elements with the same G2 element
+/// are summed. The checks of many proofs are merged under random coefficients, so proofs over the same SRS
+/// are verified with a single pairing product of a few terms, instead of one pairing product per proof.
+#[derive(Clone)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The G1 elements, with their G2 element and its prepared form.
+    terms: Vec<(E::G1Projective, E::G2Affine, <E::G2Affine as PairingCurve>::Prepared)>,
+}
+
+impl<E: PairingEngine> Default for PairingCheck<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Initializes an empty pairing check, which holds trivially.
+    pub fn new() -> Self {
+        Self { terms: Vec::new() }
+    }
 
-        g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+    /// Adds the term `e(g1, g2)` to the pairing product.
+    fn add(&mut self, g1: E::G1Projective, g2: E::G2Affine, prepared_g2: &<E::G2Affine as PairingCurve>::Prepared) {
+        match self.terms.iter_mut().find(|(_, candidate, _)| *candidate == g2) {
+            Some((sum, _, _)) => *sum += g1,
+            None => self.terms.push((g1, g2, prepared_g2.clone())),
+        }
+    }
 
-        let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
-            .into_iter()
-            .map(|a| a.prepare())
-            .collect::<Vec<_>>();
+    /// Merges the given pairing check into this one, scaled by the given coefficient.
+    /// For soundness, the coefficient must be sampled after the merged checks are fixed.
+    pub fn merge(&mut self, other: &Self, coefficient: E::Fr) {
+        for (g1, g2, prepared_g2) in &other.terms {
+            self.add(*g1 * coefficient, *g2, prepared_g2);
+        }
+    }
+
+    /// Returns the number of pairings in the pairing product.
+    pub fn num_pairings(&self) -> usize {
+        self.terms.len()
+    }
 
-        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
+    /// Returns `true` if the pairing product is the identity.
+    pub fn verify(&self) -> bool {
+        let check_time = start_timer!(|| format!("Checking a pairing product of {} pairings", self.terms.len()));
+        let g1_prepared_elems = E::G1Projective::batch_normalization_into_affine(
+            self.terms.iter().map(|(g1, _, _)| *g1).collect::<Vec<_>>(),
+        )
+        .into_iter()
+        .map(|a| a.prepare())
+        .collect::<Vec<_>>();
+
+        let g1_g2_prepared = g1_prepared_elems.iter().zip_eq(self.terms.iter().map(|(_, _, prepared_g2)| prepared_g2));
         let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
         end_timer!(check_time);
-        Ok(is_one)
+        is_one
     }
 }
 
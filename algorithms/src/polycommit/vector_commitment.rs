@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::sha256::sha256,
+    fft::{DensePolynomial, EvaluationDomain, Polynomial},
+    polycommit::{
+        kzg10::{Commitment, Powers, Proof, Randomness, VerifierKey, KZG10},
+        PCError,
+    },
+};
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{batch_inversion, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use anyhow::anyhow;
+use core::{marker::PhantomData, ops::Mul, sync::atomic::AtomicBool};
+
+/// A multi-opening of a vector commitment, which is two group elements, regardless of the number of positions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VectorProof<E: PairingEngine> {
+    /// The commitment to the combined quotient polynomial `g`.
+    pub d: E::G1Affine,
+    /// The KZG proof that `h - g` evaluates to the combined value at the challenge point.
+    pub w: E::G1Affine,
+}
+
+/// An experimental KZG vector commitment, for research on vector-commitment (Verkle) state trees.
+///
+/// A vector of `size` field elements is committed to as the evaluations of a polynomial over the
+/// multiplicative subgroup of order `size.next_power_of_two()`, so the commitment is a single KZG commitment.
+/// Any subset of positions is opened with a `VectorProof` of two group elements, following the
+/// multiproof of [Feist and Khovratovich](https://dankradfeist.de/ethereum/2021/06/18/pcs-multiproofs.html):
+/// the verifier work is linear in the number of positions, plus one pairing check.
+///
+/// The commitments are not hiding. A Verkle tree nests these commitments, by storing the hash of each
+/// child commitment in its parent vector; the nesting is left to the caller while the design is in flux.
+#[derive(Clone, Debug)]
+pub struct KZGVectorCommitment<E: PairingEngine>(PhantomData<E>);
+
+impl<E: PairingEngine> KZGVectorCommitment<E> {
+    /// Commits to the given vector, returning the commitment and the polynomial that interpolates the vector.
+    pub fn commit(powers: &Powers<E>, values: &[E::Fr]) -> Result<(Commitment<E>, DensePolynomial<E::Fr>), PCError> {
+        let domain = Self::domain(values.len())?;
+        // Interpolate the vector over the domain, padding it with zeros.
+        let polynomial = DensePolynomial::from_coefficients_vec(domain.ifft(values));
+        let (commitment, _) =
+            KZG10::commit(powers, &Polynomial::from(&polynomial), None, &AtomicBool::new(false), None)?;
+        Ok((commitment, polynomial))
+    }
+
+    /// Opens the committed vector of the given size at the given positions,
+    /// returning the values at the positions and their proof.
+    pub fn open(
+        powers: &Powers<E>,
+        commitment: &Commitment<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        size: usize,
+        indices: &[usize],
+    ) -> Result<(Vec<E::Fr>, VectorProof<E>), PCError> {
+        let domain = Self::domain(size)?;
+        let points = Self::points(&domain, size, indices)?;
+        let values = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+
+        // Compute the combined quotient `g(X) = sum_i r^i (f(X) - y_i) / (X - z_i)`.
+        let r = Self::first_challenge(commitment, indices, &values)?;
+        let mut g = DensePolynomial::zero();
+        let mut r_i = E::Fr::one();
+        for point in &points {
+            // Note: The division discards the remainder, which is `y_i`.
+            let divisor = DensePolynomial::from_coefficients_vec(vec![-*point, E::Fr::one()]);
+            g += &(&(polynomial / &divisor) * r_i);
+            r_i *= r;
+        }
+        let (d, _) = KZG10::commit(powers, &Polynomial::from(&g), None, &AtomicBool::new(false), None)?;
+
+        // Compute `h(X) = sum_i r^i f(X) / (t - z_i)`, and prove the evaluation of `h - g` at `t`.
+        let t = Self::challenge(to_bytes_le![r, d.0])?;
+        let coefficient = Self::coefficient(r, t, &points)?;
+        let quotient = &(polynomial * coefficient) - &g;
+        let proof = KZG10::open(powers, &quotient, t, &Randomness::empty())?;
+
+        Ok((values, VectorProof { d: d.0, w: proof.w }))
+    }
+
+    /// Returns `true` if the given values are at the given positions of the committed vector of the given size.
+    pub fn verify(
+        vk: &VerifierKey<E>,
+        commitment: &Commitment<E>,
+        size: usize,
+        indices: &[usize],
+        values: &[E::Fr],
+        proof: &VectorProof<E>,
+    ) -> Result<bool, PCError> {
+        if indices.len() != values.len() {
+            return Err(anyhow!("Found {} indices and {} values", indices.len(), values.len()).into());
+        }
+        let domain = Self::domain(size)?;
+        let points = Self::points(&domain, size, indices)?;
+
+        // Recompute the challenges.
+        let r = Self::first_challenge(commitment, indices, values)?;
+        let t = Self::challenge(to_bytes_le![r, proof.d])?;
+
+        // Compute the commitment to `h - g`, and the evaluation `sum_i r^i y_i / (t - z_i)`.
+        let mut denominators = points.iter().map(|point| t - point).collect::<Vec<_>>();
+        batch_inversion(&mut denominators);
+        let mut value = E::Fr::zero();
+        let mut r_i = E::Fr::one();
+        for (y_i, denominator) in values.iter().zip(&denominators) {
+            value += r_i * y_i * denominator;
+            r_i *= r;
+        }
+        let coefficient = Self::coefficient(r, t, &points)?;
+        let combined = commitment.0.mul(coefficient) - proof.d.to_projective();
+
+        KZG10::check(vk, &Commitment(combined.to_affine()), t, value, &Proof { w: proof.w, random_v: None })
+    }
+
+    /// Returns the evaluation domain for a vector of the given size.
+    fn domain(size: usize) -> Result<EvaluationDomain<E::Fr>, PCError> {
+        EvaluationDomain::new(size).ok_or_else(|| anyhow!("Unsupported vector size {size}").into())
+    }
+
+    /// Returns the domain elements for the given positions, ensuring the positions are distinct and in bounds.
+    fn points(domain: &EvaluationDomain<E::Fr>, size: usize, indices: &[usize]) -> Result<Vec<E::Fr>, PCError> {
+        if indices.is_empty() {
+            return Err(anyhow!("Expected at least one position to open").into());
+        }
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        if sorted.len() != indices.len() {
+            return Err(anyhow!("Found a duplicate position in the vector opening").into());
+        }
+        match sorted.last() {
+            Some(last) if *last < size => Ok(indices.iter().map(|index| domain.element(*index)).collect()),
+            _ => Err(anyhow!("Found an out of bounds position in the vector opening").into()),
+        }
+    }
+
+    /// Returns `sum_i r^i / (t - z_i)`, which scales the commitment to `f` into the commitment to `h`.
+    fn coefficient(r: E::Fr, t: E::Fr, points: &[E::Fr]) -> Result<E::Fr, PCError> {
+        let mut coefficient = E::Fr::zero();
+        let mut r_i = E::Fr::one();
+        for point in points {
+            let inverse = (t - point).inverse().ok_or_else(|| anyhow!("The challenge is a vector position"))?;
+            coefficient += r_i * inverse;
+            r_i *= r;
+        }
+        Ok(coefficient)
+    }
+
+    /// Returns the challenge `r`, from the commitment and the opened positions and values.
+    fn first_challenge(commitment: &Commitment<E>, indices: &[usize], values: &[E::Fr]) -> Result<E::Fr, PCError> {
+        let indices = indices.iter().map(|index| *index as u64).collect::<Vec<_>>();
+        Self::challenge(to_bytes_le![commitment, indices, values.to_vec()])
+    }
+
+    /// Returns a challenge, from the SHA-256 hash of the given transcript.
+    fn challenge(transcript: std::io::Result<Vec<u8>>) -> Result<E::Fr, PCError> {
+        let transcript = transcript.map_err(anyhow::Error::from)?;
+        Ok(E::Fr::from_bytes_le_mod_order(&sha256(&transcript)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polycommit::kzg10::KZG10DegreeBoundsConfig;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::rand::{test_rng, Uniform};
+
+    type VC = KZGVectorCommitment<Bls12_377>;
+
+    #[test]
+    fn test_vector_commitment() {
+        let rng = &mut test_rng();
+        let size = 60;
+
+        let pp = KZG10::<Bls12_377>::setup(64, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        let (powers, vk) = KZG10::trim(&pp, 64);
+
+        let vector = (0..size).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let (commitment, polynomial) = VC::commit(&powers, &vector).unwrap();
+
+        for indices in [vec![0], vec![7, 3], vec![59, 1, 30, 2, 45], (0..size).collect()] {
+            // Ensure the opening returns the vector values, and verifies.
+            let (values, proof) = VC::open(&powers, &commitment, &polynomial, size, &indices).unwrap();
+            assert_eq!(values, indices.iter().map(|i| vector[*i]).collect::<Vec<_>>());
+            assert!(VC::verify(&vk, &commitment, size, &indices, &values, &proof).unwrap());
+
+            // Ensure the opening fails to verify for a modified value.
+            let mut candidate = values.clone();
+            candidate[0] += Fr::one();
+            assert!(!VC::verify(&vk, &commitment, size, &indices, &candidate, &proof).unwrap());
+        }
+
+        // Ensure malformed openings are rejected.
+        assert!(VC::open(&powers, &commitment, &polynomial, size, &[]).is_err());
+        assert!(VC::open(&powers, &commitment, &polynomial, size, &[1, 1]).is_err());
+        assert!(VC::open(&powers, &commitment, &polynomial, size, &[size]).is_err());
+    }
+}
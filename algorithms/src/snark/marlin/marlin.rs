@@ -16,7 +16,15 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{
+        Commitment,
+        Evaluations,
+        LabeledCommitment,
+        PairingCheck,
+        QuerySet,
+        Randomness,
+        SonicKZG10,
+    },
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
         proof,
@@ -26,7 +34,10 @@ use crate::{
         CircuitVerifyingKey,
         MarlinError,
         MarlinMode,
+        ObservedPhase,
+        PreparedCircuitVerifyingKey,
         Proof,
+        ProverPhase,
         UniversalSRS,
     },
     AlgebraicSponge,
@@ -351,7 +362,12 @@ where
 
         Self::terminate(terminator)?;
 
+        // Note: The polynomial phases are observed with the number of constraints across the batch.
+        let num_constraints = batch_size * circuit_proving_key.circuit.index_info.num_constraints;
+
+        let phase = ObservedPhase::start(ProverPhase::WitnessSynthesis);
         let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
+        phase.end(num_constraints);
         let public_input = prover_state.public_inputs();
         let padded_public_input = prover_state.padded_public_inputs();
         assert_eq!(prover_state.batch_size, batch_size);
@@ -367,10 +383,13 @@ where
         // First round
 
         Self::terminate(terminator)?;
+        let phase = ObservedPhase::start(ProverPhase::RoundPolynomials(1));
         let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+        phase.end(num_constraints);
         Self::terminate(terminator)?;
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
+        let phase = ObservedPhase::start(ProverPhase::RoundCommitments(1));
         let (first_commitments, first_commitment_randomnesses) = {
             let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
             SonicKZG10::<E, FS>::commit(
@@ -379,6 +398,7 @@ where
                 Some(zk_rng),
             )?
         };
+        phase.end(first_commitments.len());
         end_timer!(first_round_comm_time);
 
         Self::absorb_labeled(&first_commitments, &mut sponge);
@@ -395,17 +415,21 @@ where
         // Second round
 
         Self::terminate(terminator)?;
+        let phase = ObservedPhase::start(ProverPhase::RoundPolynomials(2));
         let (second_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
+        phase.end(num_constraints);
         Self::terminate(terminator)?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
+        let phase = ObservedPhase::start(ProverPhase::RoundCommitments(2));
         let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
             &circuit_proving_key.committer_key,
             second_oracles.iter().map(Into::into),
             terminator,
             Some(zk_rng),
         )?;
+        phase.end(second_commitments.len());
         end_timer!(second_round_comm_time);
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
@@ -420,17 +444,21 @@ where
 
         Self::terminate(terminator)?;
 
+        let phase = ObservedPhase::start(ProverPhase::RoundPolynomials(3));
         let (prover_third_message, third_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+        phase.end(num_constraints);
         Self::terminate(terminator)?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
+        let phase = ObservedPhase::start(ProverPhase::RoundCommitments(3));
         let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
             &circuit_proving_key.committer_key,
             third_oracles.iter().map(Into::into),
             terminator,
             Some(zk_rng),
         )?;
+        phase.end(third_commitments.len());
         end_timer!(third_round_comm_time);
 
         Self::absorb_labeled_with_msg(&third_commitments, &prover_third_message, &mut sponge);
@@ -445,16 +473,20 @@ where
         Self::terminate(terminator)?;
 
         let first_round_oracles = Arc::clone(prover_state.first_round_oracles.as_ref().unwrap());
+        let phase = ObservedPhase::start(ProverPhase::RoundPolynomials(4));
         let fourth_oracles = AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)?;
+        phase.end(num_constraints);
         Self::terminate(terminator)?;
 
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
+        let phase = ObservedPhase::start(ProverPhase::RoundCommitments(4));
         let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
             &circuit_proving_key.committer_key,
             fourth_oracles.iter().map(Into::into),
             terminator,
             Some(zk_rng),
         )?;
+        phase.end(fourth_commitments.len());
         end_timer!(fourth_round_comm_time);
 
         Self::absorb_labeled(&fourth_commitments, &mut sponge);
@@ -541,6 +573,7 @@ where
 
         Self::terminate(terminator)?;
 
+        let phase = ObservedPhase::start(ProverPhase::Opening);
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
         let mut evaluations = std::collections::BTreeMap::new();
         for (label, (_, point)) in query_set.to_set() {
@@ -567,6 +600,7 @@ where
             &commitment_randomnesses,
             &mut sponge,
         )?;
+        phase.end(lc_s.len());
 
         Self::terminate(terminator)?;
 
@@ -583,6 +617,60 @@ where
         public_inputs: &[B],
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError> {
+        match Self::verify_batch_prepared_deferred(fs_parameters, prepared_verifying_key, public_inputs, proof)? {
+            Some((check, _)) => Ok(check.verify()),
+            None => Ok(false),
+        }
+    }
+
+    fn batch_verify<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: &Self::FSParameters,
+        instances: &[(&Self::VerifyingKey, &[B], &Self::Proof)],
+    ) -> Result<bool, SNARKError> {
+        let batch_time = start_timer!(|| format!("Marlin::BatchVerify with {} proofs", instances.len()));
+
+        // Verify each proof, except for its pairing check.
+        let mut checks = Vec::with_capacity(instances.len());
+        for (verifying_key, public_inputs, proof) in instances {
+            match Self::verify_batch_prepared_deferred(fs_parameters, &verifying_key.prepare(), public_inputs, proof)? {
+                Some(check) => checks.push(check),
+                None => return Ok(false),
+            }
+        }
+
+        // Sample the coefficients of the pairing checks, from the transcripts of every proof.
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_native_field_elements(&checks.iter().map(|(_, transcript)| *transcript).collect::<Vec<_>>());
+        let coefficients = sponge.squeeze_short_nonnative_field_elements::<E::Fr>(checks.len());
+
+        // Merge the pairing checks, and verify them with a single pairing product.
+        let mut combined_check = PairingCheck::new();
+        for ((check, _), coefficient) in checks.iter().zip_eq(coefficients) {
+            combined_check.merge(check, coefficient);
+        }
+        let is_valid = combined_check.verify();
+        end_timer!(batch_time);
+        Ok(is_valid)
+    }
+}
+
+impl<E: PairingEngine, FS, MM, Input> MarlinSNARK<E, FS, MM, Input>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: MarlinMode,
+    Input: ToConstraintField<E::Fr> + ?Sized,
+{
+    /// Verifies the proof, except for the final pairing check, which is returned with a digest of the transcript,
+    /// so that it can be merged with the pairing checks of other proofs.
+    /// Returns `None` if the proof is rejected before the pairing check.
+    pub fn verify_batch_prepared_deferred<B: Borrow<Input>>(
+        fs_parameters: &FS::Parameters,
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+        proof: &Proof<E>,
+    ) -> Result<Option<(PairingCheck<E>, E::Fq)>, SNARKError> {
         let circuit_verifying_key = &prepared_verifying_key.orig_vk;
         if public_inputs.is_empty() {
             return Err(SNARKError::EmptyBatch);
@@ -599,7 +687,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let batch_size = public_inputs.len();
@@ -748,7 +836,7 @@ where
         end_timer!(lc_time);
 
         let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let check = SonicKZG10::<E, FS>::check_combinations_deferred(
             &circuit_verifying_key.verifier_key,
             lc_s.values(),
             &commitments,
@@ -759,15 +847,12 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed");
-        }
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        // Compute a digest of the transcript, which binds the verifying key, public inputs, and proof.
+        // Note: The opening proof is absorbed here, as the coefficients of a batched pairing check must depend on it.
+        sponge.absorb_bytes(&to_bytes_le![&proof.pc_proof].map_err(|e| SNARKError::Crate("marlin", e.to_string()))?);
+        let transcript = sponge.squeeze_native_field_elements(1)[0];
+        end_timer!(verifier_time);
+        Ok(Some((check, transcript)))
     }
 }
 
@@ -856,4 +941,36 @@ pub mod test {
             );
         }
     }
+
+    #[test]
+    fn marlin_batch_verify_test() {
+        let mut rng = test_crypto_rng();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove two circuits of different sizes, so the proofs have different verifying keys.
+        let mut instances = vec![];
+        for (num_constraints, num_variables) in [(100, 25), (150, 40), (100, 25)] {
+            let a = Fr::rand(&mut rng);
+            let b = Fr::rand(&mut rng);
+            let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+
+            let (pk, vk) = TestSNARK::setup(&circ, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+            let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+            instances.push((vk, vec![vec![a * b]], proof));
+        }
+        let batch = instances.iter().map(|(vk, inputs, proof)| (vk, inputs.as_slice(), proof)).collect::<Vec<_>>();
+        assert!(TestSNARK::batch_verify(&fs_parameters, &batch).unwrap());
+        assert!(TestSNARK::batch_verify::<Vec<Fr>>(&fs_parameters, &[]).unwrap());
+
+        // Ensure the batch is rejected if any proof is invalid.
+        let invalid_inputs = vec![vec![Fr::rand(&mut rng)]];
+        for i in 0..batch.len() {
+            let mut candidate = batch.clone();
+            candidate[i].1 = invalid_inputs.as_slice();
+            assert!(!TestSNARK::batch_verify(&fs_parameters, &candidate).unwrap());
+        }
+        let mut candidate = batch.clone();
+        candidate[0].2 = &instances[1].2;
+        assert!(!TestSNARK::batch_verify(&fs_parameters, &candidate).unwrap_or(false));
+    }
 }
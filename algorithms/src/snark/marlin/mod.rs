@@ -45,5 +45,9 @@ pub use marlin::*;
 mod mode;
 pub use mode::*;
 
+/// Observes the phases of the Marlin prover.
+mod observer;
+pub use observer::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use parking_lot::RwLock;
+use std::{sync::Arc, time::Duration};
+
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+
+/// The observer of the prover, if one is set.
+static PROVER_OBSERVER: RwLock<Option<Arc<dyn ProverObserver>>> = parking_lot::const_rwlock(None);
+
+/// A phase of the Marlin prover.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProverPhase {
+    /// Synthesizes the witnesses of the circuits.
+    WitnessSynthesis,
+    /// Computes the polynomials of the given prover round, which is dominated by FFTs.
+    RoundPolynomials(u8),
+    /// Commits to the polynomials of the given prover round, which is dominated by MSMs.
+    RoundCommitments(u8),
+    /// Evaluates the linear combinations over the query set, and computes the opening proof.
+    Opening,
+}
+
+/// An observer of the Marlin prover, which is notified at the boundaries of each prover phase.
+///
+/// The observer is invoked on the proving thread, and should return quickly.
+pub trait ProverObserver: Send + Sync {
+    /// Called when the given phase starts.
+    fn on_phase_start(&self, _phase: ProverPhase) {}
+
+    /// Called when the given phase ends, with its elapsed time and size.
+    ///
+    /// The size is the number of constraints for the witness synthesis and the round polynomials,
+    /// the number of commitments for the round commitments, and the number of linear combinations
+    /// for the opening. If the prover fails or is terminated, the phase in progress does not end.
+    fn on_phase_end(&self, phase: ProverPhase, elapsed: Duration, size: usize);
+}
+
+/// Sets the observer of the prover, or removes it if `None` is given.
+///
+/// On `wasm`, the observer is never invoked, as the system clock is unavailable.
+pub fn set_prover_observer(observer: Option<Arc<dyn ProverObserver>>) {
+    *PROVER_OBSERVER.write() = observer;
+}
+
+/// A prover phase in progress, which notifies the observer when it starts and ends.
+pub(crate) struct ObservedPhase {
+    #[cfg(not(target_family = "wasm"))]
+    inner: Option<(ProverPhase, Arc<dyn ProverObserver>, Instant)>,
+}
+
+impl ObservedPhase {
+    /// Starts the given phase, notifying the observer, if one is set.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn start(phase: ProverPhase) -> Self {
+        let observer = PROVER_OBSERVER.read().clone();
+        let inner = observer.map(|observer| {
+            observer.on_phase_start(phase);
+            (phase, observer, Instant::now())
+        });
+        Self { inner }
+    }
+
+    /// Starts the given phase, which is not observed on `wasm`.
+    #[cfg(target_family = "wasm")]
+    pub(crate) fn start(_phase: ProverPhase) -> Self {
+        Self {}
+    }
+
+    /// Ends the phase with the given size, notifying the observer, if one is set.
+    pub(crate) fn end(self, _size: usize) {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some((phase, observer, start)) = self.inner {
+            observer.on_phase_end(phase, start.elapsed(), _size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        snark::marlin::{tests::Circuit, AHPForR1CS, MarlinHidingMode, MarlinSNARK},
+        AlgebraicSponge,
+        SNARK,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::rand::{test_crypto_rng, Uniform};
+
+    use parking_lot::Mutex;
+
+    type FS = PoseidonSponge<Fq, 2, 1>;
+    type MarlinInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, [Fr]>;
+
+    /// An observer that records the phases that end.
+    #[derive(Default)]
+    struct RecordingObserver {
+        phases: Mutex<Vec<(ProverPhase, usize)>>,
+    }
+
+    impl ProverObserver for RecordingObserver {
+        fn on_phase_end(&self, phase: ProverPhase, _elapsed: Duration, size: usize) {
+            self.phases.lock().push((phase, size));
+        }
+    }
+
+    #[test]
+    fn test_prover_observer() {
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 50, num_variables: 25 };
+        let (proving_key, _) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Prove with the observer set.
+        let observer = Arc::new(RecordingObserver::default());
+        set_prover_observer(Some(observer.clone()));
+        MarlinInst::prove(&fs_parameters, &proving_key, &circuit, rng).unwrap();
+        set_prover_observer(None);
+
+        // Ensure every phase is observed, with a nonzero size.
+        // Note: Proofs in concurrent tests may also be observed, so the phases are checked for inclusion.
+        let phases = observer.phases.lock();
+        let mut expected = vec![ProverPhase::WitnessSynthesis, ProverPhase::Opening];
+        for round in 1..=4 {
+            expected.extend([ProverPhase::RoundPolynomials(round), ProverPhase::RoundCommitments(round)]);
+        }
+        for phase in expected {
+            assert!(phases.iter().any(|(candidate, size)| *candidate == phase && *size > 0), "Missing {phase:?}");
+        }
+    }
+}
@@ -164,4 +164,18 @@ pub trait SNARK {
     ) -> Result<bool, SNARKError> {
         Self::verify_batch(fs_parameters, verifying_key, &[input], proof)
     }
+
+    /// Returns `true` if every proof is valid for its verifying key and public inputs.
+    /// Implementations may amortize the verification across the proofs, such as by batching their pairing checks.
+    fn batch_verify<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: &Self::FSParameters,
+        instances: &[(&Self::VerifyingKey, &[B], &Self::Proof)],
+    ) -> Result<bool, SNARKError> {
+        for (verifying_key, input, proof) in instances {
+            if !Self::verify_batch(fs_parameters, verifying_key, input, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::{add, constant_bits_le, rotr, xor3, Word};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean};
+
+use core::marker::PhantomData;
+
+/// The number of rounds in the BLAKE2s compression function.
+const NUM_ROUNDS: usize = 10;
+/// The number of bits in a word.
+const WORD_SIZE: usize = 32;
+/// The number of bytes in a block.
+const BLOCK_SIZE_IN_BYTES: usize = 64;
+
+/// The BLAKE2s initialization vector, which is the SHA-256 initial hash value.
+const INITIALIZATION_VECTOR: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The BLAKE2s parameter block word, for a 32-byte digest without a key, a fanout of 1, and a depth of 1.
+const PARAMETER_BLOCK: u32 = 0x01010020;
+
+/// The message word permutation of each round.
+const SIGMA: [[usize; 16]; NUM_ROUNDS] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// BLAKE2s-256, as specified in RFC 7693, without a key.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `U8::to_bits_le` of each byte.
+pub struct Blake2s<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Blake2s<E> {
+    /// Returns the 256-bit BLAKE2s digest of the given input, which must be a whole number of bytes.
+    pub fn hash(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is a whole number of bytes.
+        if input.len() % 8 != 0 {
+            E::halt(format!("BLAKE2s expects a whole number of bytes, found {} bits", input.len()))
+        }
+
+        // Initialize the hash value, with the parameter block.
+        let mut hash = INITIALIZATION_VECTOR.iter().map(|word| constant_bits_le(*word as u64, WORD_SIZE)).collect_vec();
+        hash[0] = constant_bits_le((INITIALIZATION_VECTOR[0] ^ PARAMETER_BLOCK) as u64, WORD_SIZE);
+
+        // Compress each block, where the final block is padded with zeros, and an empty input is a single block.
+        // Note: The counter and the final block flag depend only on the message length, so they are constant.
+        let num_bytes = input.len() / 8;
+        let num_blocks = core::cmp::max(1, (num_bytes + BLOCK_SIZE_IN_BYTES - 1) / BLOCK_SIZE_IN_BYTES);
+        for i in 0..num_blocks {
+            let is_final = i == num_blocks - 1;
+            let start = i * BLOCK_SIZE_IN_BYTES * 8;
+            let end = core::cmp::min(input.len(), start + BLOCK_SIZE_IN_BYTES * 8);
+            let mut block = input[start..end].to_vec();
+            block.resize(BLOCK_SIZE_IN_BYTES * 8, Boolean::constant(false));
+
+            let counter = match is_final {
+                true => num_bytes as u64,
+                false => ((i + 1) * BLOCK_SIZE_IN_BYTES) as u64,
+            };
+            hash = Self::compress(&hash, &block, counter, is_final);
+        }
+
+        // Note: BLAKE2s words are little-endian, so the digest bits are the hash bits in order.
+        hash.into_iter().flatten().collect()
+    }
+
+    /// Returns the hash value after compressing the given 512-bit block.
+    fn compress(hash: &[Word<E>], block: &[Boolean<E>], counter: u64, is_final: bool) -> Vec<Word<E>> {
+        // Note: BLAKE2s words are little-endian, so the message words are the block bits in order.
+        let message = block.chunks(WORD_SIZE).map(|word| word.to_vec()).collect_vec();
+
+        // Initialize the working vector with the hash value, the IV, the counter, and the final block flag.
+        let mut constants = INITIALIZATION_VECTOR;
+        constants[4] ^= counter as u32;
+        constants[5] ^= (counter >> 32) as u32;
+        if is_final {
+            constants[6] = !constants[6];
+        }
+        let mut v = hash.to_vec();
+        v.extend(constants.iter().map(|word| constant_bits_le(*word as u64, WORD_SIZE)));
+
+        for sigma in SIGMA {
+            // Mix the columns.
+            Self::mix(&mut v, [0, 4, 8, 12], &message[sigma[0]], &message[sigma[1]]);
+            Self::mix(&mut v, [1, 5, 9, 13], &message[sigma[2]], &message[sigma[3]]);
+            Self::mix(&mut v, [2, 6, 10, 14], &message[sigma[4]], &message[sigma[5]]);
+            Self::mix(&mut v, [3, 7, 11, 15], &message[sigma[6]], &message[sigma[7]]);
+            // Mix the diagonals.
+            Self::mix(&mut v, [0, 5, 10, 15], &message[sigma[8]], &message[sigma[9]]);
+            Self::mix(&mut v, [1, 6, 11, 12], &message[sigma[10]], &message[sigma[11]]);
+            Self::mix(&mut v, [2, 7, 8, 13], &message[sigma[12]], &message[sigma[13]]);
+            Self::mix(&mut v, [3, 4, 9, 14], &message[sigma[14]], &message[sigma[15]]);
+        }
+
+        (0..8).map(|i| xor3(&hash[i], &v[i], &v[i + 8])).collect()
+    }
+
+    /// Applies the mixing function `G` to the words at the given indices of the working vector.
+    fn mix(v: &mut [Word<E>], [a, b, c, d]: [usize; 4], x: &Word<E>, y: &Word<E>) {
+        v[a] = add(&[&v[a], &v[b], x]);
+        v[d] = rotr(&xor(&v[d], &v[a]), 16);
+        v[c] = add(&[&v[c], &v[d]]);
+        v[b] = rotr(&xor(&v[b], &v[c]), 12);
+        v[a] = add(&[&v[a], &v[b], y]);
+        v[d] = rotr(&xor(&v[d], &v[a]), 8);
+        v[c] = add(&[&v[c], &v[d]]);
+        v[b] = rotr(&xor(&v[b], &v[c]), 7);
+    }
+}
+
+/// Returns the bitwise XOR of the given words.
+fn xor<E: Environment>(a: &[Boolean<E>], b: &[Boolean<E>]) -> Word<E> {
+    a.iter().zip_eq(b).map(|(a, b)| a ^ b).collect()
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    /// The input lengths to check, in bytes, which cover the empty input, one and two blocks, and the block boundary.
+    const INPUT_LENGTHS: [usize; 5] = [0, 3, 64, 65, 128];
+
+    fn check_hash(mode: Mode) -> Result<()> {
+        use console::Hash as H;
+
+        for num_bytes in INPUT_LENGTHS {
+            // Sample the input bits.
+            let native_input = (0..num_bytes * 8).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let input = native_input.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = console::Blake2s.hash(&native_input)?;
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("BLAKE2s {mode} {num_bytes}"), || {
+                let candidate = Blake2s::hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        check_hash(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        check_hash(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        check_hash(Mode::Private)
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod point;
+use point::*;
+
+use crate::nonnative::{
+    from_limbs,
+    is_less_than_constant,
+    NonNativeField,
+    Secp256k1BaseField,
+    Secp256k1ScalarField,
+    NUM_LIMBS,
+};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean};
+
+use core::marker::PhantomData;
+
+/// The number of bits in a public key.
+const PUBLIC_KEY_SIZE_IN_BITS: usize = 512;
+/// The number of bits in a message hash.
+const MESSAGE_HASH_SIZE_IN_BITS: usize = 256;
+/// The number of bits in a signature.
+const SIGNATURE_SIZE_IN_BITS: usize = 512;
+
+/// An element of the secp256k1 base field.
+type Base<E> = NonNativeField<E, Secp256k1BaseField>;
+/// An element of the secp256k1 scalar field.
+type Scalar<E> = NonNativeField<E, Secp256k1ScalarField>;
+
+/// ECDSA signature verification over secp256k1, as used in Ethereum and Bitcoin.
+///
+/// The public key is the 64-byte concatenation of the big-endian affine coordinates `x || y`,
+/// the message hash is a 32-byte big-endian integer, and the signature is the 64-byte concatenation `r || s`.
+/// Each is given as bits, where each byte is in little-endian bit order, such that the output of `Keccak256`
+/// is a message hash. Signatures with a high `s` are accepted, as in `ecrecover`.
+///
+/// The field arithmetic is emulated with 64-bit limbs, so a verification is several million constraints.
+pub struct EcdsaSecp256k1<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> EcdsaSecp256k1<E> {
+    /// Returns `true` if the signature is valid for the given public key and message hash.
+    pub fn verify(public_key: &[Boolean<E>], message_hash: &[Boolean<E>], signature: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are the expected size.
+        if public_key.len() != PUBLIC_KEY_SIZE_IN_BITS {
+            E::halt(format!("Invalid secp256k1 public key size, found {} bits", public_key.len()))
+        }
+        if message_hash.len() != MESSAGE_HASH_SIZE_IN_BITS {
+            E::halt(format!("Invalid secp256k1 message hash size, found {} bits", message_hash.len()))
+        }
+        if signature.len() != SIGNATURE_SIZE_IN_BITS {
+            E::halt(format!("Invalid secp256k1 signature size, found {} bits", signature.len()))
+        }
+
+        let (p, n) = (Base::<E>::modulus(), Scalar::<E>::modulus());
+
+        // Parse the inputs as integers.
+        let (x_bits, y_bits) = (integer_bits_le(&public_key[..256]), integer_bits_le(&public_key[256..]));
+        let (r_bits, s_bits) = (integer_bits_le(&signature[..256]), integer_bits_le(&signature[256..]));
+        let (x, y) = (Base::from_bits_le(&x_bits), Base::from_bits_le(&y_bits));
+        let (r, s) = (Scalar::from_bits_le(&r_bits), Scalar::from_bits_le(&s_bits));
+        let z = Scalar::from_bits_le(&integer_bits_le(message_hash));
+
+        // Ensure the signature scalars are in `[1, n)`.
+        let mut is_valid = is_less_than_constant(&r_bits, &n) & is_less_than_constant(&s_bits, &n);
+        is_valid &= !r.is_zero() & !s.is_zero();
+        // Ensure the public key is on the curve `y^2 = x^3 + 7`.
+        is_valid &= is_less_than_constant(&x_bits, &p) & is_less_than_constant(&y_bits, &p);
+        is_valid &= y.square().is_equal(&x.square().mul(&x).add(&Base::constant(&7u8.into())));
+
+        // Compute `u1 = z / s` and `u2 = r / s`, where a zero `s` is replaced with one, so the circuit is satisfiable.
+        let s = Scalar::ternary(&s.is_zero(), &Scalar::constant(&1u8.into()), &s);
+        let s_inverse = s.inverse();
+        let (u1_bits, u2_bits) = (z.mul(&s_inverse).to_bits_le(), r.mul(&s_inverse).to_bits_le());
+
+        // Compute `R = u1 * G + u2 * Q`, with Shamir's trick.
+        let generator = Point::generator();
+        let public_key = Point::from_affine(x, y);
+        let generator_plus_public_key = generator.add(&public_key);
+        let mut result = Point::zero();
+        for (u1_bit, u2_bit) in u1_bits.iter().rev().zip_eq(u2_bits.iter().rev()) {
+            result = result.double();
+            let addend = Point::ternary(
+                u2_bit,
+                &Point::ternary(u1_bit, &generator_plus_public_key, &public_key),
+                &Point::ternary(u1_bit, &generator, &Point::zero()),
+            );
+            result = result.add(&addend);
+        }
+
+        // Ensure `R` is not the point at infinity.
+        is_valid &= !result.z().is_zero();
+        // Ensure the affine x-coordinate `X / Z` is `r` modulo `n`, which is `r` or, if `r + n < p`, `r + n`.
+        let r = Base::from_bits_le(&r_bits);
+        let is_r = result.x().is_equal(&r.mul(result.z()));
+        let r_plus_n = r.add(&Base::constant(&n));
+        let is_r_plus_n = is_less_than_constant(&r_bits, &(&p - &n)) & result.x().is_equal(&r_plus_n.mul(result.z()));
+        is_valid & (is_r | is_r_plus_n)
+    }
+}
+
+/// Returns the little-endian bits of the big-endian integer of the given bytes,
+/// where each byte is in little-endian bit order.
+fn integer_bits_le<E: Environment>(bytes: &[Boolean<E>]) -> Vec<Boolean<E>> {
+    bytes.chunks(8).rev().flatten().cloned().collect()
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// A public key, message hash, and signature, generated with OpenSSL.
+    const PUBLIC_KEY: &str = "09ec0d0da92178803471875cdb314e52c5973ec3620213a6a165db4e6aec88a3\
+                              31f992a9dfdf333e42933d75b6e4d8deb70e9e61c6fb50ad4413bc529f2e721e";
+    const MESSAGE_HASH: &str = "2b1082cbf3e1e426bcd7d5987a7f3c56a3893c0dc8e2606c6335049f4488c042";
+    const SIGNATURE: &str = "46dd7659f886d6c653779ef617bb604d6f9541f12b518334245a80e7446f9723\
+                             482a7169f4339410d6df40531b201fff28292a09f729a811c93eff769666ad69";
+
+    /// Returns the bits of the given hexadecimal string, where each byte is in little-endian bit order.
+    fn bits(hex: &str) -> Vec<bool> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+
+    fn check_verify(public_key: &[bool], message_hash: &[bool], signature: &[bool]) {
+        let expected = console::EcdsaSecp256k1::verify(public_key, message_hash, signature).unwrap();
+
+        // Note: A verification over variables is several million constraints, so the gadget is checked over constants,
+        // and the point arithmetic is checked over variables below.
+        Circuit::scope("EcdsaSecp256k1::verify", || {
+            let public_key: Vec<Boolean<Circuit>> = Inject::new(Mode::Constant, public_key.to_vec());
+            let message_hash: Vec<Boolean<Circuit>> = Inject::new(Mode::Constant, message_hash.to_vec());
+            let signature: Vec<Boolean<Circuit>> = Inject::new(Mode::Constant, signature.to_vec());
+
+            let candidate = EcdsaSecp256k1::verify(&public_key, &message_hash, &signature);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(0, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify() {
+        let (public_key, message_hash, signature) = (bits(PUBLIC_KEY), bits(MESSAGE_HASH), bits(SIGNATURE));
+        check_verify(&public_key, &message_hash, &signature);
+
+        // Check a different message hash.
+        let mut candidate = message_hash.clone();
+        candidate[0] = !candidate[0];
+        check_verify(&public_key, &candidate, &signature);
+
+        // Check a different signature.
+        let mut candidate = signature.clone();
+        candidate[0] = !candidate[0];
+        check_verify(&public_key, &message_hash, &candidate);
+
+        // Check a public key that is not on the curve.
+        let mut candidate = public_key.clone();
+        candidate[0] = !candidate[0];
+        check_verify(&candidate, &message_hash, &signature);
+
+        // Check a zero and an out-of-range signature.
+        check_verify(&public_key, &message_hash, &bits(&format!("{}{}", &SIGNATURE[..64], "00".repeat(32))));
+        check_verify(&public_key, &message_hash, &bits(&format!("{}{}", "ff".repeat(32), &SIGNATURE[64..])));
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The x-coordinate of the secp256k1 generator, as little-endian 64-bit limbs.
+const GENERATOR_X: [u64; NUM_LIMBS] = [0x59F2815B16F81798, 0x029BFCDB2DCE28D9, 0x55A06295CE870B07, 0x79BE667EF9DCBBAC];
+/// The y-coordinate of the secp256k1 generator, as little-endian 64-bit limbs.
+const GENERATOR_Y: [u64; NUM_LIMBS] = [0x9C47D08FFB10D4B8, 0xFD17B448A6855419, 0x5DA4FBFC0E1108A8, 0x483ADA7726A3C465];
+/// The curve coefficient `3 * b`, where `b = 7`.
+const COEFFICIENT_B3: u8 = 21;
+
+/// A secp256k1 point in homogeneous projective coordinates `(X : Y : Z)`, which is `(X / Z, Y / Z)` in affine
+/// coordinates, and where the point at infinity is `(0 : 1 : 0)`.
+///
+/// The group law uses the complete formulas of Renes, Costello, and Batina (2016) for `a = 0`,
+/// so addition and doubling have no exceptional cases, including the point at infinity.
+#[derive(Clone)]
+pub(super) struct Point<E: Environment> {
+    x: Base<E>,
+    y: Base<E>,
+    z: Base<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Returns the point at infinity.
+    pub(super) fn zero() -> Self {
+        let (zero, one) = (Base::constant(&0u8.into()), Base::constant(&1u8.into()));
+        Self { x: zero.clone(), y: one, z: zero }
+    }
+
+    /// Returns the generator, as a constant.
+    pub(super) fn generator() -> Self {
+        Self::from_affine(Base::constant(&from_limbs(&GENERATOR_X)), Base::constant(&from_limbs(&GENERATOR_Y)))
+    }
+
+    /// Returns the point of the given affine coordinates.
+    pub(super) fn from_affine(x: Base<E>, y: Base<E>) -> Self {
+        Self { x, y, z: Base::constant(&1u8.into()) }
+    }
+
+    /// Returns the projective x-coordinate.
+    pub(super) fn x(&self) -> &Base<E> {
+        &self.x
+    }
+
+    /// Returns the projective z-coordinate.
+    pub(super) fn z(&self) -> &Base<E> {
+        &self.z
+    }
+
+    /// Returns the sum of `self` and `other`, with Algorithm 7 of Renes, Costello, and Batina (2016).
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let b3 = Base::constant(&COEFFICIENT_B3.into());
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2);
+        let t1 = y1.mul(y2);
+        let t2 = z1.mul(z2);
+        let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+        let t4 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+        let y3 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+        let t0 = t0.add(&t0).add(&t0);
+        let t2 = b3.mul(&t2);
+        let z3 = t1.add(&t2);
+        let t1 = t1.sub(&t2);
+        let y3 = b3.mul(&y3);
+        let x3 = t3.mul(&t1).sub(&t4.mul(&y3));
+        let y3 = t1.mul(&z3).add(&y3.mul(&t0));
+        let z3 = z3.mul(&t4).add(&t0.mul(&t3));
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns the double of `self`, with Algorithm 9 of Renes, Costello, and Batina (2016).
+    pub(super) fn double(&self) -> Self {
+        let b3 = Base::constant(&COEFFICIENT_B3.into());
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = y.square();
+        let z3 = t0.add(&t0);
+        let z3 = z3.add(&z3);
+        let z3 = z3.add(&z3);
+        let t1 = y.mul(z);
+        let t2 = b3.mul(&z.square());
+        let x3 = t2.mul(&z3);
+        let y3 = t0.add(&t2);
+        let z3 = t1.mul(&z3);
+        let t2 = t2.add(&t2).add(&t2);
+        let t0 = t0.sub(&t2);
+        let y3 = x3.add(&t0.mul(&y3));
+        let t1 = x.mul(y);
+        let x3 = t0.mul(&t1);
+        let x3 = x3.add(&x3);
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: Base::ternary(condition, &first.x, &second.x),
+            y: Base::ternary(condition, &first.y, &second.y),
+            z: Base::ternary(condition, &first.z, &second.z),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use num_bigint::BigUint;
+
+    /// Returns the affine coordinates of the given point.
+    fn to_affine(point: &Point<Circuit>) -> Option<(BigUint, BigUint)> {
+        let p = Base::<Circuit>::modulus();
+        let z = point.z.eject_value();
+        match z == 0u8.into() {
+            true => None,
+            false => {
+                let z_inverse = z.modpow(&(&p - 2u8), &p);
+                Some((point.x.eject_value() * &z_inverse % &p, point.y.eject_value() * &z_inverse % &p))
+            }
+        }
+    }
+
+    /// Returns the given point, with each coordinate in the given mode.
+    fn inject(mode: Mode, point: &Point<Circuit>) -> Point<Circuit> {
+        Point {
+            x: Base::new(mode, &point.x.eject_value()),
+            y: Base::new(mode, &point.y.eject_value()),
+            z: Base::new(mode, &point.z.eject_value()),
+        }
+    }
+
+    #[test]
+    fn test_group_law() {
+        // Compute the points with constants.
+        let (zero, generator) = (Point::<Circuit>::zero(), Point::<Circuit>::generator());
+        let two_g = generator.double();
+        let three_g = two_g.add(&generator);
+        assert_eq!(to_affine(&two_g), to_affine(&generator.add(&generator)));
+        assert_eq!(to_affine(&three_g), to_affine(&generator.add(&two_g)));
+        assert_eq!(to_affine(&generator), to_affine(&generator.add(&zero)));
+        assert_eq!(None, to_affine(&zero.double()));
+
+        // Ensure the negation of a point sums to the point at infinity.
+        let p = Base::<Circuit>::modulus();
+        let negation = Point::from_affine(generator.x.clone(), Base::constant(&(&p - from_limbs(&GENERATOR_Y))));
+        assert_eq!(None, to_affine(&generator.add(&negation)));
+
+        // Ensure the formulas are satisfied over variables, including the exceptional cases of affine formulas.
+        for mode in [Mode::Public, Mode::Private] {
+            for (a, b) in [(&generator, &two_g), (&generator, &generator), (&zero, &three_g), (&generator, &negation)] {
+                let (x, y) = (inject(mode, a), inject(mode, b));
+                Circuit::scope(format!("{mode}"), || {
+                    assert_eq!(to_affine(&a.add(b)), to_affine(&x.add(&y)));
+                    assert_eq!(to_affine(&a.double()), to_affine(&x.double()));
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
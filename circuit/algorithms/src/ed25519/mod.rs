@@ -0,0 +1,246 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod point;
+use point::*;
+
+use crate::{
+    nonnative::{from_limbs, is_less_than_constant, Ed25519BaseField, Ed25519ScalarField, NonNativeField, NUM_LIMBS},
+    Sha512,
+};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean};
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+/// The number of bits in a public key.
+const PUBLIC_KEY_SIZE_IN_BITS: usize = 256;
+/// The number of bits in a signature.
+const SIGNATURE_SIZE_IN_BITS: usize = 512;
+/// The curve coefficient `d = -121665 / 121666`, as little-endian 64-bit limbs.
+const COEFFICIENT_D: [u64; NUM_LIMBS] =
+    [0x75EB4DCA135978A3, 0x00700A4D4141D8AB, 0x8CC740797779E898, 0x52036CEE2B6FFE73];
+
+/// An element of the edwards25519 base field.
+type Base<E> = NonNativeField<E, Ed25519BaseField>;
+/// An element of the edwards25519 scalar field.
+type Scalar<E> = NonNativeField<E, Ed25519ScalarField>;
+
+/// Ed25519 signature verification, as specified in RFC 8032.
+///
+/// The public key is the 32-byte encoding of a point, the message is any number of bytes,
+/// and the signature is the 64-byte concatenation `R || S`. Each is given as bits, where each byte
+/// is in little-endian bit order. The verification is the cofactorless equation `[S]B = R + [k]A`,
+/// checked on the encoding of `R`, where `k = SHA-512(R || A || M)`.
+///
+/// The field arithmetic is emulated with 64-bit limbs, so a verification is several million constraints.
+pub struct Ed25519<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Ed25519<E> {
+    /// Returns `true` if the signature is valid for the given public key and message.
+    pub fn verify(public_key: &[Boolean<E>], message: &[Boolean<E>], signature: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are the expected size.
+        if public_key.len() != PUBLIC_KEY_SIZE_IN_BITS {
+            E::halt(format!("Invalid Ed25519 public key size, found {} bits", public_key.len()))
+        }
+        if message.len() % 8 != 0 {
+            E::halt(format!("Ed25519 expects a whole number of bytes, found {} bits", message.len()))
+        }
+        if signature.len() != SIGNATURE_SIZE_IN_BITS {
+            E::halt(format!("Invalid Ed25519 signature size, found {} bits", signature.len()))
+        }
+
+        // Parse the inputs, where the integers are little-endian.
+        let (public_key_point, mut is_valid) = decompress(public_key);
+        let (r_bits, s_bits) = (&signature[..256], &signature[256..]);
+        // Ensure `S` is less than the group order.
+        is_valid &= is_less_than_constant(s_bits, &Scalar::<E>::modulus());
+
+        // Compute `k = SHA-512(R || A || M)`, modulo the group order, as `k_0 + 2^256 * k_1`.
+        let digest = Sha512::hash(&[r_bits, public_key, message].concat());
+        let two_to_256 = Scalar::constant(&(BigUint::from(1u8) << 256u16));
+        let k = Scalar::from_bits_le(&digest[..256]).add(&Scalar::from_bits_le(&digest[256..]).mul(&two_to_256));
+
+        // Compute `[S]B - [k]A`, with Shamir's trick.
+        let generator = Point::generator();
+        let negated_public_key = public_key_point.neg();
+        let generator_minus_public_key = generator.add(&negated_public_key);
+        let mut result = Point::zero();
+        for (s_bit, k_bit) in s_bits.iter().rev().zip_eq(k.to_bits_le().iter().rev()) {
+            result = result.double();
+            let addend = Point::ternary(
+                k_bit,
+                &Point::ternary(s_bit, &generator_minus_public_key, &negated_public_key),
+                &Point::ternary(s_bit, &generator, &Point::zero()),
+            );
+            result = result.add(&addend);
+        }
+
+        // Ensure the encoding of the result is `R`.
+        for (a, b) in result.compress().iter().zip_eq(r_bits) {
+            is_valid &= a.is_equal(b);
+        }
+        is_valid
+    }
+}
+
+/// Returns the point of the given encoding, which is the little-endian `y` with the sign of `x` in the top bit,
+/// and `true` if the encoding is valid. If the encoding is invalid, the point is unspecified.
+fn decompress<E: Environment>(bits_le: &[Boolean<E>]) -> (Point<E>, Boolean<E>) {
+    let (y_bits, sign) = (&bits_le[..255], &bits_le[255]);
+    let y = Base::from_bits_le(y_bits);
+    // Ensure the y-coordinate is reduced.
+    let mut is_valid = is_less_than_constant(y_bits, &Base::<E>::modulus());
+
+    // Compute `u = y^2 - 1` and `v = d y^2 + 1`, where `x^2 = u / v`.
+    let one = Base::constant(&1u8.into());
+    let y2 = y.square();
+    let (u, v) = (y2.sub(&one), Base::constant(&from_limbs(&COEFFICIENT_D)).mul(&y2).add(&one));
+
+    // Compute a square root of `u / v` if it is a square, and of `2u / v` otherwise, as 2 is a non-square.
+    let p = Base::<E>::modulus();
+    let ratio = u.eject_value() * v.eject_value().modpow(&(&p - 2u8), &p) % &p;
+    let (is_square, root) = match sqrt(&ratio, &p) {
+        Some(root) => (true, root),
+        None => (false, sqrt(&(ratio * 2u8 % &p), &p).unwrap_or_default()),
+    };
+    let mode = if u.is_constant() { Mode::Constant } else { Mode::Private };
+    let (is_square, root) = (Boolean::new(mode, is_square), Base::new(mode, &root));
+    // Ensure `root^2 * v` is `u` or `2u`, and that `is_square` is set if `u` is zero, so it is unique.
+    root.square().mul(&v).assert_equal(&Base::ternary(&is_square, &u, &u.add(&u)));
+    E::assert(&is_square | &!u.is_zero());
+    is_valid &= &is_square;
+
+    // Select the root with the given sign, and ensure the sign is zero if `x` is zero.
+    let x = Base::ternary(&root.to_bits_le()[0].is_equal(sign), &root, &root.neg());
+    is_valid &= x.to_bits_le()[0].is_equal(sign);
+    (Point::from_affine(x, y), is_valid)
+}
+
+/// Returns a square root of `a` modulo `p`, where `p = 5 mod 8`, if it exists.
+fn sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    // Compute the candidate `a^((p + 3) / 8)`, which is a root of `a` or `-a`.
+    let candidate = a.modpow(&((p + 3u8) >> 3u8), p);
+    // Compute `sqrt(-1) = 2^((p - 1) / 4)`, to map a root of `-a` to a root of `a`.
+    let sqrt_minus_one = BigUint::from(2u8).modpow(&((p - 1u8) >> 2u8), p);
+    [candidate.clone(), candidate * sqrt_minus_one % p].into_iter().find(|root| root * root % p == a % p)
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// The public key, message, and signature of test 2 of RFC 8032.
+    const PUBLIC_KEY: &str = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+    const MESSAGE: &str = "72";
+    const SIGNATURE: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                             085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+    /// Returns the bits of the given hexadecimal string, where each byte is in little-endian bit order.
+    fn bits(hex: &str) -> Vec<bool> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+
+    fn check_verify(public_key: &[bool], message: &[bool], signature: &[bool]) {
+        let expected = console::Ed25519::verify(public_key, message, signature).unwrap();
+
+        // Note: A verification over variables is several million constraints, so the gadget is checked over constants,
+        // and the decompression and point arithmetic are checked over variables below.
+        Circuit::scope("Ed25519::verify", || {
+            let public_key: Vec<Boolean<Circuit>> = Inject::new(Mode::Constant, public_key.to_vec());
+            let message: Vec<Boolean<Circuit>> = Inject::new(Mode::Constant, message.to_vec());
+            let signature: Vec<Boolean<Circuit>> = Inject::new(Mode::Constant, signature.to_vec());
+
+            let candidate = Ed25519::verify(&public_key, &message, &signature);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(0, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify() {
+        let (public_key, message, signature) = (bits(PUBLIC_KEY), bits(MESSAGE), bits(SIGNATURE));
+        check_verify(&public_key, &message, &signature);
+
+        // Check a different message.
+        check_verify(&public_key, &[], &signature);
+        check_verify(&public_key, &bits("73"), &signature);
+
+        // Check a different signature.
+        let mut candidate = signature.clone();
+        candidate[300] = !candidate[300];
+        check_verify(&public_key, &message, &candidate);
+
+        // Check a different public key.
+        let mut candidate = public_key.clone();
+        candidate[255] = !candidate[255];
+        check_verify(&candidate, &message, &signature);
+
+        // Check an `S` that is not less than the group order.
+        check_verify(&public_key, &message, &bits(&format!("{}{}", &SIGNATURE[..64], "ff".repeat(32))));
+    }
+
+    #[test]
+    fn test_decompress() {
+        let p = Base::<Circuit>::modulus();
+
+        // Sample a valid encoding, the encoding with the other sign, an encoding without a square root,
+        // an encoding of `x = 0` with the sign set, and an unreduced encoding.
+        let mut other_sign = bits(PUBLIC_KEY);
+        other_sign[255] = !other_sign[255];
+        let mut zero_with_sign = bits(&"00".repeat(32));
+        zero_with_sign[0] = true;
+        zero_with_sign[255] = true;
+        let mut unreduced = vec![true; 256];
+        unreduced[255] = false;
+        let cases = [
+            (bits(PUBLIC_KEY), true),
+            (other_sign, true),
+            (bits(&"02".repeat(32)), false),
+            (zero_with_sign, false),
+            (unreduced, false),
+        ];
+
+        for mode in [Mode::Constant, Mode::Private] {
+            for (encoding, expected) in &cases {
+                let y = encoding[..255].iter().rev().fold(BigUint::default(), |y, bit| (y << 1u8) + *bit as u8);
+
+                Circuit::scope(format!("{mode}"), || {
+                    let bits: Vec<Boolean<Circuit>> = Inject::new(mode, encoding.clone());
+                    let (point, is_valid) = decompress::<Circuit>(&bits);
+                    assert_eq!(*expected, is_valid.eject_value());
+                    // Ensure a valid point is on the curve, and has the given y-coordinate and sign.
+                    if *expected {
+                        let (x, candidate_y) = point.to_affine();
+                        let (x2, y2) = (&x * &x % &p, &y * &y % &p);
+                        let d = from_limbs(&COEFFICIENT_D);
+                        assert_eq!((&y2 + &p - &x2) % &p, (BigUint::from(1u8) + d * &x2 * &y2) % &p);
+                        assert_eq!(y, candidate_y);
+                        assert_eq!(encoding[255], x.bit(0));
+                    }
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
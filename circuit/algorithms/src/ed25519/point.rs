@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The x-coordinate of the edwards25519 generator, as little-endian 64-bit limbs.
+const GENERATOR_X: [u64; NUM_LIMBS] = [0xC9562D608F25D51A, 0x692CC7609525A7B2, 0xC0A4E231FDD6DC5C, 0x216936D3CD6E53FE];
+/// The y-coordinate of the edwards25519 generator, as little-endian 64-bit limbs.
+const GENERATOR_Y: [u64; NUM_LIMBS] = [0x6666666666666658, 0x6666666666666666, 0x6666666666666666, 0x6666666666666666];
+/// The curve coefficient `2d`, as little-endian 64-bit limbs.
+const COEFFICIENT_2D: [u64; NUM_LIMBS] =
+    [0xEBD69B9426B2F159, 0x00E0149A8283B156, 0x198E80F2EEF3D130, 0x2406D9DC56DFFCE7];
+
+/// An edwards25519 point in extended twisted Edwards coordinates `(X : Y : Z : T)`, which is `(X / Z, Y / Z)`
+/// in affine coordinates, where `T = XY / Z`, and where the identity is `(0 : 1 : 1 : 0)`.
+///
+/// The group law uses the formulas of Hisil, Wong, Carter, and Dawson (2008) for `a = -1`,
+/// which are complete on edwards25519, as `d` is a non-square.
+#[derive(Clone)]
+pub(super) struct Point<E: Environment> {
+    x: Base<E>,
+    y: Base<E>,
+    z: Base<E>,
+    t: Base<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Returns the identity.
+    pub(super) fn zero() -> Self {
+        let (zero, one) = (Base::constant(&0u8.into()), Base::constant(&1u8.into()));
+        Self { x: zero.clone(), y: one.clone(), z: one, t: zero }
+    }
+
+    /// Returns the generator, as a constant.
+    pub(super) fn generator() -> Self {
+        Self::from_affine(Base::constant(&from_limbs(&GENERATOR_X)), Base::constant(&from_limbs(&GENERATOR_Y)))
+    }
+
+    /// Returns the point of the given affine coordinates.
+    pub(super) fn from_affine(x: Base<E>, y: Base<E>) -> Self {
+        let t = x.mul(&y);
+        Self { x, y, z: Base::constant(&1u8.into()), t }
+    }
+
+    /// Returns the negation of `self`.
+    pub(super) fn neg(&self) -> Self {
+        Self { x: self.x.neg(), y: self.y.clone(), z: self.z.clone(), t: self.t.neg() }
+    }
+
+    /// Returns the sum of `self` and `other`, with the `add-2008-hwcd-3` formulas.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let a = self.y.sub(&self.x).mul(&other.y.sub(&other.x));
+        let b = self.y.add(&self.x).mul(&other.y.add(&other.x));
+        let c = self.t.mul(&Base::constant(&from_limbs(&COEFFICIENT_2D))).mul(&other.t);
+        let d = self.z.add(&self.z).mul(&other.z);
+        let (e, f, g, h) = (b.sub(&a), d.sub(&c), d.add(&c), b.add(&a));
+        Self { x: e.mul(&f), y: g.mul(&h), z: f.mul(&g), t: e.mul(&h) }
+    }
+
+    /// Returns the double of `self`, with the `dbl-2008-hwcd` formulas for `a = -1`.
+    pub(super) fn double(&self) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square();
+        let c = c.add(&c);
+        let e = self.x.add(&self.y).square().sub(&a).sub(&b);
+        let g = b.sub(&a);
+        let f = g.sub(&c);
+        let h = a.add(&b).neg();
+        Self { x: e.mul(&f), y: g.mul(&h), z: f.mul(&g), t: e.mul(&h) }
+    }
+
+    /// Returns the encoding of `self`, which is the little-endian `y` with the sign of `x` in the top bit.
+    pub(super) fn compress(&self) -> Vec<Boolean<E>> {
+        // Compute the inverse of `Z`, where a zero `Z` is replaced with one, so the circuit is satisfiable.
+        // Note: `Z` is nonzero for a point on the curve, so this only occurs for an invalid public key.
+        let z = Base::ternary(&self.z.is_zero(), &Base::constant(&1u8.into()), &self.z);
+        let z_inverse = z.inverse();
+        let (x, y) = (self.x.mul(&z_inverse), self.y.mul(&z_inverse));
+
+        // Note: The y-coordinate is reduced, so its top bit is zero.
+        let mut encoding = y.to_bits_le();
+        encoding[255] = x.to_bits_le()[0].clone();
+        encoding
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: Base::ternary(condition, &first.x, &second.x),
+            y: Base::ternary(condition, &first.y, &second.y),
+            z: Base::ternary(condition, &first.z, &second.z),
+            t: Base::ternary(condition, &first.t, &second.t),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+impl<E: Environment> Point<E> {
+    /// Returns the affine coordinates of `self`.
+    pub(super) fn to_affine(&self) -> (BigUint, BigUint) {
+        let p = Base::<E>::modulus();
+        let z_inverse = self.z.eject_value().modpow(&(&p - 2u8), &p);
+        (self.x.eject_value() * &z_inverse % &p, self.y.eject_value() * &z_inverse % &p)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// Returns the given point, with each coordinate in the given mode.
+    fn inject(mode: Mode, point: &Point<Circuit>) -> Point<Circuit> {
+        Point {
+            x: Base::new(mode, &point.x.eject_value()),
+            y: Base::new(mode, &point.y.eject_value()),
+            z: Base::new(mode, &point.z.eject_value()),
+            t: Base::new(mode, &point.t.eject_value()),
+        }
+    }
+
+    #[test]
+    fn test_group_law() {
+        // Compute the points with constants.
+        let (zero, generator) = (Point::<Circuit>::zero(), Point::<Circuit>::generator());
+        let two_g = generator.double();
+        let three_g = two_g.add(&generator);
+        assert_eq!(two_g.to_affine(), generator.add(&generator).to_affine());
+        assert_eq!(three_g.to_affine(), generator.add(&two_g).to_affine());
+        assert_eq!(generator.to_affine(), generator.add(&zero).to_affine());
+        assert_eq!(zero.to_affine(), zero.double().to_affine());
+        assert_eq!(zero.to_affine(), generator.add(&generator.neg()).to_affine());
+
+        // Ensure the encoding of the generator is the standard encoding.
+        let encoding = generator.compress().eject_value();
+        let mut expected = vec![false; 256];
+        (0..255).for_each(|i| expected[i] = from_limbs(&GENERATOR_Y).bit(i as u64));
+        assert_eq!(expected, encoding);
+
+        // Ensure the formulas are satisfied over variables.
+        for mode in [Mode::Public, Mode::Private] {
+            let negation = generator.neg();
+            for (a, b) in [(&generator, &two_g), (&generator, &generator), (&zero, &three_g), (&generator, &negation)] {
+                let (x, y) = (inject(mode, a), inject(mode, b));
+                Circuit::scope(format!("{mode}"), || {
+                    assert_eq!(a.add(b).to_affine(), x.add(&y).to_affine());
+                    assert_eq!(a.double().to_affine(), x.double().to_affine());
+                    assert_eq!(a.compress().eject_value(), x.compress().eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
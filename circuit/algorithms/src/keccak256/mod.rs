@@ -0,0 +1,205 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_circuit_types::{environment::prelude::*, Boolean};
+
+use core::marker::PhantomData;
+
+/// The number of rounds in Keccak-f[1600].
+const NUM_ROUNDS: usize = 24;
+/// The number of bits in a lane.
+const LANE_SIZE: usize = 64;
+/// The number of bytes absorbed per block, for a 256-bit digest.
+const RATE_IN_BYTES: usize = 136;
+
+/// The Keccak-f[1600] round constants.
+const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offset of each lane, where the lane at `(x, y)` is at index `x + 5 * y`.
+const ROTATION_OFFSETS: [usize; 25] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// A 64-bit lane, as little-endian bits.
+type Lane<E> = Vec<Boolean<E>>;
+
+/// Keccak-256, as used in Ethereum, which differs from SHA3-256 in its padding.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `U8::to_bits_le` of each byte.
+pub struct Keccak256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Keccak256<E> {
+    /// Returns the 256-bit Keccak-256 digest of the given input, which must be a whole number of bytes.
+    pub fn hash(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is a whole number of bytes.
+        if input.len() % 8 != 0 {
+            E::halt(format!("Keccak-256 expects a whole number of bytes, found {} bits", input.len()))
+        }
+
+        // Pad the message with `0x01`, zeros, and a final `0x80`, which combine into `0x81` for a single byte.
+        // Note: The padding depends only on the message length, so it is constant.
+        let num_padding_bytes = RATE_IN_BYTES - (input.len() / 8) % RATE_IN_BYTES;
+        let mut padding = vec![0u8; num_padding_bytes];
+        padding[0] |= 0x01;
+        padding[num_padding_bytes - 1] |= 0x80;
+        let mut bits_le = input.to_vec();
+        bits_le.extend(padding.iter().flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1))));
+
+        // Absorb each block into the rate lanes of the state.
+        // Note: Keccak lanes are little-endian, so the lane bits are the block bits in order.
+        let mut state = vec![vec![Boolean::constant(false); LANE_SIZE]; 25];
+        for block in bits_le.chunks(RATE_IN_BYTES * 8) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(LANE_SIZE)) {
+                *lane = xor(lane, bits);
+            }
+            state = Self::permute(state);
+        }
+
+        // Squeeze the first 256 bits of the state.
+        state.into_iter().take(4).flatten().collect()
+    }
+
+    /// Returns the state after applying the Keccak-f[1600] permutation.
+    fn permute(mut state: Vec<Lane<E>>) -> Vec<Lane<E>> {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta: XOR each lane with the parities of two neighbouring columns.
+            let parities = (0..5)
+                .map(|x| (1..5).fold(state[x].clone(), |parity, y| xor(&parity, &state[x + 5 * y])))
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                let d = xor(&parities[(x + 4) % 5], &rotl(&parities[(x + 1) % 5], 1));
+                for y in 0..5 {
+                    state[x + 5 * y] = xor(&state[x + 5 * y], &d);
+                }
+            }
+
+            // Rho and pi: Rotate each lane, and move the lane at `(x, y)` to `(y, 2x + 3y)`.
+            let mut permuted = state.clone();
+            for x in 0..5 {
+                for y in 0..5 {
+                    permuted[y + 5 * ((2 * x + 3 * y) % 5)] = rotl(&state[x + 5 * y], ROTATION_OFFSETS[x + 5 * y]);
+                }
+            }
+
+            // Chi: XOR each bit with the AND of the negated next bit and the bit after, in the row.
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = permuted[x + 5 * y]
+                        .iter()
+                        .zip_eq(&permuted[(x + 1) % 5 + 5 * y])
+                        .zip_eq(&permuted[(x + 2) % 5 + 5 * y])
+                        .map(|((a, b), c)| a ^ &(!b & c))
+                        .collect();
+                }
+            }
+
+            // Iota: XOR the first lane with the round constant, by negating the bits that are set.
+            for (i, bit) in state[0].iter_mut().enumerate() {
+                if (round_constant >> i) & 1 == 1 {
+                    *bit = !&*bit;
+                }
+            }
+        }
+        state
+    }
+}
+
+/// Returns the lane rotated left by `n` bits.
+fn rotl<E: Environment>(lane: &[Boolean<E>], n: usize) -> Lane<E> {
+    (0..LANE_SIZE).map(|i| lane[(i + LANE_SIZE - n) % LANE_SIZE].clone()).collect()
+}
+
+/// Returns the bitwise XOR of the given lanes.
+fn xor<E: Environment>(a: &[Boolean<E>], b: &[Boolean<E>]) -> Lane<E> {
+    a.iter().zip_eq(b).map(|(a, b)| a ^ b).collect()
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    /// The input lengths to check, in bytes, which cover one and two blocks, and the padding boundary.
+    const INPUT_LENGTHS: [usize; 5] = [0, 3, 135, 136, 200];
+
+    fn check_hash(mode: Mode) -> Result<()> {
+        use console::Hash as H;
+
+        for num_bytes in INPUT_LENGTHS {
+            // Sample the input bits.
+            let native_input = (0..num_bytes * 8).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let input = native_input.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = console::Keccak256.hash(&native_input)?;
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Keccak-256 {mode} {num_bytes}"), || {
+                let candidate = Keccak256::hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        check_hash(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        check_hash(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        check_hash(Mode::Private)
+    }
+}
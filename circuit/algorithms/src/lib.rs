@@ -20,14 +20,35 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod blake2s;
+pub use blake2s::Blake2s;
+
+pub mod ecdsa;
+pub use ecdsa::EcdsaSecp256k1;
+
+pub mod ed25519;
+pub use ed25519::Ed25519;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod keccak256;
+pub use keccak256::Keccak256;
+
+pub mod nonnative;
+pub use nonnative::{NonNativeField, NonNativeParameters, NonNativeProduct};
+
 pub mod pedersen;
 pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha256;
+pub use sha256::Sha256;
+
+pub mod sha512;
+pub use sha512::Sha512;
+
 pub mod traits;
 pub use traits::*;
@@ -0,0 +1,559 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod parameters;
+pub use parameters::*;
+
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The number of limbs in a non-native field element.
+pub const NUM_LIMBS: usize = 4;
+/// The number of bits in a limb.
+const LIMB_SIZE: usize = 64;
+/// The number of bits in a carry, which is a signed integer in `[-2^71, 2^71)`.
+const CARRY_SIZE: usize = 72;
+/// The maximum number of products in an unreduced sum, such that the quotient of its reduction is less than `2^259`.
+const MAX_NUM_PRODUCTS: usize = 8;
+/// The number of bits in the most significant limb of the quotient of an unreduced sum.
+const QUOTIENT_TOP_LIMB_SIZE: usize = LIMB_SIZE + 3;
+
+/// The parameters of a non-native (emulated) prime field.
+pub trait NonNativeParameters: 'static + Copy + Clone + Debug + PartialEq + Eq + Send + Sync {
+    /// The prime modulus, as little-endian 64-bit limbs, which must be at most 256 bits.
+    const MODULUS: [u64; NUM_LIMBS];
+}
+
+/// An element of a non-native prime field, represented as four 64-bit limbs of base field elements.
+///
+/// The limbs are always range checked, and the element is always reduced, such that
+/// two elements are equal if and only if their limbs are equal.
+///
+/// Each operation reduces its result with a witnessed quotient and remainder, and checks the
+/// integer identity `lhs - quotient * modulus - remainder = 0` limb by limb, propagating signed carries.
+/// As the limb products are at most 130 bits, the identity holds over the integers, not only modulo
+/// the base field. To defer the reduction of a sum of products, see `NonNativeProduct`.
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment, P: NonNativeParameters> {
+    /// The little-endian limbs.
+    limbs: Vec<Field<E>>,
+    /// PhantomData.
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Returns the modulus.
+    pub fn modulus() -> BigUint {
+        from_limbs(&P::MODULUS)
+    }
+
+    /// Returns the given value, reduced modulo the modulus, as a constant.
+    pub fn constant(value: &BigUint) -> Self {
+        let value = value % Self::modulus();
+        Self::from_limb_fields(to_limbs(&value).iter().map(|limb| Field::constant(to_field::<E>(&(*limb).into()))))
+    }
+
+    /// Initializes the given value, which must be reduced, in the given mode.
+    pub fn new(mode: Mode, value: &BigUint) -> Self {
+        match mode.is_constant() {
+            true => Self::constant(value),
+            false => {
+                // Ensure the value is reduced.
+                if value >= &Self::modulus() {
+                    E::halt("Attempted to initialize a non-native field element that is not reduced")
+                }
+                let element = Self::from_limb_fields(
+                    to_limbs(value).iter().map(|limb| Field::new(mode, to_field::<E>(&(*limb).into()))),
+                );
+                element.enforce_reduced();
+                element
+            }
+        }
+    }
+
+    /// Returns the element of the given little-endian bits, reduced modulo the modulus.
+    pub fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the value is at most 256 bits.
+        if bits_le.len() > NUM_LIMBS * LIMB_SIZE {
+            E::halt(format!("Attempted to initialize a non-native field element from {} bits", bits_le.len()))
+        }
+
+        // Compute the value and its reduction.
+        let value =
+            bits_le.iter().rev().fold(BigUint::default(), |value, bit| (value << 1u8) + bit.eject_value() as u8);
+        if bits_le.iter().all(|bit| bit.is_constant()) {
+            return Self::constant(&value);
+        }
+        let (quotient, remainder) = (&value / Self::modulus(), Self::witness(&(&value % Self::modulus())));
+
+        // Initialize the quotient, which is less than `2^(num_bits - modulus_bits + 1)`.
+        let num_quotient_bits = (bits_le.len() + 1).saturating_sub(Self::modulus().bits() as usize).max(1);
+        let quotient = Field::new(Mode::Private, to_field::<E>(&quotient.into()));
+        quotient.to_lower_bits_le(num_quotient_bits);
+
+        // Ensure `value - quotient * modulus - remainder = 0`.
+        let terms = (0..NUM_LIMBS).map(|i| {
+            let limb = bits_le.iter().skip(i * LIMB_SIZE).take(LIMB_SIZE).cloned().collect::<Vec<_>>();
+            let limb = if limb.is_empty() { Field::zero() } else { Field::from_bits_le(&limb) };
+            limb - &quotient * modulus_limb::<E, P>(i) - &remainder.limbs[i]
+        });
+        enforce_zero(terms.collect());
+        remainder
+    }
+
+    /// Returns the little-endian bits of the element.
+    pub fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE)).collect()
+    }
+
+    /// Returns the sum of `self` and `other`.
+    pub fn add(&self, other: &Self) -> Self {
+        let sum = self.eject_value() + other.eject_value();
+        if self.is_constant() && other.is_constant() {
+            return Self::constant(&sum);
+        }
+
+        // Compute the quotient, which is `0` or `1`, and the remainder.
+        let quotient = Field::from_boolean(&Boolean::new(Mode::Private, sum >= Self::modulus()));
+        let remainder = Self::witness(&(sum % Self::modulus()));
+
+        // Ensure `self + other - quotient * modulus - remainder = 0`.
+        let terms = (0..NUM_LIMBS).map(|i| {
+            &self.limbs[i] + &other.limbs[i] - &quotient * modulus_limb::<E, P>(i) - &remainder.limbs[i]
+        });
+        enforce_zero(terms.collect());
+        remainder
+    }
+
+    /// Returns the difference of `self` and `other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        let (minuend, subtrahend) = (self.eject_value(), other.eject_value());
+        let difference = (&minuend + Self::modulus() - &subtrahend) % Self::modulus();
+        if self.is_constant() && other.is_constant() {
+            return Self::constant(&difference);
+        }
+
+        // Compute the quotient, which is `1` if the difference underflows, and the remainder.
+        let quotient = Field::from_boolean(&Boolean::new(Mode::Private, minuend < subtrahend));
+        let remainder = Self::witness(&difference);
+
+        // Ensure `self - other + quotient * modulus - remainder = 0`.
+        let terms = (0..NUM_LIMBS).map(|i| {
+            &self.limbs[i] - &other.limbs[i] + &quotient * modulus_limb::<E, P>(i) - &remainder.limbs[i]
+        });
+        enforce_zero(terms.collect());
+        remainder
+    }
+
+    /// Returns the negation of `self`.
+    pub fn neg(&self) -> Self {
+        Self::constant(&0u8.into()).sub(self)
+    }
+
+    /// Returns the product of `self` and `other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        self.mul_without_reduce(other).reduce()
+    }
+
+    /// Returns the product of `self` and `other`, without reducing it modulo the modulus.
+    pub fn mul_without_reduce(&self, other: &Self) -> NonNativeProduct<E, P> {
+        // Compute the coefficients of the product of the limb polynomials in `2^64`.
+        let mut terms = vec![Field::zero(); 2 * NUM_LIMBS - 1];
+        for i in 0..NUM_LIMBS {
+            for j in 0..NUM_LIMBS {
+                terms[i + j] += &self.limbs[i] * &other.limbs[j];
+            }
+        }
+        NonNativeProduct { terms, num_products: 1, _parameters: PhantomData }
+    }
+
+    /// Returns the square of `self`.
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Returns the inverse of `self`, where the modulus must be prime.
+    /// If `self` is zero, the circuit is unsatisfiable.
+    pub fn inverse(&self) -> Self {
+        // Compute the inverse as `self^(modulus - 2)`.
+        let modulus = Self::modulus();
+        let inverse = self.eject_value().modpow(&(&modulus - 2u8), &modulus);
+        if self.is_constant() {
+            return Self::constant(&inverse);
+        }
+
+        // Ensure `self * inverse = 1`.
+        let inverse = Self::witness(&inverse);
+        self.mul(&inverse).assert_equal(&Self::constant(&1u8.into()));
+        inverse
+    }
+
+    /// Returns `true` if `self` and `other` are equal.
+    pub fn is_equal(&self, other: &Self) -> Boolean<E> {
+        // Note: The elements are reduced, so they are equal if and only if their limbs are equal.
+        let mut is_equal = Boolean::constant(true);
+        for (a, b) in self.limbs.iter().zip_eq(&other.limbs) {
+            is_equal &= a.is_equal(b);
+        }
+        is_equal
+    }
+
+    /// Ensures `self` and `other` are equal.
+    pub fn assert_equal(&self, other: &Self) {
+        self.limbs.iter().zip_eq(&other.limbs).for_each(|(a, b)| E::assert_eq(a, b));
+    }
+
+    /// Returns `true` if `self` is zero.
+    pub fn is_zero(&self) -> Boolean<E> {
+        self.is_equal(&Self::constant(&0u8.into()))
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self::from_limb_fields(first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)))
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Returns the element of the given limbs.
+    fn from_limb_fields(limbs: impl Iterator<Item = Field<E>>) -> Self {
+        Self { limbs: limbs.collect(), _parameters: PhantomData }
+    }
+
+    /// Returns a new private witness of the given reduced value, and enforces it is reduced.
+    fn witness(value: &BigUint) -> Self {
+        Self::new(Mode::Private, value)
+    }
+
+    /// Ensures the limbs are 64 bits, and the element is less than the modulus.
+    fn enforce_reduced(&self) {
+        E::assert(is_less_than_constant(&self.to_bits_le(), &Self::modulus()));
+    }
+}
+
+/// An unreduced sum of products of non-native field elements, which defers the modular reduction.
+///
+/// The sum is represented as the coefficients of the limb polynomials in `2^64`, so products are summed
+/// without any constraints, and the sum is reduced once, with a single witnessed quotient and remainder.
+/// For example, an inner product of `n` pairs costs `n` multiplications of limbs and one reduction,
+/// instead of `n` reductions and `n - 1` additions. A sum may hold at most 8 products.
+#[derive(Clone)]
+pub struct NonNativeProduct<E: Environment, P: NonNativeParameters> {
+    /// The little-endian coefficients in `2^64`, which are at most 130 bits per product.
+    terms: Vec<Field<E>>,
+    /// The number of products in the sum.
+    num_products: usize,
+    /// PhantomData.
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeProduct<E, P> {
+    /// Returns the sum of `self` and `other`, without reducing it modulo the modulus.
+    pub fn add(&self, other: &Self) -> Self {
+        // Ensure the sum holds at most `MAX_NUM_PRODUCTS` products, which bounds the quotient of the reduction.
+        let num_products = self.num_products + other.num_products;
+        if num_products > MAX_NUM_PRODUCTS {
+            E::halt(format!("An unreduced non-native sum may hold at most {MAX_NUM_PRODUCTS} products"))
+        }
+        let terms = self.terms.iter().zip_eq(&other.terms).map(|(a, b)| a + b).collect();
+        Self { terms, num_products, _parameters: PhantomData }
+    }
+
+    /// Returns the sum, reduced modulo the modulus.
+    pub fn reduce(&self) -> NonNativeField<E, P> {
+        let (value, modulus) = (self.eject_value(), NonNativeField::<E, P>::modulus());
+        if self.is_constant() {
+            return NonNativeField::constant(&value);
+        }
+
+        // Compute the quotient, which is less than `MAX_NUM_PRODUCTS * modulus < 2^259`, and the remainder.
+        let quotient = &value / &modulus;
+        let remainder = NonNativeField::witness(&(value % &modulus));
+
+        // Initialize the quotient limbs, where the most significant limb holds the upper bits.
+        let quotient = (0..NUM_LIMBS)
+            .map(|i| {
+                let (limb, num_bits) = match i == NUM_LIMBS - 1 {
+                    true => (&quotient >> (i * LIMB_SIZE), QUOTIENT_TOP_LIMB_SIZE),
+                    false => ((&quotient >> (i * LIMB_SIZE)) % (BigUint::from(1u8) << LIMB_SIZE), LIMB_SIZE),
+                };
+                let limb = Field::new(Mode::Private, to_field::<E>(&limb.into()));
+                limb.to_lower_bits_le(num_bits);
+                limb
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure `sum - quotient * modulus - remainder = 0`, as a polynomial in `2^64`.
+        let mut terms = self.terms.clone();
+        for i in 0..NUM_LIMBS {
+            for j in 0..NUM_LIMBS {
+                terms[i + j] -= &quotient[i] * modulus_limb::<E, P>(j);
+            }
+            terms[i] -= &remainder.limbs[i];
+        }
+        enforce_zero(terms);
+        remainder
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Eject for NonNativeProduct<E, P> {
+    type Primitive = BigUint;
+
+    /// Ejects the mode of the sum.
+    fn eject_mode(&self) -> Mode {
+        self.terms.eject_mode()
+    }
+
+    /// Ejects the sum as an integer.
+    fn eject_value(&self) -> Self::Primitive {
+        let terms = self.terms.iter().map(|term| to_biguint::<E>(&term.eject_value()));
+        terms.rev().fold(BigUint::default(), |value, term| (value << LIMB_SIZE) + term)
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Eject for NonNativeField<E, P> {
+    type Primitive = BigUint;
+
+    /// Ejects the mode of the element.
+    fn eject_mode(&self) -> Mode {
+        self.limbs.eject_mode()
+    }
+
+    /// Ejects the element as an integer.
+    fn eject_value(&self) -> Self::Primitive {
+        let limbs = self.limbs.iter().map(|limb| to_biguint::<E>(&limb.eject_value()));
+        limbs.rev().fold(BigUint::default(), |value, limb| (value << LIMB_SIZE) + limb)
+    }
+}
+
+/// Returns `true` if the integer of the given little-endian bits is less than the given constant.
+pub(crate) fn is_less_than_constant<E: Environment>(bits_le: &[Boolean<E>], constant: &BigUint) -> Boolean<E> {
+    // Ensure the constant fits in the given number of bits.
+    if constant.bits() as usize > bits_le.len() {
+        return Boolean::constant(true);
+    }
+
+    // Compare the bits from the least significant bit, where the most significant bit that differs decides.
+    let mut is_less_than = Boolean::constant(false);
+    for (i, bit) in bits_le.iter().enumerate() {
+        is_less_than = match constant.bit(i as u64) {
+            true => !bit | is_less_than,
+            false => !bit & is_less_than,
+        };
+    }
+    is_less_than
+}
+
+/// Ensures the integer `sum_i terms[i] * 2^(64 * i)` is zero,
+/// where each term is a signed integer with a magnitude less than `2^134`.
+///
+/// Note: For a sum of at most `MAX_NUM_PRODUCTS` products, a term is at most `8 * 4 * 2^128 = 2^133`,
+/// minus at most `3 * 2^128 + 2^67 * 2^64 < 2^132` for the quotient, so the bound holds.
+fn enforce_zero<E: Environment>(terms: Vec<Field<E>>) {
+    let limb_shift = Field::constant(to_field::<E>(&(BigInt::from(1u8) << LIMB_SIZE)));
+    let carry_offset = Field::constant(to_field::<E>(&(BigInt::from(1u8) << (CARRY_SIZE - 1))));
+
+    let num_terms = terms.len();
+    let mut carry = Field::zero();
+    for (i, term) in terms.into_iter().enumerate() {
+        let sum = term + &carry;
+        // Ensure the most significant sum is zero.
+        if i == num_terms - 1 {
+            E::assert_eq(&sum, Field::<E>::zero());
+            break;
+        }
+        // Compute the carry, which is `sum / 2^64`, as the lower 64 bits of the sum are zero.
+        carry = Field::new(Mode::Private, to_field::<E>(&(to_signed::<E>(&sum.eject_value()) >> LIMB_SIZE)));
+        // Ensure `-2^71 <= carry < 2^71`.
+        (&carry + &carry_offset).to_lower_bits_le(CARRY_SIZE);
+        // Ensure `sum = carry * 2^64`.
+        E::assert_eq(&sum, &carry * &limb_shift);
+    }
+}
+
+/// Returns the `i`-th limb of the modulus, as a constant.
+fn modulus_limb<E: Environment, P: NonNativeParameters>(i: usize) -> Field<E> {
+    Field::constant(to_field::<E>(&P::MODULUS[i].into()))
+}
+
+/// Returns the integer of the given little-endian limbs.
+pub(crate) fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << LIMB_SIZE) + *limb)
+}
+
+/// Returns the little-endian limbs of the given integer, which must be less than `2^256`.
+fn to_limbs(value: &BigUint) -> [u64; NUM_LIMBS] {
+    let mut limbs = [0u64; NUM_LIMBS];
+    limbs.iter_mut().zip(value.iter_u64_digits()).for_each(|(limb, digit)| *limb = digit);
+    limbs
+}
+
+/// Returns the base field element of the given signed integer.
+fn to_field<E: Environment>(value: &BigInt) -> console::Field<E::Network> {
+    let limb_shift = E::BaseField::from(1u128 << LIMB_SIZE);
+    let magnitude = value.magnitude().iter_u64_digits().rev().fold(E::BaseField::zero(), |field, digit| {
+        field * limb_shift + E::BaseField::from(digit)
+    });
+    match value.sign() {
+        num_bigint::Sign::Minus => console::Field::new(-magnitude),
+        _ => console::Field::new(magnitude),
+    }
+}
+
+/// Returns the integer of the given base field element.
+fn to_biguint<E: Environment>(field: &console::Field<E::Network>) -> BigUint {
+    field.to_bits_le().iter().rev().fold(BigUint::default(), |value, bit| (value << 1u8) + *bit as u8)
+}
+
+/// Returns the signed integer of the given base field element, where the negative integers are the upper half.
+fn to_signed<E: Environment>(field: &console::Field<E::Network>) -> BigInt {
+    let (positive, negative) = (to_biguint::<E>(field), to_biguint::<E>(&-*field));
+    match negative < positive {
+        true => -BigInt::from(negative),
+        false => BigInt::from(positive),
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+
+    type Element = NonNativeField<Circuit, Secp256k1BaseField>;
+
+    /// Samples a random reduced value.
+    fn sample<P: NonNativeParameters>() -> BigUint {
+        let limbs = [0; NUM_LIMBS].map(|_: u64| u64::rand(&mut test_rng()));
+        from_limbs(&limbs) % from_limbs(&P::MODULUS)
+    }
+
+    fn check_operations<P: NonNativeParameters>(mode_a: Mode, mode_b: Mode) {
+        let modulus = NonNativeField::<Circuit, P>::modulus();
+        // Note: The edge cases are zero and `modulus - 1`.
+        let values = [(0u8.into(), &modulus - 1u8), (&modulus - 1u8, &modulus - 1u8)];
+        let samples = (0..ITERATIONS).map(|_| (sample::<P>(), sample::<P>()));
+
+        for (i, (a, b)) in values.into_iter().chain(samples).enumerate() {
+            let (x, y) = (NonNativeField::<Circuit, P>::new(mode_a, &a), NonNativeField::new(mode_b, &b));
+
+            Circuit::scope(format!("{mode_a} {mode_b} {i}"), || {
+                assert_eq!((&a + &b) % &modulus, x.add(&y).eject_value());
+                assert_eq!((&a + &modulus - &b) % &modulus, x.sub(&y).eject_value());
+                assert_eq!((&a * &b) % &modulus, x.mul(&y).eject_value());
+                assert_eq!(a == b, x.is_equal(&y).eject_value());
+                if b != 0u8.into() {
+                    assert_eq!(b.modpow(&(&modulus - 2u8), &modulus), y.inverse().eject_value());
+                }
+                assert!(Circuit::is_satisfied_in_scope());
+                if mode_a.is_constant() && mode_b.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_operations() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_operations::<Secp256k1BaseField>(mode_a, mode_b);
+                check_operations::<Secp256k1ScalarField>(mode_a, mode_b);
+                check_operations::<Ed25519BaseField>(mode_a, mode_b);
+                check_operations::<Ed25519ScalarField>(mode_a, mode_b);
+                check_operations::<Bn254BaseField>(mode_a, mode_b);
+                check_operations::<Bn254ScalarField>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_without_reduce() {
+        let modulus = Element::modulus();
+        // Note: The largest sum is `MAX_NUM_PRODUCTS` products of `modulus - 1`.
+        let largest = vec![(&modulus - 1u8, &modulus - 1u8); MAX_NUM_PRODUCTS];
+        let samples = (0..MAX_NUM_PRODUCTS).map(|_| (sample::<Secp256k1BaseField>(), sample::<Secp256k1BaseField>()));
+
+        for (i, pairs) in [largest, samples.collect()].into_iter().enumerate() {
+            let expected = pairs.iter().fold(BigUint::default(), |sum, (a, b)| sum + a * b) % &modulus;
+            let pairs = pairs
+                .iter()
+                .map(|(a, b)| (Element::new(Mode::Private, a), Element::new(Mode::Private, b)))
+                .collect::<Vec<_>>();
+
+            // Compute the inner product, with one reduction.
+            let num_deferred_constraints = Circuit::scope(format!("Deferred {i}"), || {
+                let products = pairs.iter().map(|(x, y)| x.mul_without_reduce(y));
+                let candidate = products.reduce(|sum, product| sum.add(&product)).unwrap().reduce();
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                Circuit::num_constraints_in_scope()
+            });
+
+            // Compute the inner product, with a reduction per operation.
+            let num_reduced_constraints = Circuit::scope(format!("Reduced {i}"), || {
+                let products = pairs.iter().map(|(x, y)| x.mul(y));
+                let candidate = products.reduce(|sum, product| sum.add(&product)).unwrap();
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                Circuit::num_constraints_in_scope()
+            });
+            assert!(2 * num_deferred_constraints < num_reduced_constraints);
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le() {
+        for mode in [Mode::Constant, Mode::Private] {
+            for num_bits in [0, 1, 64, 200, 256] {
+                let bits = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+                let expected = bits.iter().rev().fold(BigUint::default(), |value, bit| (value << 1u8) + *bit as u8);
+
+                let bits: Vec<Boolean<Circuit>> = Inject::new(mode, bits);
+                let candidate = Element::from_bits_le(&bits);
+                assert_eq!(expected % Element::modulus(), candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+
+        // Ensure an unreduced value is reduced.
+        let bits = vec![Boolean::<Circuit>::new(Mode::Private, true); 256];
+        let candidate = Element::from_bits_le(&bits);
+        assert_eq!((BigUint::from(1u8) << 256u16) - 1u8 - Element::modulus(), candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_less_than_constant() {
+        let cases = [(0u64, 0u64), (0, 1), (5, 5), (5, 6), (6, 5), (u64::MAX, u64::MAX - 1), (1, u64::MAX)];
+        for (value, constant) in cases {
+            let bits = (0..64).map(|i| Boolean::<Circuit>::new(Mode::Private, (value >> i) & 1 == 1));
+            let bits = bits.collect::<Vec<_>>();
+            let candidate = is_less_than_constant(&bits, &constant.into());
+            assert_eq!(value < constant, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
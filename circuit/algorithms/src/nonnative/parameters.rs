@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The secp256k1 base field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1BaseField;
+
+impl NonNativeParameters for Secp256k1BaseField {
+    const MODULUS: [u64; NUM_LIMBS] = [0xFFFFFFFEFFFFFC2F, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+}
+
+/// The secp256k1 scalar field, whose modulus is the group order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1ScalarField;
+
+impl NonNativeParameters for Secp256k1ScalarField {
+    const MODULUS: [u64; NUM_LIMBS] = [0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+}
+
+/// The edwards25519 base field, with modulus `2^255 - 19`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519BaseField;
+
+impl NonNativeParameters for Ed25519BaseField {
+    const MODULUS: [u64; NUM_LIMBS] = [0xFFFFFFFFFFFFFFED, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x7FFFFFFFFFFFFFFF];
+}
+
+/// The edwards25519 scalar field, whose modulus is the prime-order subgroup order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519ScalarField;
+
+impl NonNativeParameters for Ed25519ScalarField {
+    const MODULUS: [u64; NUM_LIMBS] = [0x5812631A5CF5D3ED, 0x14DEF9DEA2F79CD6, 0x0000000000000000, 0x1000000000000000];
+}
+
+/// The BN254 base field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bn254BaseField;
+
+impl NonNativeParameters for Bn254BaseField {
+    const MODULUS: [u64; NUM_LIMBS] = [0x3C208C16D87CFD47, 0x97816A916871CA8D, 0xB85045B68181585D, 0x30644E72E131A029];
+}
+
+/// The BN254 scalar field, whose modulus is the group order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bn254ScalarField;
+
+impl NonNativeParameters for Bn254ScalarField {
+    const MODULUS: [u64; NUM_LIMBS] = [0x43E1F593F0000001, 0x2833E84879B97091, 0xB85045B68181585D, 0x30644E72E131A029];
+}
@@ -0,0 +1,233 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A duplex sponge using the Poseidon permutation, with a configurable rate and capacity.
+///
+/// Unlike `Poseidon`, which hashes a single input into a fixed number of outputs, the sponge
+/// absorbs and squeezes any number of field elements, in any order, and matches `console::PoseidonSponge`.
+#[derive(Clone)]
+pub struct PoseidonSponge<E: Environment, const RATE: usize, const CAPACITY: usize> {
+    /// The number of rounds in a full-round operation.
+    full_rounds: usize,
+    /// The number of rounds in a partial-round operation.
+    partial_rounds: usize,
+    /// The exponent used in S-boxes.
+    alpha: Field<E>,
+    /// The additive round keys, indexed by `ark[round_number][state_element_index]`.
+    ark: Vec<Vec<Field<E>>>,
+    /// The Maximally Distance Separating (MDS) matrix.
+    mds: Vec<Vec<Field<E>>>,
+    /// The state of the sponge, where the capacity elements precede the rate elements.
+    state: Vec<Field<E>>,
+    /// The mode of the sponge.
+    mode: DuplexSpongeMode,
+}
+
+#[cfg(console)]
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Inject for PoseidonSponge<E, RATE, CAPACITY> {
+    type Primitive = console::PoseidonSponge<E::Network, RATE, CAPACITY>;
+
+    /// Initializes a sponge from the given console sponge, where the parameters are constant,
+    /// and the state is injected in the given mode.
+    fn new(mode: Mode, sponge: Self::Primitive) -> Self {
+        // Initialize the Poseidon parameters.
+        let parameters = sponge.parameters();
+        let full_rounds = parameters.full_rounds;
+        let partial_rounds = parameters.partial_rounds;
+        let alpha = Field::constant(console::Field::from_u128(parameters.alpha as u128));
+        // Cache the bits for the field element.
+        alpha.to_bits_le();
+        let ark = parameters
+            .ark
+            .iter()
+            .map(|round| round.iter().map(|field| Field::constant(console::Field::new(*field))).collect())
+            .collect();
+        let mds = parameters
+            .mds
+            .iter()
+            .map(|row| row.iter().map(|field| Field::constant(console::Field::new(*field))).collect())
+            .collect();
+
+        // Initialize the state and the mode.
+        let state = sponge.state().into_iter().map(|element| Field::new(mode, element)).collect();
+        let sponge_mode = match *sponge.mode() {
+            console::DuplexSpongeMode::Absorbing { next_absorb_index } => {
+                DuplexSpongeMode::Absorbing { next_absorb_index }
+            }
+            console::DuplexSpongeMode::Squeezing { next_squeeze_index } => {
+                DuplexSpongeMode::Squeezing { next_squeeze_index }
+            }
+        };
+
+        Self { full_rounds, partial_rounds, alpha, ark, mds, state, mode: sponge_mode }
+    }
+}
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
+    /// Absorbs the given input elements into the state.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        if input.is_empty() {
+            return;
+        }
+
+        // Determine the absorb index, permuting the state if the rate is full or the sponge was squeezing.
+        let mut absorb_index = match self.mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index } if next_absorb_index < RATE => next_absorb_index,
+            _ => {
+                self.permute();
+                0
+            }
+        };
+
+        for element in input {
+            // Permute the state once the rate is full.
+            if absorb_index == RATE {
+                self.permute();
+                absorb_index = 0;
+            }
+            self.state[CAPACITY + absorb_index] += element;
+            absorb_index += 1;
+        }
+        self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: absorb_index };
+    }
+
+    /// Squeezes the specified number of elements from the state.
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        if num_outputs == 0 {
+            return vec![];
+        }
+
+        // Determine the squeeze index, permuting the state if the rate is exhausted or the sponge was absorbing.
+        let mut squeeze_index = match self.mode {
+            DuplexSpongeMode::Squeezing { next_squeeze_index } if next_squeeze_index < RATE => next_squeeze_index,
+            _ => {
+                self.permute();
+                0
+            }
+        };
+
+        let mut output = Vec::with_capacity(num_outputs as usize);
+        for _ in 0..num_outputs {
+            // Permute the state once the rate is exhausted.
+            if squeeze_index == RATE {
+                self.permute();
+                squeeze_index = 0;
+            }
+            output.push(self.state[CAPACITY + squeeze_index].clone());
+            squeeze_index += 1;
+        }
+        self.mode = DuplexSpongeMode::Squeezing { next_squeeze_index: squeeze_index };
+        output
+    }
+
+    /// Apply the permutation for all rounds in-place.
+    fn permute(&mut self) {
+        // Determine the partial rounds range bound.
+        let full_rounds_over_2 = self.full_rounds / 2;
+        let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + self.partial_rounds);
+
+        // Iterate through all rounds to permute.
+        for round in 0..(self.partial_rounds + self.full_rounds) {
+            // Apply the additive round keys.
+            for (element, ark) in self.state.iter_mut().zip_eq(&self.ark[round]) {
+                *element += ark;
+            }
+
+            // Apply the S-Box, to every element in a full round, and to the first element in a partial round.
+            match partial_round_range.contains(&round) {
+                true => self.state[0] = (&self.state[0]).pow(&self.alpha),
+                false => self.state.iter_mut().for_each(|element| *element = (&*element).pow(&self.alpha)),
+            }
+
+            // Apply the MDS matrix.
+            self.state = self
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter().zip_eq(&self.state).fold(Field::zero(), |accumulator, (mds, element)| {
+                        accumulator + element * mds
+                    })
+                })
+                .collect();
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    /// The number of elements to absorb and squeeze, in order, which cover the rate boundaries.
+    const SCHEDULE: [(usize, u16); 5] = [(0, 1), (3, 2), (5, 0), (1, 7), (8, 3)];
+
+    fn check_sponge<const RATE: usize, const CAPACITY: usize>(mode: Mode) -> Result<()> {
+        use console::AlgebraicSponge;
+
+        let mut native = console::PoseidonSponge::<CurrentNetwork, RATE, CAPACITY>::setup()?;
+        let mut sponge = PoseidonSponge::<Circuit, RATE, CAPACITY>::constant(native.clone());
+
+        Circuit::scope(format!("PoseidonSponge<{RATE}, {CAPACITY}> {mode}"), || {
+            for (num_inputs, num_outputs) in SCHEDULE {
+                // Absorb the inputs.
+                let native_input = (0..num_inputs)
+                    .map(|_| console::Field::<CurrentNetwork>::rand(&mut test_rng()))
+                    .collect::<Vec<_>>();
+                let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+                native.absorb(&native_input);
+                sponge.absorb(&input);
+
+                // Squeeze the outputs.
+                let expected = native.squeeze(num_outputs);
+                let candidate = sponge.squeeze(num_outputs);
+                assert_eq!(expected.to_vec(), candidate.eject_value());
+            }
+            assert!(Circuit::is_satisfied_in_scope());
+            if mode.is_constant() {
+                assert_eq!(0, Circuit::num_constraints_in_scope());
+            }
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_constant() -> Result<()> {
+        check_sponge::<2, 1>(Mode::Constant)?;
+        check_sponge::<4, 1>(Mode::Constant)?;
+        check_sponge::<3, 2>(Mode::Constant)
+    }
+
+    #[test]
+    fn test_sponge_public() -> Result<()> {
+        check_sponge::<2, 1>(Mode::Public)?;
+        check_sponge::<4, 1>(Mode::Public)?;
+        check_sponge::<3, 2>(Mode::Public)
+    }
+
+    #[test]
+    fn test_sponge_private() -> Result<()> {
+        check_sponge::<2, 1>(Mode::Private)?;
+        check_sponge::<4, 1>(Mode::Private)?;
+        check_sponge::<3, 2>(Mode::Private)
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+use core::marker::PhantomData;
+
+/// The SHA-256 round constants.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// The SHA-256 initial hash value.
+const INITIAL_HASH: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// A word, as little-endian bits.
+pub(crate) type Word<E> = Vec<Boolean<E>>;
+
+/// SHA-256, as specified in FIPS 180-4.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `U8::to_bits_le` of each byte.
+pub struct Sha256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Sha256<E> {
+    /// Returns the 256-bit SHA-256 digest of the given input, which must be a whole number of bytes.
+    pub fn hash(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is a whole number of bytes.
+        if input.len() % 8 != 0 {
+            E::halt(format!("SHA-256 expects a whole number of bytes, found {} bits", input.len()))
+        }
+
+        // Pad the message with a `1` bit, zeros, and the 64-bit big-endian message length.
+        // Note: The padding depends only on the message length, so it is constant.
+        let mut bytes = input.chunks(8).map(|byte| byte.to_vec()).collect::<Vec<_>>();
+        bytes.push(constant_bits_le(0x80, 8));
+        while bytes.len() % 64 != 56 {
+            bytes.push(constant_bits_le(0, 8));
+        }
+        let length_in_bits = input.len() as u64;
+        bytes.extend(length_in_bits.to_be_bytes().iter().map(|byte| constant_bits_le(*byte as u64, 8)));
+
+        // Compress each 512-bit block.
+        let mut state = INITIAL_HASH.iter().map(|value| constant_bits_le(*value as u64, 32)).collect::<Vec<_>>();
+        for block in bytes.chunks(64) {
+            // Parse the block into big-endian words.
+            let words = block.chunks(4).map(|word| word.iter().rev().flatten().cloned().collect()).collect();
+            state = Self::compress(&state, words);
+        }
+
+        // Output the state words in big-endian byte order.
+        state.iter().flat_map(|word| word.chunks(8).rev().flatten().cloned().collect::<Vec<_>>()).collect()
+    }
+
+    /// Returns the state after compressing the given 16-word block into the given state.
+    fn compress(state: &[Word<E>], mut schedule: Vec<Word<E>>) -> Vec<Word<E>> {
+        // Expand the message schedule.
+        for t in 16..64 {
+            let s0 = xor3(&rotr(&schedule[t - 15], 7), &rotr(&schedule[t - 15], 18), &shr(&schedule[t - 15], 3));
+            let s1 = xor3(&rotr(&schedule[t - 2], 17), &rotr(&schedule[t - 2], 19), &shr(&schedule[t - 2], 10));
+            let word = add(&[&s1, &schedule[t - 7], &s0, &schedule[t - 16]]);
+            schedule.push(word);
+        }
+
+        // Perform the rounds.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+        for (round_constant, word) in ROUND_CONSTANTS.iter().zip_eq(&schedule) {
+            let sigma_1 = xor3(&rotr(&e, 6), &rotr(&e, 11), &rotr(&e, 25));
+            let ch = ch(&e, &f, &g);
+            let round_constant = constant_bits_le(*round_constant as u64, 32);
+            let sigma_0 = xor3(&rotr(&a, 2), &rotr(&a, 13), &rotr(&a, 22));
+            let maj = maj(&a, &b, &c);
+
+            // Compute the new words as `t1 + t2` and `d + t1`, where `t1 = h + sigma_1 + ch + k + w`
+            // and `t2 = sigma_0 + maj`, summing each in the field before a single bit decomposition.
+            let t1 = [&h, &sigma_1, &ch, &round_constant, word]
+                .iter()
+                .fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+            let t2 = Field::from_bits_le(&sigma_0) + Field::from_bits_le(&maj);
+            h = g;
+            g = f;
+            f = e;
+            e = lower_bits(&(Field::from_bits_le(&d) + &t1), 32, 3);
+            d = c;
+            c = b;
+            b = a;
+            a = lower_bits(&(t1 + t2), 32, 3);
+        }
+
+        // Add the compressed words to the state.
+        state.iter().zip_eq([a, b, c, d, e, f, g, h].iter()).map(|(state, word)| add(&[state, word])).collect()
+    }
+}
+
+/// Returns the given value as `num_bits` constant little-endian bits.
+pub(crate) fn constant_bits_le<E: Environment>(value: u64, num_bits: usize) -> Vec<Boolean<E>> {
+    (0..num_bits).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect()
+}
+
+/// Returns the word rotated right by `n` bits.
+pub(crate) fn rotr<E: Environment>(word: &[Boolean<E>], n: usize) -> Word<E> {
+    (0..word.len()).map(|i| word[(i + n) % word.len()].clone()).collect()
+}
+
+/// Returns the word shifted right by `n` bits.
+pub(crate) fn shr<E: Environment>(word: &[Boolean<E>], n: usize) -> Word<E> {
+    (0..word.len()).map(|i| if i + n < word.len() { word[i + n].clone() } else { Boolean::constant(false) }).collect()
+}
+
+/// Returns `ch(e, f, g) = (e & f) ^ (!e & g)`, which is `e ? f : g`.
+pub(crate) fn ch<E: Environment>(e: &[Boolean<E>], f: &[Boolean<E>], g: &[Boolean<E>]) -> Word<E> {
+    e.iter().zip_eq(f).zip_eq(g).map(|((e, f), g)| Boolean::ternary(e, f, g)).collect()
+}
+
+/// Returns `maj(a, b, c) = (a & b) ^ (a & c) ^ (b & c)`, which is `(a ^ b) ? c : a`.
+pub(crate) fn maj<E: Environment>(a: &[Boolean<E>], b: &[Boolean<E>], c: &[Boolean<E>]) -> Word<E> {
+    a.iter().zip_eq(b).zip_eq(c).map(|((a, b), c)| Boolean::ternary(&(a ^ b), c, a)).collect()
+}
+
+/// Returns the bitwise XOR of the given words.
+pub(crate) fn xor3<E: Environment>(a: &[Boolean<E>], b: &[Boolean<E>], c: &[Boolean<E>]) -> Word<E> {
+    a.iter().zip_eq(b).zip_eq(c).map(|((a, b), c)| a ^ b ^ c).collect()
+}
+
+/// Returns the sum of the given words, modulo `2^n`, where `n` is the word size.
+pub(crate) fn add<E: Environment>(words: &[&Word<E>]) -> Word<E> {
+    // Note: The sum of up to 8 words is less than `2^(n + 3)`.
+    let sum = words.iter().fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+    lower_bits(&sum, words[0].len(), 3)
+}
+
+/// Returns the lower `num_bits` bits of the given sum, which is less than `2^(num_bits + num_carry_bits)`.
+pub(crate) fn lower_bits<E: Environment>(sum: &Field<E>, num_bits: usize, num_carry_bits: usize) -> Word<E> {
+    let mut bits_le = sum.to_lower_bits_le(num_bits + num_carry_bits);
+    bits_le.truncate(num_bits);
+    bits_le
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    /// The input lengths to check, in bytes, which cover one and two blocks, and the padding boundary.
+    const INPUT_LENGTHS: [usize; 5] = [0, 3, 55, 56, 100];
+
+    fn check_hash(mode: Mode) -> Result<()> {
+        use console::Hash as H;
+
+        for num_bytes in INPUT_LENGTHS {
+            // Sample the input bits.
+            let native_input = (0..num_bytes * 8).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let input = native_input.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = console::Sha256.hash(&native_input)?;
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("SHA-256 {mode} {num_bytes}"), || {
+                let candidate = Sha256::hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        check_hash(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        check_hash(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        check_hash(Mode::Private)
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::{add, ch, constant_bits_le, lower_bits, maj, rotr, shr, xor3, Word};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+use core::marker::PhantomData;
+
+/// The SHA-512 round constants.
+const ROUND_CONSTANTS: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// The SHA-512 initial hash value.
+const INITIAL_HASH: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// SHA-512, as specified in FIPS 180-4.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `U8::to_bits_le` of each byte.
+pub struct Sha512<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Sha512<E> {
+    /// Returns the 512-bit SHA-512 digest of the given input, which must be a whole number of bytes.
+    pub fn hash(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is a whole number of bytes.
+        if input.len() % 8 != 0 {
+            E::halt(format!("SHA-512 expects a whole number of bytes, found {} bits", input.len()))
+        }
+
+        // Pad the message with a `1` bit, zeros, and the 128-bit big-endian message length.
+        // Note: The padding depends only on the message length, so it is constant.
+        let mut bytes = input.chunks(8).map(|byte| byte.to_vec()).collect::<Vec<_>>();
+        bytes.push(constant_bits_le(0x80, 8));
+        while bytes.len() % 128 != 112 {
+            bytes.push(constant_bits_le(0, 8));
+        }
+        let length_in_bits = input.len() as u128;
+        bytes.extend(length_in_bits.to_be_bytes().iter().map(|byte| constant_bits_le(*byte as u64, 8)));
+
+        // Compress each 1024-bit block.
+        let mut state = INITIAL_HASH.iter().map(|value| constant_bits_le(*value, 64)).collect::<Vec<_>>();
+        for block in bytes.chunks(128) {
+            // Parse the block into big-endian words.
+            let words = block.chunks(8).map(|word| word.iter().rev().flatten().cloned().collect()).collect();
+            state = Self::compress(&state, words);
+        }
+
+        // Output the state words in big-endian byte order.
+        state.iter().flat_map(|word| word.chunks(8).rev().flatten().cloned().collect::<Vec<_>>()).collect()
+    }
+
+    /// Returns the state after compressing the given 16-word block into the given state.
+    fn compress(state: &[Word<E>], mut schedule: Vec<Word<E>>) -> Vec<Word<E>> {
+        // Expand the message schedule.
+        for t in 16..80 {
+            let s0 = xor3(&rotr(&schedule[t - 15], 1), &rotr(&schedule[t - 15], 8), &shr(&schedule[t - 15], 7));
+            let s1 = xor3(&rotr(&schedule[t - 2], 19), &rotr(&schedule[t - 2], 61), &shr(&schedule[t - 2], 6));
+            let word = add(&[&s1, &schedule[t - 7], &s0, &schedule[t - 16]]);
+            schedule.push(word);
+        }
+
+        // Perform the rounds.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+        for (round_constant, word) in ROUND_CONSTANTS.iter().zip_eq(&schedule) {
+            let sigma_1 = xor3(&rotr(&e, 14), &rotr(&e, 18), &rotr(&e, 41));
+            let ch = ch(&e, &f, &g);
+            let round_constant = constant_bits_le(*round_constant, 64);
+            let sigma_0 = xor3(&rotr(&a, 28), &rotr(&a, 34), &rotr(&a, 39));
+            let maj = maj(&a, &b, &c);
+
+            // Compute the new words as `t1 + t2` and `d + t1`, where `t1 = h + sigma_1 + ch + k + w`
+            // and `t2 = sigma_0 + maj`, summing each in the field before a single bit decomposition.
+            let t1 = [&h, &sigma_1, &ch, &round_constant, word]
+                .iter()
+                .fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+            let t2 = Field::from_bits_le(&sigma_0) + Field::from_bits_le(&maj);
+            h = g;
+            g = f;
+            f = e;
+            e = lower_bits(&(Field::from_bits_le(&d) + &t1), 64, 3);
+            d = c;
+            c = b;
+            b = a;
+            a = lower_bits(&(t1 + t2), 64, 3);
+        }
+
+        // Add the compressed words to the state.
+        state.iter().zip_eq([a, b, c, d, e, f, g, h].iter()).map(|(state, word)| add(&[state, word])).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    /// The input lengths to check, in bytes, which cover one and two blocks, and the padding boundary.
+    const INPUT_LENGTHS: [usize; 5] = [0, 3, 111, 112, 200];
+
+    fn check_hash(mode: Mode) -> Result<()> {
+        use console::Hash as H;
+
+        for num_bytes in INPUT_LENGTHS {
+            // Sample the input bits.
+            let native_input = (0..num_bytes * 8).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let input = native_input.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = console::Sha512.hash(&native_input)?;
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("SHA-512 {mode} {num_bytes}"), || {
+                let candidate = Sha512::hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        check_hash(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        check_hash(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        check_hash(Mode::Private)
+    }
+}
@@ -55,6 +55,64 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         // Ensure the final hash matches the given root.
         root.is_equal(&current_hash)
     }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, in a tree of the given depth.
+    ///
+    /// The Merkle path is padded to `DEPTH` siblings, and the siblings after the first `depth` siblings are ignored,
+    /// so the circuit is the same for every depth in `1..=DEPTH`. This costs the same as verifying a path of `DEPTH`.
+    pub fn verify_with_depth<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        depth: &U8<E>,
+    ) -> Boolean<E> {
+        // Ensure the path length matches the padded depth.
+        if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect Merkle path length")
+        }
+
+        // Returns the given value as a constant `u8`.
+        let constant_u8 = |value: u8| {
+            U8::from_bits_le(&(0..8).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect::<Vec<_>>())
+        };
+
+        // Ensure the depth is in `1..=DEPTH`.
+        let is_depth_valid = constant_u8(0).is_less_than(depth) & depth.is_less_than_or_equal(&constant_u8(DEPTH));
+
+        // Determine whether each level is within the depth, where level `i` is active if `i < depth`.
+        let is_active = (0..DEPTH).map(|i| constant_u8(i).is_less_than(depth)).collect::<Vec<_>>();
+
+        // Ensure the leaf index is within the depth, by checking that every bit above the depth is `false`.
+        let leaf_index_bits = self.leaf_index.to_bits_le();
+        let is_index_valid = leaf_index_bits.iter().enumerate().fold(Boolean::constant(true), |is_valid, (i, bit)| {
+            match is_active.get(i) {
+                Some(is_active) => is_valid & (!bit | is_active),
+                None => is_valid & !bit,
+            }
+        });
+
+        // Initialize a tracker for the current hash, by computing the leaf hash to start.
+        let mut current_hash = leaf_hasher.hash_leaf(leaf);
+
+        // Check levels between leaf level and root, where the hash is unchanged on the padded levels.
+        let levels = leaf_index_bits.iter().take(DEPTH as usize).zip_eq(&self.siblings).zip_eq(&is_active);
+        for ((bit, sibling_hash), is_active) in levels {
+            // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+            let indicator = !bit;
+            // Construct the ordering of the left & right child hash for this level.
+            let left = Field::ternary(&indicator, &current_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &current_hash);
+
+            // Update the current hash for the next level, if the level is within the depth.
+            let candidate_hash = path_hasher.hash_children(&left, &right);
+            current_hash = Field::ternary(is_active, &candidate_hash, &current_hash);
+        }
+
+        // Ensure the final hash matches the given root.
+        is_depth_valid & is_index_valid & root.is_equal(&current_hash)
+    }
 }
 
 #[cfg(all(test, console))]
@@ -148,6 +206,71 @@ mod tests {
         }};
     }
 
+    fn check_verify_with_depth(mode: Mode) -> Result<()> {
+        // Initialize the leaf hasher.
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::Poseidon4::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_leaf_hasher = Poseidon4::<Circuit>::constant(native_leaf_hasher.clone());
+
+        // Initialize the path hasher.
+        let native_path_hasher =
+            snarkvm_console_algorithms::Poseidon2::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+
+        // Compute a depth-3 Merkle tree.
+        let leaves =
+            (0..5).map(|_| (0..4).map(|_| Uniform::rand(&mut test_rng())).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let merkle_tree =
+            console::merkle_tree::MerkleTree::<_, _, _, 3>::new(&native_leaf_hasher, &native_path_hasher, &leaves)?;
+
+        for (index, merkle_leaf) in leaves.iter().enumerate() {
+            // Compute the Merkle path, padded to a depth of 8.
+            let merkle_path = merkle_tree.prove(index, merkle_leaf)?.pad::<8>()?;
+
+            // Ensure the Merkle path is valid only at the depth of the tree.
+            for depth in 0..=9u8 {
+                let expected = merkle_path.verify_with_depth(
+                    &native_leaf_hasher,
+                    &native_path_hasher,
+                    merkle_tree.root(),
+                    merkle_leaf,
+                    depth,
+                );
+                assert_eq!(depth == 3, expected);
+
+                // Initialize the Merkle path, root, leaf, and depth.
+                let path = MerklePath::<Circuit, 8>::new(mode, merkle_path.clone());
+                let root = Field::new(mode, *merkle_tree.root());
+                let leaf: Vec<_> = Inject::new(mode, merkle_leaf.clone());
+                let depth = U8::new(mode, console::U8::new(depth));
+
+                Circuit::scope(format!("Verify with depth {mode}"), || {
+                    let candidate =
+                        path.verify_with_depth(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf, &depth);
+                    assert_eq!(expected, candidate.eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_depth_constant() -> Result<()> {
+        check_verify_with_depth(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_with_depth_public() -> Result<()> {
+        check_verify_with_depth(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_with_depth_private() -> Result<()> {
+        check_verify_with_depth(Mode::Private)
+    }
+
     #[test]
     fn test_verify_bhp512_constant() -> Result<()> {
         check_verify!(BHP1024, BHP512, Constant, 32, 1024, (53360, 0, 0, 0))
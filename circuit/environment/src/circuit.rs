@@ -182,6 +182,25 @@ impl Environment for Circuit {
         })
     }
 
+    /// Returns the cached little-endian bits of the given linear combination, if it was decomposed
+    /// into `num_bits` bits of type `B` in the current circuit.
+    fn get_bits_le<B: 'static + Clone>(
+        linear_combination: &LinearCombination<Self::BaseField>,
+        num_bits: usize,
+    ) -> Option<Vec<B>> {
+        CIRCUIT.with(|circuit| {
+            let bits_le = (**circuit).borrow().get_bits_le(linear_combination, num_bits)?;
+            bits_le.downcast_ref::<Vec<B>>().cloned()
+        })
+    }
+
+    /// Caches the little-endian bits of the given linear combination in the current circuit.
+    fn set_bits_le<B: 'static + Clone>(linear_combination: &LinearCombination<Self::BaseField>, bits_le: &[B]) {
+        CIRCUIT.with(|circuit| {
+            (**circuit).borrow_mut().set_bits_le(linear_combination, bits_le.len(), Rc::new(bits_le.to_vec()))
+        })
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
@@ -369,4 +388,25 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_bits_cache() {
+        Circuit::reset();
+
+        let variable = Circuit::new_variable(Mode::Private, <Circuit as Environment>::BaseField::one());
+        let linear_combination = LinearCombination::from(variable);
+        assert!(Circuit::get_bits_le::<bool>(&linear_combination, 1).is_none());
+
+        // Ensure the bits are only cached with the `cache_bits` feature.
+        Circuit::set_bits_le(&linear_combination, &[true]);
+        match cfg!(feature = "cache_bits") {
+            true => assert_eq!(Some(vec![true]), Circuit::get_bits_le::<bool>(&linear_combination, 1)),
+            false => assert!(Circuit::get_bits_le::<bool>(&linear_combination, 1).is_none()),
+        }
+        // Ensure the bits are not returned for a different number of bits, or a different type.
+        assert!(Circuit::get_bits_le::<bool>(&linear_combination, 2).is_none());
+        assert!(Circuit::get_bits_le::<u8>(&linear_combination, 1).is_none());
+
+        Circuit::reset();
+    }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{Assignment, CircuitMetrics, Inject, LinearCombination, Mode, Variable, R1CS};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -69,6 +69,19 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         B: Into<LinearCombination<Self::BaseField>>,
         C: Into<LinearCombination<Self::BaseField>>;
 
+    /// Returns the cached little-endian bits of the given linear combination, if it was decomposed
+    /// into `num_bits` bits of type `B` in the current circuit.
+    fn get_bits_le<B: 'static + Clone>(
+        linear_combination: &LinearCombination<Self::BaseField>,
+        num_bits: usize,
+    ) -> Option<Vec<B>>;
+
+    /// Caches the little-endian bits of the given linear combination in the current circuit,
+    /// so repeated decompositions of the same linear combination do not duplicate constraints.
+    /// Note: The caller must have enforced that the bits recompose to the linear combination.
+    /// Note: The cache is a no-op unless the `cache_bits` feature is enabled.
+    fn set_bits_le<B: 'static + Clone>(linear_combination: &LinearCombination<Self::BaseField>, bits_le: &[B]);
+
     /// Adds one constraint enforcing that the given boolean is `true`.
     fn assert<Boolean: Into<LinearCombination<Self::BaseField>>>(boolean: Boolean) {
         Self::enforce(|| (boolean, Self::one(), Self::one()))
@@ -109,6 +122,11 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         (Self::num_constants(), Self::num_public(), Self::num_private(), Self::num_constraints(), Self::num_gates())
     }
 
+    /// Returns the metrics of the entire environment.
+    fn metrics() -> CircuitMetrics {
+        CircuitMetrics::from(Self::count())
+    }
+
     /// Returns the number of constants for the current scope.
     fn num_constants_in_scope() -> u64;
 
@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+/// The version of the assignment encoding.
+const ASSIGNMENT_VERSION: u16 = 0;
+
+impl<F: PrimeField> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(u64::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(u64::read_le(&mut reader)?)),
+            _ => Err(error("Invalid assignment variable")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentLC<F> {
+    /// Reads the assignment linear combination from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the constant.
+        let constant = F::read_le(&mut reader)?;
+        // Read the number of terms.
+        let num_terms = u64::read_le(&mut reader)?;
+        // Read the terms.
+        let mut terms = IndexMap::new();
+        for _ in 0..num_terms {
+            let variable = AssignmentVariable::read_le(&mut reader)?;
+            let coefficient = F::read_le(&mut reader)?;
+            // Ensure the variable is not duplicated.
+            if terms.insert(variable, coefficient).is_some() {
+                return Err(error("Duplicate variable found in an assignment linear combination"));
+            }
+        }
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentLC<F> {
+    /// Writes the assignment linear combination to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the constant.
+        self.constant.write_le(&mut writer)?;
+        // Write the number of terms.
+        (self.terms.len() as u64).write_le(&mut writer)?;
+        // Write the terms.
+        for (variable, coefficient) in &self.terms {
+            variable.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for Assignment<F> {
+    /// Reads the assignment from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != ASSIGNMENT_VERSION {
+            return Err(error("Invalid assignment version"));
+        }
+
+        // Reads the variables, which must be indexed in order from `0`.
+        let read_variables = |reader: &mut R| -> IoResult<IndexMap<Index, F>> {
+            let num_variables = u64::read_le(&mut *reader)?;
+            let mut variables = IndexMap::new();
+            for index in 0..num_variables {
+                variables.insert(index, F::read_le(&mut *reader)?);
+            }
+            Ok(variables)
+        };
+
+        // Read the public variables.
+        let public = read_variables(&mut reader)?;
+        // Read the private variables.
+        let private = read_variables(&mut reader)?;
+        // Read the number of constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+        // Read the constraints.
+        let mut constraints = Vec::new();
+        for _ in 0..num_constraints {
+            let a = AssignmentLC::read_le(&mut reader)?;
+            let b = AssignmentLC::read_le(&mut reader)?;
+            let c = AssignmentLC::read_le(&mut reader)?;
+            constraints.push((a, b, c));
+        }
+
+        // Ensure every variable in the constraints is allocated.
+        let is_allocated = |variable: &AssignmentVariable<F>| match variable {
+            AssignmentVariable::Constant(..) => true,
+            AssignmentVariable::Public(index) => public.contains_key(index),
+            AssignmentVariable::Private(index) => private.contains_key(index),
+        };
+        if !constraints.iter().all(|(a, b, c)| [a, b, c].iter().all(|lc| lc.terms.keys().all(is_allocated))) {
+            return Err(error("Unallocated variable found in an assignment constraint"));
+        }
+
+        Ok(Self { public, private, constraints })
+    }
+}
+
+impl<F: PrimeField> ToBytes for Assignment<F> {
+    /// Writes the assignment to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        ASSIGNMENT_VERSION.write_le(&mut writer)?;
+
+        // Write the variables, in order of their index.
+        let write_variables = |writer: &mut W, variables: &IndexMap<Index, F>| -> IoResult<()> {
+            (variables.len() as u64).write_le(&mut *writer)?;
+            for (i, (index, value)) in variables.iter().enumerate() {
+                if i as u64 != *index {
+                    return Err(error("Assignment variables must be indexed in order"));
+                }
+                value.write_le(&mut *writer)?;
+            }
+            Ok(())
+        };
+
+        // Write the public variables.
+        write_variables(&mut writer, &self.public)?;
+        // Write the private variables.
+        write_variables(&mut writer, &self.private)?;
+        // Write the number of constraints.
+        (self.constraints.len() as u64).write_le(&mut writer)?;
+        // Write the constraints.
+        for (a, b, c) in &self.constraints {
+            a.write_le(&mut writer)?;
+            b.write_le(&mut writer)?;
+            c.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::{environment::Assignment, prelude::*};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    type ConsoleField = snarkvm_console_types::Field<<Circuit as Environment>::Network>;
+
+    /// Returns an assignment of `a * b == c`, where `c` is public.
+    fn sample_assignment() -> Assignment<Fr> {
+        let a = Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(3));
+        let b = Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(5));
+        let c = Field::<Circuit>::new(Mode::Public, ConsoleField::from_u64(15));
+        Circuit::enforce(|| (&a, &b, &c));
+        assert!(Circuit::is_satisfied());
+        Circuit::eject_assignment_and_reset()
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = sample_assignment();
+        assert!(expected.is_satisfied());
+
+        // Ensure the assignment round trips, and remains satisfied.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate = Assignment::<Fr>::read_le(&expected_bytes[..]).unwrap();
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+        assert_eq!(expected.public_inputs(), candidate.public_inputs());
+        assert_eq!(expected.num_private(), candidate.num_private());
+        assert_eq!(expected.num_constraints(), candidate.num_constraints());
+        assert!(candidate.is_satisfied());
+
+        // Ensure an unknown version is rejected.
+        let mut bytes = expected_bytes.clone();
+        bytes[0] = 1;
+        assert!(Assignment::<Fr>::read_le(&bytes[..]).is_err());
+        // Ensure a truncated assignment is rejected.
+        assert!(Assignment::<Fr>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_bytes_tampered_witness() {
+        let expected = sample_assignment();
+        let mut bytes = expected.to_bytes_le().unwrap();
+
+        // Overwrite the first private variable, which follows the version, and the public variables.
+        let offset = 2 + 8 + expected.num_public() as usize * 32 + 8;
+        bytes[offset..offset + 32].copy_from_slice(&Fr::from(4u64).to_bytes_le().unwrap());
+
+        // Ensure the replayed assignment is no longer satisfied.
+        let candidate = Assignment::<Fr>::read_le(&bytes[..]).unwrap();
+        assert!(!candidate.is_satisfied());
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{biginteger::BigInteger, ToBytes};
+
+use std::io::{Error, ErrorKind, Result as IoResult, Write};
+
+/// The magic number of the `.r1cs` binary format.
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+/// The version of the `.r1cs` binary format.
+const R1CS_VERSION: u32 = 1;
+/// The section type of the header.
+const R1CS_HEADER_SECTION: u32 = 1;
+/// The section type of the constraints.
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+/// The section type of the wire-to-label map.
+const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the number of wires in the exported constraint system, which includes the constant `1` wire.
+    pub fn num_wires(&self) -> u64 {
+        self.num_public() + self.num_private()
+    }
+
+    /// Returns the wire of the given variable in the exported constraint system.
+    ///
+    /// The wires are ordered as in the `.r1cs` format: the constant `1` wire, which is public variable `0`,
+    /// then the remaining public variables, and then the private variables.
+    fn to_wire(&self, variable: &AssignmentVariable<F>) -> u64 {
+        match variable {
+            AssignmentVariable::Constant(..) => 0,
+            AssignmentVariable::Public(index) => *index,
+            AssignmentVariable::Private(index) => self.num_public() + *index,
+        }
+    }
+
+    /// Returns the terms of the given linear combination, as `(wire, coefficient)` pairs,
+    /// where the constants of the linear combination are accumulated into the constant `1` wire.
+    fn to_wire_terms(&self, lc: &AssignmentLC<F>) -> Vec<(u64, F)> {
+        // Keep an accumulator for constant values in the linear combination, as in the constraint converter.
+        let mut constant_accumulator = lc.constant;
+        let mut terms = Vec::with_capacity(lc.terms.len() + 1);
+        for (variable, coefficient) in lc.terms.iter() {
+            match variable {
+                AssignmentVariable::Constant(value) => constant_accumulator += *value,
+                _ => terms.push((self.to_wire(variable), *coefficient)),
+            }
+        }
+        if !constant_accumulator.is_zero() {
+            terms.insert(0, (0, constant_accumulator));
+        }
+        // Note: The `.r1cs` format does not require zero coefficients, so they are omitted.
+        terms.retain(|(_, coefficient)| !coefficient.is_zero());
+        terms
+    }
+
+    /// Writes the constraint system in the `.r1cs` binary format of circom, which can be read by snarkjs,
+    /// and by the circom readers of arkworks.
+    ///
+    /// All public variables, except for the constant `1`, are exported as public inputs,
+    /// and all private variables are exported as internal wires. Each wire is labelled with its own index.
+    /// Note: This exports the constraint system only; the witness is not written.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the wires and constraints can be indexed in the format.
+        let num_wires = u32::try_from(self.num_wires())
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Too many wires for the '.r1cs' format"))?;
+        let num_constraints = u32::try_from(self.num_constraints())
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Too many constraints for the '.r1cs' format"))?;
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        // The number of bytes of a field element, and the modulus.
+        ((F::BigInteger::NUM_LIMBS * 8) as u32).write_le(&mut header)?;
+        F::modulus().write_le(&mut header)?;
+        num_wires.write_le(&mut header)?;
+        // The number of public outputs.
+        0u32.write_le(&mut header)?;
+        // The number of public inputs, excluding the constant `1`.
+        ((self.num_public() - 1) as u32).write_le(&mut header)?;
+        // The number of private inputs.
+        0u32.write_le(&mut header)?;
+        // The number of labels.
+        (num_wires as u64).write_le(&mut header)?;
+        num_constraints.write_le(&mut header)?;
+
+        // Construct the constraints section.
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.constraints.iter() {
+            for lc in [a, b, c] {
+                let terms = self.to_wire_terms(lc);
+                (terms.len() as u32).write_le(&mut constraints)?;
+                for (wire, coefficient) in terms {
+                    (wire as u32).write_le(&mut constraints)?;
+                    coefficient.to_repr().write_le(&mut constraints)?;
+                }
+            }
+        }
+
+        // Construct the wire-to-label section.
+        let mut labels = Vec::with_capacity(num_wires as usize * 8);
+        for wire in 0..num_wires as u64 {
+            wire.write_le(&mut labels)?;
+        }
+
+        // Write the file header.
+        writer.write_all(R1CS_MAGIC)?;
+        R1CS_VERSION.write_le(&mut writer)?;
+        3u32.write_le(&mut writer)?;
+        // Write each section, prefixed by its type and size in bytes.
+        for (section_type, section) in [
+            (R1CS_HEADER_SECTION, header),
+            (R1CS_CONSTRAINTS_SECTION, constraints),
+            (R1CS_WIRE_TO_LABEL_SECTION, labels),
+        ] {
+            section_type.write_le(&mut writer)?;
+            (section.len() as u64).write_le(&mut writer)?;
+            writer.write_all(&section)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the constraint system in the `.r1cs` binary format of circom.
+    pub fn to_r1cs_bytes(&self) -> IoResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_r1cs(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the constraint system as a JSON string, in the form of `snarkjs r1cs export json`,
+    /// for debugging and external analysis. The field elements are written in decimal.
+    pub fn to_r1cs_json(&self) -> String {
+        // Returns the given linear combination as a JSON object, from wire to coefficient.
+        let lc_to_json = |lc: &AssignmentLC<F>| {
+            let terms = self.to_wire_terms(lc);
+            let terms = terms.iter().map(|(wire, coefficient)| format!("\"{wire}\":\"{coefficient}\""));
+            format!("{{{}}}", terms.collect::<Vec<_>>().join(","))
+        };
+
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|(a, b, c)| format!("[{},{},{}]", lc_to_json(a), lc_to_json(b), lc_to_json(c)))
+            .collect::<Vec<_>>();
+        let map = (0..self.num_wires()).map(|wire| wire.to_string()).collect::<Vec<_>>();
+
+        format!(
+            "{{\"n8\":{},\"prime\":\"{}\",\"nVars\":{},\"nOutputs\":0,\"nPubInputs\":{},\"nPrvInputs\":0,\
+             \"nLabels\":{},\"nConstraints\":{},\"constraints\":[{}],\"map\":[{}]}}",
+            F::BigInteger::NUM_LIMBS * 8,
+            F::modulus(),
+            self.num_wires(),
+            self.num_public() - 1,
+            self.num_wires(),
+            self.num_constraints(),
+            constraints.join(","),
+            map.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_circuit::environment::Assignment;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::ToBytes;
+
+    type ConsoleField = snarkvm_console_types::Field<<Circuit as Environment>::Network>;
+
+    /// Reads a little-endian `u32` at the given offset.
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// Reads a little-endian `u64` at the given offset.
+    fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+    }
+
+    /// Returns an assignment of `a * b == c`, and `a + 2 == d`, where `c` and `d` are public.
+    fn sample_assignment() -> Assignment<Fr> {
+        let a = Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(3));
+        let b = Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(5));
+        let c = Field::<Circuit>::new(Mode::Public, ConsoleField::from_u64(15));
+        let d = Field::<Circuit>::new(Mode::Public, ConsoleField::from_u64(5));
+        Circuit::enforce(|| (&a, &b, &c));
+        Circuit::assert_eq(&a + Field::constant(ConsoleField::from_u64(2)), &d);
+        assert!(Circuit::is_satisfied());
+        Circuit::eject_assignment_and_reset()
+    }
+
+    #[test]
+    fn test_r1cs_bytes() {
+        let assignment = sample_assignment();
+        let bytes = assignment.to_r1cs_bytes().unwrap();
+
+        // Ensure the file header.
+        assert_eq!(b"r1cs", &bytes[0..4]);
+        assert_eq!(1, read_u32(&bytes, 4));
+        assert_eq!(3, read_u32(&bytes, 8));
+
+        // Ensure the header section.
+        assert_eq!(1, read_u32(&bytes, 12));
+        let header_size = read_u64(&bytes, 16) as usize;
+        assert_eq!(4 + 32 + 4 * 4 + 8 + 4, header_size);
+        assert_eq!(32, read_u32(&bytes, 24));
+        assert_eq!(Fr::modulus().to_bytes_le().unwrap(), bytes[28..60]);
+        // The wires are the constant `1`, the 2 public inputs, and the 2 private variables.
+        assert_eq!(5, read_u32(&bytes, 60));
+        assert_eq!(0, read_u32(&bytes, 64));
+        assert_eq!(2, read_u32(&bytes, 68));
+        assert_eq!(0, read_u32(&bytes, 72));
+        assert_eq!(5, read_u64(&bytes, 76));
+        assert_eq!(2, read_u32(&bytes, 84));
+
+        // Ensure the first constraint is `a * b == c`, where `a` and `b` are wires 3 and 4, and `c` is wire 1.
+        let offset = 12 + 12 + header_size;
+        assert_eq!(2, read_u32(&bytes, offset));
+        let constraints_size = read_u64(&bytes, offset + 4) as usize;
+        let constraints = &bytes[offset + 12..offset + 12 + constraints_size];
+        let one = Fr::one().to_repr().to_bytes_le().unwrap();
+        for (i, wire) in [3u32, 4, 1].into_iter().enumerate() {
+            let start = i * (4 + 4 + 32);
+            assert_eq!(1, read_u32(constraints, start));
+            assert_eq!(wire, read_u32(constraints, start + 4));
+            assert_eq!(one, constraints[start + 8..start + 40]);
+        }
+
+        // Ensure the wire-to-label section is the identity map.
+        let offset = offset + 12 + constraints_size;
+        assert_eq!(3, read_u32(&bytes, offset));
+        assert_eq!(5 * 8, read_u64(&bytes, offset + 4));
+        for wire in 0..5 {
+            assert_eq!(wire as u64, read_u64(&bytes, offset + 12 + wire * 8));
+        }
+        assert_eq!(offset + 12 + 5 * 8, bytes.len());
+    }
+
+    #[test]
+    fn test_r1cs_json() {
+        let assignment = sample_assignment();
+        let json = assignment.to_r1cs_json();
+
+        assert!(json.starts_with(&format!("{{\"n8\":32,\"prime\":\"{}\",\"nVars\":5,", Fr::modulus())));
+        assert!(json.contains("\"nPubInputs\":2,\"nPrvInputs\":0,\"nLabels\":5,\"nConstraints\":2,"));
+        // Ensure the constraints are exported in order.
+        assert!(json.contains("\"constraints\":[[{\"3\":\"1\"},{\"4\":\"1\"},{\"1\":\"1\"}],"));
+        assert!(json.ends_with("\"map\":[0,1,2,3,4]}"));
+    }
+}
@@ -14,11 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod bytes;
+mod export;
+
 use crate::Index;
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::cfg_iter;
 
 use indexmap::IndexMap;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of constraints that are converted concurrently, before they are enforced.
+const CONSTRAINT_CHUNK_SIZE: usize = 1 << 14;
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 enum AssignmentVariable<F: PrimeField> {
     Constant(F),
@@ -101,6 +111,25 @@ impl<F: PrimeField> Assignment<F> {
     pub fn num_constraints(&self) -> u64 {
         self.constraints.len() as u64
     }
+
+    /// Returns `true` if all constraints in the assignment are satisfied by its variables,
+    /// such as to re-verify an assignment that was read from bytes.
+    pub fn is_satisfied(&self) -> bool {
+        // Evaluates a linear combination on the variables of the assignment.
+        let evaluate = |lc: &AssignmentLC<F>| -> Option<F> {
+            // Note: Constant terms are accumulated without their coefficient, as in the constraint converter.
+            lc.terms.iter().try_fold(lc.constant, |sum, (variable, coefficient)| match variable {
+                AssignmentVariable::Constant(value) => Some(sum + value),
+                AssignmentVariable::Public(index) => Some(sum + *self.public.get(index)? * coefficient),
+                AssignmentVariable::Private(index) => Some(sum + *self.private.get(index)? * coefficient),
+            })
+        };
+
+        cfg_iter!(self.constraints).all(|(a, b, c)| match (evaluate(a), evaluate(b), evaluate(c)) {
+            (Some(a), Some(b), Some(c)) => a * b == c,
+            _ => false,
+        })
+    }
 }
 
 impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
@@ -156,56 +185,60 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
             assert!(result.is_none(), "Overwrote an existing private variable in the converter");
         }
 
-        // Enforce all of the constraints.
-        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
-            // Converts terms from one linear combination in the first system to the second system.
-            let convert_linear_combination = |lc: &AssignmentLC<F>| -> snarkvm_r1cs::LinearCombination<F> {
-                // Initialize a linear combination for the second system.
-                let mut linear_combination = snarkvm_r1cs::LinearCombination::<F>::zero();
-
-                // Keep an accumulator for constant values in the linear combination.
-                let mut constant_accumulator = lc.constant;
-                // Process every term in the linear combination.
-                for (variable, coefficient) in lc.terms.iter() {
-                    match variable {
-                        AssignmentVariable::Constant(value) => {
-                            constant_accumulator += *value;
-                        }
-                        AssignmentVariable::Public(index) => {
-                            let gadget = converter.public.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_r1cs::Index::Public((index + 1) as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
-                        AssignmentVariable::Private(index) => {
-                            let gadget = converter.private.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_r1cs::Index::Private(*index as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The private variable in the second system must match the first system"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
+        // Converts terms from one linear combination in the first system to the second system.
+        let convert_linear_combination = |lc: &AssignmentLC<F>| -> snarkvm_r1cs::LinearCombination<F> {
+            // Initialize a linear combination for the second system.
+            let mut linear_combination = snarkvm_r1cs::LinearCombination::<F>::zero();
+
+            // Keep an accumulator for constant values in the linear combination.
+            let mut constant_accumulator = lc.constant;
+            // Process every term in the linear combination.
+            for (variable, coefficient) in lc.terms.iter() {
+                match variable {
+                    AssignmentVariable::Constant(value) => {
+                        constant_accumulator += *value;
+                    }
+                    AssignmentVariable::Public(index) => {
+                        let gadget = converter.public.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_r1cs::Index::Public((index + 1) as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                        );
+                        linear_combination += (*coefficient, *gadget);
+                    }
+                    AssignmentVariable::Private(index) => {
+                        let gadget = converter.private.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_r1cs::Index::Private(*index as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The private variable in the second system must match the first system"
+                        );
+                        linear_combination += (*coefficient, *gadget);
                     }
                 }
+            }
 
-                // Finally, add the accumulated constant value to the linear combination.
-                linear_combination +=
-                    (constant_accumulator, snarkvm_r1cs::Variable::new_unchecked(snarkvm_r1cs::Index::Public(0)));
+            // Finally, add the accumulated constant value to the linear combination.
+            linear_combination +=
+                (constant_accumulator, snarkvm_r1cs::Variable::new_unchecked(snarkvm_r1cs::Index::Public(0)));
 
-                // Return the linear combination of the second system.
-                linear_combination
-            };
+            // Return the linear combination of the second system.
+            linear_combination
+        };
 
-            cs.enforce(
-                || format!("Constraint {i}"),
-                |lc| lc + convert_linear_combination(a),
-                |lc| lc + convert_linear_combination(b),
-                |lc| lc + convert_linear_combination(c),
-            );
+        // Enforce all of the constraints, converting the linear combinations of each chunk concurrently.
+        for (chunk_index, chunk) in self.constraints.chunks(CONSTRAINT_CHUNK_SIZE).enumerate() {
+            let constraints = cfg_iter!(chunk)
+                .map(|(a, b, c)| {
+                    (convert_linear_combination(a), convert_linear_combination(b), convert_linear_combination(c))
+                })
+                .collect::<Vec<_>>();
+
+            for (j, (a, b, c)) in constraints.into_iter().enumerate() {
+                let i = chunk_index * CONSTRAINT_CHUNK_SIZE + j;
+                cs.enforce(|| format!("Constraint {i}"), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            }
         }
 
         // Ensure the given `cs` matches in size with the first system.
@@ -126,6 +126,14 @@ impl<F: PrimeField> LinearCombination<F> {
         &self.terms
     }
 
+    /// Returns the constant and the terms sorted by variable, which identify the linear combination.
+    pub(super) fn to_key(&self) -> (F, Vec<(Variable<F>, F)>) {
+        let mut terms =
+            self.terms.iter().map(|(variable, coefficient)| (variable.clone(), *coefficient)).collect::<Vec<_>>();
+        terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+        (self.constant, terms)
+    }
+
     /// Returns the number of addition gates in the linear combination.
     pub(super) fn num_additions(&self) -> u64 {
         // Increment by one if the constant is nonzero and the number of terms is nonzero.
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::{
+    fmt,
+    ops::{Add, Sub},
+};
+
+/// The number of constants, public variables, private variables, constraints, and gates in a circuit,
+/// or in a section of a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CircuitMetrics {
+    num_constants: u64,
+    num_public: u64,
+    num_private: u64,
+    num_constraints: u64,
+    num_gates: u64,
+}
+
+impl CircuitMetrics {
+    /// Initializes new metrics.
+    pub const fn new(
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        num_gates: u64,
+    ) -> Self {
+        Self { num_constants, num_public, num_private, num_constraints, num_gates }
+    }
+
+    /// Returns the number of constants.
+    pub const fn num_constants(&self) -> u64 {
+        self.num_constants
+    }
+
+    /// Returns the number of public variables.
+    pub const fn num_public(&self) -> u64 {
+        self.num_public
+    }
+
+    /// Returns the number of private variables.
+    pub const fn num_private(&self) -> u64 {
+        self.num_private
+    }
+
+    /// Returns the number of public and private variables.
+    pub const fn num_variables(&self) -> u64 {
+        self.num_public + self.num_private
+    }
+
+    /// Returns the number of constraints.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the number of gates.
+    pub const fn num_gates(&self) -> u64 {
+        self.num_gates
+    }
+
+    /// Returns the average number of gates per constraint, or `0.0` if there are no constraints.
+    pub fn density(&self) -> f64 {
+        match self.num_constraints {
+            0 => 0.0,
+            num_constraints => self.num_gates as f64 / num_constraints as f64,
+        }
+    }
+}
+
+impl From<(u64, u64, u64, u64, u64)> for CircuitMetrics {
+    /// Initializes new metrics from a tuple of `(constants, public, private, constraints, gates)`.
+    fn from((num_constants, num_public, num_private, num_constraints, num_gates): (u64, u64, u64, u64, u64)) -> Self {
+        Self::new(num_constants, num_public, num_private, num_constraints, num_gates)
+    }
+}
+
+impl Add for CircuitMetrics {
+    type Output = Self;
+
+    /// Returns the sum of the metrics.
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(
+            self.num_constants + other.num_constants,
+            self.num_public + other.num_public,
+            self.num_private + other.num_private,
+            self.num_constraints + other.num_constraints,
+            self.num_gates + other.num_gates,
+        )
+    }
+}
+
+impl Sub for CircuitMetrics {
+    type Output = Self;
+
+    /// Returns the difference of the metrics, such as between two points in the synthesis of a circuit.
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(
+            self.num_constants.saturating_sub(other.num_constants),
+            self.num_public.saturating_sub(other.num_public),
+            self.num_private.saturating_sub(other.num_private),
+            self.num_constraints.saturating_sub(other.num_constraints),
+            self.num_gates.saturating_sub(other.num_gates),
+        )
+    }
+}
+
+impl fmt::Display for CircuitMetrics {
+    /// Prints the metrics.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Constant: {}, Public: {}, Private: {}, Constraints: {}, Gates: {}, Density: {:.2}",
+            self.num_constants,
+            self.num_public,
+            self.num_private,
+            self.num_constraints,
+            self.num_gates,
+            self.density()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_metrics() {
+        Circuit::reset();
+
+        // Ensure the metrics of an empty circuit only contain the public variable for `one`.
+        let start = Circuit::metrics();
+        assert_eq!(CircuitMetrics::new(0, 1, 0, 0, 0), start);
+        assert_eq!(0.0, start.density());
+
+        // Add a constraint on two private variables.
+        let a = Circuit::new_variable(Mode::Private, Default::default());
+        let b = Circuit::new_variable(Mode::Private, Default::default());
+        Circuit::enforce(|| (a, Circuit::one(), b));
+
+        // Ensure the difference counts the new variables and constraint.
+        let section = Circuit::metrics() - start;
+        assert_eq!(2, section.num_private());
+        assert_eq!(2, section.num_variables());
+        assert_eq!(1, section.num_constraints());
+        assert_eq!(section.num_gates() as f64, section.density());
+        assert_eq!(Circuit::metrics(), start + section);
+
+        Circuit::reset();
+    }
+}
@@ -20,10 +20,13 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 
-use std::rc::Rc;
+use std::{any::Any, collections::HashMap, rc::Rc};
 
 pub type Scope = String;
 
+/// The key of a cached bit decomposition, as the identifying key of the linear combination and the number of bits.
+type BitsKey<F> = ((F, Vec<(Variable<F>, F)>), usize);
+
 #[derive(Debug)]
 pub struct R1CS<F: PrimeField> {
     constants: Vec<Variable<F>>,
@@ -32,6 +35,8 @@ pub struct R1CS<F: PrimeField> {
     constraints: Vec<Constraint<F>>,
     counter: Counter<F>,
     gates: u64,
+    /// The bit decompositions of the linear combinations in the constraint system.
+    bits_le: HashMap<BitsKey<F>, Rc<dyn Any>>,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -44,6 +49,7 @@ impl<F: PrimeField> R1CS<F> {
             constraints: Default::default(),
             counter: Default::default(),
             gates: 0,
+            bits_le: Default::default(),
         }
     }
 
@@ -88,6 +94,23 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.add_constraint(constraint);
     }
 
+    /// Returns the cached `num_bits` little-endian bits of the given linear combination, if they exist.
+    /// Note: The cache is only enabled with the `cache_bits` feature, as it changes the constraint counts.
+    pub(crate) fn get_bits_le(&self, lc: &LinearCombination<F>, num_bits: usize) -> Option<Rc<dyn Any>> {
+        match cfg!(feature = "cache_bits") && !lc.is_constant() {
+            true => self.bits_le.get(&(lc.to_key(), num_bits)).cloned(),
+            false => None,
+        }
+    }
+
+    /// Caches the `num_bits` little-endian bits of the given linear combination.
+    /// Note: The bits of a constant are not cached, as they do not incur constraints.
+    pub(crate) fn set_bits_le(&mut self, lc: &LinearCombination<F>, num_bits: usize, bits_le: Rc<dyn Any>) {
+        if cfg!(feature = "cache_bits") && !lc.is_constant() {
+            self.bits_le.insert((lc.to_key(), num_bits), bits_le);
+        }
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     pub(crate) fn is_satisfied(&self) -> bool {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
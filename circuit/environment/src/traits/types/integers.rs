@@ -19,8 +19,12 @@ use crate::prelude::*;
 /// Representation of an integer.
 pub trait IntegerTrait<I: IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16>, U32: IntegerCore<u32>>:
     IntegerCore<I>
+    + FunnelShl<U8, Output = Self>
+    + FunnelShr<U8, Output = Self>
     + PowChecked<U8, Output = Self>
     + PowWrapped<U8, Output = Self>
+    + Rotl<U8, Output = Self>
+    + Rotr<U8, Output = Self>
     + Shl<U8, Output = Self>
     + ShlAssign<U8>
     + ShlChecked<U8, Output = Self>
@@ -29,8 +33,12 @@ pub trait IntegerTrait<I: IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16
     + ShrAssign<U8>
     + ShrChecked<U8, Output = Self>
     + ShrWrapped<U8, Output = Self>
+    + FunnelShl<U16, Output = Self>
+    + FunnelShr<U16, Output = Self>
     + PowChecked<U16, Output = Self>
     + PowWrapped<U16, Output = Self>
+    + Rotl<U16, Output = Self>
+    + Rotr<U16, Output = Self>
     + Shl<U16, Output = Self>
     + ShlAssign<U16>
     + ShlChecked<U16, Output = Self>
@@ -39,8 +47,12 @@ pub trait IntegerTrait<I: IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16
     + ShrAssign<U16>
     + ShrChecked<U16, Output = Self>
     + ShrWrapped<U16, Output = Self>
+    + FunnelShl<U32, Output = Self>
+    + FunnelShr<U32, Output = Self>
     + PowChecked<U32, Output = Self>
     + PowWrapped<U32, Output = Self>
+    + Rotl<U32, Output = Self>
+    + Rotr<U32, Output = Self>
     + Shl<U32, Output = Self>
     + ShlAssign<U32>
     + ShlChecked<U32, Output = Self>
@@ -313,6 +313,20 @@ impl Environment for AleoV0 {
         E::enforce(constraint)
     }
 
+    /// Returns the cached little-endian bits of the given linear combination, if it was decomposed
+    /// into `num_bits` bits of type `B` in the current circuit.
+    fn get_bits_le<B: 'static + Clone>(
+        linear_combination: &LinearCombination<Self::BaseField>,
+        num_bits: usize,
+    ) -> Option<Vec<B>> {
+        E::get_bits_le(linear_combination, num_bits)
+    }
+
+    /// Caches the little-endian bits of the given linear combination in the current circuit.
+    fn set_bits_le<B: 'static + Clone>(linear_combination: &LinearCombination<Self::BaseField>, bits_le: &[B]) {
+        E::set_bits_le(linear_combination, bits_le)
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         E::is_satisfied()
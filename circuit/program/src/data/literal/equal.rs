@@ -38,6 +38,8 @@ impl<A: Aleo> Equal<Self> for Literal<A> {
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_equal(b),
             _ => Boolean::constant(false),
         }
     }
@@ -61,6 +63,8 @@ impl<A: Aleo> Equal<Self> for Literal<A> {
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_not_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_not_equal(b),
             _ => Boolean::constant(true),
         }
     }
@@ -37,7 +37,9 @@ impl<A: Aleo> Literal<A> {
             13 => Literal::U128(U128::from_bits_le(literal)),
             14 => Literal::Scalar(Scalar::from_bits_le(literal)),
             15 => Literal::String(StringType::from_bits_le(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
+            16 => Literal::I256(I256::from_bits_le(literal)),
+            17 => Literal::U256(U256::from_bits_le(literal)),
+            18.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
         }
     }
 
@@ -61,7 +63,9 @@ impl<A: Aleo> Literal<A> {
             13 => Literal::U128(U128::from_bits_be(literal)),
             14 => Literal::Scalar(Scalar::from_bits_be(literal)),
             15 => Literal::String(StringType::from_bits_be(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
+            16 => Literal::I256(I256::from_bits_be(literal)),
+            17 => Literal::U256(U256::from_bits_be(literal)),
+            18.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
         }
     }
 }
@@ -134,6 +138,8 @@ mod tests {
             let range = 0..rng.gen_range(0..Circuit::MAX_STRING_BYTES / 4);
             let string: String = range.map(|_| rng.gen::<char>()).collect();
             check_serialization(Literal::<Circuit>::String(StringType::new(mode, console::StringType::new(&string))));
+            check_serialization(Literal::<Circuit>::I256(I256::new(mode, Uniform::rand(rng))));
+            check_serialization(Literal::<Circuit>::U256(U256::new(mode, Uniform::rand(rng))));
         }
     }
 
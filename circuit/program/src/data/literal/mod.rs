@@ -60,6 +60,10 @@ pub enum Literal<A: Aleo> {
     Scalar(Scalar<A>),
     /// The string type.
     String(StringType<A>),
+    /// The 256-bit signed integer type.
+    I256(I256<A>),
+    /// The 256-bit unsigned integer type.
+    U256(U256<A>),
 }
 
 #[cfg(console)]
@@ -85,6 +89,8 @@ impl<A: Aleo> Inject for Literal<A> {
             Self::Primitive::U128(u128) => Self::U128(U128::new(mode, u128)),
             Self::Primitive::Scalar(scalar) => Self::Scalar(Scalar::new(mode, scalar)),
             Self::Primitive::String(string) => Self::String(StringType::new(mode, string)),
+            Self::Primitive::I256(i256) => Self::I256(I256::new(mode, i256)),
+            Self::Primitive::U256(u256) => Self::U256(U256::new(mode, u256)),
         }
     }
 }
@@ -112,6 +118,8 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::U128(literal) => literal.eject_mode(),
             Self::Scalar(literal) => literal.eject_mode(),
             Self::String(literal) => literal.eject_mode(),
+            Self::I256(literal) => literal.eject_mode(),
+            Self::U256(literal) => literal.eject_mode(),
         }
     }
 
@@ -134,6 +142,8 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::U128(literal) => Self::Primitive::U128(literal.eject_value()),
             Self::Scalar(literal) => Self::Primitive::Scalar(literal.eject_value()),
             Self::String(literal) => Self::Primitive::String(literal.eject_value()),
+            Self::I256(literal) => Self::Primitive::I256(literal.eject_value()),
+            Self::U256(literal) => Self::Primitive::U256(literal.eject_value()),
         }
     }
 }
@@ -160,6 +170,8 @@ impl<A: Aleo> Parser for Literal<A> {
             map(U128::parse, |literal| Self::U128(literal)),
             map(Scalar::parse, |literal| Self::Scalar(literal)),
             map(StringType::parse, |literal| Self::String(literal)),
+            map(I256::parse, |literal| Self::I256(literal)),
+            map(U256::parse, |literal| Self::U256(literal)),
         ))(string)
     }
 }
@@ -204,6 +216,8 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => U128::<A>::type_name(),
             Self::Scalar(..) => Scalar::<A>::type_name(),
             Self::String(..) => StringType::<A>::type_name(),
+            Self::I256(..) => I256::<A>::type_name(),
+            Self::U256(..) => U256::<A>::type_name(),
         }
     }
 }
@@ -235,6 +249,8 @@ impl<A: Aleo> Display for Literal<A> {
             Self::U128(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::I256(literal) => Display::fmt(literal, f),
+            Self::U256(literal) => Display::fmt(literal, f),
         }
     }
 }
@@ -36,6 +36,8 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => console::U128::<A::Network>::size_in_bits() as u16,
             Self::Scalar(..) => console::Scalar::<A::Network>::size_in_bits() as u16,
             Self::String(string) => string.to_bits_le().len() as u16,
+            Self::I256(..) => console::I256::<A::Network>::size_in_bits() as u16,
+            Self::U256(..) => console::U256::<A::Network>::size_in_bits() as u16,
         }))
     }
 }
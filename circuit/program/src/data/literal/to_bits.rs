@@ -52,6 +52,8 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::U128(literal) => literal.to_bits_le(),
             Literal::Scalar(literal) => literal.to_bits_le(),
             Literal::String(literal) => literal.to_bits_le(),
+            Literal::I256(literal) => literal.to_bits_le(),
+            Literal::U256(literal) => literal.to_bits_le(),
         }
     }
 
@@ -74,6 +76,8 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::U128(literal) => literal.to_bits_be(),
             Literal::Scalar(literal) => literal.to_bits_be(),
             Literal::String(literal) => literal.to_bits_be(),
+            Literal::I256(literal) => literal.to_bits_be(),
+            Literal::U256(literal) => literal.to_bits_be(),
         }
     }
 }
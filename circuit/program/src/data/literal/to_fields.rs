@@ -47,6 +47,13 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::U128(literal) => vec![literal.to_field()],
             Literal::Scalar(literal) => vec![literal.to_field()],
             Literal::String(literal) => literal.to_fields(),
+            // Note: A 256-bit integer does not fit in a field element, so its bits are packed into field elements.
+            Literal::I256(literal) => {
+                literal.to_bits_le().chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+            }
+            Literal::U256(literal) => {
+                literal.to_bits_le().chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+            }
         }
     }
 }
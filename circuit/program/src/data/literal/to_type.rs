@@ -37,6 +37,8 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => console::LiteralType::U128,
             Self::Scalar(..) => console::LiteralType::Scalar,
             Self::String(..) => console::LiteralType::String,
+            Self::I256(..) => console::LiteralType::I256,
+            Self::U256(..) => console::LiteralType::U256,
         }
     }
 }
@@ -36,6 +36,8 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => console::U8::new(13),
             Self::Scalar(..) => console::U8::new(14),
             Self::String(..) => console::U8::new(15),
+            Self::I256(..) => console::U8::new(16),
+            Self::U256(..) => console::U8::new(17),
         })
     }
 }
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns the number of elements in the array.
+    pub fn array_len(&self) -> U32<A> {
+        match self {
+            Self::Array(_, length, ..) => length.clone(),
+            _ => A::halt("Plaintext is not an array"),
+        }
+    }
+
+    /// Returns the element of the array at the given index.
+    /// This method halts if the index is not less than the number of elements.
+    pub fn array_get(&self, index: &U32<A>) -> Literal<A> {
+        match self {
+            Self::Array(array_type, length, elements, _) => {
+                // Ensure the index is less than the number of elements.
+                A::assert(index.is_less_than(length));
+                // Select the bits of the element at the index.
+                let mut bits_le = elements[0].to_bits_le();
+                for (position, element) in elements.iter().enumerate().skip(1) {
+                    let is_selected = index.is_equal(&U32::constant(console::U32::new(position as u32)));
+                    bits_le = element
+                        .to_bits_le()
+                        .iter()
+                        .zip_eq(&bits_le)
+                        .map(|(element_bit, bit)| Boolean::ternary(&is_selected, element_bit, bit))
+                        .collect();
+                }
+                // Recover the element from the selected bits.
+                Literal::from_bits_le(&U8::constant(console::U8::new(array_type.element_type() as u8)), &bits_le)
+            }
+            _ => A::halt("Plaintext is not an array"),
+        }
+    }
+
+    /// Ensures the number of elements is within the capacity, and the unused capacity is filled with zero bits.
+    pub(super) fn enforce_array(
+        array_type: &console::ArrayType<A::Network>,
+        length: &U32<A>,
+        elements: &[Literal<A>],
+    ) {
+        // Ensure the number of elements is within the capacity.
+        A::assert(length.is_less_than_or_equal(&U32::constant(*array_type.capacity())));
+        // Ensure each element beyond the number of elements is zero.
+        for (position, element) in elements.iter().enumerate() {
+            let is_used = U32::constant(console::U32::new(position as u32)).is_less_than(length);
+            let is_zero = element.to_bits_le().iter().fold(Boolean::constant(true), |is_zero, bit| is_zero & !bit);
+            A::assert(is_used | is_zero);
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn sample_array(mode: Mode) -> Plaintext<Circuit> {
+        let plaintext = console::Plaintext::<<Circuit as Environment>::Network>::from_str(
+            "[1u64, 2u64, 3u64] as [u64; 4u32]",
+        )
+        .unwrap();
+        Plaintext::new(mode, plaintext)
+    }
+
+    #[test]
+    fn test_array_inject_and_eject() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let expected = console::Plaintext::from_str("[1u64, 2u64, 3u64] as [u64; 4u32]").unwrap();
+            let candidate = sample_array(mode);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode, candidate.eject_mode());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_array_len() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = sample_array(mode).array_len();
+            assert_eq!(console::U32::new(3), candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_array_get() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for (index, expected) in [1u64, 2, 3].into_iter().enumerate() {
+                let array = sample_array(mode);
+                let index = U32::new(mode, console::U32::new(index as u32));
+                let candidate = array.array_get(&index);
+                assert_eq!(console::Literal::U64(console::U64::new(expected)), candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+
+        // Ensure an index beyond the number of elements is not satisfied.
+        let array = sample_array(Mode::Private);
+        let _candidate = array.array_get(&U32::new(Mode::Private, console::U32::new(3)));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_array_from_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let array = sample_array(mode);
+            let candidate = Plaintext::<Circuit>::from_bits_le(&array.to_bits_le());
+            assert_eq!(array.eject_value(), candidate.eject_value());
+            let candidate = Plaintext::<Circuit>::from_bits_be(&array.to_bits_be());
+            assert_eq!(array.eject_value(), candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -34,7 +34,13 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(false),
             },
-            (Self::Literal(..), _) | (Self::Interface(..), _) => Boolean::constant(false),
+            (Self::Array(type_a, length_a, a, _), Self::Array(type_b, length_b, b, _)) => match type_a == type_b {
+                // Check the number of elements and each element for equality.
+                // Note: The padding is enforced to be zero, so the padded elements may be compared directly.
+                true => a.iter().zip_eq(b.iter()).fold(length_a.is_equal(length_b), |equal, (a, b)| equal & a.is_equal(b)),
+                false => Boolean::constant(false),
+            },
+            (Self::Literal(..), _) | (Self::Interface(..), _) | (Self::Array(..), _) => Boolean::constant(false),
         }
     }
 
@@ -53,7 +59,16 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(true),
             },
-            (Self::Literal(..), _) | (Self::Interface(..), _) => Boolean::constant(true),
+            (Self::Array(type_a, length_a, a, _), Self::Array(type_b, length_b, b, _)) => match type_a == type_b {
+                // Check the number of elements and each element for inequality.
+                // Note: The padding is enforced to be zero, so the padded elements may be compared directly.
+                true => a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .fold(length_a.is_not_equal(length_b), |not_equal, (a, b)| not_equal | a.is_not_equal(b)),
+                false => Boolean::constant(true),
+            },
+            (Self::Literal(..), _) | (Self::Interface(..), _) | (Self::Array(..), _) => Boolean::constant(true),
         }
     }
 }
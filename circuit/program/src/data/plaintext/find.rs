@@ -27,6 +27,7 @@ impl<A: Aleo> Plaintext<A> {
         match self {
             // Halts if the value is not an interface.
             Self::Literal(..) => A::halt("Literal is not an interface"),
+            Self::Array(..) => A::halt("Array is not an interface"),
             // Retrieve the value of the member (from the value).
             Self::Interface(members, ..) => {
                 // Initialize the members starting from the top-level.
@@ -41,7 +42,9 @@ impl<A: Aleo> Plaintext<A> {
                     if i != path.len() - 1 {
                         match submembers.get(identifier) {
                             // Halts if the member is not an interface.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be an interface"),
+                            Some(Self::Literal(..)) | Some(Self::Array(..)) => {
+                                bail!("'{identifier}' must be an interface")
+                            }
                             // Retrieve the member and update `submembers` for the next iteration.
                             Some(Self::Interface(members, ..)) => submembers = members,
                             // Halts if the member does not exist.
@@ -16,6 +16,8 @@
 
 use super::*;
 
+use num_traits::FromPrimitive;
+
 impl<A: Aleo> FromBits for Plaintext<A> {
     type Boolean = Boolean<A>;
 
@@ -74,6 +76,43 @@ impl<A: Aleo> FromBits for Plaintext<A> {
                 Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            let element_variant = U8::from_bits_le(&bits_le[counter..counter + 8]);
+            counter += 8;
+
+            let capacity = U32::from_bits_le(&bits_le[counter..counter + 32]).eject_value();
+            counter += 32;
+
+            let length = U32::from_bits_le(&bits_le[counter..counter + 32]);
+            counter += 32;
+
+            let array_type = match console::LiteralType::from_u8(*element_variant.eject_value()) {
+                Some(element_type) => match console::ArrayType::new(element_type, capacity) {
+                    Ok(array_type) => array_type,
+                    Err(error) => A::halt(format!("{error}")),
+                },
+                None => A::halt("Unknown array element type."),
+            };
+
+            let element_size = array_type.padding().size_in_bits() as usize;
+            let mut elements = Vec::with_capacity(*capacity as usize);
+            for _ in 0..*capacity {
+                elements.push(Literal::from_bits_le(&element_variant, &bits_le[counter..counter + element_size]));
+                counter += element_size;
+            }
+
+            // Ensure the array is well-formed.
+            Self::enforce_array(&array_type, &length, &elements);
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the array.
+                Ok(_) => Self::Array(array_type, length, elements, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
@@ -135,6 +174,39 @@ impl<A: Aleo> FromBits for Plaintext<A> {
                 Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            let element_variant = U8::from_bits_be(&bits_be[counter..counter + 8]);
+            counter += 8;
+
+            let capacity = U32::from_bits_be(&bits_be[counter..counter + 32]).eject_value();
+            counter += 32;
+
+            let length = U32::from_bits_be(&bits_be[counter..counter + 32]);
+            counter += 32;
+
+            let array_type = match console::LiteralType::from_u8(*element_variant.eject_value()) {
+                Some(element_type) => match console::ArrayType::new(element_type, capacity) {
+                    Ok(array_type) => array_type,
+                    Err(error) => A::halt(format!("{error}")),
+                },
+                None => A::halt("Unknown array element type."),
+            };
+
+            let element_size = array_type.padding().size_in_bits() as usize;
+            let mut elements = Vec::with_capacity(*capacity as usize);
+            for _ in 0..*capacity {
+                elements.push(Literal::from_bits_be(&element_variant, &bits_be[counter..counter + element_size]));
+                counter += element_size;
+            }
+
+            // Ensure the array is well-formed.
+            Self::enforce_array(&array_type, &length, &elements);
+
+            // Return the array.
+            // Note: The big-endian bits are not cached, as the array only caches its little-endian bits.
+            Self::Array(array_type, length, elements, Default::default())
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
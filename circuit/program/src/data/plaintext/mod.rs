@@ -17,6 +17,7 @@
 #[cfg(test)]
 use snarkvm_circuit_types::environment::assert_scope;
 
+mod array;
 mod encrypt;
 mod equal;
 mod find;
@@ -29,7 +30,7 @@ mod to_fields;
 
 use crate::{Ciphertext, Identifier, Literal, Visibility};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U16, U8};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U16, U32, U8};
 
 #[derive(Clone)]
 pub enum Plaintext<A: Aleo> {
@@ -37,6 +38,9 @@ pub enum Plaintext<A: Aleo> {
     Literal(Literal<A>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext interface.
     Interface(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext array, with the number of elements, and the elements padded up to the capacity.
+    /// Note: The array only caches its little-endian bits.
+    Array(console::ArrayType<A::Network>, U32<A>, Vec<Literal<A>>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -50,6 +54,18 @@ impl<A: Aleo> Inject for Plaintext<A> {
             Self::Primitive::Interface(interface, _) => {
                 Self::Interface(Inject::new(mode, interface), Default::default())
             }
+            Self::Primitive::Array(array_type, elements, _) => {
+                // Inject the number of elements.
+                let length = U32::new(mode, console::U32::new(elements.len() as u32));
+                // Inject the elements, and fill the unused capacity with the padding.
+                let padding = array_type.padding();
+                let elements = (0..**array_type.capacity() as usize)
+                    .map(|index| Literal::new(mode, elements.get(index).unwrap_or(&padding).clone()))
+                    .collect::<Vec<_>>();
+                // Ensure the array is well-formed.
+                Self::enforce_array(&array_type, &length, &elements);
+                Self::Array(array_type, length, elements, Default::default())
+            }
         }
     }
 }
@@ -67,6 +83,7 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .map(|(identifier, value)| (identifier, value).eject_mode())
                 .collect::<Vec<_>>()
                 .eject_mode(),
+            Self::Array(_, length, elements, _) => (length, elements).eject_mode(),
         }
     }
 
@@ -78,6 +95,14 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 interface.iter().map(|pair| pair.eject_value()).collect(),
                 Default::default(),
             ),
+            Self::Array(array_type, length, elements, _) => {
+                // Eject the elements, without the padding.
+                let elements = elements.iter().take(*length.eject_value() as usize).map(Eject::eject_value).collect();
+                match console::Plaintext::new_array(*array_type, elements) {
+                    Ok(plaintext) => plaintext,
+                    Err(error) => A::halt(format!("Failed to eject the plaintext array: {error}")),
+                }
+            }
         }
     }
 }
@@ -45,6 +45,19 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                     bits_le
                 })
                 .clone(),
+            Self::Array(array_type, length, elements, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(false)]; // Variant bit.
+                    bits_le.extend(U8::constant(console::U8::new(array_type.element_type() as u8)).to_bits_le());
+                    bits_le.extend(U32::constant(*array_type.capacity()).to_bits_le());
+                    bits_le.extend(length.to_bits_le());
+                    // Note: The elements are padded to the capacity, so the array size is determined by its type.
+                    for element in elements {
+                        bits_le.extend(element.to_bits_le());
+                    }
+                    bits_le
+                })
+                .clone(),
         }
     }
 
@@ -74,6 +87,18 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                     bits_be
                 })
                 .clone(),
+            // Note: The array only caches its little-endian bits.
+            Self::Array(array_type, length, elements, _) => {
+                let mut bits_be = vec![Boolean::constant(true), Boolean::constant(false)]; // Variant bit.
+                bits_be.extend(U8::constant(console::U8::new(array_type.element_type() as u8)).to_bits_be());
+                bits_be.extend(U32::constant(*array_type.capacity()).to_bits_be());
+                bits_be.extend(length.to_bits_be());
+                // Note: The elements are padded to the capacity, so the array size is determined by its type.
+                for element in elements {
+                    bits_be.extend(element.to_bits_be());
+                }
+                bits_be
+            }
         }
     }
 }
@@ -71,7 +71,8 @@ impl<E: Environment> FromBits for Field<E> {
         let mut bits_le = bits_le.iter().take(size_in_bits).cloned().collect::<Vec<_>>();
         bits_le.resize(size_in_bits, Boolean::constant(false));
 
-        // Store the little-endian bits in the output.
+        // Cache the little-endian bits in the circuit, and store them in the output.
+        E::set_bits_le(&output.linear_combination, &bits_le);
         if output.bits_le.set(bits_le).is_err() {
             E::halt("Detected corrupt internal state for the bits of a field element")
         }
@@ -37,8 +37,13 @@ impl<E: Environment> ToBits for &Field<E> {
     fn to_bits_le(&self) -> Vec<Self::Boolean> {
         self.bits_le
             .get_or_init(|| {
+                // Return the cached bits, if this linear combination was already decomposed in the circuit.
+                if let Some(bits_le) = E::get_bits_le(&self.linear_combination, E::BaseField::size_in_bits()) {
+                    return bits_le;
+                }
+
                 // Construct a vector of `Boolean`s comprising the bits of the field value.
-                let bits_le = witness!(|self| self.to_bits_le());
+                let bits_le: Vec<Boolean<E>> = witness!(|self| self.to_bits_le());
 
                 // Reconstruct the bits as a linear combination representing the original field value.
                 let mut accumulator = Field::zero();
@@ -51,6 +56,8 @@ impl<E: Environment> ToBits for &Field<E> {
                 // Ensure value * 1 == (2^i * b_i + ... + 2^0 * b_0)
                 E::assert_eq(*self, accumulator);
 
+                // Cache the bits, so later decompositions of this linear combination do not duplicate constraints.
+                E::set_bits_le(&self.linear_combination, &bits_le);
                 bits_le
             })
             .clone()
@@ -175,6 +182,38 @@ mod tests {
         check_to_bits_be(Mode::Private);
     }
 
+    #[cfg(feature = "cache_bits")]
+    #[test]
+    fn test_to_bits_le_is_cached() {
+        for mode in [Mode::Public, Mode::Private] {
+            let candidate = Field::<Circuit>::new(mode, Uniform::rand(&mut test_rng()));
+            // Note: A field element of the same linear combination does not share the bits of `candidate`.
+            let copy = Field::<Circuit>::from(LinearCombination::from(&candidate));
+
+            let expected = Circuit::scope(format!("{mode} decompose"), || {
+                let bits_le = candidate.to_bits_le();
+                assert_count!(ToBits<Boolean>() => Field, &mode);
+                bits_le
+            });
+
+            // Ensure the decompositions of the same linear combination do not incur additional costs.
+            Circuit::scope(format!("{mode} cached"), || {
+                assert_eq!(expected.eject_value(), copy.to_bits_le().eject_value());
+                assert_eq!(expected.eject_value(), (&copy + Field::zero()).to_bits_le().eject_value());
+                assert_eq!(0, Circuit::num_constraints_in_scope());
+            });
+
+            // Ensure the cache is cleared with the circuit.
+            Circuit::reset();
+            let candidate = Field::<Circuit>::new(mode, Uniform::rand(&mut test_rng()));
+            Circuit::scope(format!("{mode} reset"), || {
+                candidate.to_bits_le();
+                assert_count!(ToBits<Boolean>() => Field, &mode);
+            });
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_one() {
         /// Checks that the field element, when converted to little-endian bits, is well-formed.
@@ -32,8 +32,13 @@ impl<E: Environment> ToLowerBits for Field<E> {
             ))
         }
 
+        // Return the cached bits, if this linear combination was already decomposed into `k` bits in the circuit.
+        if let Some(bits) = E::get_bits_le(&self.linear_combination, k) {
+            return bits;
+        }
+
         // Construct a vector of `Boolean`s comprising the bits of the field value.
-        let bits = witness!(|self| self.to_bits_le().into_iter().take(k).collect::<Vec<_>>());
+        let bits: Vec<Boolean<E>> = witness!(|self| self.to_bits_le().into_iter().take(k).collect::<Vec<_>>());
 
         // Reconstruct the bits as a linear combination representing the original field value.
         let mut accumulator = Field::zero();
@@ -47,6 +52,8 @@ impl<E: Environment> ToLowerBits for Field<E> {
         // and ensures that b_n, ..., b_{n-k} are all equal to zero.
         E::assert_eq(self, accumulator);
 
+        // Cache the bits, so later decompositions of this linear combination do not duplicate constraints.
+        E::set_bits_le(&self.linear_combination, &bits);
         bits
     }
 
@@ -0,0 +1,349 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+pub use console::RoundingMode;
+
+/// A signed fixed-point number with `FRACTIONAL_BITS` fractional bits, stored as a 64-bit integer `raw`,
+/// whose value is `raw / 2^FRACTIONAL_BITS`. The number of fractional bits must be less than 63.
+///
+/// Multiplication and division witness the rounded quotient `q`, the rounding bit `u`, and the remainder `r`,
+/// and enforce `n = (q - u) * d + r`, where `0 <= r < d`, and `u` is determined by the rounding mode.
+/// As the magnitudes are at most 2^126, this equation holds over the integers, and the witnesses are unique.
+#[derive(Clone)]
+pub struct Fixed<E: Environment, const FRACTIONAL_BITS: u32> {
+    /// The underlying integer, scaled by `2^FRACTIONAL_BITS`.
+    raw: I64<E>,
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Fixed<E, FRACTIONAL_BITS> {
+    /// Initializes a new fixed-point number from its integer representation, scaled by `2^FRACTIONAL_BITS`.
+    pub fn from_raw(raw: I64<E>) -> Self {
+        Self { raw }
+    }
+
+    /// Returns the integer representation, scaled by `2^FRACTIONAL_BITS`.
+    pub fn raw(&self) -> &I64<E> {
+        &self.raw
+    }
+
+    /// Returns the `sum` of `self` and `other`, halting on overflow.
+    pub fn add_checked(&self, other: &Self) -> Self {
+        Self::from_raw(self.raw.add_checked(&other.raw))
+    }
+
+    /// Returns the `difference` of `self` and `other`, halting on overflow.
+    pub fn sub_checked(&self, other: &Self) -> Self {
+        Self::from_raw(self.raw.sub_checked(&other.raw))
+    }
+
+    /// Returns the `product` of `self` and `other`, rounded with the given mode, halting on overflow.
+    pub fn mul_checked(&self, other: &Self, mode: RoundingMode) -> Self {
+        if self.is_constant() && other.is_constant() {
+            match self.eject_value().checked_mul(&other.eject_value(), mode) {
+                Some(product) => Self::constant(product),
+                None => E::halt("Fixed-point overflow on multiplication of two constants"),
+            }
+        } else {
+            // Compute `(a * b) / 2^FRACTIONAL_BITS`, where the denominator is positive.
+            let numerator = signed_field(&self.raw) * signed_field(&other.raw);
+            let denominator = Field::constant(console::Field::new(E::BaseField::from(1u128 << FRACTIONAL_BITS)));
+            let is_negative = self.raw.msb() ^ other.raw.msb();
+
+            // Compute the witnesses.
+            let values = (*self.raw.eject_value() as i128 * *other.raw.eject_value() as i128, 1 << FRACTIONAL_BITS);
+            let quotient = self.eject_value().checked_mul(&other.eject_value(), mode);
+
+            Self::divide_rounded(&numerator, &denominator, &is_negative, values, quotient, mode)
+        }
+    }
+
+    /// Returns the `quotient` of `self` and `other`, rounded with the given mode,
+    /// halting on overflow or division by zero.
+    pub fn div_checked(&self, other: &Self, mode: RoundingMode) -> Self {
+        if self.is_constant() && other.is_constant() {
+            match self.eject_value().checked_div(&other.eject_value(), mode) {
+                Some(quotient) => Self::constant(quotient),
+                None => E::halt("Fixed-point overflow or division by zero on division of two constants"),
+            }
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            E::halt("Attempted to divide by zero.")
+        } else {
+            // Compute `(a * 2^FRACTIONAL_BITS) / b`, negating both sides if `b` is negative.
+            let scale = Field::constant(console::Field::new(E::BaseField::from(1u128 << FRACTIONAL_BITS)));
+            let (dividend, divisor) = (signed_field(&self.raw) * scale, signed_field(&other.raw));
+            let is_divisor_negative = other.raw.msb();
+            let numerator = Field::ternary(is_divisor_negative, &-&dividend, &dividend);
+            let denominator = Field::ternary(is_divisor_negative, &-&divisor, &divisor);
+            let is_negative = self.raw.msb() ^ other.raw.msb();
+
+            // Compute the witnesses.
+            let (dividend, divisor) =
+                ((*self.raw.eject_value() as i128) << FRACTIONAL_BITS, *other.raw.eject_value() as i128);
+            let values = match divisor.is_negative() {
+                true => (-dividend, -divisor),
+                false => (dividend, divisor),
+            };
+            let quotient = self.eject_value().checked_div(&other.eject_value(), mode);
+
+            Self::divide_rounded(&numerator, &denominator, &is_negative, values, quotient, mode)
+        }
+    }
+
+    /// Returns the rounded quotient of `numerator` and the positive `denominator`.
+    /// Here, `is_negative` is `true` if the quotient is negative, `values` are the numerator and denominator values,
+    /// and `quotient` is the expected rounded quotient, or `None` on overflow, which does not satisfy the circuit.
+    fn divide_rounded(
+        numerator: &Field<E>,
+        denominator: &Field<E>,
+        is_negative: &Boolean<E>,
+        values: (i128, i128),
+        quotient: Option<console::Fixed<E::Network, FRACTIONAL_BITS>>,
+        mode: RoundingMode,
+    ) -> Self {
+        // Compute the floor of the quotient and the remainder, as witnesses.
+        let (numerator_value, denominator_value) = values;
+        let quotient_value = quotient.map(|quotient| quotient.raw()).unwrap_or_default();
+        let (floor_value, remainder_value) = match denominator_value > 0 {
+            true => (numerator_value.div_euclid(denominator_value), numerator_value.rem_euclid(denominator_value)),
+            // Note: This band-aid prevents a panic when the divisor is 0, in which case the circuit is not satisfied.
+            false => (0, 0),
+        };
+        let quotient = I64::new(Mode::Private, console::Integer::new(quotient_value));
+        let rounds_up = Boolean::new(Mode::Private, quotient_value as i128 - floor_value == 1);
+        let remainder = U64::new(Mode::Private, console::Integer::new(remainder_value as u64));
+
+        // Ensure that `numerator = (quotient - rounds_up) * denominator + remainder`.
+        let remainder = remainder.to_field();
+        let floor = signed_field(&quotient) - Field::from_boolean(&rounds_up);
+        E::assert_eq(numerator, floor * denominator + &remainder);
+        // Ensure that the remainder is less than the denominator.
+        (denominator - &remainder - Field::one()).to_lower_bits_le(64);
+
+        // Ensure the rounding bit is determined by the rounding mode.
+        let is_exact = remainder.is_equal(&Field::zero());
+        // Returns `(2 * remainder > denominator, 2 * remainder == denominator)`.
+        // Note: As `2 * remainder < 2 * denominator <= 2^64`, the top bit of `2 * remainder - denominator + 2^64`
+        // is set iff `2 * remainder >= denominator`.
+        let compare_half = || {
+            let doubled = remainder.double();
+            let is_tie = doubled.is_equal(denominator);
+            let offset = Field::constant(console::Field::new(E::BaseField::from(1u128 << 64)));
+            let is_greater_or_tie = match (doubled - denominator + offset).to_lower_bits_le(65).pop() {
+                Some(bit) => bit,
+                None => E::halt("Malformed expression detected during fixed-point rounding"),
+            };
+            (is_greater_or_tie & !&is_tie, is_tie)
+        };
+        match mode {
+            RoundingMode::TowardZero => E::assert_eq(&rounds_up, !is_exact & is_negative),
+            RoundingMode::Floor => E::assert_eq(&rounds_up, E::zero()),
+            RoundingMode::Ceil => E::assert_eq(&rounds_up, !is_exact),
+            RoundingMode::NearestTiesAway => {
+                let (is_greater, is_tie) = compare_half();
+                E::assert_eq(&rounds_up, is_greater | (is_tie & !is_negative));
+            }
+            RoundingMode::NearestTiesEven => {
+                // Off a tie, the quotient rounds up iff the remainder exceeds half, and on a tie, the quotient is even.
+                let (is_greater, is_tie) = compare_half();
+                E::assert_eq(&rounds_up & !&is_tie, is_greater);
+                E::assert(!(is_tie & &quotient.to_bits_le()[0]));
+            }
+        }
+
+        Self::from_raw(quotient)
+    }
+}
+
+/// Returns the signed value of the given integer, as a field element.
+fn signed_field<E: Environment>(integer: &I64<E>) -> Field<E> {
+    let offset = Field::constant(console::Field::new(E::BaseField::from(1u128 << 64)));
+    integer.to_field() - Field::from_boolean(integer.msb()) * offset
+}
+
+#[cfg(console)]
+impl<E: Environment, const FRACTIONAL_BITS: u32> Inject for Fixed<E, FRACTIONAL_BITS> {
+    type Primitive = console::Fixed<E::Network, FRACTIONAL_BITS>;
+
+    /// Initializes a new fixed-point number.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self::from_raw(I64::new(mode, console::Integer::new(value.raw())))
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const FRACTIONAL_BITS: u32> Eject for Fixed<E, FRACTIONAL_BITS> {
+    type Primitive = console::Fixed<E::Network, FRACTIONAL_BITS>;
+
+    /// Ejects the mode of the fixed-point number.
+    fn eject_mode(&self) -> Mode {
+        self.raw.eject_mode()
+    }
+
+    /// Ejects the fixed-point number as a console fixed-point number.
+    fn eject_value(&self) -> Self::Primitive {
+        console::Fixed::from_raw(*self.raw.eject_value())
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Ternary for Fixed<E, FRACTIONAL_BITS> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self::from_raw(I64::ternary(condition, &first.raw, &second.raw))
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Equal<Self> for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.raw.is_equal(&other.raw)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        self.raw.is_not_equal(&other.raw)
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Compare<Self> for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        self.raw.is_less_than(&other.raw)
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        self.raw.is_greater_than(&other.raw)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        self.raw.is_less_than_or_equal(&other.raw)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        self.raw.is_greater_than_or_equal(&other.raw)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::TowardZero,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::NearestTiesAway,
+        RoundingMode::NearestTiesEven,
+    ];
+
+    type Primitive = console::Fixed<<Circuit as Environment>::Network, 16>;
+
+    fn check_operations(first: Primitive, second: Primitive, mode_a: Mode, mode_b: Mode) {
+        for rounding_mode in MODES {
+            let a = Fixed::<Circuit, 16>::new(mode_a, first);
+            let b = Fixed::<Circuit, 16>::new(mode_b, second);
+
+            // Ensure the circuit is satisfied iff the console operation succeeds.
+            match first.checked_mul(&second, rounding_mode) {
+                Some(expected) => {
+                    assert_eq!(expected, a.mul_checked(&b, rounding_mode).eject_value());
+                    assert!(Circuit::is_satisfied(), "{first} * {second} ({rounding_mode:?})");
+                }
+                None if !(mode_a.is_constant() && mode_b.is_constant()) => {
+                    a.mul_checked(&b, rounding_mode);
+                    assert!(!Circuit::is_satisfied(), "{first} * {second} ({rounding_mode:?})");
+                }
+                None => (),
+            }
+            Circuit::reset();
+
+            let a = Fixed::<Circuit, 16>::new(mode_a, first);
+            let b = Fixed::<Circuit, 16>::new(mode_b, second);
+            match first.checked_div(&second, rounding_mode) {
+                Some(expected) => {
+                    assert_eq!(expected, a.div_checked(&b, rounding_mode).eject_value());
+                    assert!(Circuit::is_satisfied(), "{first} / {second} ({rounding_mode:?})");
+                }
+                None if !mode_b.is_constant() => {
+                    a.div_checked(&b, rounding_mode);
+                    assert!(!Circuit::is_satisfied(), "{first} / {second} ({rounding_mode:?})");
+                }
+                None => (),
+            }
+            Circuit::reset();
+        }
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Check operands that do not overflow, and operands that may overflow.
+            let first = Primitive::from_raw(i32::rand(rng) as i64);
+            let second = Primitive::from_raw(i32::rand(rng) as i64);
+            check_operations(first, second, mode_a, mode_b);
+            check_operations(Uniform::rand(rng), Uniform::rand(rng), mode_a, mode_b);
+        }
+
+        // Check the ties, and the boundaries.
+        let epsilon = Primitive::from_raw(1);
+        let half = Primitive::from_raw(1 << 15);
+        for (first, second) in [(epsilon, half), (-epsilon, half), (Primitive::from_raw(3), half), (half, epsilon)] {
+            check_operations(first, second, mode_a, mode_b);
+        }
+        let (min, max, zero) = (Primitive::MIN, Primitive::MAX, Primitive::zero());
+        for (first, second) in [(max, min), (min, -epsilon), (half, zero)] {
+            check_operations(first, second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_fixed() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let first = Primitive::from_raw(3 << 15);
+        let second = Primitive::from_raw(-5 << 14);
+
+        let a = Fixed::<Circuit, 16>::new(Mode::Private, first);
+        let b = Fixed::<Circuit, 16>::new(Mode::Public, second);
+        assert_eq!(first + second, a.add_checked(&b).eject_value());
+        assert_eq!(first - second, a.sub_checked(&b).eject_value());
+        assert!(a.is_greater_than(&b).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the upper `I::BITS` bits of `bits_le`, after shifting `bits_le` to the left by `rhs` bits,
+    /// where `bits_le` has `2 * I::BITS` bits, and `rhs` is taken modulo `I::BITS`.
+    pub(crate) fn funnel_shl_bits<M: Magnitude>(bits_le: &[Boolean<E>], rhs: &Integer<E, M>) -> Vec<Boolean<E>> {
+        let num_bits = I::BITS as usize;
+        // As `I::BITS` is a power of two, the lower bits of `rhs` are the shift amount modulo `I::BITS`.
+        let shift_bits_le = &rhs.bits_le[..I::BITS.trailing_zeros() as usize];
+
+        if shift_bits_le.iter().all(|bit| bit.is_constant()) {
+            // If the shift amount is a constant, then we can select the bits directly.
+            let shift = shift_bits_le.iter().rev().fold(0usize, |shift, bit| (shift << 1) | bit.eject_value() as usize);
+            bits_le[num_bits - shift..2 * num_bits - shift].to_vec()
+        } else {
+            // Otherwise, select the bits with a barrel shifter, starting from the most significant shift bit.
+            // After the shift bit `2^k` is processed, the window holds the `I::BITS + 2^k - 1` bits
+            // that may still be selected by the remaining (lower) shift bits.
+            // Note: The lowest bit of `bits_le` is never selected, as the shift amount is less than `I::BITS`.
+            let mut window = bits_le[1..].to_vec();
+            for (k, bit) in shift_bits_le.iter().enumerate().rev() {
+                let offset = 1 << k;
+                window = (0..num_bits + offset - 1)
+                    .map(|i| Boolean::ternary(bit, &window[i], &window[i + offset]))
+                    .collect();
+            }
+            window
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> FunnelShl<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Shifts `first` to the left by `rhs` bits, filling the vacated bits with the upper bits of `second`,
+    /// where `rhs` is taken modulo the number of bits in `first`.
+    #[inline]
+    fn funnel_shl(first: &Self, second: &Self, rhs: &Integer<E, M>) -> Self::Output {
+        // Concatenate `second` and `first`, as the lower and upper halves respectively.
+        let bits_le = second.bits_le.iter().chain(first.bits_le.iter()).cloned().collect::<Vec<_>>();
+        Self { bits_le: Self::funnel_shl_bits(&bits_le, rhs), phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn FunnelShl<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // The number of ternary operations in the barrel shifter.
+        let depth = I::BITS.trailing_zeros() as u64;
+        let num_ternaries = (depth * I::BITS) + I::BITS - 1 - depth;
+
+        match case {
+            (_, _, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _, _) | (_, Mode::Constant, _) => Count::less_than(0, 0, num_ternaries, num_ternaries),
+            (_, _, _) => Count::is(0, 0, num_ternaries, num_ternaries),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn FunnelShl<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, CircuitType<Integer<E, M>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (mode_a, mode_b, CircuitType::Constant(constant)) => {
+                // Note: Casting `rhs` to a `u32` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+                match constant.eject_value().to_u32().unwrap() % I::BITS as u32 {
+                    0 => *mode_a,
+                    _ => Mode::combine(*mode_a, [*mode_b]),
+                }
+            }
+            (Mode::Constant, _, _) | (_, Mode::Constant, _) => {
+                E::halt("The output mode of a funnel shift of a constant by a variable depends on the values.")
+            }
+            (_, _, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 4;
+
+    fn check_funnel_shl<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        third: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_c: Mode,
+    ) {
+        let expected = console::Integer::funnel_shl(&first, &second, &third);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let c = Integer::<Circuit, M>::new(mode_c, third);
+        Circuit::scope(name, || {
+            let candidate = Integer::funnel_shl(&a, &b, &c);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                FunnelShl<Integer<Circuit, M>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b, mode_c)
+            );
+            // Note: The output mode of a constant shifted by a variable depends on the values.
+            if mode_c.is_constant() || (!mode_a.is_constant() && !mode_b.is_constant()) {
+                assert_output_mode!(
+                    Integer<Circuit, I>,
+                    FunnelShl<Integer<Circuit, M>, Output = Integer<Circuit, I>>,
+                    &(mode_a, mode_b, CircuitType::from(&c)),
+                    candidate
+                );
+            }
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut test_rng());
+            let second = Uniform::rand(&mut test_rng());
+
+            let name = format!("FunnelShl: ({}, {}) << {} {}", mode_a, mode_b, mode_c, i);
+            check_funnel_shl::<I, u8>(&name, first, second, Uniform::rand(&mut test_rng()), mode_a, mode_b, mode_c);
+            check_funnel_shl::<I, u16>(&name, first, second, Uniform::rand(&mut test_rng()), mode_a, mode_b, mode_c);
+            check_funnel_shl::<I, u32>(&name, first, second, Uniform::rand(&mut test_rng()), mode_a, mode_b, mode_c);
+        }
+
+        // Check that every shift amount, including the multiples of the number of bits, is computed correctly.
+        let first = Uniform::rand(&mut test_rng());
+        let second = Uniform::rand(&mut test_rng());
+        for shift in 0..=(2 * I::BITS as u16) {
+            let name = format!("FunnelShl: ({}, {}) << {} ({})", mode_a, mode_b, mode_c, shift);
+            check_funnel_shl::<I, u16>(&name, first, second, console::Integer::new(shift), mode_a, mode_b, mode_c);
+        }
+    }
+
+    #[test]
+    fn test_funnel_shl_matches_rotl() {
+        for _ in 0..ITERATIONS {
+            let first = console::Integer::<<Circuit as Environment>::Network, u32>::rand(&mut test_rng());
+            for shift in 0..u8::MAX {
+                let shift = console::Integer::<_, u8>::new(shift);
+                // Ensure a funnel shift of a value with itself is a rotation.
+                let expected = console::Integer::new((*first).rotate_left(*shift as u32));
+                assert_eq!(expected, console::Integer::funnel_shl(&first, &first, &shift));
+                let a = U32::<Circuit>::new(Mode::Private, first);
+                let b = U8::<Circuit>::new(Mode::Private, shift);
+                assert_eq!(expected, U32::funnel_shl(&a, &a, &b).eject_value());
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, first, second, shift);
+    test_integer_ternary!(run_test, i16, first, second, shift);
+    test_integer_ternary!(run_test, i32, first, second, shift);
+    test_integer_ternary!(run_test, i64, first, second, shift);
+    test_integer_ternary!(run_test, i128, first, second, shift);
+
+    test_integer_ternary!(run_test, u8, first, second, shift);
+    test_integer_ternary!(run_test, u16, first, second, shift);
+    test_integer_ternary!(run_test, u32, first, second, shift);
+    test_integer_ternary!(run_test, u64, first, second, shift);
+    test_integer_ternary!(run_test, u128, first, second, shift);
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> FunnelShr<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Shifts `second` to the right by `rhs` bits, filling the vacated bits with the lower bits of `first`,
+    /// where `rhs` is taken modulo the number of bits in `second`.
+    #[inline]
+    fn funnel_shr(first: &Self, second: &Self, rhs: &Integer<E, M>) -> Self::Output {
+        // A right shift of the concatenation is a left shift of its bit reversal, whose output is also reversed.
+        let bits_be = first.bits_le.iter().rev().chain(second.bits_le.iter().rev()).cloned().collect::<Vec<_>>();
+        let mut bits_le = Self::funnel_shl_bits(&bits_be, rhs);
+        bits_le.reverse();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn FunnelShr<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // The number of ternary operations in the barrel shifter.
+        let depth = I::BITS.trailing_zeros() as u64;
+        let num_ternaries = (depth * I::BITS) + I::BITS - 1 - depth;
+
+        match case {
+            (_, _, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _, _) | (_, Mode::Constant, _) => Count::less_than(0, 0, num_ternaries, num_ternaries),
+            (_, _, _) => Count::is(0, 0, num_ternaries, num_ternaries),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn FunnelShr<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, CircuitType<Integer<E, M>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (mode_a, mode_b, CircuitType::Constant(constant)) => {
+                // Note: Casting `rhs` to a `u32` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+                match constant.eject_value().to_u32().unwrap() % I::BITS as u32 {
+                    0 => *mode_b,
+                    _ => Mode::combine(*mode_a, [*mode_b]),
+                }
+            }
+            (Mode::Constant, _, _) | (_, Mode::Constant, _) => {
+                E::halt("The output mode of a funnel shift of a constant by a variable depends on the values.")
+            }
+            (_, _, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 4;
+
+    fn check_funnel_shr<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        third: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_c: Mode,
+    ) {
+        let expected = console::Integer::funnel_shr(&first, &second, &third);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let c = Integer::<Circuit, M>::new(mode_c, third);
+        Circuit::scope(name, || {
+            let candidate = Integer::funnel_shr(&a, &b, &c);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                FunnelShr<Integer<Circuit, M>, Output = Integer<Circuit, I>>,
+                &(mode_a, mode_b, mode_c)
+            );
+            // Note: The output mode of a constant shifted by a variable depends on the values.
+            if mode_c.is_constant() || (!mode_a.is_constant() && !mode_b.is_constant()) {
+                assert_output_mode!(
+                    Integer<Circuit, I>,
+                    FunnelShr<Integer<Circuit, M>, Output = Integer<Circuit, I>>,
+                    &(mode_a, mode_b, CircuitType::from(&c)),
+                    candidate
+                );
+            }
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut test_rng());
+            let second = Uniform::rand(&mut test_rng());
+
+            let name = format!("FunnelShr: ({}, {}) >> {} {}", mode_a, mode_b, mode_c, i);
+            check_funnel_shr::<I, u8>(&name, first, second, Uniform::rand(&mut test_rng()), mode_a, mode_b, mode_c);
+            check_funnel_shr::<I, u16>(&name, first, second, Uniform::rand(&mut test_rng()), mode_a, mode_b, mode_c);
+            check_funnel_shr::<I, u32>(&name, first, second, Uniform::rand(&mut test_rng()), mode_a, mode_b, mode_c);
+        }
+
+        // Check that every shift amount, including the multiples of the number of bits, is computed correctly.
+        let first = Uniform::rand(&mut test_rng());
+        let second = Uniform::rand(&mut test_rng());
+        for shift in 0..=(2 * I::BITS as u16) {
+            let name = format!("FunnelShr: ({}, {}) >> {} ({})", mode_a, mode_b, mode_c, shift);
+            check_funnel_shr::<I, u16>(&name, first, second, console::Integer::new(shift), mode_a, mode_b, mode_c);
+        }
+    }
+
+    #[test]
+    fn test_funnel_shr_matches_rotr() {
+        for _ in 0..ITERATIONS {
+            let first = console::Integer::<<Circuit as Environment>::Network, u32>::rand(&mut test_rng());
+            for shift in 0..u8::MAX {
+                let shift = console::Integer::<_, u8>::new(shift);
+                // Ensure a funnel shift of a value with itself is a rotation.
+                let expected = console::Integer::new((*first).rotate_right(*shift as u32));
+                assert_eq!(expected, console::Integer::funnel_shr(&first, &first, &shift));
+                let a = U32::<Circuit>::new(Mode::Private, first);
+                let b = U8::<Circuit>::new(Mode::Private, shift);
+                assert_eq!(expected, U32::funnel_shr(&a, &a, &b).eject_value());
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, first, second, shift);
+    test_integer_ternary!(run_test, i16, first, second, shift);
+    test_integer_ternary!(run_test, i32, first, second, shift);
+    test_integer_ternary!(run_test, i64, first, second, shift);
+    test_integer_ternary!(run_test, i128, first, second, shift);
+
+    test_integer_ternary!(run_test, u8, first, second, shift);
+    test_integer_ternary!(run_test, u16, first, second, shift);
+    test_integer_ternary!(run_test, u32, first, second, shift);
+    test_integer_ternary!(run_test, u64, first, second, shift);
+    test_integer_ternary!(run_test, u128, first, second, shift);
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Compare<Self> for Integer256<E, SIGNED> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            Boolean::constant(self.eject_value() < other.eject_value())
+        } else {
+            match SIGNED {
+                // Flipping the sign bits maps the signed order onto the unsigned order.
+                true => {
+                    let flip_msb = |integer: &Self| {
+                        let mut bits_le = integer.bits_le.clone();
+                        bits_le[SIZE_IN_BITS - 1] = !integer.msb();
+                        U256::<E> { bits_le }
+                    };
+                    flip_msb(self).is_less_than(&flip_msb(other))
+                }
+                // If `self - other` borrows, then `self < other`.
+                false => !self.sub_with_borrow(other).1,
+            }
+        }
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+
+    fn check_compare<const SIGNED: bool>(
+        first: console::Integer256<<Circuit as Environment>::Network, SIGNED>,
+        second: console::Integer256<<Circuit as Environment>::Network, SIGNED>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+        let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+        assert_eq!(first < second, a.is_less_than(&b).eject_value());
+        assert_eq!(first > second, a.is_greater_than(&b).eject_value());
+        assert_eq!(first <= second, a.is_less_than_or_equal(&b).eject_value());
+        assert_eq!(first >= second, a.is_greater_than_or_equal(&b).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_test<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut test_rng());
+            let second = Uniform::rand(&mut test_rng());
+            check_compare::<SIGNED>(first, second, mode_a, mode_b);
+            check_compare::<SIGNED>(first, first, mode_a, mode_b);
+        }
+
+        let (min, max) = (console::Integer256::MIN, console::Integer256::MAX);
+        check_compare::<SIGNED>(min, max, mode_a, mode_b);
+        check_compare::<SIGNED>(max, min, mode_a, mode_b);
+        check_compare::<SIGNED>(min, console::Integer256::zero(), mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_compare() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<false>(mode_a, mode_b);
+                run_test::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
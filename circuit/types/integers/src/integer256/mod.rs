@@ -0,0 +1,305 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod arithmetic;
+mod compare;
+
+use super::*;
+
+pub type I256<E> = Integer256<E, true>;
+pub type U256<E> = Integer256<E, false>;
+
+/// The number of bits in a 256-bit integer.
+const SIZE_IN_BITS: usize = 256;
+/// The number of bits in each limb of a product.
+/// Note: This is chosen so the sums of limb products fit within the base field.
+const LIMB_SIZE_IN_BITS: usize = 64;
+
+/// A 256-bit integer, as little-endian bits.
+/// If `SIGNED` is `true`, the bits are interpreted in two's complement.
+///
+/// As 256-bit integers do not fit in a base field element, the arithmetic operates
+/// on 128-bit halves for addition, subtraction, and comparison, and on 64-bit limbs for multiplication.
+#[derive(Clone)]
+pub struct Integer256<E: Environment, const SIGNED: bool> {
+    bits_le: Vec<Boolean<E>>,
+}
+
+impl<E: Environment, const SIGNED: bool> Integer256<E, SIGNED> {
+    /// Returns the number of bits in the integer.
+    pub const fn size_in_bits() -> u16 {
+        SIZE_IN_BITS as u16
+    }
+
+    /// Returns the integer with all bits set to the given constant.
+    fn constant_bits(bit: bool) -> Self {
+        Self { bits_le: vec![Boolean::constant(bit); SIZE_IN_BITS] }
+    }
+
+    /// Returns the integer with the same bits, reinterpreted with the given signedness.
+    fn cast<const OTHER: bool>(self) -> Integer256<E, OTHER> {
+        Integer256 { bits_le: self.bits_le }
+    }
+
+    /// Returns the lower and upper 128-bit halves of the integer, as field elements.
+    fn to_halves(&self) -> (Field<E>, Field<E>) {
+        let (lower, upper) = self.bits_le.split_at(SIZE_IN_BITS / 2);
+        (Field::from_bits_le(lower), Field::from_bits_le(upper))
+    }
+
+    /// Returns the 64-bit limbs of the integer, as field elements.
+    fn to_limbs(&self) -> Vec<Field<E>> {
+        self.bits_le.chunks(LIMB_SIZE_IN_BITS).map(Field::from_bits_le).collect()
+    }
+}
+
+/// Returns `2^exponent` as a constant field element.
+fn power_of_two<E: Environment>(exponent: usize) -> Field<E> {
+    let mut value = E::BaseField::one();
+    for _ in 0..exponent {
+        value = value.double();
+    }
+    Field::constant(console::Field::new(value))
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> Inject for Integer256<E, SIGNED> {
+    type Primitive = console::Integer256<E::Network, SIGNED>;
+
+    /// Initializes a new integer.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self { bits_le: value.to_bits_le().into_iter().map(|bit| Boolean::new(mode, bit)).collect() }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> Eject for Integer256<E, SIGNED> {
+    type Primitive = console::Integer256<E::Network, SIGNED>;
+
+    /// Ejects the mode of the integer.
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    /// Ejects the integer circuit as a console integer value.
+    fn eject_value(&self) -> Self::Primitive {
+        let bits_le = self.bits_le.iter().map(|bit| bit.eject_value()).collect::<Vec<_>>();
+        match console::Integer256::from_bits_le(&bits_le) {
+            Ok(value) => value,
+            Err(error) => E::halt(format!("Failed to eject a 256-bit integer: {error}")),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> Debug for Integer256<E, SIGNED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> Display for Integer256<E, SIGNED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> TypeName for Integer256<E, SIGNED> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        console::Integer256::<E::Network, SIGNED>::type_name()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> Parser for Integer256<E, SIGNED> {
+    /// Parses a string into an integer circuit.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the integer from the string.
+        let (string, integer) = console::Integer256::parse(string)?;
+        // Parse the mode from the string.
+        let (string, mode) = opt(pair(tag("."), Mode::parse))(string)?;
+
+        match mode {
+            Some((_, mode)) => Ok((string, Integer256::new(mode, integer))),
+            None => Ok((string, Integer256::new(Mode::Constant, integer))),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const SIGNED: bool> FromStr for Integer256<E, SIGNED> {
+    type Err = Error;
+
+    /// Parses a string into an integer circuit.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> ToBits for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self`.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+
+    /// Outputs the big-endian bit representation of `self`.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_be = self.to_bits_le();
+        bits_be.reverse();
+        bits_be
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> FromBits for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new integer from a list of little-endian bits *with* trailing zeros.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the excess bits are zero.
+        if bits_le.len() > SIZE_IN_BITS {
+            let should_be_zero = bits_le[SIZE_IN_BITS..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+            E::assert_eq(E::zero(), should_be_zero);
+        }
+
+        // Construct the sanitized list of bits, resizing up if necessary.
+        let mut bits_le = bits_le.iter().take(SIZE_IN_BITS).cloned().collect::<Vec<_>>();
+        bits_le.resize(SIZE_IN_BITS, Boolean::constant(false));
+        Self { bits_le }
+    }
+
+    /// Initializes a new integer from a list of big-endian bits *with* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MSB for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the MSB of the integer.
+    fn msb(&self) -> &Self::Boolean {
+        match self.bits_le.last() {
+            Some(msb) => msb,
+            // Note: `E::halt` should never be invoked as `self.bits_le.len()` is greater than zero.
+            None => E::halt("Malformed integer detected while retrieving the MSB"),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Ternary for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self {
+            bits_le: first
+                .bits_le
+                .iter()
+                .zip_eq(second.bits_le.iter())
+                .map(|(first_bit, second_bit)| Boolean::ternary(condition, first_bit, second_bit))
+                .collect(),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Equal<Self> for Integer256<E, SIGNED> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        // Compare the integers as two 128-bit halves, as each half fits within a base field element.
+        let (self_lower, self_upper) = self.to_halves();
+        let (other_lower, other_upper) = other.to_halves();
+        self_lower.is_equal(&other_lower) & self_upper.is_equal(&other_upper)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_new<const SIGNED: bool>(mode: Mode) {
+        for _ in 0..ITERATIONS {
+            let expected = Uniform::rand(&mut test_rng());
+            let candidate = Integer256::<Circuit, SIGNED>::new(mode, expected);
+            assert_eq!(mode, candidate.eject_mode());
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(expected.to_bits_le(), candidate.to_bits_le().eject_value());
+            assert_eq!(expected, Integer256::<Circuit, SIGNED>::from_bits_be(&candidate.to_bits_be()).eject_value());
+        }
+        Circuit::reset();
+    }
+
+    fn check_equal<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        for _ in 0..ITERATIONS {
+            let first: console::Integer256<_, SIGNED> = Uniform::rand(&mut test_rng());
+            let second: console::Integer256<_, SIGNED> = Uniform::rand(&mut test_rng());
+
+            let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+            let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+            assert!(!a.is_equal(&b).eject_value());
+            assert!(a.is_not_equal(&b).eject_value());
+            assert!(a.is_equal(&Integer256::new(mode_b, first)).eject_value());
+            assert!(Circuit::is_satisfied());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_new() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_new::<false>(mode);
+            check_new::<true>(mode);
+        }
+    }
+
+    #[test]
+    fn test_equal() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_equal::<false>(mode_a, mode_b);
+                check_equal::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
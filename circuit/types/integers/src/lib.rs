@@ -29,6 +29,10 @@ pub mod compare;
 pub mod div_checked;
 pub mod div_wrapped;
 pub mod equal;
+pub mod fixed;
+pub mod funnel_shl;
+pub mod funnel_shr;
+pub mod integer256;
 pub mod modulo;
 pub mod mul_checked;
 pub mod mul_wrapped;
@@ -39,6 +43,8 @@ pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
 pub mod rem_wrapped;
+pub mod rotl;
+pub mod rotr;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
@@ -48,6 +54,9 @@ pub mod sub_wrapped;
 pub mod ternary;
 pub mod xor;
 
+pub use fixed::{Fixed, RoundingMode};
+pub use integer256::{Integer256, I256, U256};
+
 pub type I8<E> = Integer<E, i8>;
 pub type I16<E> = Integer<E, i16>;
 pub type I32<E> = Integer<E, i32>;
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Rotr<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the right by `rhs` bits, where `rhs` is taken modulo the number of bits in `self`.
+    #[inline]
+    fn rotr(&self, rhs: &Integer<E, M>) -> Self::Output {
+        // A right rotation is a right funnel shift of `self` with itself.
+        Self::funnel_shr(self, self, rhs)
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn Rotr<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        count!(Integer<E, I>, FunnelShr<Integer<E, M>, Output = Integer<E, I>>, &(case.0, case.0, case.1))
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn Rotr<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (mode_a, Mode::Constant) => mode_a,
+            (Mode::Constant, _) => {
+                E::halt("The output mode of a rotation of a constant by a variable depends on the values.")
+            }
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rotr<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let expected = first.rotate_right(second.to_u32().unwrap());
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        Circuit::scope(name, || {
+            let candidate = a.rotr(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(Rotr(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+            // Note: The output mode of a constant rotated by a variable depends on the values.
+            if !mode_a.is_constant() || mode_b.is_constant() {
+                assert_output_mode!(Rotr(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b), candidate);
+            }
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut test_rng());
+            let second = Uniform::rand(&mut test_rng());
+
+            let name = format!("Rotr: {} >>> {} {}", mode_a, mode_b, i);
+            check_rotr::<I, M>(&name, first, second, mode_a, mode_b);
+
+            // Check that rotating right by one is computed correctly.
+            let name = format!("Rotr One: {} >>> {} {}", mode_a, mode_b, i);
+            check_rotr::<I, M>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            // Check that rotating right by zero is computed correctly.
+            let name = format!("Rotr Zero: {} >>> {} {}", mode_a, mode_b, i);
+            check_rotr::<I, M>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+        RangeInclusive<M>: Iterator<Item = M>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in M::MIN..=M::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, M>::new(second);
+
+                let name = format!("Rotr: ({} >>> {})", first, second);
+                check_rotr::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, u8, rotr);
+    test_integer_binary!(run_test, i8, u16, rotr);
+    test_integer_binary!(run_test, i8, u32, rotr);
+
+    test_integer_binary!(run_test, i16, u8, rotr);
+    test_integer_binary!(run_test, i16, u16, rotr);
+    test_integer_binary!(run_test, i16, u32, rotr);
+
+    test_integer_binary!(run_test, i32, u8, rotr);
+    test_integer_binary!(run_test, i32, u16, rotr);
+    test_integer_binary!(run_test, i32, u32, rotr);
+
+    test_integer_binary!(run_test, i64, u8, rotr);
+    test_integer_binary!(run_test, i64, u16, rotr);
+    test_integer_binary!(run_test, i64, u32, rotr);
+
+    test_integer_binary!(run_test, i128, u8, rotr);
+    test_integer_binary!(run_test, i128, u16, rotr);
+    test_integer_binary!(run_test, i128, u32, rotr);
+
+    test_integer_binary!(run_test, u8, u8, rotr);
+    test_integer_binary!(run_test, u8, u16, rotr);
+    test_integer_binary!(run_test, u8, u32, rotr);
+
+    test_integer_binary!(run_test, u16, u8, rotr);
+    test_integer_binary!(run_test, u16, u16, rotr);
+    test_integer_binary!(run_test, u16, u32, rotr);
+
+    test_integer_binary!(run_test, u32, u8, rotr);
+    test_integer_binary!(run_test, u32, u16, rotr);
+    test_integer_binary!(run_test, u32, u32, rotr);
+
+    test_integer_binary!(run_test, u64, u8, rotr);
+    test_integer_binary!(run_test, u64, u16, rotr);
+    test_integer_binary!(run_test, u64, u32, rotr);
+
+    test_integer_binary!(run_test, u128, u8, rotr);
+    test_integer_binary!(run_test, u128, u16, rotr);
+    test_integer_binary!(run_test, u128, u32, rotr);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, rotr, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, rotr, exhaustive);
+}
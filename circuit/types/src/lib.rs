@@ -30,7 +30,7 @@ pub use boolean::Boolean;
 pub use environment::prelude::*;
 pub use field::Field;
 pub use group::Group;
-pub use integers::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+pub use integers::{I128, I16, I256, I32, I64, I8, U128, U16, U256, U32, U64, U8};
 pub use scalar::Scalar;
 pub use string::StringType;
 
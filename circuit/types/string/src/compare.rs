@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Compare<Self> for StringType<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`, in the lexicographic order of their bytes.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        // If one string is a prefix of the other, the shorter string is less than the longer string.
+        // Note: The lengths of the strings are known when synthesizing the circuit, so this is a constant.
+        let is_shorter = Boolean::constant(self.bytes.len() < other.bytes.len());
+
+        // Starting from the last common byte, the comparison is decided by the first byte that differs.
+        self.bytes.iter().zip(&other.bytes).rev().fold(is_shorter, |is_less_than, (a, b)| {
+            Boolean::ternary(&a.is_equal(b), &is_less_than, &a.is_less_than(b))
+        })
+    }
+
+    /// Returns `true` if `self` is greater than `other`, in the lexicographic order of their bytes.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`, in the lexicographic order of their bytes.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`, in the lexicographic order of their bytes.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_compare(mode_a: Mode, mode_b: Mode, first: &str, second: &str) {
+        let expected_a = console::StringType::<<Circuit as Environment>::Network>::new(first);
+        let expected_b = console::StringType::<<Circuit as Environment>::Network>::new(second);
+        let a = StringType::<Circuit>::new(mode_a, expected_a.clone());
+        let b = StringType::<Circuit>::new(mode_b, expected_b.clone());
+
+        Circuit::scope(&format!("{mode_a} {mode_b}"), || {
+            assert_eq!(*expected_a.is_less_than(&expected_b), a.is_less_than(&b).eject_value());
+            assert_eq!(*expected_a.is_greater_than(&expected_b), a.is_greater_than(&b).eject_value());
+            assert_eq!(*expected_a.is_less_than_or_equal(&expected_b), a.is_less_than_or_equal(&b).eject_value());
+            assert_eq!(*expected_a.is_greater_than_or_equal(&expected_b), a.is_greater_than_or_equal(&b).eject_value());
+            // Ensure a comparison of constants is a constant.
+            if mode_a.is_constant() && mode_b.is_constant() {
+                assert!(a.is_less_than(&b).is_constant());
+            }
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample two random strings.
+            let first: console::StringType<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let second: console::StringType<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            check_compare(mode_a, mode_b, &first, &second);
+            check_compare(mode_a, mode_b, &first, &first);
+        }
+
+        // Check the strings that differ only in length, or only in their last byte.
+        check_compare(mode_a, mode_b, "", "");
+        check_compare(mode_a, mode_b, "", "alice");
+        check_compare(mode_a, mode_b, "alice", "alice.aleo");
+        check_compare(mode_a, mode_b, "alice.aleo", "alice");
+        check_compare(mode_a, mode_b, "alice", "alicf");
+        check_compare(mode_a, mode_b, "bob", "alice");
+    }
+
+    #[test]
+    fn test_compare_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_compare_constant_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_compare_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_compare_public_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_compare_public_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_compare_public_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_compare_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_compare_private_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_compare_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -17,11 +17,13 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+mod compare;
 mod equal;
 mod helpers;
+mod ternary;
 
 #[cfg(test)]
-use console::test_rng;
+use console::{test_rng, Uniform};
 #[cfg(test)]
 use snarkvm_circuit_environment::assert_scope;
 
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Ternary for StringType<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    ///
+    /// As the length of a string determines the shape of its circuit, this halts
+    /// if `first` and `second` do not have the same number of bytes.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Ensure the strings are of the same length.
+        if first.bytes.len() != second.bytes.len() {
+            E::halt(format!(
+                "Ternary requires strings of equal length, found {} and {}",
+                first.bytes.len(),
+                second.bytes.len()
+            ))
+        }
+
+        // Determine the mode of the output.
+        let mode = match condition.is_constant() {
+            true => match condition.eject_value() {
+                true => first.mode,
+                false => second.mode,
+            },
+            false => Mode::combine(condition.eject_mode(), [first.mode, second.mode]),
+        };
+
+        // Select each byte of the output.
+        let bytes = first.bytes.iter().zip_eq(&second.bytes).map(|(a, b)| U8::ternary(condition, a, b)).collect();
+        Self { mode, bytes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_ternary(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        let first = console::StringType::<<Circuit as Environment>::Network>::new("alice.aleo");
+        let second = console::StringType::<<Circuit as Environment>::Network>::new("bobby.aleo");
+
+        for value in [true, false] {
+            let expected = match value {
+                true => first.clone(),
+                false => second.clone(),
+            };
+            let condition = Boolean::<Circuit>::new(mode_condition, value);
+            let a = StringType::<Circuit>::new(mode_a, first.clone());
+            let b = StringType::<Circuit>::new(mode_b, second.clone());
+
+            Circuit::scope(&format!("{value} ? {mode_a} : {mode_b}"), || {
+                let candidate = StringType::ternary(&condition, &a, &b);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure a constant condition selects the string, without adding constraints.
+                if mode_condition.is_constant() {
+                    assert_scope!(0, 0, 0, 0);
+                }
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_ternary() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    check_ternary(mode_condition, mode_a, mode_b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ternary_halts_on_unequal_lengths() {
+        let a = StringType::<Circuit>::new(Mode::Private, console::StringType::new("alice"));
+        let b = StringType::<Circuit>::new(Mode::Private, console::StringType::new("alice.aleo"));
+        let condition = Boolean::<Circuit>::new(Mode::Private, true);
+
+        let result = std::panic::catch_unwind(|| StringType::ternary(&condition, &a, &b));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_types::Field;
+
+use core::marker::PhantomData;
+
+static ALIAS_PREFIX: &str = "aleoalias";
+static ALIAS_DOMAIN: &str = "AleoAddressAlias0";
+
+/// The number of bytes of the address digest in an alias.
+const ALIAS_SIZE_IN_BYTES: usize = 10;
+
+/// A short alias for an account address, for payment interfaces to display a human-verifiable identifier.
+///
+/// The alias is the leading 80 bits of a domain-separated hash of the address, encoded in bech32m
+/// (e.g. `aleoalias1...`, at 32 characters instead of 63). The bech32m checksum detects typos in the alias,
+/// and `AddressAlias::resolve` recovers the full address from a set of known addresses, such as an address book.
+///
+/// Note: An alias is not a substitute for the address; funds must always be sent to the resolved full address.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AddressAlias<N: Network> {
+    /// The leading bytes of the address digest.
+    digest: [u8; ALIAS_SIZE_IN_BYTES],
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> TryFrom<Address<N>> for AddressAlias<N> {
+    type Error = Error;
+
+    /// Derives the alias of the given address.
+    fn try_from(address: Address<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&address)
+    }
+}
+
+impl<N: Network> TryFrom<&Address<N>> for AddressAlias<N> {
+    type Error = Error;
+
+    /// Derives the alias of the given address.
+    fn try_from(address: &Address<N>) -> Result<Self, Self::Error> {
+        // Hash the address with the alias domain.
+        let hash = N::hash_psd2(&[Field::new_domain_separator(ALIAS_DOMAIN), address.to_field()?])?;
+        // Truncate the hash to the alias size.
+        let mut digest = [0u8; ALIAS_SIZE_IN_BYTES];
+        digest.copy_from_slice(&hash.to_bytes_le()?[..ALIAS_SIZE_IN_BYTES]);
+        Ok(Self { digest, _phantom: PhantomData })
+    }
+}
+
+impl<N: Network> AddressAlias<N> {
+    /// Returns `true` if the alias belongs to the given address.
+    pub fn matches(&self, address: &Address<N>) -> bool {
+        matches!(Self::try_from(address), Ok(alias) if alias == *self)
+    }
+
+    /// Returns the full address of the alias, from the given set of known addresses.
+    /// Returns an error if no address matches, or if more than one distinct address matches.
+    pub fn resolve<'a>(&self, addresses: impl IntoIterator<Item = &'a Address<N>>) -> Result<Address<N>> {
+        let mut candidates = addresses.into_iter().filter(|address| self.matches(address)).unique();
+        match (candidates.next(), candidates.next()) {
+            (Some(address), None) => Ok(*address),
+            (Some(_), Some(_)) => bail!("The alias '{self}' is ambiguous in the given addresses"),
+            (None, _) => bail!("The alias '{self}' does not match any of the given addresses"),
+        }
+    }
+}
+
+impl<N: Network> FromStr for AddressAlias<N> {
+    type Err = Error;
+
+    /// Reads in an address alias string, and ensures its checksum is valid.
+    fn from_str(alias: &str) -> Result<Self, Self::Err> {
+        // Decode the alias string from bech32m, which checks the checksum.
+        let (hrp, data, variant) = bech32::decode(alias)?;
+        if hrp != ALIAS_PREFIX {
+            bail!("Failed to decode address alias: '{hrp}' is an invalid prefix")
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found an address alias that is not bech32m encoded: {alias}");
+        }
+        // Decode the alias data from u5 to u8.
+        let bytes = Vec::from_base32(&data)?;
+        ensure!(bytes.len() == ALIAS_SIZE_IN_BYTES, "Invalid address alias length: found {} bytes", bytes.len());
+        let mut digest = [0u8; ALIAS_SIZE_IN_BYTES];
+        digest.copy_from_slice(&bytes);
+        Ok(Self { digest, _phantom: PhantomData })
+    }
+}
+
+impl<N: Network> Debug for AddressAlias<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AddressAlias<N> {
+    /// Writes the address alias as a bech32m string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Encode the digest into bech32m.
+        let string =
+            bech32::encode(ALIAS_PREFIX, self.digest.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        // Output the string.
+        Display::fmt(&string, f)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address alias.
+            let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let expected = AddressAlias::try_from(&address)?;
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(32, candidate.len());
+            assert!(candidate.starts_with("aleoalias1"));
+            assert_eq!(expected, AddressAlias::from_str(&candidate)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_typo_is_detected() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let alias = AddressAlias::try_from(&address)?.to_string();
+
+        // Ensure substituting any single character of the data is detected.
+        let prefix_length = ALIAS_PREFIX.len() + 1;
+        for index in prefix_length..alias.len() {
+            let mut typo = alias.clone().into_bytes();
+            typo[index] = if typo[index] == b'q' { b'p' } else { b'q' };
+            let typo = String::from_utf8(typo).unwrap();
+            assert!(AddressAlias::<CurrentNetwork>::from_str(&typo).is_err());
+        }
+        // Ensure an address is not accepted as an alias.
+        assert!(AddressAlias::<CurrentNetwork>::from_str(&address.to_string()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample an address book.
+        let addresses = (0..ITERATIONS)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        for address in &addresses {
+            // Ensure the alias resolves to its address.
+            let alias = AddressAlias::from_str(&AddressAlias::try_from(address)?.to_string())?;
+            assert!(alias.matches(address));
+            assert_eq!(*address, alias.resolve(&addresses)?);
+            // Ensure the alias does not resolve without its address.
+            assert!(alias.resolve(addresses.iter().filter(|candidate| *candidate != address)).is_err());
+        }
+        Ok(())
+    }
+}
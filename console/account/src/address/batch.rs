@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::scrub::Secret;
+use snarkvm_console_types::{Field, Group};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The derivation of many addresses at once, such as for the hot wallet of an exchange.
+pub trait TryFromBatch<N: Network>: Sized {
+    /// Derives the account address of each of the given private keys, in order.
+    fn try_from_batch(private_keys: &[PrivateKey<N>]) -> Result<Vec<Self>>;
+}
+
+impl<N: Network> TryFromBatch<N> for Address<N> {
+    /// Derives the account address of each of the given private keys, in order.
+    ///
+    /// The fixed-base scalar multiplications are computed in parallel, and the conversions into affine coordinates,
+    /// of `(pk_sig, pr_sig)` to derive `sk_prf`, and of the addresses, each share a single batch inversion,
+    /// instead of one field inversion per group element.
+    fn try_from_batch(private_keys: &[PrivateKey<N>]) -> Result<Vec<Self>> {
+        // Compute pk_sig := G^sk_sig and pr_sig := G^r_sig, for each private key.
+        let points = cfg_iter!(private_keys)
+            .map(|private_key| {
+                [*N::g_scalar_multiply(&private_key.sk_sig()), *N::g_scalar_multiply(&private_key.r_sig())]
+            })
+            .collect::<Vec<_>>();
+        // Convert (pk_sig, pr_sig) into affine coordinates, using a batch inversion.
+        let points = N::Projective::batch_normalization_into_affine(points.into_iter().flatten().collect());
+
+        // Compute the address := G^view_key, where view_key := sk_sig + r_sig + sk_prf, for each private key.
+        let addresses = cfg_iter!(private_keys)
+            .enumerate()
+            .map(|(index, private_key)| {
+                let (pk_sig, pr_sig) = (points[2 * index], points[2 * index + 1]);
+                // Compute sk_prf := HashToScalar(pk_sig || pr_sig).
+                let sk_prf = N::hash_to_scalar_psd4(&[
+                    Field::new(pk_sig.to_x_coordinate()),
+                    Field::new(pr_sig.to_x_coordinate()),
+                ])?;
+                // Compute view_key := sk_sig + r_sig + sk_prf, which is scrubbed on drop.
+                let view_key = Secret::new(private_key.sk_sig() + private_key.r_sig() + sk_prf);
+                Ok(*N::g_scalar_multiply(&view_key))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Convert the addresses into affine coordinates, using a batch inversion.
+        let addresses = N::Projective::batch_normalization_into_affine(addresses);
+
+        Ok(addresses.into_iter().map(|address| Address::new(Group::new(address))).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_try_from_batch() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for size in [0, 1, 2, 7, 64] {
+            // Sample the private keys.
+            let private_keys = (0..size).map(|_| PrivateKey::<CurrentNetwork>::new(rng)).collect::<Result<Vec<_>>>()?;
+
+            // Ensure the batch derivation matches the derivation of each address.
+            let expected = private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+            assert_eq!(expected, Address::try_from_batch(&private_keys)?);
+        }
+        Ok(())
+    }
+}
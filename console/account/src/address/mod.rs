@@ -14,6 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod alias;
+pub use alias::*;
+
+#[cfg(feature = "private_key")]
+mod batch;
+#[cfg(feature = "private_key")]
+pub use batch::*;
+
+mod shared_secret;
+pub use shared_secret::*;
+
 mod try_from;
 
 #[cfg(feature = "compute_key")]
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The Diffie-Hellman key exchange on account addresses.
+///
+/// For an address `A = view_key * G` and a scalar `r`, the shared secret is derived from the point `r * A`,
+/// which the owner of the address recomputes as `view_key * R` from the public point `R = r * G`.
+/// The point is passed through a domain-separated key derivation, `HashPSD2(shared_secret_domain, (r * A).x)`,
+/// so the shared secret is unrelated to any other hash of the same point, such as a record view key.
+pub trait SharedSecret<N: Network> {
+    /// Returns the shared secret between the address and the given (ephemeral or view key) scalar.
+    fn shared_secret(&self, scalar: &Scalar<N>) -> Result<Field<N>>;
+}
+
+impl<N: Network> SharedSecret<N> for Address<N> {
+    /// Returns the shared secret between the address and the given (ephemeral or view key) scalar.
+    fn shared_secret(&self, scalar: &Scalar<N>) -> Result<Field<N>> {
+        // Ensure the scalar is nonzero, as the shared secret would otherwise be public.
+        ensure!(!scalar.is_zero(), "Cannot derive a shared secret from a zero scalar");
+        derive_shared_secret(**self * *scalar)
+    }
+}
+
+/// Returns the shared secret for the given Diffie-Hellman point.
+fn derive_shared_secret<N: Network>(point: Group<N>) -> Result<Field<N>> {
+    // Ensure the point is not the identity, as the shared secret would otherwise be public.
+    ensure!(!point.is_zero(), "Cannot derive a shared secret from the identity");
+    N::hash_psd2(&[N::shared_secret_domain(), point.to_x_coordinate()])
+}
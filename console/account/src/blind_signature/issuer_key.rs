@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The secret key of a credential issuer, which signs blinded messages.
+///
+/// The issuer key is sampled independently of any account key, and its signatures are `BlindSignature`s
+/// under a separate domain, so a blind signing session can never produce a signature for an account.
+///
+/// Note: Blind Schnorr signatures are forgeable if the issuer answers many sessions concurrently (the ROS attack).
+/// A session mutably borrows the issuer key, so an issuer key has at most one open session at a time.
+pub struct IssuerKey<N: Network> {
+    /// The secret key.
+    sk: Secret<Scalar<N>>,
+    /// The public key, as `sk * G`.
+    public_key: Group<N>,
+}
+
+impl<N: Network> IssuerKey<N> {
+    /// Samples a new issuer key.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the secret key from the scalar field.
+        let sk = Secret::new(Scalar::rand(rng));
+        // Compute the public key.
+        let public_key = N::g_scalar_multiply(&sk);
+        Self { sk, public_key }
+    }
+
+    /// Returns the issuer public key, which verifies the blind signatures of the issuer.
+    pub const fn public_key(&self) -> Group<N> {
+        self.public_key
+    }
+
+    /// Returns the secret key.
+    pub(super) fn sk(&self) -> Scalar<N> {
+        *self.sk
+    }
+}
+
+impl<N: Network> fmt::Debug for IssuerKey<N> {
+    /// Writes the public key of the issuer, without revealing the secret key.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IssuerKey").field("public_key", &self.public_key).finish_non_exhaustive()
+    }
+}
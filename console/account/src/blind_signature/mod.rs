@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod issuer_key;
+pub use issuer_key::IssuerKey;
+
+mod session;
+pub use session::BlindSession;
+
+mod signature;
+pub use signature::BlindSignature;
+
+mod unblinder;
+pub use unblinder::Unblinder;
+
+use crate::scrub::Secret;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The domain separator for the challenge of a blind signature.
+const BLIND_SIGNATURE_DOMAIN: &str = "AleoBlindSignature0";
+
+/// Returns the challenge for the given nonce commitment, issuer public key, and message, where:
+///     challenge := HashToScalar(domain, g_r, public_key, message)
+fn compute_challenge<N: Network>(g_r: Group<N>, public_key: Group<N>, message: &[Field<N>]) -> Result<Scalar<N>> {
+    // Construct the hash input as (domain, r * G, public_key, message).
+    let mut preimage = Vec::with_capacity(3 + message.len());
+    preimage.push(Field::new_domain_separator(BLIND_SIGNATURE_DOMAIN));
+    preimage.extend([g_r, public_key].map(|point| point.to_x_coordinate()));
+    preimage.extend(message);
+    // Compute the challenge.
+    N::hash_to_scalar_psd8(&preimage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_blind_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample the issuer.
+            let mut issuer_key = IssuerKey::<CurrentNetwork>::new(rng);
+            let public_key = issuer_key.public_key();
+            // Sample the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+            // Run the signing session.
+            let (session, commitment) = issuer_key.start_session(rng);
+            let (unblinder, blinded_challenge) = Unblinder::new(public_key, commitment, &message, rng)?;
+            let blinded_response = session.sign(blinded_challenge)?;
+            let signature = unblinder.unblind(blinded_response)?;
+
+            // Ensure the signature verifies under the issuer public key.
+            assert!(signature.verify(&public_key, &message));
+            // Ensure the issuer can not link the signature to the session.
+            assert_ne!(blinded_challenge, signature.challenge());
+            assert_ne!(blinded_response, signature.response());
+
+            // Ensure the signature is invalid for an incorrect message, or another issuer.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!signature.verify(&public_key, &failure_message));
+            }
+            assert!(!signature.verify(&IssuerKey::<CurrentNetwork>::new(rng).public_key(), &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_blind_sign_invalid_response() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the issuer, and another issuer.
+        let mut issuer_key = IssuerKey::<CurrentNetwork>::new(rng);
+        let public_key = issuer_key.public_key();
+        let mut other_issuer_key = IssuerKey::<CurrentNetwork>::new(rng);
+        let message: Vec<_> = (0..3).map(|_| Uniform::rand(rng)).collect();
+
+        // Ensure a response from another issuer is rejected.
+        let (_, commitment) = issuer_key.start_session(rng);
+        let (session, _) = other_issuer_key.start_session(rng);
+        let (unblinder, blinded_challenge) = Unblinder::new(public_key, commitment, &message, rng)?;
+        assert!(unblinder.unblind(session.sign(blinded_challenge)?).is_err());
+
+        // Ensure a response under the nonce of another session is rejected.
+        let (_, commitment) = issuer_key.start_session(rng);
+        let (session, _) = issuer_key.start_session(rng);
+        let (unblinder, blinded_challenge) = Unblinder::new(public_key, commitment, &message, rng)?;
+        assert!(unblinder.unblind(session.sign(blinded_challenge)?).is_err());
+
+        // Ensure a zero challenge is rejected.
+        let (session, _) = issuer_key.start_session(rng);
+        assert!(session.sign(Scalar::zero()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A blind signing session of an issuer, which holds the secret nonce of the session.
+pub struct BlindSession<'a, N: Network> {
+    /// The issuer key, which is borrowed for the duration of the session.
+    issuer_key: &'a mut IssuerKey<N>,
+    /// The secret nonce.
+    nonce: Secret<Scalar<N>>,
+}
+
+impl<N: Network> IssuerKey<N> {
+    /// Starts a blind signing session, and returns it with the nonce commitment `nonce * G`, which is shared with the user.
+    pub fn start_session<R: Rng + CryptoRng>(&mut self, rng: &mut R) -> (BlindSession<'_, N>, Group<N>) {
+        // Sample the secret nonce from the scalar field.
+        let nonce = Secret::new(Scalar::rand(rng));
+        // Compute the commitment.
+        let commitment = N::g_scalar_multiply(&nonce);
+        (BlindSession { issuer_key: self, nonce }, commitment)
+    }
+}
+
+impl<N: Network> BlindSession<'_, N> {
+    /// Returns the blinded response to the given blinded challenge, as `nonce - blinded_challenge * sk`,
+    /// and closes the session.
+    pub fn sign(self, blinded_challenge: Scalar<N>) -> Result<Scalar<N>> {
+        // Ensure the blinded challenge is nonzero, as a zero challenge would not bind the response to the key.
+        ensure!(!blinded_challenge.is_zero(), "The blinded challenge must be nonzero");
+
+        // Compute the blinded response.
+        // Note: The product is scrubbed on drop, as it reveals the secret key.
+        let product = Secret::new(blinded_challenge * self.issuer_key.sk());
+        Ok(*self.nonce - *product)
+    }
+}
+
+impl<N: Network> fmt::Debug for BlindSession<'_, N> {
+    /// Writes the fingerprint of the secret nonce, without revealing the secret nonce.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nonce = [self.nonce.to_field().map_err(|_| fmt::Error)?];
+        snarkvm_console_network::fmt_redacted::<N>(f, "BlindSession", &nonce)
+    }
+}
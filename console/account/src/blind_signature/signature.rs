@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A blind signature `(challenge, response)` of an issuer, which verifies under the issuer public key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlindSignature<N: Network> {
+    /// The verifier challenge to check against.
+    challenge: Scalar<N>,
+    /// The prover response to the challenge.
+    response: Scalar<N>,
+}
+
+impl<N: Network> BlindSignature<N> {
+    /// Initializes a blind signature from the given challenge and response.
+    pub const fn new(challenge: Scalar<N>, response: Scalar<N>) -> Self {
+        Self { challenge, response }
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Verifies (challenge == challenge') where:
+    ///     challenge' := HashToScalar(domain, G^response public_key^challenge, public_key, message)
+    pub fn verify(&self, public_key: &Group<N>, message: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            return false;
+        }
+        // Ensure the public key is not the identity.
+        if public_key.is_zero() {
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * public_key).
+        let g_r = N::g_scalar_multiply(&self.response) + (*public_key * self.challenge);
+        // Compute the candidate challenge, and ensure it is correct.
+        match compute_challenge(g_r, *public_key, message) {
+            Ok(candidate_challenge) => self.challenge == candidate_challenge,
+            Err(_) => false,
+        }
+    }
+}
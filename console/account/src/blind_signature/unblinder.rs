@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The state of a user in a blind signing session, which unblinds the response of the issuer into a blind signature.
+pub struct Unblinder<N: Network> {
+    /// The public key of the issuer.
+    public_key: Group<N>,
+    /// The message.
+    message: Vec<Field<N>>,
+    /// The blinding factor of the response.
+    alpha: Scalar<N>,
+    /// The unblinded challenge.
+    challenge: Scalar<N>,
+}
+
+impl<N: Network> Unblinder<N> {
+    /// Blinds the challenge for the given message, under the given issuer public key and nonce commitment,
+    /// and returns the unblinder with the blinded challenge, which is shared with the issuer, where:
+    ///     g_r' := commitment + alpha * G - beta * public_key
+    ///     challenge := HashToScalar(domain, g_r', public_key, message)
+    ///     blinded_challenge := challenge + beta
+    pub fn new<R: Rng + CryptoRng>(
+        public_key: Group<N>,
+        commitment: Group<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<(Self, Scalar<N>)> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Ensure the public key and the commitment are not the identity.
+        ensure!(!public_key.is_zero(), "The issuer public key must not be the identity");
+        ensure!(!commitment.is_zero(), "The nonce commitment must not be the identity");
+
+        // Sample the blinding factors from the scalar field.
+        let (alpha, beta) = (Scalar::rand(rng), Scalar::rand(rng));
+
+        // Compute the blinded `g_r` := commitment + (alpha * G) - (beta * public_key).
+        let g_r = commitment + N::g_scalar_multiply(&alpha) - (public_key * beta);
+
+        // Compute the challenge, and blind it.
+        let challenge = compute_challenge(g_r, public_key, message)?;
+        let blinded_challenge = challenge + beta;
+
+        Ok((Self { public_key, message: message.to_vec(), alpha, challenge }, blinded_challenge))
+    }
+
+    /// Unblinds the given blinded response from the issuer into a blind signature, as
+    /// `response := blinded_response + alpha`, and ensures the signature is valid for the message
+    /// under the issuer public key.
+    pub fn unblind(self, blinded_response: Scalar<N>) -> Result<BlindSignature<N>> {
+        // Compute the signature.
+        let signature = BlindSignature::new(self.challenge, blinded_response + self.alpha);
+        // Ensure the signature is valid, as the issuer may respond incorrectly.
+        ensure!(signature.verify(&self.public_key, &self.message), "The blinded response from the issuer is invalid");
+        Ok(signature)
+    }
+}
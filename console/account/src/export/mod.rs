@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A versioned, self-describing binary container for exchanging account keys between tools, of the form:
+//! ```text
+//! magic (4 bytes) || version (u8) || network ID (u16) || key type (u8) || payload length (u32) || payload || checksum
+//! ```
+//! where all integers are little-endian, the payload is the little-endian byte encoding of the key,
+//! and the checksum is the first 4 bytes of the SHA-256 hash of all of the preceding bytes.
+
+use crate::{ComputeKey, PrivateKey, scrub::Secret, ViewKey};
+use snarkvm_console_network::prelude::*;
+
+use sha2::{Digest, Sha256};
+
+/// The magic bytes that prefix a key container.
+const CONTAINER_MAGIC: [u8; 4] = *b"ALEK";
+/// The version of the key container format.
+const CONTAINER_VERSION: u8 = 1;
+/// The number of bytes in the header of a key container.
+const HEADER_SIZE_IN_BYTES: usize = 4 + 1 + 2 + 1 + 4;
+/// The number of bytes in the checksum of a key container.
+const CHECKSUM_SIZE_IN_BYTES: usize = 4;
+/// The maximum number of bytes in the payload of a key container.
+const MAX_PAYLOAD_SIZE_IN_BYTES: usize = 1024;
+
+/// The type of the key in a key container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum KeyType {
+    /// An account private key.
+    PrivateKey = 0,
+    /// An account view key.
+    ViewKey = 1,
+    /// An account compute key.
+    ComputeKey = 2,
+}
+
+impl TryFrom<u8> for KeyType {
+    type Error = Error;
+
+    /// Returns the key type for the given byte.
+    fn try_from(key_type: u8) -> Result<Self> {
+        match key_type {
+            0 => Ok(Self::PrivateKey),
+            1 => Ok(Self::ViewKey),
+            2 => Ok(Self::ComputeKey),
+            _ => bail!("Unknown key type {key_type} in the key container"),
+        }
+    }
+}
+
+/// Returns the key type of the given key container, without importing the key.
+pub fn peek_key_type<N: Network>(container: &[u8]) -> Result<KeyType> {
+    let (key_type, _) = decode::<N>(container)?;
+    Ok(key_type)
+}
+
+/// Returns the key container for the given key type and payload, on the current network.
+fn encode<N: Network>(key_type: KeyType, payload: &[u8]) -> Result<Vec<u8>> {
+    ensure!(payload.len() <= MAX_PAYLOAD_SIZE_IN_BYTES, "The key payload exceeds {MAX_PAYLOAD_SIZE_IN_BYTES} bytes");
+
+    let mut container = Vec::with_capacity(HEADER_SIZE_IN_BYTES + payload.len() + CHECKSUM_SIZE_IN_BYTES);
+    container.extend_from_slice(&CONTAINER_MAGIC);
+    container.push(CONTAINER_VERSION);
+    container.extend_from_slice(&N::ID.to_le_bytes());
+    container.push(key_type as u8);
+    container.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    container.extend_from_slice(payload);
+    container.extend_from_slice(&checksum(&container));
+    Ok(container)
+}
+
+/// Returns the key type and payload of the given key container, on the current network.
+fn decode<N: Network>(container: &[u8]) -> Result<(KeyType, &[u8])> {
+    ensure!(container.len() >= HEADER_SIZE_IN_BYTES + CHECKSUM_SIZE_IN_BYTES, "The key container is truncated");
+    // Ensure the checksum is correct, before interpreting any other field.
+    let (body, expected_checksum) = container.split_at(container.len() - CHECKSUM_SIZE_IN_BYTES);
+    ensure!(checksum(body) == expected_checksum, "The key container checksum is invalid");
+
+    // Ensure the magic bytes and version are correct.
+    let (header, payload) = body.split_at(HEADER_SIZE_IN_BYTES);
+    ensure!(header[0..4] == CONTAINER_MAGIC, "The bytes are not a key container");
+    ensure!(header[4] == CONTAINER_VERSION, "Unsupported key container version {}", header[4]);
+    // Ensure the key is for the current network.
+    let network_id = u16::from_le_bytes([header[5], header[6]]);
+    ensure!(network_id == N::ID, "The key container is for network {network_id}, expected network {}", N::ID);
+    // Ensure the payload length is correct.
+    let key_type = KeyType::try_from(header[7])?;
+    let payload_len = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+    ensure!(payload_len == payload.len(), "The key container payload length is invalid");
+    Ok((key_type, payload))
+}
+
+/// Returns the checksum of the given bytes.
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_SIZE_IN_BYTES] {
+    let hash = Sha256::digest(bytes);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Returns the key of the given key type from the given key container, ensuring the payload is fully consumed.
+fn import<N: Network, K: FromBytes>(expected: KeyType, container: &[u8]) -> Result<K> {
+    let (key_type, mut payload) = decode::<N>(container)?;
+    ensure!(key_type == expected, "Expected a key container for a {expected:?}, found a {key_type:?}");
+    let key = K::read_le(&mut payload)?;
+    ensure!(payload.is_empty(), "The key container payload has trailing bytes");
+    Ok(key)
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key, in a binary key container.
+    pub fn export(&self) -> Result<Vec<u8>> {
+        // Note: The payload is scrubbed on drop.
+        let payload = Secret::new(self.to_bytes_le()?);
+        encode::<N>(KeyType::PrivateKey, &payload)
+    }
+
+    /// Returns the private key from the given binary key container.
+    pub fn import(container: &[u8]) -> Result<Self> {
+        import::<N, _>(KeyType::PrivateKey, container)
+    }
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Returns the view key, in a binary key container.
+    pub fn export(&self) -> Result<Vec<u8>> {
+        // Note: The payload is scrubbed on drop.
+        let payload = Secret::new(self.to_bytes_le()?);
+        encode::<N>(KeyType::ViewKey, &payload)
+    }
+
+    /// Returns the view key from the given binary key container.
+    pub fn import(container: &[u8]) -> Result<Self> {
+        import::<N, _>(KeyType::ViewKey, container)
+    }
+}
+
+impl<N: Network> ComputeKey<N> {
+    /// Returns the compute key, in a binary key container.
+    pub fn export(&self) -> Result<Vec<u8>> {
+        encode::<N>(KeyType::ComputeKey, &self.to_bytes_le()?)
+    }
+
+    /// Returns the compute key from the given binary key container.
+    pub fn import(container: &[u8]) -> Result<Self> {
+        import::<N, _>(KeyType::ComputeKey, container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_export_and_import() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample the keys.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+
+            // Ensure each key round trips.
+            assert_eq!(private_key, PrivateKey::import(&private_key.export()?)?);
+            assert_eq!(view_key, ViewKey::import(&view_key.export()?)?);
+            assert_eq!(compute_key, ComputeKey::import(&compute_key.export()?)?);
+
+            // Ensure the key type is self-describing, and a key is not imported as another key type.
+            assert_eq!(KeyType::ViewKey, peek_key_type::<CurrentNetwork>(&view_key.export()?)?);
+            assert!(ViewKey::<CurrentNetwork>::import(&private_key.export()?).is_err());
+            assert!(PrivateKey::<CurrentNetwork>::import(&compute_key.export()?).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let container = private_key.export()?;
+        assert_eq!(CONTAINER_MAGIC, container[0..4]);
+
+        // Ensure a truncated or modified container is rejected.
+        assert!(PrivateKey::<CurrentNetwork>::import(&container[..container.len() - 1]).is_err());
+        assert!(PrivateKey::<CurrentNetwork>::import(&[]).is_err());
+        for index in 0..container.len() {
+            let mut candidate = container.clone();
+            candidate[index] ^= 1;
+            assert!(PrivateKey::<CurrentNetwork>::import(&candidate).is_err());
+        }
+
+        // Ensure a container for another network is rejected, even with a valid checksum.
+        let mut body = container[..container.len() - CHECKSUM_SIZE_IN_BYTES].to_vec();
+        body[5..7].copy_from_slice(&(CurrentNetwork::ID + 1).to_le_bytes());
+        let candidate = [body.clone(), checksum(&body).to_vec()].concat();
+        assert!(PrivateKey::<CurrentNetwork>::import(&candidate).is_err());
+        Ok(())
+    }
+}
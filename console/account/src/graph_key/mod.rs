@@ -19,15 +19,18 @@ mod serialize;
 mod string;
 mod try_from;
 
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
 #[cfg(feature = "view_key")]
 use crate::ViewKey;
 
-use snarkvm_console_network::prelude::*;
+use snarkvm_console_network::{fmt_redacted, prelude::*, Revealed};
 use snarkvm_console_types::Field;
 
 use base58::{FromBase58, ToBase58};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct GraphKey<N: Network> {
     /// The graph key `sk_tag` := Hash(view_key || ctr).
     sk_tag: Field<N>,
@@ -47,6 +47,20 @@ impl<N: Network> fmt::Display for GraphKey<N> {
     }
 }
 
+impl<N: Network> fmt::Debug for GraphKey<N> {
+    /// Writes the fingerprint of the account graph key, without revealing the account graph key.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_redacted::<N>(f, "GraphKey", &[self.sk_tag])
+    }
+}
+
+impl<N: Network> GraphKey<N> {
+    /// Returns a reference to the account graph key, whose `Debug` implementation writes the account graph key in full.
+    pub const fn reveal(&self) -> Revealed<Self> {
+        Revealed::new(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +86,23 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_debug_is_redacted() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new graph key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let graph_key = GraphKey::try_from(view_key)?;
+
+            // Ensure the debug representation does not reveal the graph key.
+            let candidate = format!("{graph_key:?}");
+            assert!(candidate.starts_with("GraphKey(<redacted "));
+            assert!(!candidate.contains(&graph_key.to_string()));
+            assert!(!candidate.contains(&graph_key.sk_tag().to_string()));
+            // Ensure the revealed representation matches the string representation.
+            assert_eq!(graph_key.to_string(), format!("{:?}", graph_key.reveal()));
+        }
+        Ok(())
+    }
 }
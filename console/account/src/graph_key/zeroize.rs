@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::scrub::scrub;
+
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for GraphKey<N> {
+    /// Overwrites the graph key with zero.
+    ///
+    /// Note: As the graph key is `Copy`, it is not zeroized on drop, and zeroizing it does not zeroize its copies.
+    /// To zeroize the graph key on drop, wrap it in `Zeroizing`.
+    fn zeroize(&mut self) {
+        scrub(&mut self.sk_tag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?)?;
+        let mut graph_key = GraphKey::try_from(view_key)?;
+        graph_key.zeroize();
+        assert_eq!(Field::zero(), graph_key.sk_tag());
+        Ok(())
+    }
+}
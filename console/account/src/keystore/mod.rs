@@ -0,0 +1,279 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod string;
+
+use crate::{PrivateKey, scrub::Secret};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Address;
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm,
+    Nonce,
+};
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u8 = 1;
+/// The name of the key derivation function.
+const KEYSTORE_KDF: &str = "scrypt";
+/// The name of the cipher.
+const KEYSTORE_CIPHER: &str = "aes-256-gcm";
+/// The number of bytes in the scrypt salt.
+const SALT_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in the AES-GCM nonce.
+const NONCE_SIZE_IN_BYTES: usize = 12;
+/// The number of bytes in the AES-256 key.
+const KEY_SIZE_IN_BYTES: usize = 32;
+
+/// A private key, encrypted under a password, in a versioned JSON container of the form:
+/// ```json
+/// {
+///   "version": 1,
+///   "address": "aleo1...",
+///   "kdf": { "name": "scrypt", "log_n": 15, "r": 8, "p": 1, "salt": "..." },
+///   "cipher": { "name": "aes-256-gcm", "nonce": "...", "ciphertext": "..." }
+/// }
+/// ```
+/// The encryption key is derived from the password with scrypt, and the private key is encrypted with
+/// AES-256-GCM, where the address is authenticated as associated data.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Keystore<N: Network> {
+    /// The version of the keystore format.
+    version: u8,
+    /// The address of the encrypted private key.
+    address: Address<N>,
+    /// The key derivation parameters.
+    kdf: KdfParams,
+    /// The cipher parameters.
+    cipher: CipherParams,
+}
+
+/// The scrypt parameters, where the salt is hex-encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    name: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+/// The AES-GCM parameters, where the nonce and ciphertext are hex-encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    name: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl<N: Network> Keystore<N> {
+    /// The default scrypt cost parameter, as a power of two.
+    pub const DEFAULT_LOG_N: u8 = 15;
+    /// The maximum scrypt cost parameter accepted when decrypting, as a power of two.
+    pub const MAX_LOG_N: u8 = 20;
+    /// The scrypt block size parameter.
+    const SCRYPT_R: u32 = 8;
+    /// The scrypt parallelization parameter.
+    const SCRYPT_P: u32 = 1;
+
+    /// Encrypts the given private key under the given password, with the default scrypt cost.
+    pub fn encrypt<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, password: &str, rng: &mut R) -> Result<Self> {
+        Self::encrypt_with_log_n(private_key, password, Self::DEFAULT_LOG_N, rng)
+    }
+
+    /// Encrypts the given private key under the given password, with a scrypt cost of `2^log_n`.
+    pub fn encrypt_with_log_n<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        log_n: u8,
+        rng: &mut R,
+    ) -> Result<Self> {
+        ensure!(log_n <= Self::MAX_LOG_N, "The scrypt cost 2^{log_n} exceeds the maximum 2^{}", Self::MAX_LOG_N);
+
+        // Compute the address.
+        let address = Address::try_from(private_key)?;
+
+        // Sample the salt and nonce.
+        let salt: [u8; SALT_SIZE_IN_BYTES] = rng.gen();
+        let nonce: [u8; NONCE_SIZE_IN_BYTES] = rng.gen();
+
+        // Derive the encryption key, and serialize the private key, which are both scrubbed on drop.
+        let key = Secret::new(Self::derive_key(password, &salt, log_n, Self::SCRYPT_R, Self::SCRYPT_P)?);
+        let plaintext = Secret::new(private_key.to_bytes_le()?);
+        let aad = address.to_bytes_le()?;
+        // Encrypt the private key, authenticating the address.
+        let ciphertext = Aes256Gcm::new_from_slice(key.as_slice())
+            .map_err(|_| anyhow!("Invalid keystore encryption key"))
+            .and_then(|cipher| {
+                cipher
+                    .encrypt(Nonce::from_slice(&nonce), Payload { msg: &plaintext, aad: &aad })
+                    .map_err(|_| anyhow!("Failed to encrypt the private key"))
+            })?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address,
+            kdf: KdfParams {
+                name: KEYSTORE_KDF.to_string(),
+                log_n,
+                r: Self::SCRYPT_R,
+                p: Self::SCRYPT_P,
+                salt: hex::encode(salt),
+            },
+            cipher: CipherParams {
+                name: KEYSTORE_CIPHER.to_string(),
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(ciphertext),
+            },
+        })
+    }
+
+    /// Decrypts the private key with the given password.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey<N>> {
+        // Ensure the keystore is supported.
+        ensure!(self.version == KEYSTORE_VERSION, "Unsupported keystore version {}", self.version);
+        ensure!(self.kdf.name == KEYSTORE_KDF, "Unsupported keystore key derivation '{}'", self.kdf.name);
+        ensure!(self.cipher.name == KEYSTORE_CIPHER, "Unsupported keystore cipher '{}'", self.cipher.name);
+        // Ensure the scrypt cost is bounded, as the keystore may be untrusted.
+        ensure!(
+            self.kdf.log_n <= Self::MAX_LOG_N,
+            "The scrypt cost 2^{} exceeds the maximum 2^{}",
+            self.kdf.log_n,
+            Self::MAX_LOG_N
+        );
+        // Ensure the remaining scrypt parameters match the supported parameters, as they also scale the cost.
+        ensure!(self.kdf.r == Self::SCRYPT_R, "Unsupported scrypt block size {}", self.kdf.r);
+        ensure!(self.kdf.p == Self::SCRYPT_P, "Unsupported scrypt parallelization {}", self.kdf.p);
+
+        // Decode the salt, nonce, and ciphertext.
+        let salt = hex::decode(&self.kdf.salt)?;
+        let nonce = hex::decode(&self.cipher.nonce)?;
+        let ciphertext = hex::decode(&self.cipher.ciphertext)?;
+        ensure!(salt.len() == SALT_SIZE_IN_BYTES, "Invalid keystore salt length {}", salt.len());
+        ensure!(nonce.len() == NONCE_SIZE_IN_BYTES, "Invalid keystore nonce length {}", nonce.len());
+
+        // Derive the encryption key, which is scrubbed on drop.
+        let key = Secret::new(Self::derive_key(password, &salt, self.kdf.log_n, Self::SCRYPT_R, Self::SCRYPT_P)?);
+        let aad = self.address.to_bytes_le()?;
+        // Decrypt the private key, authenticating the address, where the plaintext is scrubbed on drop.
+        let plaintext = Secret::new(
+            Aes256Gcm::new_from_slice(key.as_slice())
+                .map_err(|_| anyhow!("Invalid keystore encryption key"))
+                .and_then(|cipher| {
+                    cipher.decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &aad }).map_err(|_| {
+                        anyhow!("Failed to decrypt the keystore (incorrect password or corrupted keystore)")
+                    })
+                })?,
+        );
+
+        // Ensure the private key matches the address.
+        let private_key = PrivateKey::from_bytes_le(&plaintext)?;
+        ensure!(Address::try_from(&private_key)? == self.address, "The keystore address does not match");
+        Ok(private_key)
+    }
+
+    /// Returns the version of the keystore format.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the address of the encrypted private key.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the encryption key derived from the password with scrypt.
+    fn derive_key(password: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<[u8; KEY_SIZE_IN_BYTES]> {
+        let params = scrypt::Params::new(log_n, r, p).map_err(|error| anyhow!("Invalid scrypt parameters: {error}"))?;
+        let mut key = [0u8; KEY_SIZE_IN_BYTES];
+        scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+            .map_err(|error| anyhow!("Failed to derive the keystore key: {error}"))?;
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A low scrypt cost, to keep the tests fast.
+    const TEST_LOG_N: u8 = 8;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_keystore() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+            // Ensure the keystore decrypts to the private key, only with the password.
+            let keystore = Keystore::encrypt_with_log_n(&expected, "correct horse", TEST_LOG_N, rng)?;
+            assert_eq!(Address::try_from(&expected)?, *keystore.address());
+            assert_eq!(expected, keystore.decrypt("correct horse")?);
+            assert!(keystore.decrypt("incorrect horse").is_err());
+            assert!(keystore.decrypt("").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_tampered() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = Keystore::encrypt_with_log_n(&private_key, "password", TEST_LOG_N, rng)?;
+
+        // Ensure a keystore with a substituted address is rejected.
+        let mut candidate = keystore.clone();
+        candidate.address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(candidate.decrypt("password").is_err());
+
+        // Ensure a keystore with a modified ciphertext is rejected.
+        let mut candidate = keystore.clone();
+        let mut ciphertext = hex::decode(&candidate.cipher.ciphertext)?;
+        ciphertext[0] ^= 1;
+        candidate.cipher.ciphertext = hex::encode(ciphertext);
+        assert!(candidate.decrypt("password").is_err());
+
+        // Ensure a keystore with an unsupported version or excessive cost is rejected.
+        let mut candidate = keystore.clone();
+        candidate.version = KEYSTORE_VERSION + 1;
+        assert!(candidate.decrypt("password").is_err());
+        let mut candidate = keystore.clone();
+        candidate.kdf.log_n = Keystore::<CurrentNetwork>::MAX_LOG_N + 1;
+        assert!(candidate.decrypt("password").is_err());
+        let mut candidate = keystore.clone();
+        candidate.kdf.r = u32::MAX;
+        assert!(candidate.decrypt("password").is_err());
+        let mut candidate = keystore.clone();
+        candidate.kdf.p = u32::MAX;
+        assert!(candidate.decrypt("password").is_err());
+
+        // Ensure a keystore with a truncated salt is rejected.
+        let mut candidate = keystore;
+        candidate.kdf.salt = candidate.kdf.salt[..2].to_string();
+        assert!(candidate.decrypt("password").is_err());
+        Ok(())
+    }
+}
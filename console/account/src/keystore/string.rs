@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromStr for Keystore<N> {
+    type Err = Error;
+
+    /// Reads in a keystore from a JSON string.
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(keystore)?)
+    }
+}
+
+impl<N: Network> fmt::Debug for Keystore<N> {
+    /// Prints the keystore as a JSON string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> fmt::Display for Keystore<N> {
+    /// Writes the keystore as a pretty-printed JSON string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string_pretty(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample a new keystore.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = Keystore::encrypt_with_log_n(&private_key, "password", 8, rng)?;
+
+        // Ensure the string representation round trips.
+        let candidate = Keystore::<CurrentNetwork>::from_str(&expected.to_string())?;
+        assert_eq!(expected, candidate);
+        assert_eq!(private_key, candidate.decrypt("password")?);
+
+        // Ensure the container exposes the versioned fields.
+        let json = serde_json::Value::from_str(&expected.to_string())?;
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["address"], expected.address().to_string());
+        assert_eq!(json["kdf"]["name"], "scrypt");
+        assert_eq!(json["cipher"]["name"], "aes-256-gcm");
+        Ok(())
+    }
+}
@@ -22,31 +22,72 @@ pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group,
 
 pub mod address;
 
+#[cfg(feature = "blind_signature")]
+pub mod blind_signature;
+#[cfg(feature = "blind_signature")]
+pub use blind_signature::*;
+
 #[cfg(feature = "compute_key")]
 pub mod compute_key;
 #[cfg(feature = "compute_key")]
 pub use compute_key::*;
 
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "export")]
+pub use export::{peek_key_type, KeyType};
+
 #[cfg(feature = "graph_key")]
 pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "keystore")]
+pub use keystore::*;
+
+#[cfg(feature = "multisig")]
+pub mod multisig;
+#[cfg(feature = "multisig")]
+pub use multisig::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
 pub use private_key::*;
 
+#[cfg(feature = "ring_signature")]
+pub mod ring_signature;
+#[cfg(feature = "ring_signature")]
+pub use ring_signature::*;
+
+#[cfg(feature = "sign_in")]
+pub mod sign_in;
+#[cfg(feature = "sign_in")]
+pub use sign_in::*;
+
 #[cfg(feature = "signature")]
 pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "threshold")]
+pub mod threshold;
+#[cfg(feature = "threshold")]
+pub use threshold::*;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
 pub use view_key::*;
 
+#[cfg(any(feature = "private_key", feature = "blind_signature"))]
+mod scrub;
+
+#[cfg(feature = "zeroize")]
+pub use ::zeroize::Zeroizing;
+
 // #[cfg(test)]
 // mod tests {
 //     use crate::{testnet2::Testnet2, Account, Address, Network, PrivateKey, ViewKey};
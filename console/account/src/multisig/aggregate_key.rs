@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The aggregation of the compute keys of an m-of-m multisignature account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateKey<N: Network> {
+    /// The compute keys of the signers, in signing order.
+    compute_keys: Vec<ComputeKey<N>>,
+    /// The key aggregation coefficient of each signer.
+    coefficients: Vec<Scalar<N>>,
+    /// The aggregate compute key.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> AggregateKey<N> {
+    /// Aggregates the given compute keys, where the signers must agree on the order of the compute keys.
+    ///
+    /// Each signer `i` is assigned the coefficient `a_i := HashToScalar(DOMAIN || L || pk_sig_i)`, where `L` is
+    /// the list of all signer keys, and the aggregate compute key is `(sum(a_i * pk_sig_i), sum(a_i * pr_sig_i))`.
+    /// The coefficients prevent a rogue signer from choosing its key to cancel out the keys of the other signers.
+    pub fn new(compute_keys: &[ComputeKey<N>]) -> Result<Self> {
+        // Ensure there is at least one signer.
+        ensure!(!compute_keys.is_empty(), "A multisignature requires at least one signer");
+        // Ensure the signers are unique.
+        ensure!(!has_duplicates(compute_keys.iter()), "Found a duplicate signer in the multisignature");
+
+        // Construct the key aggregation preimage as (DOMAIN, L).
+        let mut preimage = Vec::with_capacity(2 + 2 * compute_keys.len());
+        preimage.push(Field::new_domain_separator(MULTISIG_KEY_DOMAIN));
+        preimage.extend(compute_keys.iter().flat_map(|key| [key.pk_sig(), key.pr_sig()].map(|g| g.to_x_coordinate())));
+
+        // Compute the key aggregation coefficient of each signer.
+        let coefficients = compute_keys
+            .iter()
+            .map(|compute_key| {
+                let mut preimage = preimage.clone();
+                preimage.push(compute_key.pk_sig().to_x_coordinate());
+                N::hash_to_scalar_psd8(&preimage)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the aggregate pk_sig and pr_sig.
+        let (pk_sig, pr_sig) = compute_keys.iter().zip_eq(&coefficients).fold(
+            (Group::zero(), Group::zero()),
+            |(pk_sig, pr_sig), (compute_key, coefficient)| {
+                (pk_sig + compute_key.pk_sig() * *coefficient, pr_sig + compute_key.pr_sig() * *coefficient)
+            },
+        );
+        // Derive the aggregate compute key.
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+
+        Ok(Self { compute_keys: compute_keys.to_vec(), coefficients, compute_key })
+    }
+
+    /// Returns the compute keys of the signers, in signing order.
+    pub fn compute_keys(&self) -> &[ComputeKey<N>] {
+        &self.compute_keys
+    }
+
+    /// Returns the key aggregation coefficient of the signer at the given index.
+    pub fn coefficient(&self, index: usize) -> Result<Scalar<N>> {
+        self.coefficients.get(index).copied().ok_or_else(|| anyhow!("Signer index {index} is out of bounds"))
+    }
+
+    /// Returns the aggregate compute key.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Returns the address of the multisignature account.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! MuSig2-style m-of-m multisignatures, which produce a standard `Signature<N>`.
+//!
+//! 1. The signers aggregate their compute keys into an `AggregateKey`, whose address owns the account.
+//! 2. In the first round, each signer samples a `SecretNonce`, and shares its `PublicNonce`.
+//! 3. In the second round, each signer opens a `SigningSession` over the public nonces and the message,
+//!    and shares its `PartialSignature`. The partial signatures are aggregated into a `Signature<N>`,
+//!    which verifies under the aggregate address with `Signature::verify`.
+
+mod aggregate_key;
+pub use aggregate_key::AggregateKey;
+
+mod nonce;
+pub use nonce::{PublicNonce, SecretNonce};
+
+mod session;
+pub use session::{PartialSignature, SigningSession};
+
+use crate::{ComputeKey, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+static MULTISIG_KEY_DOMAIN: &str = "AleoMultisigKeyAggregation0";
+static MULTISIG_NONCE_DOMAIN: &str = "AleoMultisigNonceBinding0";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Signs the given message with all of the given private keys, and returns the aggregate address and signature.
+    fn sign(
+        private_keys: &[PrivateKey<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+    ) -> Result<(Address<CurrentNetwork>, Signature<CurrentNetwork>)> {
+        let rng = &mut test_crypto_rng();
+
+        // Aggregate the compute keys.
+        let compute_keys = private_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+        let aggregate_key = AggregateKey::new(&compute_keys)?;
+
+        // Round 1: Sample the nonces.
+        let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+            private_keys.iter().map(|_| SecretNonce::new(rng)).unzip();
+
+        // Round 2: Compute the partial signatures.
+        let session = SigningSession::new(aggregate_key.clone(), &public_nonces, message)?;
+        let partial_signatures = private_keys
+            .iter()
+            .zip_eq(secret_nonces)
+            .enumerate()
+            .map(|(index, (private_key, secret_nonce))| session.sign(private_key, index, secret_nonce))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure each partial signature is valid.
+        for (index, partial_signature) in partial_signatures.iter().enumerate() {
+            assert!(session.verify_partial(index, partial_signature));
+        }
+
+        // Aggregate the partial signatures.
+        Ok((aggregate_key.to_address(), session.aggregate(&partial_signatures)?))
+    }
+
+    #[test]
+    fn test_multisig_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 1..=ITERATIONS {
+            // Sample the signers.
+            let private_keys = (0..i).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+            // Sample the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+            // Ensure the aggregate signature verifies under the aggregate address.
+            let (address, signature) = sign(&private_keys, &message)?;
+            assert!(signature.verify(&address, &message));
+            assert_eq!(address, signature.to_address());
+
+            // Ensure the aggregate signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!signature.verify(&address, &failure_message));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_multisig_invalid_partial_signature() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the signers.
+        let private_keys = (0..3).map(|_| PrivateKey::<CurrentNetwork>::new(rng)).collect::<Result<Vec<_>>>()?;
+        let compute_keys = private_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+        let aggregate_key = AggregateKey::new(&compute_keys)?;
+        let message: Vec<_> = (0..3).map(|_| Uniform::rand(rng)).collect();
+
+        let (mut secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+            private_keys.iter().map(|_| SecretNonce::new(rng)).unzip();
+        let session = SigningSession::new(aggregate_key, &public_nonces, &message)?;
+
+        // Ensure a signer cannot sign at another signer's index.
+        assert!(session.sign(&private_keys[0], 1, secret_nonces.remove(1)).is_err());
+
+        // Ensure a partial signature is not valid for another signer.
+        let partial_signature = session.sign(&private_keys[0], 0, secret_nonces.remove(0))?;
+        assert!(session.verify_partial(0, &partial_signature));
+        assert!(!session.verify_partial(1, &partial_signature));
+
+        // Ensure the partial signatures cannot be aggregated with a missing signer.
+        assert!(session.aggregate(&[partial_signature]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The secret nonces of a signer for a single signing session.
+///
+/// Note: The secret nonce is consumed when signing, as reusing it across two sessions reveals the private key.
+pub struct SecretNonce<N: Network> {
+    /// The first secret nonce.
+    r_1: Scalar<N>,
+    /// The second secret nonce.
+    r_2: Scalar<N>,
+}
+
+/// The public nonces of a signer for a single signing session, shared with the other signers in the first round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicNonce<N: Network> {
+    /// The first public nonce `g_r_1` := G^r_1.
+    g_r_1: Group<N>,
+    /// The second public nonce `g_r_2` := G^r_2.
+    g_r_2: Group<N>,
+}
+
+impl<N: Network> SecretNonce<N> {
+    /// Samples a new secret nonce, and returns it with its public nonce.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> (Self, PublicNonce<N>) {
+        // Sample the secret nonces from the scalar field.
+        let (r_1, r_2) = (Scalar::rand(rng), Scalar::rand(rng));
+        // Compute the public nonces.
+        let public_nonce = PublicNonce { g_r_1: N::g_scalar_multiply(&r_1), g_r_2: N::g_scalar_multiply(&r_2) };
+        (Self { r_1, r_2 }, public_nonce)
+    }
+
+    /// Returns the first and second secret nonce.
+    pub(super) const fn to_scalars(&self) -> (Scalar<N>, Scalar<N>) {
+        (self.r_1, self.r_2)
+    }
+}
+
+impl<N: Network> fmt::Debug for SecretNonce<N> {
+    /// Writes the fingerprint of the secret nonce, without revealing the secret nonce.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nonces = [self.r_1.to_field().map_err(|_| fmt::Error)?, self.r_2.to_field().map_err(|_| fmt::Error)?];
+        snarkvm_console_network::fmt_redacted::<N>(f, "SecretNonce", &nonces)
+    }
+}
+
+impl<N: Network> PublicNonce<N> {
+    /// Returns the first public nonce.
+    pub const fn g_r_1(&self) -> Group<N> {
+        self.g_r_1
+    }
+
+    /// Returns the second public nonce.
+    pub const fn g_r_2(&self) -> Group<N> {
+        self.g_r_2
+    }
+}
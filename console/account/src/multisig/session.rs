@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The partial signature of a single signer, shared with the other signers in the second round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartialSignature<N: Network>(Scalar<N>);
+
+impl<N: Network> PartialSignature<N> {
+    /// Returns the partial response of the signer.
+    pub const fn response(&self) -> Scalar<N> {
+        self.0
+    }
+}
+
+/// The second round of a multisignature, over the public nonces of every signer and the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningSession<N: Network> {
+    /// The aggregate key of the signers.
+    aggregate_key: AggregateKey<N>,
+    /// The public nonces of the signers, in signing order.
+    public_nonces: Vec<PublicNonce<N>>,
+    /// The nonce binding factor `b` := HashToScalar(DOMAIN || pk_sig || pr_sig || R_1 || R_2 || message).
+    binding: Scalar<N>,
+    /// The verifier challenge of the aggregate signature.
+    challenge: Scalar<N>,
+}
+
+impl<N: Network> SigningSession<N> {
+    /// Initializes a signing session for the given message, from the public nonces of every signer, where:
+    ///     g_r := R_1 + b * R_2
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    /// matching the challenge of `Signature::sign` for the aggregate compute key.
+    pub fn new(aggregate_key: AggregateKey<N>, public_nonces: &[PublicNonce<N>], message: &[Field<N>]) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Ensure there is one public nonce for each signer.
+        ensure!(
+            public_nonces.len() == aggregate_key.compute_keys().len(),
+            "Expected {} public nonces, found {}",
+            aggregate_key.compute_keys().len(),
+            public_nonces.len()
+        );
+
+        // Aggregate the public nonces.
+        let g_r_1 = public_nonces.iter().map(PublicNonce::g_r_1).fold(Group::zero(), |sum, g| sum + g);
+        let g_r_2 = public_nonces.iter().map(PublicNonce::g_r_2).fold(Group::zero(), |sum, g| sum + g);
+
+        // Retrieve the aggregate pk_sig and pr_sig.
+        let pk_sig = aggregate_key.compute_key().pk_sig();
+        let pr_sig = aggregate_key.compute_key().pr_sig();
+
+        // Compute the nonce binding factor, which binds the aggregate nonce to the signers and message.
+        let mut preimage = Vec::with_capacity(5 + message.len());
+        preimage.push(Field::new_domain_separator(MULTISIG_NONCE_DOMAIN));
+        preimage.extend([pk_sig, pr_sig, g_r_1, g_r_2].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        let binding = N::hash_to_scalar_psd8(&preimage)?;
+
+        // Compute `g_r` := R_1 + b * R_2.
+        let g_r = g_r_1 + g_r_2 * binding;
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *aggregate_key.to_address()].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok(Self { aggregate_key, public_nonces: public_nonces.to_vec(), binding, challenge })
+    }
+
+    /// Returns the partial signature of the signer at the given index, where:
+    ///     response_i := r_1 + b * r_2 - challenge * a_i * private_key.sk_sig()
+    pub fn sign(
+        &self,
+        private_key: &PrivateKey<N>,
+        index: usize,
+        secret_nonce: SecretNonce<N>,
+    ) -> Result<PartialSignature<N>> {
+        // Ensure the private key belongs to the signer at the given index.
+        match self.aggregate_key.compute_keys().get(index) {
+            Some(compute_key) => ensure!(
+                *compute_key == ComputeKey::try_from(private_key)?,
+                "The private key does not belong to signer {index}"
+            ),
+            None => bail!("Signer index {index} is out of bounds"),
+        }
+        // Retrieve the key aggregation coefficient of the signer.
+        let coefficient = self.aggregate_key.coefficient(index)?;
+
+        // Compute the partial response.
+        let (r_1, r_2) = secret_nonce.to_scalars();
+        Ok(PartialSignature(r_1 + self.binding * r_2 - self.challenge * coefficient * private_key.sk_sig()))
+    }
+
+    /// Returns `true` if the given partial signature is valid for the signer at the given index, i.e.
+    ///     response_i * G + (challenge * a_i) * pk_sig_i == R_1_i + b * R_2_i
+    pub fn verify_partial(&self, index: usize, partial_signature: &PartialSignature<N>) -> bool {
+        // Retrieve the compute key, public nonce, and coefficient of the signer.
+        let (compute_key, public_nonce, coefficient) = match (
+            self.aggregate_key.compute_keys().get(index),
+            self.public_nonces.get(index),
+            self.aggregate_key.coefficient(index),
+        ) {
+            (Some(compute_key), Some(public_nonce), Ok(coefficient)) => (compute_key, public_nonce, coefficient),
+            _ => return false,
+        };
+
+        // Compute the left-hand side and right-hand side of the verification equation.
+        let candidate =
+            N::g_scalar_multiply(&partial_signature.0) + compute_key.pk_sig() * (self.challenge * coefficient);
+        let expected = public_nonce.g_r_1() + public_nonce.g_r_2() * self.binding;
+        candidate == expected
+    }
+
+    /// Aggregates the partial signatures of every signer, in signing order, into a signature.
+    pub fn aggregate(&self, partial_signatures: &[PartialSignature<N>]) -> Result<Signature<N>> {
+        // Ensure there is one partial signature for each signer.
+        ensure!(
+            partial_signatures.len() == self.aggregate_key.compute_keys().len(),
+            "Expected {} partial signatures, found {}",
+            self.aggregate_key.compute_keys().len(),
+            partial_signatures.len()
+        );
+        // Compute the response as the sum of the partial responses.
+        let response = partial_signatures.iter().fold(Scalar::zero(), |sum, partial| sum + partial.0);
+        // Output the signature.
+        Ok(Signature::from((self.challenge, response, self.aggregate_key.compute_key())))
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+/// A path of hardened child indices, written as `m/44'/0'/1'`, which derives a child private key from a parent.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    /// The hardened child indices, from the parent to the child.
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// The maximum child index, as the indices at or above `2^31` are reserved in BIP32 for hardened derivation.
+    pub const MAX_INDEX: u32 = (1 << 31) - 1;
+
+    /// Initializes a derivation path from the given hardened child indices.
+    pub fn new(indices: &[u32]) -> Result<Self> {
+        // Ensure each index is within bounds.
+        for index in indices {
+            ensure!(*index <= Self::MAX_INDEX, "Child index {index} exceeds the maximum index {}", Self::MAX_INDEX);
+        }
+        Ok(Self { indices: indices.to_vec() })
+    }
+
+    /// Returns the hardened child indices.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns a new derivation path, with the given hardened child index appended.
+    pub fn child(&self, index: u32) -> Result<Self> {
+        ensure!(index <= Self::MAX_INDEX, "Child index {index} exceeds the maximum index {}", Self::MAX_INDEX);
+        let mut indices = self.indices.clone();
+        indices.push(index);
+        Ok(Self { indices })
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parses a derivation path of the form `m/44'/0'/1'`, where each index must be hardened (`'` or `h`).
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut components = path.split('/');
+        // Ensure the path starts from the master key.
+        ensure!(components.next() == Some("m"), "Derivation path '{path}' must start with 'm'");
+
+        let indices = components
+            .map(|component| {
+                // Ensure the index is hardened.
+                let index = match component.strip_suffix('\'').or_else(|| component.strip_suffix('h')) {
+                    Some(index) => index,
+                    None => bail!("Derivation path '{path}' contains a non-hardened index '{component}'"),
+                };
+                index.parse::<u32>().map_err(|_| anyhow!("Derivation path '{path}' contains an invalid index"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(&indices)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    /// Writes the derivation path in the form `m/44'/0'/1'`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.indices {
+            write!(f, "/{index}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given derivation path.
+    ///
+    /// Each step derives the child seed as `HashPSD4(DOMAIN || parent_seed || index)`. As only hardened
+    /// derivation is supported, the parent seed also serves as the BIP32 chain code, and a child key
+    /// reveals nothing about its parent or siblings.
+    pub fn derive(&self, path: &DerivationPath) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Derive the seed at each step of the path.
+        let seed = path
+            .indices()
+            .iter()
+            .try_fold(self.seed, |seed, index| N::hash_psd4(&[domain, seed, Field::from_u32(*index)]))?;
+        // Output the child private key.
+        Self::try_from(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+            // Ensure the empty path derives the private key itself.
+            assert_eq!(private_key, private_key.derive(&DerivationPath::default())?);
+
+            // Ensure the derivation is deterministic.
+            let path = DerivationPath::from_str("m/44'/0'/1'")?;
+            let child = private_key.derive(&path)?;
+            assert_eq!(child, private_key.derive(&path)?);
+            assert_ne!(child, private_key);
+
+            // Ensure the derivation composes along the path.
+            let parent = private_key.derive(&DerivationPath::from_str("m/44'/0'")?)?;
+            assert_eq!(child, parent.derive(&DerivationPath::new(&[1])?)?);
+
+            // Ensure sibling keys are distinct.
+            let sibling = private_key.derive(&DerivationPath::from_str("m/44'/0'/2'")?)?;
+            assert_ne!(child, sibling);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derivation_path_string() -> Result<()> {
+        // Ensure the string representation round trips.
+        for path in ["m", "m/0'", "m/44'/0'/1'", "m/2147483647'"] {
+            assert_eq!(path, DerivationPath::from_str(path)?.to_string());
+        }
+        // Ensure the `h` suffix is accepted for hardened indices.
+        assert_eq!(DerivationPath::from_str("m/44h/0h")?, DerivationPath::new(&[44, 0])?);
+        assert_eq!(DerivationPath::from_str("m/44'/0'")?.child(1)?, DerivationPath::new(&[44, 0, 1])?);
+
+        // Ensure invalid paths are rejected.
+        for path in ["", "44'/0'", "m/0", "m/0'/1", "m/a'", "m/2147483648'", "m//0'"] {
+            assert!(DerivationPath::from_str(path).is_err(), "Parsed the invalid path '{path}'");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use bip39::{Language, Mnemonic};
+use hmac::Hmac;
+use sha2::Sha512;
+
+/// The number of words in an account mnemonic, which encodes 256 bits of entropy.
+const MNEMONIC_NUM_WORDS: usize = 24;
+/// The number of PBKDF2 rounds used to stretch the passphrase, as in BIP39.
+const MNEMONIC_KDF_ROUNDS: u32 = 2048;
+/// The PBKDF2 salt used to stretch the passphrase, as in BIP39.
+const MNEMONIC_KDF_SALT: &[u8] = b"mnemonic";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account private key from a 24-word BIP39 mnemonic and an optional passphrase.
+    ///
+    /// The mnemonic encodes 256 bits of entropy with a SHA-256 checksum, which is reduced into the account seed.
+    /// If the passphrase is non-empty, it is stretched with PBKDF2-HMAC-SHA512 and added to the account seed,
+    /// such that the same mnemonic with a different passphrase recovers an unrelated account.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Parse the mnemonic, which checks each word and the checksum.
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, phrase)
+            .map_err(|error| anyhow!("Invalid account mnemonic: {error}"))?;
+        // Ensure the mnemonic has the expected number of words.
+        ensure!(
+            mnemonic.word_count() == MNEMONIC_NUM_WORDS,
+            "Invalid account mnemonic: found {} words, expected {MNEMONIC_NUM_WORDS}",
+            mnemonic.word_count()
+        );
+        // Reduce the entropy into a field element.
+        let entropy = Field::new(N::Field::from_bytes_le_mod_order(&mnemonic.to_entropy()));
+        // Output the private key.
+        Self::try_from(entropy + Self::passphrase_to_field(passphrase))
+    }
+
+    /// Returns the 24-word BIP39 mnemonic of the account private key, without a passphrase.
+    ///
+    /// Note: A private key recovered from a mnemonic with a passphrase, or from a mnemonic whose entropy
+    /// exceeds the field modulus, returns a different mnemonic that recovers the same private key.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        self.to_mnemonic_with_passphrase("")
+    }
+
+    /// Returns the 24-word BIP39 mnemonic that recovers the account private key with the given passphrase.
+    pub fn to_mnemonic_with_passphrase(&self, passphrase: &str) -> Result<String> {
+        // Remove the passphrase from the account seed.
+        let entropy = self.seed - Self::passphrase_to_field(passphrase);
+        // Encode the entropy as a mnemonic.
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy.to_bytes_le()?)
+            .map_err(|error| anyhow!("Failed to encode the account mnemonic: {error}"))?;
+        Ok(mnemonic.to_string())
+    }
+
+    /// Returns the field element of the given passphrase, which is zero for the empty passphrase.
+    fn passphrase_to_field(passphrase: &str) -> Field<N> {
+        match passphrase.is_empty() {
+            true => Field::zero(),
+            false => {
+                // Stretch the passphrase.
+                let mut output = [0u8; 64];
+                pbkdf2::pbkdf2::<Hmac<Sha512>>(
+                    passphrase.as_bytes(),
+                    MNEMONIC_KDF_SALT,
+                    MNEMONIC_KDF_ROUNDS,
+                    &mut output,
+                );
+                // Reduce the output into a field element.
+                Field::new(N::Field::from_bytes_le_mod_order(&output))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_mnemonic() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+            // Ensure the mnemonic has 24 words, and recovers the private key.
+            let mnemonic = expected.to_mnemonic()?;
+            assert_eq!(MNEMONIC_NUM_WORDS, mnemonic.split_whitespace().count());
+            assert_eq!(expected, PrivateKey::from_mnemonic(&mnemonic, "")?);
+
+            // Ensure the mnemonic with a passphrase recovers the private key, only with the passphrase.
+            let mnemonic = expected.to_mnemonic_with_passphrase("correct horse battery staple")?;
+            assert_eq!(expected, PrivateKey::from_mnemonic(&mnemonic, "correct horse battery staple")?);
+            assert_ne!(expected, PrivateKey::from_mnemonic(&mnemonic, "")?);
+            assert_ne!(expected, PrivateKey::from_mnemonic(&mnemonic, "correct horse battery")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_invalid() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let mnemonic = PrivateKey::<CurrentNetwork>::new(rng)?.to_mnemonic()?;
+        let mut words: Vec<_> = mnemonic.split_whitespace().collect();
+
+        // Ensure a mnemonic with the wrong number of words is rejected.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words[..12].join(" "), "").is_err());
+
+        // Ensure a mnemonic with an invalid checksum is rejected.
+        // Note: Flipping the lowest bit of the last word only changes the checksum bits.
+        let word_list = Language::English.word_list();
+        let last = Language::English.find_word(words[MNEMONIC_NUM_WORDS - 1]).unwrap();
+        words[MNEMONIC_NUM_WORDS - 1] = word_list[(last ^ 1) as usize];
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words.join(" "), "").is_err());
+
+        // Ensure a mnemonic with an unknown word is rejected.
+        words[0] = "aleo";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words.join(" "), "").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_reduces_entropy() -> Result<()> {
+        // Ensure a valid mnemonic whose entropy exceeds the field modulus is accepted.
+        let phrase = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+        let private_key = PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "")?;
+        // Ensure the canonical mnemonic recovers the same private key.
+        let canonical = private_key.to_mnemonic()?;
+        assert_ne!(phrase, canonical);
+        assert_eq!(private_key, PrivateKey::from_mnemonic(&canonical, "")?);
+        Ok(())
+    }
+}
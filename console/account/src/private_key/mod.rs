@@ -14,20 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod derive;
+pub use derive::DerivationPath;
+
 mod bytes;
 mod serialize;
 mod string;
 mod try_from;
 
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+
 #[cfg(feature = "signature")]
 mod sign;
 
-use snarkvm_console_network::prelude::*;
+#[cfg(feature = "signature")]
+mod signer;
+#[cfg(feature = "signature")]
+pub use signer::Signer;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+use snarkvm_console_network::{fmt_redacted, prelude::*, Revealed};
 use snarkvm_console_types::{Field, Scalar};
 
 use base58::{FromBase58, ToBase58};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct PrivateKey<N: Network> {
     /// The account seed that derives the full private key.
     seed: Field<N>,
@@ -16,6 +16,7 @@
 
 use super::*;
 use crate::Signature;
+use snarkvm_console_network::DomainTag;
 
 impl<N: Network> PrivateKey<N> {
     /// Returns a signature for the given message (as field elements) using the private key.
@@ -23,6 +24,22 @@ impl<N: Network> PrivateKey<N> {
         Signature::sign(self, message, rng)
     }
 
+    /// Returns a deterministic signature for the given message (as field elements) using the private key,
+    /// where the nonce is derived from the private key and the message, instead of sampled from an RNG.
+    pub fn sign_deterministic(&self, message: &[Field<N>]) -> Result<Signature<N>> {
+        Signature::sign_deterministic(self, message)
+    }
+
+    /// Returns a signature for the given message (as field elements), bound to the given network and epoch domain.
+    pub fn sign_with_domain<R: Rng + CryptoRng>(
+        &self,
+        domain: &DomainTag<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Signature<N>> {
+        Signature::sign_with_domain(self, domain, message, rng)
+    }
+
     /// Returns a signature for the given message (as bytes) using the private key.
     pub fn sign_bytes<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bytes(self, message, rng)
@@ -32,6 +49,11 @@ impl<N: Network> PrivateKey<N> {
     pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bits(self, message, rng)
     }
+
+    /// Returns a signature for the given digest of a message, which was hashed by the caller, using the private key.
+    pub fn sign_prehashed<R: Rng + CryptoRng>(&self, digest: Field<N>, rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign_prehashed(self, digest, rng)
+    }
 }
 
 #[cfg(test)]
@@ -112,4 +134,94 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_sign_and_verify_with_domain() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message, only in its own domain.
+            let domain = DomainTag::new(1)?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = private_key.sign_with_domain(&domain, &message, rng)?;
+            assert!(signature.verify_with_domain(&address, &domain, &message));
+            assert!(!signature.verify_with_domain(&address, &DomainTag::new(2)?, &message));
+            assert!(!signature.verify_with_domain(&address, &DomainTag::untagged(1), &message));
+            assert!(!signature.verify(&address, &message));
+
+            // Check that the signature is not valid for the message prefixed with the tag.
+            let tagged_message = [&[domain.to_field().unwrap()], message.as_slice()].concat();
+            assert!(!signature.verify(&address, &tagged_message));
+
+            // Check that an untagged signature is not valid in a tagged domain.
+            let signature = private_key.sign(&message, rng)?;
+            assert!(signature.verify_with_domain(&address, &DomainTag::untagged(0), &message));
+            assert!(!signature.verify_with_domain(&address, &domain, &message));
+
+            // Check that an untagged signature for the message prefixed with the tag is not valid in the domain.
+            let signature = private_key.sign(&tagged_message, rng)?;
+            assert!(!signature.verify_with_domain(&address, &domain, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_deterministic() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message, and is reproducible.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = private_key.sign_deterministic(&message)?;
+            assert!(signature.verify(&address, &message));
+            assert_eq!(signature, private_key.sign_deterministic(&message)?);
+
+            // Check that the nonce differs across messages and private keys.
+            let other_message: Vec<_> = (0..=i).map(|_| Uniform::rand(rng)).collect();
+            let other_signature = private_key.sign_deterministic(&other_message)?;
+            assert!(other_signature.verify(&address, &other_message));
+            assert_ne!(signature.challenge(), other_signature.challenge());
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            assert_ne!(signature.challenge(), other_private_key.sign_deterministic(&message)?.challenge());
+
+            // Check that the randomized signature remains the default.
+            assert_ne!(signature, private_key.sign(&message, rng)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify_prehashed() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the digest.
+            let digest = Uniform::rand(rng);
+            let signature = private_key.sign_prehashed(digest, rng)?;
+            assert!(signature.verify_prehashed(&address, digest));
+            assert!(!signature.verify_prehashed(&address, Uniform::rand(rng)));
+
+            // Check that the signature is not valid for the digest as a message, and vice versa.
+            assert!(!signature.verify(&address, &[digest]));
+            assert!(!private_key.sign(&[digest], rng)?.verify_prehashed(&address, digest));
+
+            // Check that a signature for the digest prefixed with the domain separator is not valid for the digest.
+            let message = [Signature::<CurrentNetwork>::prehashed_domain(), digest];
+            assert!(!private_key.sign(&message, rng)?.verify_prehashed(&address, digest));
+            assert!(!signature.verify(&address, &message));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Address, ComputeKey, Signature};
+use snarkvm_console_network::DomainTag;
+
+/// A private key prepared for signing many messages, such as in a high-throughput service.
+///
+/// `PrivateKey::sign` derives the compute key and address from the private key on every call,
+/// which costs three scalar multiplications and a hash. A signer derives them once, and reuses them for each signature.
+#[derive(Copy, Clone)]
+pub struct Signer<N: Network> {
+    /// The private key.
+    private_key: PrivateKey<N>,
+    /// The compute key of the private key.
+    compute_key: ComputeKey<N>,
+    /// The address of the private key.
+    address: Address<N>,
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns a signer, which caches the compute key and address of the private key across signatures.
+    pub fn to_signer(&self) -> Result<Signer<N>> {
+        let compute_key = ComputeKey::try_from(self)?;
+        let address = compute_key.to_address();
+        Ok(Signer { private_key: *self, compute_key, address })
+    }
+}
+
+impl<N: Network> Signer<N> {
+    /// Returns the compute key of the signer.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the signer.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns a signature for the given message (as field elements).
+    pub fn sign<R: Rng + CryptoRng>(&self, message: &[Field<N>], rng: &mut R) -> Result<Signature<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Sample a random nonce from the scalar field, and sign the message.
+        self.sign_with_nonce(None, message, Scalar::rand(rng))
+    }
+
+    /// Returns a deterministic signature for the given message (as field elements),
+    /// where the nonce is derived from the private key and the message, instead of sampled from an RNG.
+    pub fn sign_deterministic(&self, message: &[Field<N>]) -> Result<Signature<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Derive the nonce from the private key and the message, and sign the message.
+        let nonce = Signature::deterministic_nonce(&self.private_key, message)?;
+        self.sign_with_nonce(None, message, nonce)
+    }
+
+    /// Returns a signature for the given message (as field elements), bound to the given network and epoch domain.
+    pub fn sign_with_domain<R: Rng + CryptoRng>(
+        &self,
+        domain: &DomainTag<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Signature<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Sample a random nonce from the scalar field, and sign the message in the domain.
+        self.sign_with_nonce(domain.to_field(), message, Scalar::rand(rng))
+    }
+
+    /// Returns a signature for the given message (as bytes).
+    pub fn sign_bytes<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
+        self.sign_bits(&message.to_bits_le(), rng)
+    }
+
+    /// Returns a signature for the given message (as bits).
+    pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
+        // Pack the bits into field elements.
+        let fields =
+            message.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
+        // Sign the message.
+        self.sign(&fields, rng)
+    }
+
+    /// Returns a signature for the given digest of a message, which was hashed by the caller.
+    pub fn sign_prehashed<R: Rng + CryptoRng>(&self, digest: Field<N>, rng: &mut R) -> Result<Signature<N>> {
+        // Sample a random nonce from the scalar field, and sign the digest in the prehashed domain.
+        self.sign_with_nonce(Some(Signature::prehashed_domain()), &[digest], Scalar::rand(rng))
+    }
+
+    /// Returns a signature for the given message and nonce, in the given domain.
+    fn sign_with_nonce(
+        &self,
+        domain: Option<Field<N>>,
+        message: &[Field<N>],
+        nonce: Scalar<N>,
+    ) -> Result<Signature<N>> {
+        Signature::sign_prepared(&self.private_key, &self.compute_key, &self.address, domain, message, nonce)
+    }
+}
+
+impl<N: Network> fmt::Debug for Signer<N> {
+    /// Writes the address of the signer, without revealing the private key.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Signer").field("address", &self.address).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_signer() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key, and prepare a signer.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let signer = private_key.to_signer()?;
+            let address = Address::try_from(&private_key)?;
+            assert_eq!(address, *signer.address());
+            assert_eq!(ComputeKey::try_from(&private_key)?, *signer.compute_key());
+
+            // Check that the signatures are valid for the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            assert!(signer.sign(&message, rng)?.verify(&address, &message));
+            let domain = DomainTag::new(1)?;
+            assert!(signer.sign_with_domain(&domain, &message, rng)?.verify_with_domain(&address, &domain, &message));
+
+            // Check that the deterministic signature matches the signature of the private key.
+            assert_eq!(private_key.sign_deterministic(&message)?, signer.sign_deterministic(&message)?);
+
+            // Check that the signatures are valid for the message as bytes, bits, and a digest.
+            let bytes: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            assert!(signer.sign_bytes(&bytes, rng)?.verify_bytes(&address, &bytes));
+            let bits: Vec<bool> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            assert!(signer.sign_bits(&bits, rng)?.verify_bits(&address, &bits));
+            let digest = Uniform::rand(rng);
+            assert!(signer.sign_prehashed(digest, rng)?.verify_prehashed(&address, digest));
+        }
+        Ok(())
+    }
+}
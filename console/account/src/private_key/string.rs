@@ -47,6 +47,20 @@ impl<N: Network> fmt::Display for PrivateKey<N> {
     }
 }
 
+impl<N: Network> fmt::Debug for PrivateKey<N> {
+    /// Writes the fingerprint of the account private key, without revealing the account private key.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_redacted::<N>(f, "PrivateKey", &[self.seed])
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns a reference to the account private key, whose `Debug` implementation writes the account private key in full.
+    pub const fn reveal(&self) -> Revealed<Self> {
+        Revealed::new(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +83,20 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_debug_is_redacted() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+            // Ensure the debug representation does not reveal the private key.
+            let candidate = format!("{private_key:?}");
+            assert!(candidate.starts_with("PrivateKey(<redacted "));
+            assert!(!candidate.contains(&private_key.to_string()));
+            // Ensure the revealed representation matches the string representation.
+            assert_eq!(private_key.to_string(), format!("{:?}", private_key.reveal()));
+        }
+        Ok(())
+    }
 }
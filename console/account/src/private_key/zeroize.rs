@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::scrub::scrub;
+
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for PrivateKey<N> {
+    /// Overwrites the account seed and the derived secret keys with zero.
+    ///
+    /// Note: As the private key is `Copy`, it is not zeroized on drop, and zeroizing it does not zeroize its copies.
+    /// To zeroize the private key on drop, wrap it in `Zeroizing`.
+    fn zeroize(&mut self) {
+        scrub(&mut self.seed);
+        scrub(&mut self.sk_sig);
+        scrub(&mut self.r_sig);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        let mut private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        private_key.zeroize();
+        assert_eq!(Field::zero(), private_key.seed());
+        assert_eq!(Scalar::zero(), private_key.sk_sig());
+        assert_eq!(Scalar::zero(), private_key.r_sig());
+        Ok(())
+    }
+
+    #[test]
+    fn test_zeroizing() -> Result<()> {
+        fn assert_zeroize_on_drop<T: ::zeroize::ZeroizeOnDrop>(_: &T) {}
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let wrapped = crate::Zeroizing::new(private_key);
+        assert_eq!(private_key, *wrapped);
+        // Ensure the wrapped private key is zeroized on drop.
+        assert_zeroize_on_drop(&wrapped);
+        Ok(())
+    }
+}
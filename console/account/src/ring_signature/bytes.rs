@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for RingSignature<N> {
+    /// Reads a ring signature from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the challenge.
+        let challenge = FromBytes::read_le(&mut reader)?;
+        // Read the number of responses.
+        let num_responses = u16::read_le(&mut reader)?;
+        // Ensure the number of responses is valid.
+        if num_responses == 0 || num_responses as usize > MAX_RING_SIZE {
+            return Err(error(format!("Invalid ring signature size {num_responses}")));
+        }
+        // Read the responses.
+        let responses = (0..num_responses).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { challenge, responses })
+    }
+}
+
+impl<N: Network> ToBytes for RingSignature<N> {
+    /// Writes a ring signature to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the challenge.
+        self.challenge.write_le(&mut writer)?;
+        // Write the number of responses.
+        (self.responses.len() as u16).write_le(&mut writer)?;
+        // Write the responses.
+        self.responses.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use crate::ring_signature::test_helpers;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 8;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for size in 1..=ITERATIONS {
+            // Sample a new ring signature.
+            let (private_keys, ring) = test_helpers::sample_ring(size, rng)?;
+            let signature = RingSignature::sign(&private_keys[0], &ring, &[Uniform::rand(rng)], rng)?;
+
+            // Check the byte representation.
+            let signature_bytes = signature.to_bytes_le()?;
+            assert_eq!(signature, RingSignature::read_le(&signature_bytes[..])?);
+            assert!(RingSignature::<CurrentNetwork>::read_le(&signature_bytes[..signature_bytes.len() - 1]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! AOS-style ring signatures, which prove one account in a ring signed a message, without revealing which.
+//!
+//! The ring is given as the compute keys of the accounts, and the signer proves knowledge of the `sk_sig`
+//! of one `pk_sig` in the ring, which is the same authority that `Signature` proves for a single account.
+//! The compute key of an account is public once the account has signed, such as in a transaction.
+
+mod bytes;
+mod verify;
+
+#[cfg(feature = "private_key")]
+mod sign;
+
+use crate::ComputeKey;
+#[cfg(feature = "private_key")]
+use crate::PrivateKey;
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+static RING_SIGNATURE_DOMAIN: &str = "AleoRingSignature0";
+
+/// The maximum number of accounts in a ring.
+pub const MAX_RING_SIZE: usize = 256;
+
+/// A signature by one account in a ring of accounts, which does not reveal the signer.
+///
+/// For a ring of compute keys with signing keys `(P_0, ..., P_{n-1})` and randomizers `(Q_0, ..., Q_{n-1})`,
+/// the signature `(c_0, r_0, ..., r_{n-1})` is valid if the chain of challenges:
+///     c_{i+1} := HashToScalar(ring_hash, r_i * G + c_i * P_i)
+/// closes, such that `c_n == c_0`, where `ring_hash := Hash(DOMAIN, P_0, Q_0, ..., P_{n-1}, Q_{n-1}, message)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RingSignature<N: Network> {
+    /// The challenge of the first account in the ring.
+    challenge: Scalar<N>,
+    /// The response of each account in the ring.
+    responses: Vec<Scalar<N>>,
+}
+
+impl<N: Network> RingSignature<N> {
+    /// Returns the challenge of the first account in the ring.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the response of each account in the ring.
+    pub fn responses(&self) -> &[Scalar<N>] {
+        &self.responses
+    }
+
+    /// Returns the number of accounts in the ring.
+    pub fn ring_size(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Ensures the given ring and message are well-formed.
+    fn check_ring(ring: &[ComputeKey<N>], message: &[Field<N>]) -> Result<()> {
+        // Ensure the ring is nonempty, and within the maximum size.
+        ensure!(!ring.is_empty(), "A ring signature requires at least one account");
+        ensure!(
+            ring.len() <= MAX_RING_SIZE,
+            "The ring has {} accounts, exceeding the maximum of {MAX_RING_SIZE}",
+            ring.len()
+        );
+        // Ensure the signing keys are unique.
+        ensure!(
+            !has_duplicates(ring.iter().map(|compute_key| compute_key.pk_sig())),
+            "Found a duplicate account in the ring"
+        );
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        ensure!(
+            message.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize,
+            "The message exceeds the maximum allowed size"
+        );
+        Ok(())
+    }
+
+    /// Returns the ring hash as `Hash(DOMAIN, P_0, Q_0, ..., P_{n-1}, Q_{n-1}, message)`.
+    fn ring_hash(ring: &[ComputeKey<N>], message: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(1 + 2 * ring.len() + message.len());
+        preimage.push(Field::new_domain_separator(RING_SIGNATURE_DOMAIN));
+        preimage.extend(ring.iter().flat_map(|compute_key| {
+            [compute_key.pk_sig().to_x_coordinate(), compute_key.pr_sig().to_x_coordinate()]
+        }));
+        preimage.extend(message);
+        N::hash_psd8(&preimage)
+    }
+
+    /// Returns the next challenge in the chain, as `HashToScalar(ring_hash, commitment)`.
+    fn next_challenge(ring_hash: Field<N>, commitment: Group<N>) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd2(&[ring_hash, commitment.to_x_coordinate()])
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a ring of the given size, and returns the private keys and the ring.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn sample_ring<R: Rng + CryptoRng>(
+        size: usize,
+        rng: &mut R,
+    ) -> Result<(Vec<PrivateKey<CurrentNetwork>>, Vec<ComputeKey<CurrentNetwork>>)> {
+        let private_keys = (0..size).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let ring = private_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+        Ok((private_keys, ring))
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::scrub::Secret;
+
+impl<N: Network> RingSignature<N> {
+    /// Returns a ring signature for the given message, by the given private key, whose compute key must be in the ring.
+    pub fn sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        ring: &[ComputeKey<N>],
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the ring and message are well-formed.
+        Self::check_ring(ring, message)?;
+
+        // Derive the compute key, and find the signer in the ring.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        let signer = match ring.iter().position(|candidate| *candidate == compute_key) {
+            Some(signer) => signer,
+            None => bail!("The signer '{}' is not in the ring", compute_key.to_address()),
+        };
+
+        // Compute the ring hash.
+        let ring_hash = Self::ring_hash(ring, message)?;
+
+        // Sample a random nonce from the scalar field.
+        let nonce = Secret::new(Scalar::rand(rng));
+
+        // Compute the challenge of the next account as `HashToScalar(ring_hash, nonce * G)`.
+        let size = ring.len();
+        let mut challenges = vec![Scalar::zero(); size];
+        let mut responses = vec![Scalar::zero(); size];
+        let mut index = (signer + 1) % size;
+        challenges[index] = Self::next_challenge(ring_hash, N::g_scalar_multiply(&nonce))?;
+
+        // Close the chain of challenges, with a random response for every other account in the ring.
+        while index != signer {
+            responses[index] = Scalar::rand(rng);
+            let commitment = N::g_scalar_multiply(&responses[index]) + (ring[index].pk_sig() * challenges[index]);
+            challenges[(index + 1) % size] = Self::next_challenge(ring_hash, commitment)?;
+            index = (index + 1) % size;
+        }
+
+        // Compute the response of the signer, as `nonce - challenge * sk_sig`.
+        // Note: The nonce and product are scrubbed on drop, as either reveals `sk_sig`.
+        let product = Secret::new(challenges[signer] * private_key.sk_sig());
+        responses[signer] = *nonce - *product;
+
+        Ok(Self { challenge: challenges[0], responses })
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> RingSignature<N> {
+    /// Returns `true` if the signature is valid for the given message, by one of the accounts in the given ring.
+    pub fn verify(&self, ring: &[ComputeKey<N>], message: &[Field<N>]) -> bool {
+        // Ensure the ring and message are well-formed, and there is a response for each account in the ring.
+        if Self::check_ring(ring, message).is_err() || ring.len() != self.responses.len() {
+            return false;
+        }
+
+        // Compute the ring hash.
+        let ring_hash = match Self::ring_hash(ring, message) {
+            Ok(ring_hash) => ring_hash,
+            Err(_) => return false,
+        };
+
+        // Recompute the chain of challenges, as `c_{i+1} := HashToScalar(ring_hash, r_i * G + c_i * P_i)`.
+        let mut challenge = self.challenge;
+        for (compute_key, response) in ring.iter().zip_eq(&self.responses) {
+            let commitment = N::g_scalar_multiply(response) + (compute_key.pk_sig() * challenge);
+            challenge = match Self::next_challenge(ring_hash, commitment) {
+                Ok(challenge) => challenge,
+                Err(_) => return false,
+            };
+        }
+
+        // Ensure the chain of challenges closes.
+        challenge == self.challenge
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use crate::ring_signature::test_helpers;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 8;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for size in 1..=ITERATIONS {
+            // Sample a ring, and a message.
+            let (private_keys, ring) = test_helpers::sample_ring(size, rng)?;
+            let message: Vec<_> = (0..size).map(|_| Uniform::rand(rng)).collect();
+
+            for private_key in &private_keys {
+                // Check that the signature is valid for the message and ring.
+                let signature = RingSignature::sign(private_key, &ring, &message, rng)?;
+                assert_eq!(size, signature.ring_size());
+                assert!(signature.verify(&ring, &message));
+
+                // Check that the signature is invalid for an incorrect message.
+                let failure_message: Vec<_> = (0..size).map(|_| Uniform::rand(rng)).collect();
+                if message != failure_message {
+                    assert!(!signature.verify(&ring, &failure_message));
+                }
+
+                // Check that the signature is invalid for a reordered or different ring.
+                if size > 1 {
+                    let mut reordered = ring.clone();
+                    reordered.rotate_left(1);
+                    assert!(!signature.verify(&reordered, &message));
+                }
+                let (_, other_ring) = test_helpers::sample_ring(size, rng)?;
+                assert!(!signature.verify(&other_ring, &message));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let (private_keys, ring) = test_helpers::sample_ring(3, rng)?;
+        let message: Vec<Field<CurrentNetwork>> = vec![Uniform::rand(rng)];
+
+        // Ensure a signer outside of the ring cannot sign.
+        let outsider = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(RingSignature::sign(&outsider, &ring, &message, rng).is_err());
+        // Ensure an empty ring, or a ring with duplicate accounts, is rejected.
+        assert!(RingSignature::sign(&private_keys[0], &[], &message, rng).is_err());
+        let duplicated = [ring[0], ring[0], ring[1]];
+        assert!(RingSignature::sign(&private_keys[0], &duplicated, &message, rng).is_err());
+
+        // Ensure a signature with a missing response is rejected.
+        let mut signature = RingSignature::sign(&private_keys[0], &ring, &message, rng)?;
+        signature.responses.pop();
+        assert!(!signature.verify(&ring, &message));
+        assert!(!signature.verify(&ring[..2], &message));
+
+        // Ensure the signature is bound to the randomizer of each account in the ring.
+        let signature = RingSignature::sign(&private_keys[0], &ring, &message, rng)?;
+        let mut substituted = ring.clone();
+        substituted[1] = ComputeKey::try_from((ring[1].pk_sig(), ring[2].pr_sig()))?;
+        assert!(!signature.verify(&substituted, &message));
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Scalar};
+
+use core::{
+    hint::black_box,
+    ops::DerefMut,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+/// A secret value that can be overwritten with zero.
+pub(crate) trait Scrub {
+    /// Overwrites the secret value with zero.
+    fn scrub(&mut self);
+}
+
+impl Scrub for u8 {
+    fn scrub(&mut self) {
+        *self = 0;
+    }
+}
+
+impl<E: Environment> Scrub for Field<E> {
+    fn scrub(&mut self) {
+        *self = Field::zero();
+    }
+}
+
+impl<E: Environment> Scrub for Scalar<E> {
+    fn scrub(&mut self) {
+        *self = Scalar::zero();
+    }
+}
+
+#[cfg(feature = "view_key")]
+impl<N: Network> Scrub for crate::ViewKey<N> {
+    fn scrub(&mut self) {
+        *self = Self::from_scalar(Scalar::zero());
+    }
+}
+
+impl<T: Scrub, const N: usize> Scrub for [T; N] {
+    fn scrub(&mut self) {
+        self.iter_mut().for_each(Scrub::scrub);
+    }
+}
+
+impl<T: Scrub> Scrub for Vec<T> {
+    fn scrub(&mut self) {
+        self.iter_mut().for_each(Scrub::scrub);
+    }
+}
+
+/// Overwrites the given secret value with zero.
+///
+/// As the crate forbids unsafe code, the write is not volatile; instead, the value is passed through
+/// `black_box` and a compiler fence, so the compiler may not elide the write as a dead store.
+pub(crate) fn scrub<T: Scrub>(value: &mut T) {
+    value.scrub();
+    black_box(value);
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A transient secret, such as a signing nonce, which is scrubbed when it goes out of scope.
+///
+/// Note: As the scalars and field elements are `Copy`, the copies taken from a secret are not scrubbed.
+pub(crate) struct Secret<T: Scrub>(T);
+
+impl<T: Scrub> Secret<T> {
+    /// Initializes a new secret from the given value.
+    pub(crate) const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Scrub> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Scrub> DerefMut for Secret<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Scrub> Drop for Secret<T> {
+    /// Scrubs the secret on drop.
+    fn drop(&mut self) {
+        scrub(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_scrub() {
+        let mut rng = test_rng();
+
+        let mut scalars = [Scalar::<CurrentNetwork>::rand(&mut rng), Scalar::rand(&mut rng)];
+        scrub(&mut scalars);
+        assert_eq!([Scalar::zero(); 2], scalars);
+
+        let mut bytes = vec![1u8, 2, 3];
+        scrub(&mut bytes);
+        assert_eq!(vec![0u8; 3], bytes);
+    }
+
+    #[test]
+    fn test_secret() {
+        let mut secret = Secret::new(vec![Field::<CurrentNetwork>::one(); 2]);
+        assert_eq!(vec![Field::one(); 2], *secret);
+        secret[1] = Field::zero();
+        assert_eq!(vec![Field::one(), Field::zero()], *secret);
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Sign-in with an Aleo account, by a challenge/response protocol between a server and a wallet.
+//!
+//! 1. The server issues a `SignInChallenge` with `SignInVerifier::issue`, for its domain, with a random nonce
+//!    and an expiry, and sends the challenge (in its canonical string form) to the wallet.
+//! 2. The wallet displays the challenge, and signs it with `SignInChallenge::sign`.
+//! 3. The server verifies the signature with `SignInVerifier::verify`, which returns the address of the account.
+//!    Each challenge is accepted at most once, and only before it expires.
+
+mod string;
+
+mod verifier;
+pub use verifier::SignInVerifier;
+
+use crate::{PrivateKey, SignableMessage, Signature, SigningDomain};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field};
+
+/// The maximum number of bytes in the domain of a sign-in challenge.
+pub const MAX_SIGN_IN_DOMAIN_BYTES: usize = 253;
+
+/// A challenge from a server, which a wallet signs to prove ownership of an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignInChallenge<N: Network> {
+    /// The domain of the server, such as `"example.com"`.
+    domain: String,
+    /// The random nonce of the challenge.
+    nonce: Field<N>,
+    /// The time the challenge was issued, in seconds since the Unix epoch.
+    issued_at: u64,
+    /// The time the challenge expires, in seconds since the Unix epoch.
+    expires_at: u64,
+}
+
+impl<N: Network> SignInChallenge<N> {
+    /// Initializes a new sign-in challenge.
+    pub fn new(domain: &str, nonce: Field<N>, issued_at: u64, expires_at: u64) -> Result<Self> {
+        // Ensure the domain is nonempty, bounded, and printable, as it is displayed to the user.
+        ensure!(!domain.is_empty(), "The sign-in domain must be nonempty");
+        ensure!(
+            domain.len() <= MAX_SIGN_IN_DOMAIN_BYTES,
+            "The sign-in domain exceeds {MAX_SIGN_IN_DOMAIN_BYTES} bytes"
+        );
+        ensure!(
+            domain.bytes().all(|byte| byte.is_ascii_graphic()),
+            "The sign-in domain '{domain}' must be printable ASCII, without whitespace"
+        );
+        // Ensure the challenge expires after it is issued.
+        ensure!(issued_at < expires_at, "The sign-in challenge must expire after it is issued");
+        Ok(Self { domain: domain.to_string(), nonce, issued_at, expires_at })
+    }
+
+    /// Returns the domain of the server.
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Returns the random nonce of the challenge.
+    pub const fn nonce(&self) -> Field<N> {
+        self.nonce
+    }
+
+    /// Returns the time the challenge was issued, in seconds since the Unix epoch.
+    pub const fn issued_at(&self) -> u64 {
+        self.issued_at
+    }
+
+    /// Returns the time the challenge expires, in seconds since the Unix epoch.
+    pub const fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Returns `true` if the challenge is valid at the given time, in seconds since the Unix epoch.
+    pub const fn is_valid_at(&self, now: u64) -> bool {
+        self.issued_at <= now && now < self.expires_at
+    }
+
+    /// Returns the signing domain of the challenge, which binds the signature to the network and server domain.
+    pub fn signing_domain(&self) -> Result<SigningDomain<N>> {
+        SigningDomain::for_message::<Self>(&self.domain)
+    }
+
+    /// Returns a signature for the challenge, by the given private key.
+    pub fn sign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign_typed(private_key, &self.signing_domain()?, self, rng)
+    }
+
+    /// Returns the signer address, if the given signature is valid for the challenge.
+    /// This does not check the challenge has not expired, or has not been used before; see `SignInVerifier`.
+    pub fn verify_signature(&self, signature: &Signature<N>) -> Result<Address<N>> {
+        let address = signature.to_address();
+        ensure!(
+            signature.verify_typed(&address, &self.signing_domain()?, self),
+            "The sign-in signature is invalid for the challenge"
+        );
+        Ok(address)
+    }
+}
+
+impl<N: Network> SignableMessage<N> for SignInChallenge<N> {
+    /// The purpose of a sign-in challenge.
+    const PURPOSE: &'static str = "sign-in";
+
+    /// Returns the challenge as field elements, as `(nonce, issued_at, expires_at)`,
+    /// where the network and domain are bound by the signing domain.
+    fn to_signable_fields(&self) -> Result<Vec<Field<N>>> {
+        Ok(vec![self.nonce, Field::from_u64(self.issued_at), Field::from_u64(self.expires_at)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign_and_verify_signature() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let challenge = SignInChallenge::new("example.com", Uniform::rand(rng), 1_000, 1_300)?;
+
+        // Ensure the signature is valid for the challenge, and yields the address of the signer.
+        let signature = challenge.sign(&private_key, rng)?;
+        assert_eq!(Address::try_from(&private_key)?, challenge.verify_signature(&signature)?);
+
+        // Ensure the signature is invalid for another domain, nonce, or expiry.
+        let candidate = SignInChallenge::new("example.org", challenge.nonce(), 1_000, 1_300)?;
+        assert!(candidate.verify_signature(&signature).is_err());
+        let candidate = SignInChallenge::new("example.com", Uniform::rand(rng), 1_000, 1_300)?;
+        assert!(candidate.verify_signature(&signature).is_err());
+        let candidate = SignInChallenge::new("example.com", challenge.nonce(), 1_000, 1_301)?;
+        assert!(candidate.verify_signature(&signature).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_fails() {
+        let nonce = Field::<CurrentNetwork>::from_u64(1);
+        assert!(SignInChallenge::new("", nonce, 0, 1).is_err());
+        assert!(SignInChallenge::new("example .com", nonce, 0, 1).is_err());
+        assert!(SignInChallenge::new("example.com\n", nonce, 0, 1).is_err());
+        assert!(SignInChallenge::new(&"a".repeat(MAX_SIGN_IN_DOMAIN_BYTES + 1), nonce, 0, 1).is_err());
+        assert!(SignInChallenge::new("example.com", nonce, 1, 1).is_err());
+    }
+}
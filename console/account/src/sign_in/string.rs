@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The statement of a sign-in challenge, which follows the domain on the first line.
+const STATEMENT: &str = " wants you to sign in with your Aleo account.";
+
+impl<N: Network> FromStr for SignInChallenge<N> {
+    type Err = Error;
+
+    /// Reads in a sign-in challenge from its canonical string form.
+    fn from_str(challenge: &str) -> Result<Self, Self::Err> {
+        // Ensure the challenge has the expected number of lines.
+        let lines = challenge.split('\n').collect::<Vec<_>>();
+        ensure!(lines.len() == 5, "Invalid sign-in challenge: expected 5 lines, found {}", lines.len());
+
+        // Parse the domain.
+        let domain = match lines[0].strip_suffix(STATEMENT) {
+            Some(domain) => domain.to_string(),
+            None => bail!("Invalid sign-in challenge: missing the statement"),
+        };
+        // Parse the network ID, and ensure it is the current network.
+        let network_id = strip_line(lines[1], "Network ID: ")?.parse::<u16>()?;
+        ensure!(network_id == N::ID, "The sign-in challenge is for network {network_id}, expected {}", N::ID);
+        // Parse the nonce, issuance time, and expiry time.
+        let nonce = Field::from_str(strip_line(lines[2], "Nonce: ")?)?;
+        let issued_at = strip_line(lines[3], "Issued At: ")?.parse::<u64>()?;
+        let expires_at = strip_line(lines[4], "Expires At: ")?.parse::<u64>()?;
+
+        // Ensure the challenge is in its canonical form.
+        let candidate = Self::new(&domain, nonce, issued_at, expires_at)?;
+        ensure!(candidate.to_string() == challenge, "The sign-in challenge is not in its canonical form");
+        Ok(candidate)
+    }
+}
+
+/// Returns the value of the given line, after the given prefix.
+fn strip_line<'a>(line: &'a str, prefix: &str) -> Result<&'a str> {
+    match line.strip_prefix(prefix) {
+        Some(value) => Ok(value),
+        None => bail!("Invalid sign-in challenge: expected a line starting with '{prefix}'"),
+    }
+}
+
+impl<N: Network> fmt::Display for SignInChallenge<N> {
+    /// Writes the sign-in challenge in its canonical string form, which is displayed to the user by a wallet.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{STATEMENT}\nNetwork ID: {}\nNonce: {}\nIssued At: {}\nExpires At: {}",
+            self.domain,
+            N::ID,
+            self.nonce,
+            self.issued_at,
+            self.expires_at
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Ensure the string representation round trips.
+        let expected = SignInChallenge::<CurrentNetwork>::new("example.com", Uniform::rand(rng), 1_000, 1_300)?;
+        let candidate = SignInChallenge::<CurrentNetwork>::from_str(&expected.to_string())?;
+        assert_eq!(expected, candidate);
+        assert!(expected.to_string().starts_with("example.com wants you to sign in with your Aleo account.\n"));
+
+        // Ensure a non-canonical string is rejected.
+        let string = expected.to_string();
+        assert!(SignInChallenge::<CurrentNetwork>::from_str(&format!("{string}\n")).is_err());
+        assert!(SignInChallenge::<CurrentNetwork>::from_str(&string.replace("1300", "01300")).is_err());
+        let string = string.replace("Network ID: 3", "Network ID: 4");
+        assert!(SignInChallenge::<CurrentNetwork>::from_str(&string).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::HashMap;
+
+/// The server side of sign-in, which issues challenges, and accepts each challenge at most once before it expires.
+#[derive(Clone, Debug)]
+pub struct SignInVerifier<N: Network> {
+    /// The domain of the server.
+    domain: String,
+    /// The number of seconds a challenge is valid for.
+    validity: u64,
+    /// The expiry time of each outstanding challenge, by nonce.
+    pending: HashMap<Field<N>, u64>,
+}
+
+impl<N: Network> SignInVerifier<N> {
+    /// The default number of seconds a challenge is valid for.
+    pub const DEFAULT_VALIDITY: u64 = 300;
+
+    /// Initializes a new verifier for the given server domain, where challenges are valid for 5 minutes.
+    pub fn new(domain: &str) -> Result<Self> {
+        // Ensure the domain is well-formed.
+        SignInChallenge::<N>::new(domain, Field::zero(), 0, 1)?;
+        Ok(Self { domain: domain.to_string(), validity: Self::DEFAULT_VALIDITY, pending: Default::default() })
+    }
+
+    /// Sets the number of seconds a challenge is valid for.
+    pub fn validity(mut self, validity: u64) -> Self {
+        self.validity = validity;
+        self
+    }
+
+    /// Returns the domain of the server.
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Returns the number of outstanding challenges.
+    pub fn num_pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Issues a new challenge at the given time, in seconds since the Unix epoch.
+    pub fn issue<R: Rng + CryptoRng>(&mut self, now: u64, rng: &mut R) -> Result<SignInChallenge<N>> {
+        // Remove the expired challenges.
+        self.prune(now);
+        // Sample a random nonce, and issue the challenge.
+        let expires_at = now.checked_add(self.validity).ok_or_else(|| anyhow!("The sign-in expiry overflows"))?;
+        let challenge = SignInChallenge::new(&self.domain, Uniform::rand(rng), now, expires_at)?;
+        self.pending.insert(challenge.nonce(), expires_at);
+        Ok(challenge)
+    }
+
+    /// Returns the signer address, if the given signature is valid for the given challenge at the given time,
+    /// where the challenge must have been issued by this verifier, not expired, and not used before.
+    pub fn verify(&mut self, challenge: &SignInChallenge<N>, signature: &Signature<N>, now: u64) -> Result<Address<N>> {
+        // Remove the expired challenges.
+        self.prune(now);
+        // Ensure the challenge is for this server.
+        ensure!(challenge.domain() == self.domain, "The sign-in challenge is for another domain");
+        // Ensure the challenge was issued by this verifier, and is outstanding.
+        match self.pending.get(&challenge.nonce()) {
+            Some(expires_at) => ensure!(
+                *expires_at == challenge.expires_at(),
+                "The sign-in challenge does not match the issued challenge"
+            ),
+            None => bail!("The sign-in challenge is unknown, expired, or was already used"),
+        }
+        // Ensure the challenge is valid at the given time.
+        ensure!(challenge.is_valid_at(now), "The sign-in challenge is not valid at time {now}");
+
+        // Verify the signature.
+        let address = challenge.verify_signature(signature)?;
+        // Consume the challenge, so it can not be replayed.
+        self.pending.remove(&challenge.nonce());
+        Ok(address)
+    }
+
+    /// Removes the challenges that expired at the given time.
+    pub fn prune(&mut self, now: u64) {
+        self.pending.retain(|_, expires_at| now < *expires_at);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign_in() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let mut verifier = SignInVerifier::<CurrentNetwork>::new("example.com")?.validity(60);
+
+        // Ensure a signed challenge yields the address of the signer, only once.
+        let challenge = verifier.issue(1_000, rng)?;
+        assert_eq!(1, verifier.num_pending());
+        let signature = challenge.sign(&private_key, rng)?;
+        assert_eq!(Address::try_from(&private_key)?, verifier.verify(&challenge, &signature, 1_030)?);
+        assert!(verifier.verify(&challenge, &signature, 1_030).is_err());
+        assert_eq!(0, verifier.num_pending());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_in_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let mut verifier = SignInVerifier::<CurrentNetwork>::new("example.com")?.validity(60);
+
+        // Ensure an expired challenge is rejected, and pruned.
+        let challenge = verifier.issue(1_000, rng)?;
+        let signature = challenge.sign(&private_key, rng)?;
+        assert!(verifier.verify(&challenge, &signature, 1_060).is_err());
+        assert_eq!(0, verifier.num_pending());
+
+        // Ensure a challenge that was not issued by the verifier is rejected.
+        let challenge = SignInChallenge::new("example.com", Uniform::rand(rng), 1_000, 1_060)?;
+        let signature = challenge.sign(&private_key, rng)?;
+        assert!(verifier.verify(&challenge, &signature, 1_030).is_err());
+
+        // Ensure a challenge with an extended expiry is rejected.
+        let challenge = verifier.issue(1_000, rng)?;
+        let extended = SignInChallenge::new("example.com", challenge.nonce(), 1_000, 2_000)?;
+        let signature = extended.sign(&private_key, rng)?;
+        assert!(verifier.verify(&extended, &signature, 1_030).is_err());
+
+        // Ensure an invalid signature does not consume the challenge.
+        let signature =
+            SignInChallenge::new("example.org", challenge.nonce(), 1_000, 1_060)?.sign(&private_key, rng)?;
+        assert!(verifier.verify(&challenge, &signature, 1_030).is_err());
+        assert!(verifier.verify(&challenge, &challenge.sign(&private_key, rng)?, 1_030).is_ok());
+        Ok(())
+    }
+}
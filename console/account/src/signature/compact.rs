@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// The number of bytes in the compact encoding of a signature.
+    pub const COMPACT_SIZE_IN_BYTES: usize = 2 * Scalar::<N>::SIZE_IN_BYTES;
+
+    /// Returns the compact encoding of the signature, as `challenge || response` (64 bytes).
+    ///
+    /// The compact encoding omits the compute key of the signer, so it is only suitable
+    /// where the verifier already knows the compute key, which is supplied to `from_compact_bytes`.
+    pub fn to_compact_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(Self::COMPACT_SIZE_IN_BYTES);
+        self.challenge.write_le(&mut bytes)?;
+        self.response.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the signature from its compact encoding, as `challenge || response`, and the compute key of the signer.
+    pub fn from_compact_bytes(bytes: &[u8], compute_key: ComputeKey<N>) -> Result<Self> {
+        // Ensure the encoding is the expected size.
+        ensure!(
+            bytes.len() == Self::COMPACT_SIZE_IN_BYTES,
+            "Invalid compact signature length: found {} bytes, expected {}",
+            bytes.len(),
+            Self::COMPACT_SIZE_IN_BYTES
+        );
+        let (challenge, response) = bytes.split_at(Scalar::<N>::SIZE_IN_BYTES);
+        let challenge = Scalar::read_le(challenge)?;
+        let response = Scalar::read_le(response)?;
+        Ok(Self { challenge, response, compute_key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_compact_bytes() -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let signature = test_helpers::sample_signature(i);
+
+            // Check the compact byte representation.
+            let compact_bytes = signature.to_compact_bytes()?;
+            assert_eq!(64, compact_bytes.len());
+            assert!(compact_bytes.len() < signature.to_bytes_le()?.len());
+            assert_eq!(signature, Signature::from_compact_bytes(&compact_bytes, signature.compute_key())?);
+            assert!(
+                Signature::<CurrentNetwork>::from_compact_bytes(&compact_bytes[1..], signature.compute_key()).is_err()
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use thiserror::Error;
+
+/// The reason a signature failed to verify.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+pub enum VerifyError {
+    /// The message has more field elements than the maximum allowed size.
+    #[error("The signed message has {} field elements, exceeding the maximum of {}", _0, _1)]
+    OversizedMessage(usize, usize),
+    /// The challenge does not match the challenge recomputed from the signature and message.
+    #[error("The signature challenge does not match the message")]
+    BadChallenge,
+    /// The compute key of the signature does not derive the given address.
+    #[error("The signature was not produced by the given address")]
+    AddressMismatch,
+}
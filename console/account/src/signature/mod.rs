@@ -14,7 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod error;
+pub use error::*;
+
+mod typed;
+pub use typed::*;
+
 mod bytes;
+mod compact;
 mod parse;
 mod serialize;
 mod verify;
@@ -28,9 +35,15 @@ use crate::ComputeKey;
 use crate::PrivateKey;
 
 use crate::address::Address;
-use snarkvm_console_network::prelude::*;
+use snarkvm_console_network::{prelude::*, DomainTag};
 use snarkvm_console_types::{Field, Scalar};
 
+/// The domain separator for a signature over a prehashed message.
+const PREHASHED_DOMAIN: &str = "AleoPrehashedMessage0";
+
+/// A signed message, as the address of the signer, the message, and the signature.
+pub type SignedMessage<'a, N> = (Address<N>, &'a [Field<N>], &'a Signature<N>);
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
     /// The verifier challenge to check against.
@@ -75,6 +88,12 @@ impl<N: Network> Signature<N> {
     pub fn to_address(&self) -> Address<N> {
         self.compute_key.to_address()
     }
+
+    /// Returns the domain of the challenge preimage for a signature over a prehashed message,
+    /// which separates it from a signature over a message of one field element.
+    pub(crate) fn prehashed_domain() -> Field<N> {
+        Field::new_domain_separator(PREHASHED_DOMAIN)
+    }
 }
 
 #[cfg(test)]
@@ -15,6 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::scrub::Secret;
+
+/// The domain separator for the derivation of a deterministic nonce.
+const DETERMINISTIC_NONCE_DOMAIN: &str = "AleoDeterministicNonce0";
 
 impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and RNG, where:
@@ -26,33 +30,109 @@ impl<N: Network> Signature<N> {
             bail!("Cannot sign the message: the message exceeds maximum allowed size")
         }
 
-        // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
-        // Compute `g_r` as `nonce * G`.
-        let g_r = N::g_scalar_multiply(&nonce);
+        // Sample a random nonce from the scalar field, and sign the message.
+        Self::sign_with_nonce(private_key, None, message, Scalar::rand(rng))
+    }
+
+    /// Returns a signature for a given message, where the nonce is derived from the private key and the message,
+    /// in the spirit of RFC 6979:
+    ///     nonce := HashToScalar(domain, sk_sig, r_sig, message)
+    ///
+    /// This is intended for signers without a reliable source of randomness, such as air-gapped or embedded devices,
+    /// as a weak RNG in `Signature::sign` may reuse a nonce across messages, which reveals the private key.
+    /// Signing the same message twice yields the same signature.
+    pub fn sign_deterministic(private_key: &PrivateKey<N>, message: &[Field<N>]) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Derive the nonce from the private key and the message, and sign the message.
+        Self::sign_with_nonce(private_key, None, message, Self::deterministic_nonce(private_key, message)?)
+    }
 
+    /// Returns the nonce for a deterministic signature of the given message.
+    pub(crate) fn deterministic_nonce(private_key: &PrivateKey<N>, message: &[Field<N>]) -> Result<Scalar<N>> {
+        // Construct the hash input as (domain, sk_sig, r_sig, message).
+        // Note: The preimage is scrubbed on drop, as it contains the private key.
+        let mut preimage = Secret::new(Vec::with_capacity(3 + message.len()));
+        preimage.push(Field::new_domain_separator(DETERMINISTIC_NONCE_DOMAIN));
+        preimage.extend([private_key.sk_sig().to_field()?, private_key.r_sig().to_field()?]);
+        preimage.extend(message);
+
+        // Compute the nonce.
+        N::hash_to_scalar_psd8(&preimage)
+    }
+
+    /// Returns a signature `(challenge, response, compute_key)` for a given message and nonce, in the given domain.
+    fn sign_with_nonce(
+        private_key: &PrivateKey<N>,
+        domain: Option<Field<N>>,
+        message: &[Field<N>],
+        nonce: Scalar<N>,
+    ) -> Result<Self> {
         // Derive the compute key from the private key.
         let compute_key = ComputeKey::try_from(private_key)?;
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+        // Sign the message.
+        Self::sign_prepared(private_key, &compute_key, &address, domain, message, nonce)
+    }
+
+    /// Returns a signature `(challenge, response, compute_key)` for a given message and nonce,
+    /// where the compute key and address were derived from the private key by the caller.
+    /// If a domain is given, it is prepended to the challenge preimage, ahead of `r * G`.
+    pub(crate) fn sign_prepared(
+        private_key: &PrivateKey<N>,
+        compute_key: &ComputeKey<N>,
+        address: &Address<N>,
+        domain: Option<Field<N>>,
+        message: &[Field<N>],
+        nonce: Scalar<N>,
+    ) -> Result<Self> {
+        // Note: The nonce and the product are scrubbed on drop, as either reveals the signature secret key.
+        let nonce = Secret::new(nonce);
+        // Compute `g_r` as `nonce * G`.
+        let g_r = N::g_scalar_multiply(&nonce);
+
         // Retrieve pk_sig.
         let pk_sig = compute_key.pk_sig();
         // Retrieve pr_sig.
         let pr_sig = compute_key.pr_sig();
 
-        // Derive the address from the compute key.
-        let address = Address::try_from(compute_key)?;
-
-        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
-        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        // Construct the hash input as (domain, r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(5 + message.len());
+        preimage.extend(domain);
+        preimage.extend([g_r, pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
         preimage.extend(message);
 
         // Compute the verifier challenge.
         let challenge = N::hash_to_scalar_psd8(&preimage)?;
         // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
+        let product = Secret::new(challenge * private_key.sk_sig());
+        let response = *nonce - *product;
 
         // Output the signature.
-        Ok(Self { challenge, response, compute_key })
+        Ok(Self { challenge, response, compute_key: *compute_key })
+    }
+
+    /// Returns a signature for the given message (as field elements), bound to the given network and epoch domain.
+    ///
+    /// The domain tag is bound into the challenge preimage, rather than the message, so a tagged signature
+    /// is never a valid signature for any message without the tag, and vice versa.
+    pub fn sign_with_domain<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        domain: &DomainTag<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Sample a random nonce from the scalar field, and sign the message in the domain.
+        Self::sign_with_nonce(private_key, domain.to_field(), message, Scalar::rand(rng))
     }
 
     /// Returns a signature for the given message (as bytes) using the private key.
@@ -77,4 +157,17 @@ impl<N: Network> Signature<N> {
         // Sign the message.
         Self::sign(private_key, &fields, rng)
     }
+
+    /// Returns a signature for the given digest of a message, which was hashed by the caller.
+    ///
+    /// This allows a large message, such as a streamed file, to be hashed incrementally,
+    /// instead of converting the entire message into field elements to sign it.
+    pub fn sign_prehashed<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        digest: Field<N>,
+        rng: &mut R,
+    ) -> Result<Signature<N>> {
+        // Sample a random nonce from the scalar field, and sign the digest in the prehashed domain.
+        Self::sign_with_nonce(private_key, Some(Self::prehashed_domain()), &[digest], Scalar::rand(rng))
+    }
 }
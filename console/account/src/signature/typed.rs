@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The domain separator for a signature over structured data.
+const TYPED_DATA_DOMAIN: &str = "AleoTypedData0";
+
+/// A structured message that may be signed off-chain, such as a login challenge or an order.
+pub trait SignableMessage<N: Network> {
+    /// The purpose of the message, which is hashed into the signing domain, such as `"login"`.
+    const PURPOSE: &'static str;
+
+    /// Returns the message as field elements.
+    fn to_signable_fields(&self) -> Result<Vec<Field<N>>>;
+}
+
+/// The signing domain of a structured message, which binds a signature to a chain ID, program ID, and purpose.
+///
+/// A typed signature is over the message `(typed_data_domain, domain_hash, message)`, where:
+///     domain_hash := HashPSD8(chain_id, HashPSD8(program_id), HashPSD8(purpose))
+/// As the message is prefixed with a domain separator that is not used for transaction authorizations,
+/// a typed signature can not be replayed as a transaction authorization, or in a different domain.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SigningDomain<N: Network> {
+    /// The program ID of the application, such as `"token.aleo"`.
+    program_id: String,
+    /// The purpose of the message.
+    purpose: String,
+    /// The hash of the signing domain.
+    hash: Field<N>,
+}
+
+impl<N: Network> SigningDomain<N> {
+    /// Returns the signing domain for the given program ID and purpose, on the current network.
+    pub fn new(program_id: &str, purpose: &str) -> Result<Self> {
+        ensure!(!program_id.is_empty(), "The program ID of a signing domain must be nonempty");
+        ensure!(!purpose.is_empty(), "The purpose of a signing domain must be nonempty");
+
+        // Compute the domain hash as (chain_id, program_id, purpose).
+        let preimage = [Field::from_u16(N::ID), Self::hash_string(program_id)?, Self::hash_string(purpose)?];
+        let hash = N::hash_psd8(&preimage)?;
+        Ok(Self { program_id: program_id.to_string(), purpose: purpose.to_string(), hash })
+    }
+
+    /// Returns the signing domain for the given program ID, and the purpose of the given message type.
+    pub fn for_message<M: SignableMessage<N>>(program_id: &str) -> Result<Self> {
+        Self::new(program_id, M::PURPOSE)
+    }
+
+    /// Returns the program ID of the signing domain.
+    pub fn program_id(&self) -> &str {
+        &self.program_id
+    }
+
+    /// Returns the purpose of the signing domain.
+    pub fn purpose(&self) -> &str {
+        &self.purpose
+    }
+
+    /// Returns the hash of the signing domain.
+    pub const fn hash(&self) -> Field<N> {
+        self.hash
+    }
+
+    /// Returns the signed message for the given message fields, in this signing domain.
+    fn tag_fields(&self, message: &[Field<N>]) -> Vec<Field<N>> {
+        let mut tagged = Vec::with_capacity(2 + message.len());
+        tagged.push(Field::new_domain_separator(TYPED_DATA_DOMAIN));
+        tagged.push(self.hash);
+        tagged.extend(message);
+        tagged
+    }
+
+    /// Returns the hash of the given string, prefixed by its length in bytes.
+    fn hash_string(string: &str) -> Result<Field<N>> {
+        let mut fields = vec![Field::from_u64(string.len() as u64)];
+        for chunk in string.as_bytes().to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+            fields.push(Field::from_bits_le(chunk)?);
+        }
+        N::hash_psd8(&fields)
+    }
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature for the given structured message, in the given signing domain.
+    #[cfg(feature = "private_key")]
+    pub fn sign_typed<M: SignableMessage<N>, R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        domain: &SigningDomain<N>,
+        message: &M,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the signing domain matches the purpose of the message.
+        ensure!(
+            domain.purpose == M::PURPOSE,
+            "The signing domain purpose '{}' does not match the message purpose '{}'",
+            domain.purpose,
+            M::PURPOSE
+        );
+        // Sign the message, prefixed with the signing domain.
+        Self::sign(private_key, &domain.tag_fields(&message.to_signable_fields()?), rng)
+    }
+
+    /// Verifies a signature for the given address and structured message, in the given signing domain.
+    pub fn verify_typed<M: SignableMessage<N>>(
+        &self,
+        address: &Address<N>,
+        domain: &SigningDomain<N>,
+        message: &M,
+    ) -> bool {
+        // Ensure the signing domain matches the purpose of the message.
+        if domain.purpose != M::PURPOSE {
+            return false;
+        }
+        // Verify the message, prefixed with the signing domain.
+        match message.to_signable_fields() {
+            Ok(fields) => self.verify(address, &domain.tag_fields(&fields)),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 25;
+
+    /// A sample login challenge.
+    struct Login {
+        nonce: u64,
+    }
+
+    impl SignableMessage<CurrentNetwork> for Login {
+        const PURPOSE: &'static str = "login";
+
+        fn to_signable_fields(&self) -> Result<Vec<Field<CurrentNetwork>>> {
+            Ok(vec![Field::from_u64(self.nonce)])
+        }
+    }
+
+    /// A sample order, with the same fields as the login challenge.
+    struct Order {
+        amount: u64,
+    }
+
+    impl SignableMessage<CurrentNetwork> for Order {
+        const PURPOSE: &'static str = "order";
+
+        fn to_signable_fields(&self) -> Result<Vec<Field<CurrentNetwork>>> {
+            Ok(vec![Field::from_u64(self.amount)])
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify_typed() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let domain = SigningDomain::for_message::<Login>("auth.aleo")?;
+        assert_eq!("auth.aleo", domain.program_id());
+        assert_eq!("login", domain.purpose());
+
+        for _ in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message, only in its own domain.
+            let nonce = rng.gen();
+            let signature = Signature::sign_typed(&private_key, &domain, &Login { nonce }, rng)?;
+            assert!(signature.verify_typed(&address, &domain, &Login { nonce }));
+            assert!(!signature.verify_typed(&address, &domain, &Login { nonce: nonce.wrapping_add(1) }));
+            assert!(!signature.verify_typed(&address, &SigningDomain::new("other.aleo", "login")?, &Login { nonce }));
+
+            // Check that the signature is not valid for a message with the same fields, but a different purpose.
+            let order_domain = SigningDomain::for_message::<Order>("auth.aleo")?;
+            assert!(!signature.verify_typed(&address, &order_domain, &Order { amount: nonce }));
+            assert!(!signature.verify_typed(&address, &order_domain, &Login { nonce }));
+
+            // Check that the signature is not valid for the raw message.
+            assert!(!signature.verify(&address, &[Field::from_u64(nonce)]));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_signing_domain() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Ensure the domain hash is unique to the program ID and purpose.
+        let domain = SigningDomain::<CurrentNetwork>::new("auth.aleo", "login")?;
+        assert_eq!(domain.hash(), SigningDomain::<CurrentNetwork>::new("auth.aleo", "login")?.hash());
+        assert_ne!(domain.hash(), SigningDomain::<CurrentNetwork>::new("auth.aleo", "logout")?.hash());
+        assert_ne!(domain.hash(), SigningDomain::<CurrentNetwork>::new("auth.aleo", "login\0")?.hash());
+
+        // Ensure an empty or mismatched domain is rejected.
+        assert!(SigningDomain::<CurrentNetwork>::new("", "login").is_err());
+        assert!(SigningDomain::<CurrentNetwork>::new("auth.aleo", "").is_err());
+        let order_domain = SigningDomain::for_message::<Order>("auth.aleo")?;
+        assert!(Signature::sign_typed(&private_key, &order_domain, &Login { nonce: 0 }, rng).is_err());
+        Ok(())
+    }
+}
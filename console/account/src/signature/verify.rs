@@ -20,10 +20,31 @@ impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        self.verify_checked(address, message).is_ok()
+    }
+
+    /// Verifies (challenge == challenge') && (address == address') where:
+    ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
+    /// and returns the reason the signature is invalid, if it fails to verify.
+    pub fn verify_checked(&self, address: &Address<N>, message: &[Field<N>]) -> Result<(), VerifyError> {
+        self.verify_in_domain(address, None, message)
+    }
+
+    /// Verifies the signature for the given message (as field elements), bound to the given network and epoch domain.
+    pub fn verify_with_domain(&self, address: &Address<N>, domain: &DomainTag<N>, message: &[Field<N>]) -> bool {
+        self.verify_in_domain(address, domain.to_field(), message).is_ok()
+    }
+
+    /// Verifies the signature for the given message, where the domain, if given, is prepended to the challenge preimage.
+    fn verify_in_domain(
+        &self,
+        address: &Address<N>,
+        domain: Option<Field<N>>,
+        message: &[Field<N>],
+    ) -> Result<(), VerifyError> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
-            eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
-            return false;
+            return Err(VerifyError::OversizedMessage(message.len(), N::MAX_DATA_SIZE_IN_FIELDS as usize));
         }
 
         // Retrieve pk_sig.
@@ -34,29 +55,55 @@ impl<N: Network> Signature<N> {
         // Compute `g_r` := (response * G) + (challenge * pk_sig).
         let g_r = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
 
-        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
+        // Construct the hash input as (domain, r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(5 + message.len());
+        preimage.extend(domain);
         preimage.extend([g_r, pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
         preimage.extend(message);
 
-        // Hash to derive the verifier challenge, and return `false` if this operation fails.
-        let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
-            // Output the computed candidate challenge.
-            Ok(candidate_challenge) => candidate_challenge,
-            // Return `false` if the challenge errored.
-            Err(_) => return false,
-        };
-
-        // Derive the address from the compute key, and return `false` if this operation fails.
-        let candidate_address = match Address::try_from(self.compute_key) {
-            // Output the computed candidate address.
-            Ok(candidate_address) => candidate_address,
-            // Return `false` if the address errored.
-            Err(_) => return false,
-        };
-
-        // Return `true` if the candidate challenge and address are correct.
-        self.challenge == candidate_challenge && *address == candidate_address
+        // Check the challenge and address.
+        self.check_challenge_and_address(address, &preimage)
+    }
+
+    /// Returns `true` if every signature in the given list is valid for its address and message.
+    ///
+    /// Note: This is not a randomized batch verification. As a signature carries the challenge rather than
+    /// the nonce commitment `g_r`, the verifier must recompute each `g_r` to check its challenge, so the
+    /// verification equations cannot be folded into a single random linear combination and one multi-scalar
+    /// multiplication. The scalar multiplications and hashes are computed per signature, and only the field
+    /// inversions are shared, by converting all of the group elements into affine coordinates at once.
+    pub fn verify_many(batch: &[SignedMessage<N>]) -> bool {
+        // Ensure the number of field elements in each message does not exceed the maximum allowed size.
+        if batch.iter().any(|(_, message, _)| message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize) {
+            return false;
+        }
+
+        // Compute the group elements as (r * G, pk_sig, pr_sig, address) for each signature.
+        let mut points = Vec::with_capacity(4 * batch.len());
+        for (address, _, signature) in batch {
+            // Retrieve pk_sig.
+            let pk_sig = signature.compute_key.pk_sig();
+            // Retrieve pr_sig.
+            let pr_sig = signature.compute_key.pr_sig();
+            // Compute `g_r` := (response * G) + (challenge * pk_sig).
+            let g_r = N::g_scalar_multiply(&signature.response) + (pk_sig * signature.challenge);
+            points.extend([*g_r, *pk_sig, *pr_sig, ***address]);
+        }
+        // Convert the group elements into affine coordinates, using a batch inversion.
+        let points = N::Projective::batch_normalization_into_affine(points);
+
+        for ((address, message, signature), points) in batch.iter().zip_eq(points.chunks(4)) {
+            // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+            let mut preimage = Vec::with_capacity(4 + message.len());
+            preimage.extend(points.iter().map(|point| Field::new(point.to_x_coordinate())));
+            preimage.extend(*message);
+
+            // Return `false` if the candidate challenge or address is incorrect.
+            if signature.check_challenge_and_address(address, &preimage).is_err() {
+                return false;
+            }
+        }
+        true
     }
 
     /// Verifies a signature for the given address and message (as bytes).
@@ -70,10 +117,31 @@ impl<N: Network> Signature<N> {
         // Pack the bits into field elements.
         match message.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>() {
             Ok(fields) => self.verify(address, &fields),
-            Err(error) => {
-                eprintln!("Failed to verify signature: {error}");
-                false
-            }
+            Err(_) => false,
+        }
+    }
+
+    /// Verifies a signature for the given address and digest of a message, which was hashed by the caller.
+    pub fn verify_prehashed(&self, address: &Address<N>, digest: Field<N>) -> bool {
+        // Verify the digest in the prehashed domain.
+        self.verify_in_domain(address, Some(Self::prehashed_domain()), &[digest]).is_ok()
+    }
+
+    /// Checks the compute key derives the given address, and the challenge matches the hash of the given preimage.
+    fn check_challenge_and_address(&self, address: &Address<N>, preimage: &[Field<N>]) -> Result<(), VerifyError> {
+        // Derive the address from the compute key, where a failure to derive is treated as a mismatch.
+        let candidate_address = Address::try_from(self.compute_key).map_err(|_| VerifyError::AddressMismatch)?;
+        // Ensure the candidate address is correct.
+        if *address != candidate_address {
+            return Err(VerifyError::AddressMismatch);
+        }
+
+        // Hash to derive the verifier challenge, where a failure to hash is treated as a bad challenge.
+        let candidate_challenge = N::hash_to_scalar_psd8(preimage).map_err(|_| VerifyError::BadChallenge)?;
+        // Ensure the candidate challenge is correct.
+        match self.challenge == candidate_challenge {
+            true => Ok(()),
+            false => Err(VerifyError::BadChallenge),
         }
     }
 }
@@ -156,4 +224,79 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_verify_many() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the signatures.
+        let mut addresses = Vec::with_capacity(ITERATIONS as usize);
+        let mut messages = Vec::with_capacity(ITERATIONS as usize);
+        let mut signatures = Vec::with_capacity(ITERATIONS as usize);
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            addresses.push(Address::try_from(&private_key)?);
+
+            // Sign a random message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            signatures.push(Signature::sign(&private_key, &message, rng)?);
+            messages.push(message);
+        }
+
+        // Check that the batch is valid.
+        let mut batch: Vec<_> = addresses
+            .iter()
+            .zip_eq(&messages)
+            .zip_eq(&signatures)
+            .map(|((address, message), signature)| (*address, message.as_slice(), signature))
+            .collect();
+        assert!(Signature::verify_many(&batch));
+        // Check that an empty batch is valid.
+        assert!(Signature::<CurrentNetwork>::verify_many(&[]));
+
+        // Check that the batch is invalid if a single message is incorrect.
+        let failure_message: Vec<_> = (0..3).map(|_| Uniform::rand(rng)).collect();
+        batch[3].1 = &failure_message;
+        assert!(!Signature::verify_many(&batch));
+
+        // Check that the batch is invalid if a single address is incorrect.
+        batch[3].1 = &messages[3];
+        assert!(Signature::verify_many(&batch));
+        batch[5].0 = addresses[6];
+        assert!(!Signature::verify_many(&batch));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_checked() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample an address and a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Check that the signature is valid for the message.
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        let signature = Signature::sign(&private_key, &message, rng)?;
+        assert_eq!(Ok(()), signature.verify_checked(&address, &message));
+
+        // Check that an incorrect message is a bad challenge.
+        let failure_message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        assert_eq!(Err(VerifyError::BadChallenge), signature.verify_checked(&address, &failure_message));
+
+        // Check that an incorrect address is an address mismatch.
+        let failure_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert_eq!(Err(VerifyError::AddressMismatch), signature.verify_checked(&failure_address, &message));
+
+        // Check that an oversized message is rejected.
+        let max = CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize;
+        let oversized_message = vec![Field::zero(); max + 1];
+        assert_eq!(
+            Err(VerifyError::OversizedMessage(max + 1, max)),
+            signature.verify_checked(&address, &oversized_message)
+        );
+        assert!(!signature.verify(&address, &oversized_message));
+        Ok(())
+    }
 }
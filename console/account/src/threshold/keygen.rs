@@ -0,0 +1,368 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The public package broadcast by a participant in the distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyGenPackage<N: Network> {
+    /// The index of the participant.
+    index: u16,
+    /// The commitments `a_k * G` to the coefficients of the secret polynomial of the participant.
+    commitments: Vec<Group<N>>,
+    /// The contribution `r_i * G` of the participant to the public randomizer `pr_sig`.
+    pr_sig: Group<N>,
+    /// The challenge of the proof of knowledge of the constant coefficient and the randomizer contribution.
+    challenge: Scalar<N>,
+    /// The response of the proof of knowledge of the constant coefficient.
+    response: Scalar<N>,
+    /// The response of the proof of knowledge of the randomizer contribution.
+    pr_response: Scalar<N>,
+}
+
+/// The secret state of a participant in the distributed key generation.
+pub struct KeyGenSecret<N: Network> {
+    /// The index of the participant.
+    index: u16,
+    /// The number of participants.
+    num_participants: u16,
+    /// The coefficients of the secret polynomial of the participant.
+    coefficients: Vec<Scalar<N>>,
+}
+
+/// The long-lived signing share of a participant, output by the distributed key generation.
+#[derive(Clone)]
+pub struct KeyShare<N: Network> {
+    /// The index of the participant.
+    index: u16,
+    /// The secret signing share `s_i := f(i)`.
+    signing_share: Scalar<N>,
+    /// The public threshold key of the group.
+    threshold_key: ThresholdKey<N>,
+}
+
+/// The public key of a t-of-n threshold account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdKey<N: Network> {
+    /// The minimum number of signers.
+    threshold: u16,
+    /// The public verification share `s_i * G` of each participant, indexed from `1`.
+    verification_shares: BTreeMap<u16, Group<N>>,
+    /// The group compute key.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> KeyGenSecret<N> {
+    /// Samples the secret polynomial of the participant with the given index (from `1`), and returns it
+    /// with the package to broadcast to the other participants.
+    pub fn new<R: Rng + CryptoRng>(
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, KeyGenPackage<N>)> {
+        // Ensure the parameters are well-formed.
+        ensure!(threshold > 0 && threshold <= num_participants, "Invalid threshold {threshold} of {num_participants}");
+        ensure!(index > 0 && index <= num_participants, "Invalid participant index {index} of {num_participants}");
+
+        // Sample the coefficients of the secret polynomial, of degree `threshold - 1`.
+        let coefficients: Vec<Scalar<N>> = (0..threshold).map(|_| Scalar::rand(rng)).collect();
+        // Commit to each coefficient.
+        let commitments: Vec<_> = coefficients.iter().map(N::g_scalar_multiply).collect();
+        // Sample the contribution to the public randomizer.
+        // Note: The randomizer contribution is discarded, as the group signs with `sk_sig` alone.
+        let r_sig = crate::scrub::Secret::new(Scalar::rand(rng));
+        let pr_sig = N::g_scalar_multiply(&r_sig);
+
+        // Prove knowledge of the constant coefficient and the randomizer contribution, binding the proof
+        // to the participant index. Otherwise, the last participant to broadcast could choose its contributions
+        // as a function of the others, and learn the view key of the threshold account.
+        let (nonce, pr_nonce) = (Scalar::rand(rng), Scalar::rand(rng));
+        let challenge = keygen_challenge::<N>(
+            index,
+            &commitments[0],
+            &pr_sig,
+            &N::g_scalar_multiply(&nonce),
+            &N::g_scalar_multiply(&pr_nonce),
+        )?;
+        let response = nonce - challenge * coefficients[0];
+        let pr_response = pr_nonce - challenge * *r_sig;
+
+        let package = KeyGenPackage { index, commitments, pr_sig, challenge, response, pr_response };
+        Ok((Self { index, num_participants, coefficients }, package))
+    }
+
+    /// Returns the secret share `f(recipient)` for the participant with the given index,
+    /// which must be sent to the recipient over a private channel.
+    pub fn share_for(&self, recipient: u16) -> Result<Scalar<N>> {
+        ensure!(
+            recipient > 0 && recipient <= self.num_participants,
+            "Invalid participant index {recipient} of {}",
+            self.num_participants
+        );
+        Ok(evaluate(&self.coefficients, recipient))
+    }
+
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<N: Network> KeyGenPackage<N> {
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns `true` if the proof of knowledge of the constant coefficient and the randomizer contribution is valid.
+    pub fn verify(&self) -> bool {
+        // Ensure the package contains the constant coefficient.
+        let commitment = match self.commitments.first() {
+            Some(commitment) => commitment,
+            None => return false,
+        };
+        // Compute `g_r` := (response * G) + (challenge * commitment).
+        let g_r = N::g_scalar_multiply(&self.response) + (*commitment * self.challenge);
+        // Compute `g_s` := (pr_response * G) + (challenge * pr_sig).
+        let g_s = N::g_scalar_multiply(&self.pr_response) + (self.pr_sig * self.challenge);
+        // Ensure the challenge matches.
+        matches!(
+            keygen_challenge::<N>(self.index, commitment, &self.pr_sig, &g_r, &g_s),
+            Ok(challenge) if challenge == self.challenge
+        )
+    }
+
+    /// Returns the public image `f(index) * G` of the secret polynomial at the given index.
+    fn evaluate_commitments(&self, index: u16) -> Group<N> {
+        let x = Scalar::<N>::new(N::Scalar::from(index));
+        self.commitments.iter().rev().fold(Group::zero(), |sum, commitment| sum * x + commitment)
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Completes the distributed key generation for the participant with the given index,
+    /// from the packages of all participants and the secret shares sent to this participant.
+    pub fn new(index: u16, packages: &[KeyGenPackage<N>], shares: &[(u16, Scalar<N>)]) -> Result<Self> {
+        // Ensure there is at least one participant.
+        let threshold = match packages.first() {
+            Some(package) => package.commitments.len(),
+            None => bail!("The key generation requires at least one participant"),
+        };
+        // Ensure the participant indices are unique.
+        ensure!(!has_duplicates(packages.iter().map(|package| package.index)), "Found a duplicate participant");
+        // Ensure every participant sent a share.
+        ensure!(packages.len() == shares.len(), "Expected {} shares, found {}", packages.len(), shares.len());
+
+        let mut signing_share = Scalar::zero();
+        for package in packages {
+            // Ensure the package is well-formed.
+            ensure!(package.commitments.len() == threshold, "Participant {} used the wrong threshold", package.index);
+            ensure!(package.verify(), "Participant {} sent an invalid proof of knowledge", package.index);
+
+            // Retrieve the share from the participant.
+            let share = match shares.iter().find(|(sender, _)| *sender == package.index) {
+                Some((_, share)) => *share,
+                None => bail!("Missing the share from participant {}", package.index),
+            };
+            // Ensure the share is consistent with the commitments of the participant.
+            ensure!(
+                N::g_scalar_multiply(&share) == package.evaluate_commitments(index),
+                "Participant {} sent an invalid share",
+                package.index
+            );
+            signing_share += share;
+        }
+
+        // Compute the threshold key.
+        let threshold_key = ThresholdKey::new(packages)?;
+        // Ensure the signing share matches the verification share.
+        ensure!(
+            threshold_key.verification_share(index)? == N::g_scalar_multiply(&signing_share),
+            "The signing share does not match the verification share"
+        );
+
+        Ok(Self { index, signing_share, threshold_key })
+    }
+
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the secret signing share.
+    pub const fn signing_share(&self) -> Scalar<N> {
+        self.signing_share
+    }
+
+    /// Returns the public threshold key of the group.
+    pub const fn threshold_key(&self) -> &ThresholdKey<N> {
+        &self.threshold_key
+    }
+}
+
+impl<N: Network> fmt::Debug for KeyShare<N> {
+    /// Writes the fingerprint of the signing share, without revealing the signing share.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let signing_share = self.signing_share.to_field().map_err(|_| fmt::Error)?;
+        snarkvm_console_network::fmt_redacted::<N>(f, "KeyShare", &[signing_share])
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> zeroize::Zeroize for KeyGenSecret<N> {
+    /// Overwrites the coefficients of the secret polynomial with zero.
+    fn zeroize(&mut self) {
+        crate::scrub::scrub(&mut self.coefficients);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> Drop for KeyGenSecret<N> {
+    /// Zeroizes the secret polynomial on drop.
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> zeroize::ZeroizeOnDrop for KeyGenSecret<N> {}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> zeroize::Zeroize for KeyShare<N> {
+    /// Overwrites the signing share with zero.
+    fn zeroize(&mut self) {
+        crate::scrub::scrub(&mut self.signing_share);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> Drop for KeyShare<N> {
+    /// Zeroizes the signing share on drop.
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> zeroize::ZeroizeOnDrop for KeyShare<N> {}
+
+impl<N: Network> ThresholdKey<N> {
+    /// Derives the threshold key from the packages of all participants in the distributed key generation.
+    pub fn new(packages: &[KeyGenPackage<N>]) -> Result<Self> {
+        // Ensure there is at least one participant.
+        let threshold = match packages.first() {
+            Some(package) => package.commitments.len(),
+            None => bail!("The key generation requires at least one participant"),
+        };
+
+        // Compute pk_sig := sum(a_{i,0} * G).
+        let pk_sig = packages.iter().fold(Group::zero(), |sum, package| sum + package.commitments[0]);
+        // Compute pr_sig := sum(pr_sig_i).
+        let pr_sig = packages.iter().fold(Group::zero(), |sum, package| sum + package.pr_sig);
+        // Compute the verification share of each participant.
+        let verification_shares = packages
+            .iter()
+            .map(|recipient| {
+                let share: Group<N> =
+                    packages.iter().map(|package| package.evaluate_commitments(recipient.index)).sum();
+                (recipient.index, share)
+            })
+            .collect();
+
+        Ok(Self {
+            threshold: u16::try_from(threshold)?,
+            verification_shares,
+            compute_key: ComputeKey::try_from((pk_sig, pr_sig))?,
+        })
+    }
+
+    /// Returns the minimum number of signers.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the public verification share of the participant with the given index.
+    pub fn verification_share(&self, index: u16) -> Result<Group<N>> {
+        self.verification_shares.get(&index).copied().ok_or_else(|| anyhow!("Participant {index} does not exist"))
+    }
+
+    /// Returns the group compute key.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Returns the address of the threshold account.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+}
+
+/// Returns the challenge of the proof of knowledge for the participant with the given index.
+fn keygen_challenge<N: Network>(
+    index: u16,
+    commitment: &Group<N>,
+    pr_sig: &Group<N>,
+    g_r: &Group<N>,
+    g_s: &Group<N>,
+) -> Result<Scalar<N>> {
+    N::hash_to_scalar_psd8(&[
+        Field::new_domain_separator(THRESHOLD_KEYGEN_DOMAIN),
+        Field::from_u16(index),
+        commitment.to_x_coordinate(),
+        pr_sig.to_x_coordinate(),
+        g_r.to_x_coordinate(),
+        g_s.to_x_coordinate(),
+    ])
+}
+
+/// Evaluates the polynomial with the given coefficients at the given index.
+fn evaluate<N: Network>(coefficients: &[Scalar<N>], index: u16) -> Scalar<N> {
+    let x = Scalar::<N>::new(N::Scalar::from(index));
+    coefficients.iter().rev().fold(Scalar::zero(), |sum, coefficient| sum * x + coefficient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_keygen_package_rogue_contribution() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let (_, package) = KeyGenSecret::<CurrentNetwork>::new(1, 2, 3, rng)?;
+        assert!(package.verify());
+
+        // Ensure a randomizer contribution chosen without knowledge of its discrete log is rejected.
+        let mut rogue = package.clone();
+        rogue.pr_sig = package.pr_sig - Group::rand(rng);
+        assert!(!rogue.verify());
+
+        // Ensure a constant coefficient chosen without knowledge of its discrete log is rejected.
+        let mut rogue = package.clone();
+        rogue.commitments[0] = package.commitments[0] - Group::rand(rng);
+        assert!(!rogue.verify());
+
+        // Ensure the proof is bound to the participant index.
+        let mut rogue = package;
+        rogue.index = 2;
+        assert!(!rogue.verify());
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! FROST-style t-of-n threshold signatures, which produce a standard `Signature<N>`.
+//!
+//! 1. In the distributed key generation, each participant samples a `KeyGenSecret`, broadcasts its
+//!    `KeyGenPackage`, and privately sends a share to every other participant. Each participant then
+//!    derives its `KeyShare`, and all participants agree on the `ThresholdKey`, whose address owns the account.
+//! 2. In the first round, each signer samples a `SigningNonce`, and shares its `NonceCommitment`.
+//! 3. In the second round, each signer opens a `ThresholdSession` over the nonce commitments and the message,
+//!    and shares its `ThresholdPartialSignature`. The partial signatures of any `t` signers are aggregated
+//!    into a `Signature<N>`, which verifies under the threshold address with `Signature::verify`.
+
+mod keygen;
+pub use keygen::{KeyGenPackage, KeyGenSecret, KeyShare, ThresholdKey};
+
+mod sign;
+pub use sign::{NonceCommitment, SigningNonce, ThresholdPartialSignature, ThresholdSession};
+
+use crate::{ComputeKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+use std::collections::BTreeMap;
+
+static THRESHOLD_KEYGEN_DOMAIN: &str = "AleoThresholdKeyGen0";
+static THRESHOLD_NONCE_DOMAIN: &str = "AleoThresholdNonceBinding0";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Runs the distributed key generation, and returns the key share of each participant.
+    fn keygen(threshold: u16, num_participants: u16) -> Result<Vec<KeyShare<CurrentNetwork>>> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the secret polynomial of each participant.
+        let (secrets, packages): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|index| KeyGenSecret::new(index, threshold, num_participants, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // Derive the key share of each participant, from the shares sent by every participant.
+        (1..=num_participants)
+            .map(|index| {
+                let shares = secrets
+                    .iter()
+                    .map(|secret| Ok((secret.index(), secret.share_for(index)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                KeyShare::new(index, &packages, &shares)
+            })
+            .collect()
+    }
+
+    /// Signs the given message with the given key shares, and returns the signature.
+    fn sign(
+        key_shares: &[&KeyShare<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+    ) -> Result<Signature<CurrentNetwork>> {
+        let rng = &mut test_crypto_rng();
+
+        // Round 1: Sample the nonces.
+        let (signing_nonces, commitments): (Vec<_>, Vec<_>) =
+            key_shares.iter().map(|key_share| SigningNonce::new(key_share, rng)).unzip();
+
+        // Round 2: Compute the partial signatures.
+        let session = ThresholdSession::new(key_shares[0].threshold_key().clone(), &commitments, message)?;
+        let partial_signatures = key_shares
+            .iter()
+            .zip_eq(signing_nonces)
+            .map(|(key_share, signing_nonce)| session.sign(key_share, signing_nonce))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure each partial signature is valid.
+        for partial_signature in &partial_signatures {
+            assert!(session.verify_partial(partial_signature));
+        }
+
+        // Aggregate the partial signatures.
+        session.aggregate(&partial_signatures)
+    }
+
+    #[test]
+    fn test_threshold_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let key_shares = keygen(threshold, num_participants)?;
+            let address = key_shares[0].threshold_key().to_address();
+            // Ensure every participant agrees on the threshold key.
+            for key_share in &key_shares {
+                assert_eq!(key_shares[0].threshold_key(), key_share.threshold_key());
+            }
+
+            // Sample the message.
+            let message: Vec<_> = (0..threshold).map(|_| Uniform::rand(rng)).collect();
+
+            // Ensure any `threshold` signers, and all signers, produce a valid signature.
+            let first: Vec<_> = key_shares.iter().take(threshold as usize).collect();
+            let last: Vec<_> = key_shares.iter().rev().take(threshold as usize).collect();
+            let all: Vec<_> = key_shares.iter().collect();
+            for signers in [first, last, all] {
+                let signature = sign(&signers, &message)?;
+                assert!(signature.verify(&address, &message));
+                assert_eq!(address, signature.to_address());
+
+                // Ensure the signature is invalid for an incorrect message.
+                let failure_message: Vec<_> = (0..threshold).map(|_| Uniform::rand(rng)).collect();
+                if message != failure_message {
+                    assert!(!signature.verify(&address, &failure_message));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_too_few_signers() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let key_shares = keygen(3, 5)?;
+        let message: Vec<_> = (0..3).map(|_| Uniform::rand(rng)).collect();
+
+        // Ensure a session cannot be opened with fewer than `threshold` signers.
+        let commitments: Vec<_> =
+            key_shares.iter().take(2).map(|key_share| SigningNonce::new(key_share, rng).1).collect();
+        assert!(ThresholdSession::new(key_shares[0].threshold_key().clone(), &commitments, &message).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_invalid_partial_signature() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let key_shares = keygen(2, 3)?;
+        let message: Vec<_> = (0..2).map(|_| Uniform::rand(rng)).collect();
+
+        let (mut signing_nonces, commitments): (Vec<_>, Vec<_>) =
+            key_shares.iter().take(2).map(|key_share| SigningNonce::new(key_share, rng)).unzip();
+        let session = ThresholdSession::new(key_shares[0].threshold_key().clone(), &commitments, &message)?;
+
+        // Ensure a signer outside the session cannot sign.
+        let (outside_nonce, _) = SigningNonce::new(&key_shares[2], rng);
+        assert!(session.sign(&key_shares[2], outside_nonce).is_err());
+
+        // Ensure a signer cannot sign with another signer's nonce.
+        assert!(session.sign(&key_shares[0], signing_nonces.remove(1)).is_err());
+
+        // Ensure a tampered partial signature is rejected.
+        let partial_signature = session.sign(&key_shares[0], signing_nonces.remove(0))?;
+        assert!(session.verify_partial(&partial_signature));
+        let tampered = ThresholdPartialSignature::from((1, partial_signature.response() + Scalar::one()));
+        assert!(!session.verify_partial(&tampered));
+
+        // Ensure the partial signatures cannot be aggregated with a missing signer.
+        assert!(session.aggregate(&[partial_signature]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_invalid_share() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let (secrets, packages): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| KeyGenSecret::<CurrentNetwork>::new(index, 2, 3, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // Ensure a share that does not match the sender's commitments is rejected.
+        let mut shares =
+            secrets.iter().map(|secret| Ok((secret.index(), secret.share_for(1)?))).collect::<Result<Vec<_>>>()?;
+        assert!(KeyShare::new(1, &packages, &shares).is_ok());
+        shares[1].1 += Scalar::one();
+        assert!(KeyShare::new(1, &packages, &shares).is_err());
+
+        // Ensure a share cannot be computed for an index outside the participants.
+        assert!(secrets[0].share_for(0).is_err());
+        assert!(secrets[0].share_for(4).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The secret nonces of a signer for a single signing session.
+///
+/// Note: The signing nonce is consumed when signing, as reusing it across two sessions reveals the signing share.
+pub struct SigningNonce<N: Network> {
+    /// The hiding nonce.
+    d: Scalar<N>,
+    /// The binding nonce.
+    e: Scalar<N>,
+}
+
+/// The public commitment to the signing nonces of a signer, shared with the other signers in the first round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonceCommitment<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The hiding commitment `D` := G^d.
+    hiding: Group<N>,
+    /// The binding commitment `E` := G^e.
+    binding: Group<N>,
+}
+
+/// The partial signature of a single signer, shared with the coordinator in the second round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ThresholdPartialSignature<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The partial response of the signer.
+    response: Scalar<N>,
+}
+
+/// The second round of a threshold signature, over the nonce commitments of the signers and the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdSession<N: Network> {
+    /// The threshold key of the group.
+    threshold_key: ThresholdKey<N>,
+    /// The nonce commitments of the signers, sorted by index.
+    commitments: Vec<NonceCommitment<N>>,
+    /// The binding factor `rho_i` of each signer, in the order of `commitments`.
+    binding_factors: Vec<Scalar<N>>,
+    /// The Lagrange coefficient `lambda_i` of each signer, in the order of `commitments`.
+    lagrange_coefficients: Vec<Scalar<N>>,
+    /// The verifier challenge of the threshold signature.
+    challenge: Scalar<N>,
+}
+
+impl<N: Network> SigningNonce<N> {
+    /// Samples a new signing nonce for the given key share, and returns it with its nonce commitment.
+    pub fn new<R: Rng + CryptoRng>(key_share: &KeyShare<N>, rng: &mut R) -> (Self, NonceCommitment<N>) {
+        // Sample the secret nonces from the scalar field.
+        let (d, e) = (Scalar::rand(rng), Scalar::rand(rng));
+        // Compute the nonce commitment.
+        let commitment = NonceCommitment {
+            index: key_share.index(),
+            hiding: N::g_scalar_multiply(&d),
+            binding: N::g_scalar_multiply(&e),
+        };
+        (Self { d, e }, commitment)
+    }
+}
+
+impl<N: Network> fmt::Debug for SigningNonce<N> {
+    /// Writes the fingerprint of the signing nonce, without revealing the signing nonce.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nonces = [self.d.to_field().map_err(|_| fmt::Error)?, self.e.to_field().map_err(|_| fmt::Error)?];
+        snarkvm_console_network::fmt_redacted::<N>(f, "SigningNonce", &nonces)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> zeroize::Zeroize for SigningNonce<N> {
+    /// Overwrites the hiding and binding nonces with zero.
+    fn zeroize(&mut self) {
+        crate::scrub::scrub(&mut self.d);
+        crate::scrub::scrub(&mut self.e);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> Drop for SigningNonce<N> {
+    /// Zeroizes the signing nonce on drop, which also scrubs the nonce once it is consumed by signing.
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<N: Network> zeroize::ZeroizeOnDrop for SigningNonce<N> {}
+
+impl<N: Network> NonceCommitment<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the hiding commitment.
+    pub const fn hiding(&self) -> Group<N> {
+        self.hiding
+    }
+
+    /// Returns the binding commitment.
+    pub const fn binding(&self) -> Group<N> {
+        self.binding
+    }
+}
+
+impl<N: Network> From<(u16, Scalar<N>)> for ThresholdPartialSignature<N> {
+    /// Initializes a partial signature from the index of the signer and its partial response.
+    fn from((index, response): (u16, Scalar<N>)) -> Self {
+        Self { index, response }
+    }
+}
+
+impl<N: Network> ThresholdPartialSignature<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the partial response of the signer.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+impl<N: Network> ThresholdSession<N> {
+    /// Initializes a signing session for the given message, from the nonce commitments of the signers, where:
+    ///     rho_i := HashToScalar(DOMAIN || i || address || message || commitments)
+    ///     g_r := sum(D_i + rho_i * E_i)
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    /// matching the challenge of `Signature::sign` for the group compute key.
+    pub fn new(
+        threshold_key: ThresholdKey<N>,
+        commitments: &[NonceCommitment<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Ensure there are enough signers.
+        ensure!(
+            commitments.len() >= threshold_key.threshold() as usize,
+            "Expected at least {} signers, found {}",
+            threshold_key.threshold(),
+            commitments.len()
+        );
+        // Ensure the signers are unique.
+        ensure!(!has_duplicates(commitments.iter().map(|c| c.index)), "Found a duplicate signer in the session");
+        // Ensure every signer is a participant.
+        for commitment in commitments {
+            threshold_key.verification_share(commitment.index)?;
+        }
+
+        // Sort the commitments by signer index, so that every signer derives the same session.
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|commitment| commitment.index);
+
+        // Construct the binding preimage as (address, message, commitments), so the binding factors
+        // are bound to the threshold key, as well as to the message and the signers.
+        let mut preimage = Vec::with_capacity(3 + message.len() + 3 * commitments.len());
+        preimage.push(Field::new_domain_separator(THRESHOLD_NONCE_DOMAIN));
+        preimage.push(Field::zero());
+        preimage.push(threshold_key.to_address().to_x_coordinate());
+        preimage.extend(message);
+        preimage.extend(commitments.iter().flat_map(|commitment| {
+            [
+                Field::from_u16(commitment.index),
+                commitment.hiding.to_x_coordinate(),
+                commitment.binding.to_x_coordinate(),
+            ]
+        }));
+
+        // Compute the binding factor of each signer.
+        let binding_factors = commitments
+            .iter()
+            .map(|commitment| {
+                preimage[1] = Field::from_u16(commitment.index);
+                N::hash_to_scalar_psd8(&preimage)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the Lagrange coefficient of each signer, at zero.
+        let indices: Vec<_> =
+            commitments.iter().map(|commitment| Scalar::<N>::new(N::Scalar::from(commitment.index))).collect();
+        let lagrange_coefficients = indices
+            .iter()
+            .map(|x_i| {
+                let (numerator, denominator) = indices
+                    .iter()
+                    .filter(|x_j| x_j != &x_i)
+                    .fold((Scalar::one(), Scalar::one()), |(num, den), x_j| (num * x_j, den * (*x_j - x_i)));
+                Ok(numerator * denominator.inverse()?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute `g_r` := sum(D_i + rho_i * E_i).
+        let g_r = commitments
+            .iter()
+            .zip_eq(&binding_factors)
+            .map(|(commitment, rho)| commitment.hiding + commitment.binding * *rho)
+            .sum::<Group<N>>();
+
+        // Retrieve the group pk_sig and pr_sig.
+        let pk_sig = threshold_key.compute_key().pk_sig();
+        let pr_sig = threshold_key.compute_key().pr_sig();
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *threshold_key.to_address()].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok(Self { threshold_key, commitments, binding_factors, lagrange_coefficients, challenge })
+    }
+
+    /// Returns the partial signature of the given key share, where:
+    ///     response_i := d_i + rho_i * e_i - challenge * lambda_i * s_i
+    pub fn sign(
+        &self,
+        key_share: &KeyShare<N>,
+        signing_nonce: SigningNonce<N>,
+    ) -> Result<ThresholdPartialSignature<N>> {
+        // Ensure the key share belongs to the same group.
+        ensure!(key_share.threshold_key() == &self.threshold_key, "The key share belongs to another threshold key");
+        // Retrieve the position of the signer in the session.
+        let position = self.position(key_share.index())?;
+        // Ensure the signing nonce matches the nonce commitment of the signer.
+        ensure!(
+            N::g_scalar_multiply(&signing_nonce.d) == self.commitments[position].hiding
+                && N::g_scalar_multiply(&signing_nonce.e) == self.commitments[position].binding,
+            "The signing nonce does not match the nonce commitment of signer {}",
+            key_share.index()
+        );
+
+        // Compute the partial response.
+        let (rho, lambda) = (self.binding_factors[position], self.lagrange_coefficients[position]);
+        let response = signing_nonce.d + rho * signing_nonce.e - self.challenge * lambda * key_share.signing_share();
+        Ok(ThresholdPartialSignature { index: key_share.index(), response })
+    }
+
+    /// Returns `true` if the given partial signature is valid for its signer, i.e.
+    ///     response_i * G + (challenge * lambda_i) * Y_i == D_i + rho_i * E_i
+    pub fn verify_partial(&self, partial_signature: &ThresholdPartialSignature<N>) -> bool {
+        // Retrieve the position and verification share of the signer.
+        let (position, verification_share) = match (
+            self.position(partial_signature.index),
+            self.threshold_key.verification_share(partial_signature.index),
+        ) {
+            (Ok(position), Ok(verification_share)) => (position, verification_share),
+            _ => return false,
+        };
+
+        // Compute the left-hand side and right-hand side of the verification equation.
+        let (commitment, rho, lambda) =
+            (&self.commitments[position], self.binding_factors[position], self.lagrange_coefficients[position]);
+        let candidate =
+            N::g_scalar_multiply(&partial_signature.response) + verification_share * (self.challenge * lambda);
+        let expected = commitment.hiding + commitment.binding * rho;
+        candidate == expected
+    }
+
+    /// Aggregates the partial signatures of every signer in the session into a signature.
+    pub fn aggregate(&self, partial_signatures: &[ThresholdPartialSignature<N>]) -> Result<Signature<N>> {
+        // Ensure there is one partial signature for each signer.
+        ensure!(
+            partial_signatures.len() == self.commitments.len(),
+            "Expected {} partial signatures, found {}",
+            self.commitments.len(),
+            partial_signatures.len()
+        );
+        // Ensure the signers are unique.
+        ensure!(!has_duplicates(partial_signatures.iter().map(|p| p.index)), "Found a duplicate partial signature");
+        // Ensure each partial signature is valid, to identify a misbehaving signer.
+        for partial_signature in partial_signatures {
+            ensure!(
+                self.verify_partial(partial_signature),
+                "Signer {} sent an invalid partial signature",
+                partial_signature.index
+            );
+        }
+
+        // Compute the response as the sum of the partial responses.
+        let response = partial_signatures.iter().map(|partial| partial.response).sum();
+        // Output the signature.
+        Ok(Signature::from((self.challenge, response, self.threshold_key.compute_key())))
+    }
+
+    /// Returns the position of the signer with the given index in the session.
+    fn position(&self, index: u16) -> Result<usize> {
+        self.commitments
+            .iter()
+            .position(|commitment| commitment.index == index)
+            .ok_or_else(|| anyhow!("Signer {index} is not in the session"))
+    }
+}
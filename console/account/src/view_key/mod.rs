@@ -16,22 +16,26 @@
 
 mod bytes;
 mod serialize;
+mod shared_secret;
 mod string;
 mod to_address;
 mod try_from;
 
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
 #[cfg(feature = "compute_key")]
 use crate::ComputeKey;
 #[cfg(feature = "private_key")]
 use crate::PrivateKey;
 
-use snarkvm_console_network::prelude::*;
+use snarkvm_console_network::{fmt_redacted, prelude::*, Revealed};
 use snarkvm_console_types::{Address, Scalar};
 
 use base58::{FromBase58, ToBase58};
 
 /// The account view key used to decrypt records and ciphertext.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ViewKey<N: Network>(Scalar<N>);
 
 impl<N: Network> ViewKey<N> {
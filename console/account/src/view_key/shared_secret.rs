@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::address::SharedSecret;
+use snarkvm_console_types::Field;
+
+impl<N: Network> ViewKey<N> {
+    /// Returns the shared secret between the view key and the given address,
+    /// which is also derived by the owner of the address from the address of this view key.
+    ///
+    /// To derive a shared secret with a one-time key, the sender samples a scalar `r`, publishes `R = r * G`
+    /// as an address, and computes `address.shared_secret(&r)`; the recipient computes `view_key.shared_secret(&R)`.
+    pub fn shared_secret(&self, address: &Address<N>) -> Result<Field<N>> {
+        address.shared_secret(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_shared_secret() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample two accounts.
+            let alice = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let bob = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+            // Ensure both accounts derive the same shared secret.
+            let expected = alice.shared_secret(&bob.to_address())?;
+            assert_eq!(expected, bob.shared_secret(&alice.to_address())?);
+
+            // Ensure a third account derives a different shared secret.
+            let eve = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert_ne!(expected, eve.shared_secret(&alice.to_address())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_secret_ephemeral() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample the recipient.
+            let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let address = view_key.to_address();
+
+            // Sample the ephemeral key of the sender.
+            let r = Scalar::<CurrentNetwork>::rand(rng);
+            let r_address = Address::new(CurrentNetwork::g_scalar_multiply(&r));
+
+            // Ensure the sender and recipient derive the same shared secret.
+            assert_eq!(address.shared_secret(&r)?, view_key.shared_secret(&r_address)?);
+        }
+
+        // Ensure a zero scalar is rejected.
+        let address = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?.to_address();
+        assert!(address.shared_secret(&Scalar::zero()).is_err());
+        Ok(())
+    }
+}
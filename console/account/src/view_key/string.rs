@@ -47,6 +47,20 @@ impl<N: Network> fmt::Display for ViewKey<N> {
     }
 }
 
+impl<N: Network> fmt::Debug for ViewKey<N> {
+    /// Writes the fingerprint of the account view key, without revealing the account view key.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_redacted::<N>(f, "ViewKey", &[self.0.to_field().map_err(|_| fmt::Error)?])
+    }
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Returns a reference to the account view key, whose `Debug` implementation writes the account view key in full.
+    pub const fn reveal(&self) -> Revealed<Self> {
+        Revealed::new(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +84,22 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_debug_is_redacted() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(private_key)?;
+
+            // Ensure the debug representation does not reveal the view key.
+            let candidate = format!("{view_key:?}");
+            assert!(candidate.starts_with("ViewKey(<redacted "));
+            assert!(!candidate.contains(&view_key.to_string()));
+            assert!(!candidate.contains(&(*view_key).to_string()));
+            // Ensure the revealed representation matches the string representation.
+            assert_eq!(view_key.to_string(), format!("{:?}", view_key.reveal()));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::scrub::scrub;
+
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for ViewKey<N> {
+    /// Overwrites the account view key with zero.
+    ///
+    /// Note: As the view key is `Copy`, it is not zeroized on drop, and zeroizing it does not zeroize its copies.
+    /// To zeroize the view key on drop, wrap it in `Zeroizing`.
+    fn zeroize(&mut self) {
+        scrub(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        let mut view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?)?;
+        view_key.zeroize();
+        assert_eq!(Scalar::zero(), *view_key);
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::{bits_le_to_bytes, bytes_to_bits_le};
+use snarkvm_console_types::prelude::*;
+
+/// BLAKE2s-256, as specified in RFC 7693, without a key.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `u8::to_bits_le` of each byte.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Blake2s;
+
+impl Hash for Blake2s {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the 256-bit BLAKE2s digest of the given input, which must be a whole number of bytes.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bytes_to_bits_le(blake2s_simd::blake2s(&bits_le_to_bytes(input)?).as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake2s() -> Result<()> {
+        // Check the digests in RFC 7693.
+        for (input, expected) in [
+            ("", "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"),
+            ("abc", "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"),
+        ] {
+            let digest = Blake2s.hash(&bytes_to_bits_le(input.as_bytes()))?;
+            assert_eq!(expected, hex::encode(bits_le_to_bytes(&digest)?));
+        }
+
+        // Ensure a partial byte is rejected.
+        assert!(Blake2s.hash(&[true; 7]).is_err());
+        Ok(())
+    }
+}
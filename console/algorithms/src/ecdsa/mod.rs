@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::bits_le_to_bytes;
+use snarkvm_console_types::prelude::*;
+
+use num_bigint::BigUint;
+
+/// The secp256k1 base field modulus, in big-endian hexadecimal.
+const BASE_FIELD_MODULUS: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+/// The secp256k1 group order, in big-endian hexadecimal.
+const GROUP_ORDER: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+/// The x-coordinate of the secp256k1 generator, in big-endian hexadecimal.
+const GENERATOR_X: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// The y-coordinate of the secp256k1 generator, in big-endian hexadecimal.
+const GENERATOR_Y: &str = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// An affine secp256k1 point, where `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// ECDSA signature verification over secp256k1, as used in Ethereum and Bitcoin.
+///
+/// The public key is the 64-byte concatenation of the big-endian affine coordinates `x || y`,
+/// the message hash is a 32-byte big-endian integer, and the signature is the 64-byte concatenation `r || s`.
+/// Each is given as bits, where each byte is in little-endian bit order, to match the circuit gadget.
+/// Signatures with a high `s` are accepted, as in `ecrecover`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct EcdsaSecp256k1;
+
+impl EcdsaSecp256k1 {
+    /// The number of bits in a public key.
+    pub const PUBLIC_KEY_SIZE_IN_BITS: usize = 512;
+    /// The number of bits in a message hash.
+    pub const MESSAGE_HASH_SIZE_IN_BITS: usize = 256;
+    /// The number of bits in a signature.
+    pub const SIGNATURE_SIZE_IN_BITS: usize = 512;
+
+    /// Returns `true` if the signature is valid for the given public key and message hash.
+    pub fn verify(public_key: &[bool], message_hash: &[bool], signature: &[bool]) -> Result<bool> {
+        // Ensure the inputs are the expected size.
+        ensure!(public_key.len() == Self::PUBLIC_KEY_SIZE_IN_BITS, "Invalid secp256k1 public key size");
+        ensure!(message_hash.len() == Self::MESSAGE_HASH_SIZE_IN_BITS, "Invalid secp256k1 message hash size");
+        ensure!(signature.len() == Self::SIGNATURE_SIZE_IN_BITS, "Invalid secp256k1 signature size");
+
+        let (p, n) = (constant(BASE_FIELD_MODULUS), constant(GROUP_ORDER));
+
+        // Parse the inputs.
+        let public_key = bits_le_to_bytes(public_key)?;
+        let signature = bits_le_to_bytes(signature)?;
+        let (x, y) = (BigUint::from_bytes_be(&public_key[..32]), BigUint::from_bytes_be(&public_key[32..]));
+        let z = BigUint::from_bytes_be(&bits_le_to_bytes(message_hash)?) % &n;
+        let (r, s) = (BigUint::from_bytes_be(&signature[..32]), BigUint::from_bytes_be(&signature[32..]));
+
+        // Ensure the signature scalars are in `[1, n)`.
+        let zero = BigUint::from(0u8);
+        if r == zero || r >= n || s == zero || s >= n {
+            return Ok(false);
+        }
+        // Ensure the public key is on the curve `y^2 = x^3 + 7`.
+        if x >= p || y >= p || (&y * &y) % &p != (&x * &x * &x + 7u8) % &p {
+            return Ok(false);
+        }
+
+        // Compute `R = (z / s) * G + (r / s) * Q`.
+        let s_inverse = s.modpow(&(&n - 2u8), &n);
+        let generator = Some((constant(GENERATOR_X), constant(GENERATOR_Y)));
+        let u1_g = mul(&generator, &((&z * &s_inverse) % &n), &p);
+        let u2_q = mul(&Some((x, y)), &((&r * &s_inverse) % &n), &p);
+
+        // Ensure `R` is not the point at infinity, and that its x-coordinate is `r`, modulo `n`.
+        match add(&u1_g, &u2_q, &p) {
+            Some((r_x, _)) => Ok(r_x % &n == r),
+            None => Ok(false),
+        }
+    }
+}
+
+/// Returns the integer of the given big-endian hexadecimal constant.
+fn constant(hex: &str) -> BigUint {
+    // Note: The constants are well-formed, so this is infallible.
+    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap_or_default()
+}
+
+/// Returns the sum of the given points, with the affine addition law.
+fn add(a: &Point, b: &Point, p: &BigUint) -> Point {
+    let ((a_x, a_y), (b_x, b_y)) = match (a, b) {
+        (None, _) => return b.clone(),
+        (_, None) => return a.clone(),
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    let lambda = match a_x == b_x {
+        // Return the point at infinity, if `a = -b`.
+        true if (a_y + b_y) % p == BigUint::from(0u8) => return None,
+        // Compute the tangent slope `3x^2 / 2y`, if `a = b`.
+        true => (a_x * a_x * 3u8) * (a_y * 2u8).modpow(&(p - 2u8), p),
+        // Compute the chord slope `(b_y - a_y) / (b_x - a_x)`.
+        false => (b_y + p - a_y) * ((b_x + p - a_x) % p).modpow(&(p - 2u8), p),
+    } % p;
+
+    let x = (&lambda * &lambda + p * 2u8 - a_x - b_x) % p;
+    let y = (&lambda * ((a_x + p - &x) % p) + p - a_y) % p;
+    Some((x, y))
+}
+
+/// Returns the product of the given point and scalar, with double-and-add.
+fn mul(base: &Point, scalar: &BigUint, p: &BigUint) -> Point {
+    (0..scalar.bits()).rev().fold(None, |accumulator, i| {
+        let accumulator = add(&accumulator, &accumulator, p);
+        match scalar.bit(i) {
+            true => add(&accumulator, base, p),
+            false => accumulator,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha256::bytes_to_bits_le;
+
+    /// A public key, message hash, and signature, generated with OpenSSL.
+    const PUBLIC_KEY: &str = "09ec0d0da92178803471875cdb314e52c5973ec3620213a6a165db4e6aec88a3\
+                              31f992a9dfdf333e42933d75b6e4d8deb70e9e61c6fb50ad4413bc529f2e721e";
+    const MESSAGE_HASH: &str = "2b1082cbf3e1e426bcd7d5987a7f3c56a3893c0dc8e2606c6335049f4488c042";
+    const SIGNATURE: &str = "46dd7659f886d6c653779ef617bb604d6f9541f12b518334245a80e7446f9723\
+                             482a7169f4339410d6df40531b201fff28292a09f729a811c93eff769666ad69";
+
+    fn bits(hex: &str) -> Vec<bool> {
+        bytes_to_bits_le(&hex::decode(hex).unwrap())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let (public_key, message_hash, signature) = (bits(PUBLIC_KEY), bits(MESSAGE_HASH), bits(SIGNATURE));
+        assert!(EcdsaSecp256k1::verify(&public_key, &message_hash, &signature)?);
+
+        // Ensure the signature with the negated `s` is valid.
+        let n = constant(GROUP_ORDER);
+        let s = BigUint::from_bytes_be(&hex::decode(&SIGNATURE[64..])?);
+        let high_s = format!("{}{:064x}", &SIGNATURE[..64], &n - s);
+        assert!(EcdsaSecp256k1::verify(&public_key, &message_hash, &bits(&high_s))?);
+
+        // Ensure a different message hash is rejected.
+        let mut candidate = message_hash.clone();
+        candidate[0] = !candidate[0];
+        assert!(!EcdsaSecp256k1::verify(&public_key, &candidate, &signature)?);
+
+        // Ensure a different signature is rejected.
+        let mut candidate = signature.clone();
+        candidate[0] = !candidate[0];
+        assert!(!EcdsaSecp256k1::verify(&public_key, &message_hash, &candidate)?);
+
+        // Ensure a public key that is not on the curve is rejected.
+        let mut candidate = public_key.clone();
+        candidate[0] = !candidate[0];
+        assert!(!EcdsaSecp256k1::verify(&candidate, &message_hash, &signature)?);
+
+        // Ensure a zero or out-of-range signature is rejected.
+        let zero_s = format!("{}{}", &SIGNATURE[..64], "00".repeat(32));
+        assert!(!EcdsaSecp256k1::verify(&public_key, &message_hash, &bits(&zero_s))?);
+        let large_r = format!("{}{}", "ff".repeat(32), &SIGNATURE[64..]);
+        assert!(!EcdsaSecp256k1::verify(&public_key, &message_hash, &bits(&large_r))?);
+
+        // Ensure inputs of the wrong size are rejected.
+        assert!(EcdsaSecp256k1::verify(&public_key[8..], &message_hash, &signature).is_err());
+        assert!(EcdsaSecp256k1::verify(&public_key, &message_hash[8..], &signature).is_err());
+        assert!(EcdsaSecp256k1::verify(&public_key, &message_hash, &signature[8..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::bits_le_to_bytes;
+use snarkvm_console_types::prelude::*;
+
+use num_bigint::BigUint;
+use sha2::Digest;
+
+/// The edwards25519 group order, in big-endian hexadecimal.
+const GROUP_ORDER: &str = "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+
+/// An affine edwards25519 point.
+type Point = (BigUint, BigUint);
+
+/// Ed25519 signature verification, as specified in RFC 8032.
+///
+/// The public key is the 32-byte encoding of a point, the message is any number of bytes,
+/// and the signature is the 64-byte concatenation `R || S`. Each is given as bits, where each byte
+/// is in little-endian bit order, to match the circuit gadget.
+/// The verification is the cofactorless equation `[S]B = R + [k]A`, checked on the encoding of `R`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ed25519;
+
+impl Ed25519 {
+    /// The number of bits in a public key.
+    pub const PUBLIC_KEY_SIZE_IN_BITS: usize = 256;
+    /// The number of bits in a signature.
+    pub const SIGNATURE_SIZE_IN_BITS: usize = 512;
+
+    /// Returns `true` if the signature is valid for the given public key and message.
+    pub fn verify(public_key: &[bool], message: &[bool], signature: &[bool]) -> Result<bool> {
+        // Ensure the inputs are the expected size.
+        ensure!(public_key.len() == Self::PUBLIC_KEY_SIZE_IN_BITS, "Invalid Ed25519 public key size");
+        ensure!(signature.len() == Self::SIGNATURE_SIZE_IN_BITS, "Invalid Ed25519 signature size");
+
+        let curve = Curve::new();
+        let l = BigUint::parse_bytes(GROUP_ORDER.as_bytes(), 16).unwrap_or_default();
+
+        // Parse the inputs.
+        let (public_key, message, signature) =
+            (bits_le_to_bytes(public_key)?, bits_le_to_bytes(message)?, bits_le_to_bytes(signature)?);
+        let public_key_point = match curve.decompress(&public_key) {
+            Some(point) => point,
+            None => return Ok(false),
+        };
+        let s = BigUint::from_bytes_le(&signature[32..]);
+        // Ensure `S` is less than the group order.
+        if s >= l {
+            return Ok(false);
+        }
+
+        // Compute `k = SHA-512(R || A || M)`, modulo the group order.
+        let digest = sha2::Sha512::digest([&signature[..32], &public_key, &message].concat());
+        let k = BigUint::from_bytes_le(&digest) % &l;
+
+        // Ensure the encoding of `[S]B - [k]A` is `R`.
+        let negated_public_key = ((&curve.p - public_key_point.0) % &curve.p, public_key_point.1);
+        let result = curve.add(&curve.mul(&curve.generator(), &s), &curve.mul(&negated_public_key, &k));
+        Ok(curve.compress(&result) == signature[..32])
+    }
+}
+
+/// The parameters of edwards25519, the twisted Edwards curve `-x^2 + y^2 = 1 + d x^2 y^2`.
+struct Curve {
+    /// The base field modulus `2^255 - 19`.
+    p: BigUint,
+    /// The curve coefficient `d = -121665 / 121666`.
+    d: BigUint,
+}
+
+impl Curve {
+    /// Returns the curve parameters.
+    fn new() -> Self {
+        let p = (BigUint::from(1u8) << 255u16) - 19u8;
+        let d = (&p - 121665u32) * BigUint::from(121666u32).modpow(&(&p - 2u8), &p) % &p;
+        Self { p, d }
+    }
+
+    /// Returns the generator, which is the point with `y = 4 / 5` and an even `x`.
+    fn generator(&self) -> Point {
+        let y = BigUint::from(4u8) * BigUint::from(5u8).modpow(&(&self.p - 2u8), &self.p) % &self.p;
+        // Note: The generator is on the curve, so this is infallible.
+        (self.recover_x(&y, false).unwrap_or_default(), y)
+    }
+
+    /// Returns the x-coordinate with the given sign for the given y-coordinate, if it exists.
+    fn recover_x(&self, y: &BigUint, sign: bool) -> Option<BigUint> {
+        let p = &self.p;
+        // Compute `x^2 = (y^2 - 1) / (d y^2 + 1)`.
+        let y2 = y * y % p;
+        let u = (&y2 + p - 1u8) % p;
+        let v = (&self.d * &y2 + 1u8) % p;
+        let x = sqrt(&(u * v.modpow(&(p - 2u8), p) % p), p)?;
+        // Ensure the sign is zero, if `x` is zero.
+        match (x == BigUint::from(0u8), x.bit(0) == sign) {
+            (true, _) if sign => None,
+            (_, true) => Some(x),
+            (_, false) => Some(p - x),
+        }
+    }
+
+    /// Returns the point of the given encoding, which is the little-endian `y` with the sign of `x` in the top bit.
+    fn decompress(&self, bytes: &[u8]) -> Option<Point> {
+        let mut y = BigUint::from_bytes_le(bytes);
+        let sign = y.bit(255);
+        y.set_bit(255, false);
+        // Ensure the y-coordinate is reduced.
+        if y >= self.p {
+            return None;
+        }
+        Some((self.recover_x(&y, sign)?, y))
+    }
+
+    /// Returns the encoding of the given point.
+    fn compress(&self, point: &Point) -> Vec<u8> {
+        let mut encoding = point.1.clone();
+        encoding.set_bit(255, point.0.bit(0));
+        let mut bytes = encoding.to_bytes_le();
+        bytes.resize(32, 0);
+        bytes
+    }
+
+    /// Returns the sum of the given points, with the complete affine addition law.
+    fn add(&self, a: &Point, b: &Point) -> Point {
+        let p = &self.p;
+        let ((x1, y1), (x2, y2)) = (a, b);
+        let dxy = &self.d * x1 * x2 % p * y1 * y2 % p;
+        let x = (x1 * y2 + y1 * x2) * (&dxy + 1u8).modpow(&(p - 2u8), p) % p;
+        let y = (y1 * y2 + x1 * x2) * ((p + 1u8 - dxy) % p).modpow(&(p - 2u8), p) % p;
+        (x, y)
+    }
+
+    /// Returns the product of the given point and scalar, with double-and-add.
+    fn mul(&self, base: &Point, scalar: &BigUint) -> Point {
+        (0..scalar.bits()).rev().fold((BigUint::from(0u8), BigUint::from(1u8)), |accumulator, i| {
+            let accumulator = self.add(&accumulator, &accumulator);
+            match scalar.bit(i) {
+                true => self.add(&accumulator, base),
+                false => accumulator,
+            }
+        })
+    }
+}
+
+/// Returns a square root of `a` modulo `p`, where `p = 5 mod 8`, if it exists.
+fn sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    // Compute the candidate `a^((p + 3) / 8)`, which is a root of `a` or `-a`.
+    let candidate = a.modpow(&((p + 3u8) >> 3u8), p);
+    // Compute `sqrt(-1) = 2^((p - 1) / 4)`, to map a root of `-a` to a root of `a`.
+    let sqrt_minus_one = BigUint::from(2u8).modpow(&((p - 1u8) >> 2u8), p);
+    [candidate.clone(), candidate * sqrt_minus_one % p].into_iter().find(|root| root * root % p == a % p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha256::bytes_to_bits_le;
+
+    /// The public key, message, and signature of test 2 of RFC 8032.
+    const PUBLIC_KEY: &str = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+    const MESSAGE: &str = "72";
+    const SIGNATURE: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                             085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+    fn bits(hex: &str) -> Vec<bool> {
+        bytes_to_bits_le(&hex::decode(hex).unwrap())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let (public_key, message, signature) = (bits(PUBLIC_KEY), bits(MESSAGE), bits(SIGNATURE));
+        assert!(Ed25519::verify(&public_key, &message, &signature)?);
+
+        // Ensure a different message is rejected.
+        assert!(!Ed25519::verify(&public_key, &[], &signature)?);
+        assert!(!Ed25519::verify(&public_key, &bits("73"), &signature)?);
+
+        // Ensure a different signature is rejected.
+        let mut candidate = signature.clone();
+        candidate[300] = !candidate[300];
+        assert!(!Ed25519::verify(&public_key, &message, &candidate)?);
+
+        // Ensure a different public key is rejected.
+        let mut candidate = public_key.clone();
+        candidate[255] = !candidate[255];
+        assert!(!Ed25519::verify(&candidate, &message, &signature)?);
+
+        // Ensure an `S` that is not less than the group order is rejected.
+        let large_s = format!("{}{}", &SIGNATURE[..64], "ff".repeat(32));
+        assert!(!Ed25519::verify(&public_key, &message, &bits(&large_s))?);
+
+        // Ensure inputs of the wrong size are rejected.
+        assert!(Ed25519::verify(&public_key[8..], &message, &signature).is_err());
+        assert!(Ed25519::verify(&public_key, &message[1..], &signature).is_err());
+        assert!(Ed25519::verify(&public_key, &message, &signature[8..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::{bits_le_to_bytes, bytes_to_bits_le};
+use snarkvm_console_types::prelude::*;
+
+use sha3::Digest;
+
+/// Keccak-256, as used in Ethereum, which differs from SHA3-256 in its padding.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `u8::to_bits_le` of each byte.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keccak256;
+
+impl Hash for Keccak256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the 256-bit Keccak-256 digest of the given input, which must be a whole number of bytes.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bytes_to_bits_le(&sha3::Keccak256::digest(bits_le_to_bytes(input)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keccak256() -> Result<()> {
+        // Check the digests used in Ethereum.
+        for (input, expected) in [
+            ("", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+            ("abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        ] {
+            let digest = Keccak256.hash(&bytes_to_bits_le(input.as_bytes()))?;
+            assert_eq!(expected, hex::encode(bits_le_to_bytes(&digest)?));
+        }
+
+        // Ensure a partial byte is rejected.
+        assert!(Keccak256.hash(&[true; 7]).is_err());
+        Ok(())
+    }
+}
@@ -23,14 +23,32 @@ pub use snarkvm_console_types::prelude::*;
 pub mod bhp;
 pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 
+mod blake2s;
+pub use blake2s::Blake2s;
+
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::EcdsaSecp256k1;
+
+mod ed25519;
+pub use ed25519::Ed25519;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod keccak256;
+pub use keccak256::Keccak256;
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
-pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+pub use poseidon::{AlgebraicSponge, DuplexSpongeMode, Poseidon, Poseidon2, Poseidon4, Poseidon8, PoseidonSponge};
+
+mod sha256;
+pub use sha256::Sha256;
+
+mod sha512;
+pub use sha512::Sha512;
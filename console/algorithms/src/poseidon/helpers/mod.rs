@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod sponge;
-pub(super) use sponge::*;
+pub use sponge::*;
 
 mod state;
 pub(super) use state::*;
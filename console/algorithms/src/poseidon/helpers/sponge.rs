@@ -19,7 +19,7 @@ use crate::poseidon::{
     State,
 };
 use snarkvm_console_types::{prelude::*, Field};
-use snarkvm_fields::PoseidonParameters;
+use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
 
 use smallvec::SmallVec;
 use std::sync::Arc;
@@ -100,6 +100,28 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<E
     }
 }
 
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
+    /// Initializes a new sponge, with the default Poseidon parameters for the given rate and capacity.
+    pub fn setup() -> Result<Self> {
+        Ok(Self::new(&Arc::new(E::Field::default_poseidon_parameters_with_capacity::<RATE, CAPACITY>()?)))
+    }
+
+    /// Returns the Poseidon parameters of the sponge.
+    pub fn parameters(&self) -> &Arc<PoseidonParameters<E::Field, RATE, CAPACITY>> {
+        &self.parameters
+    }
+
+    /// Returns the state of the sponge, where the capacity elements precede the rate elements.
+    pub fn state(&self) -> Vec<Field<E>> {
+        self.state.iter().copied().collect()
+    }
+
+    /// Returns the mode of the sponge.
+    pub fn mode(&self) -> &DuplexSpongeMode {
+        &self.mode
+    }
+}
+
 impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
     #[inline]
     fn apply_ark(&mut self, round_number: usize) {
@@ -22,6 +22,8 @@ mod hash_to_group;
 mod hash_to_scalar;
 mod prf;
 
+pub use helpers::{AlgebraicSponge, DuplexSpongeMode, PoseidonSponge};
+
 use crate::{poseidon::helpers::*, Elligator2};
 use snarkvm_console_types::prelude::*;
 use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
@@ -59,6 +61,11 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
         })
     }
 
+    /// Returns an instance of Poseidon with the same parameters, under the given domain separator.
+    pub fn with_domain(&self, domain: Field<E>) -> Self {
+        Self { domain, parameters: self.parameters.clone() }
+    }
+
     /// Returns the domain separator for the hash function.
     pub fn domain(&self) -> Field<E> {
         self.domain
@@ -172,4 +179,19 @@ mod tests {
         single_rate_test::<7>();
         single_rate_test::<8>();
     }
+
+    #[test]
+    fn test_parameters_with_capacity() -> Result<()> {
+        // Ensure the parameters for a larger capacity are the parameters of the same state width.
+        let expected = Fq::default_poseidon_parameters::<4>()?;
+        let candidate = Fq::default_poseidon_parameters_with_capacity::<2, 3>()?;
+        assert_eq!(expected.ark, candidate.ark);
+        assert_eq!(expected.mds, candidate.mds);
+        assert_eq!((expected.full_rounds, expected.partial_rounds), (candidate.full_rounds, candidate.partial_rounds));
+
+        // Ensure a capacity of 0, or a state width without parameters, is rejected.
+        assert!(Fq::default_poseidon_parameters_with_capacity::<4, 0>().is_err());
+        assert!(Fq::default_poseidon_parameters_with_capacity::<8, 2>().is_err());
+        Ok(())
+    }
 }
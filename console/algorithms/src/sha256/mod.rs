@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_console_types::prelude::*;
+
+use sha2::Digest;
+
+/// SHA-256, as specified in FIPS 180-4.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `u8::to_bits_le` of each byte.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha256;
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the 256-bit SHA-256 digest of the given input, which must be a whole number of bytes.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bytes_to_bits_le(&sha2::Sha256::digest(bits_le_to_bytes(input)?)))
+    }
+}
+
+/// Returns the bytes of the given bits, where each byte is in little-endian bit order.
+pub(crate) fn bits_le_to_bytes(bits_le: &[bool]) -> Result<Vec<u8>> {
+    ensure!(bits_le.len() % 8 == 0, "Expected a whole number of bytes, found {} bits", bits_le.len());
+    Ok(bits_le.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8)).collect())
+}
+
+/// Returns the bits of the given bytes, where each byte is in little-endian bit order.
+pub(crate) fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+    bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() -> Result<()> {
+        // Check the test vectors from FIPS 180-4.
+        for (input, expected) in [
+            ("", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            ("abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (
+                "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+        ] {
+            let digest = Sha256.hash(&bytes_to_bits_le(input.as_bytes()))?;
+            assert_eq!(expected, hex::encode(bits_le_to_bytes(&digest)?));
+        }
+
+        // Ensure a partial byte is rejected.
+        assert!(Sha256.hash(&[true; 7]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::sha256::{bits_le_to_bytes, bytes_to_bits_le};
+use snarkvm_console_types::prelude::*;
+
+use sha2::Digest;
+
+/// SHA-512, as specified in FIPS 180-4.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// such that the bits of a byte string are the concatenation of `u8::to_bits_le` of each byte.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha512;
+
+impl Hash for Sha512 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the 512-bit SHA-512 digest of the given input, which must be a whole number of bytes.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bytes_to_bits_le(&sha2::Sha512::digest(bits_le_to_bytes(input)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha512() -> Result<()> {
+        // Check the test vectors from FIPS 180-4.
+        for (input, expected) in [
+            (
+                "",
+                "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+                 47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+            ),
+            (
+                "abc",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                 2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+        ] {
+            let digest = Sha512.hash(&bytes_to_bits_le(input.as_bytes()))?;
+            assert_eq!(expected, hex::encode(bits_le_to_bytes(&digest)?));
+        }
+
+        // Ensure a partial byte is rejected.
+        assert!(Sha512.hash(&[true; 7]).is_err());
+        Ok(())
+    }
+}
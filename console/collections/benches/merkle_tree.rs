@@ -49,6 +49,18 @@ fn new(c: &mut Criterion) {
     }
 }
 
+fn new_psd(c: &mut Criterion) {
+    for num_leaves in NUM_LEAVES {
+        let leaves = (0..*num_leaves).map(|_| vec![Field::<Testnet3>::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+        c.bench_function(&format!("MerkleTree::new with Poseidon ({} leaves)", num_leaves), move |b| {
+            b.iter(|| {
+                let _tree = Testnet3::merkle_tree_psd::<DEPTH>(&leaves).unwrap();
+            })
+        });
+    }
+}
+
 fn append(c: &mut Criterion) {
     for num_leaves in NUM_LEAVES {
         let leaves = generate_leaves!(*num_leaves);
@@ -93,7 +105,7 @@ fn append(c: &mut Criterion) {
 criterion_group! {
     name = merkle_tree;
     config = Criterion::default().sample_size(10);
-    targets = new, append
+    targets = new, new_psd, append
 }
 
 criterion_main!(merkle_tree);
@@ -21,3 +21,5 @@
 pub use snarkvm_console_types::prelude::*;
 
 pub mod merkle_tree;
+
+pub mod sparse_merkle_tree;
@@ -0,0 +1,338 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The right frontier of an append-only Merkle tree, which is the list of roots of its perfect subtrees,
+/// from the leftmost (largest) to the rightmost (smallest).
+///
+/// The frontier has one subtree root for each bit set in the number of leaves,
+/// which suffices to append leaves and compute the Merkle root.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleFrontier<E: Environment, const DEPTH: u8> {
+    /// The number of leaves in the tree.
+    number_of_leaves: u64,
+    /// The roots of the perfect subtrees, from the leftmost to the rightmost.
+    subtrees: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(u64, Vec<Field<E>>)> for MerkleFrontier<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle frontier.
+    fn try_from((number_of_leaves, subtrees): (u64, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the number of leaves is within the tree depth.
+        ensure!((number_of_leaves as u128) <= (1u128 << DEPTH), "Found an out of bounds number of Merkle leaves");
+        // Ensure there is one subtree for each bit set in the number of leaves.
+        ensure!(
+            subtrees.len() == number_of_leaves.count_ones() as usize,
+            "Found an incorrect number of subtrees in the Merkle frontier"
+        );
+        // Return the Merkle frontier.
+        Ok(Self { number_of_leaves, subtrees })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleFrontier<E, DEPTH> {
+    /// Returns the number of leaves in the tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+
+    /// Returns the roots of the perfect subtrees, from the leftmost to the rightmost.
+    pub fn subtrees(&self) -> &[Field<E>] {
+        &self.subtrees
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleFrontier<E, DEPTH> {
+    /// Reads in a Merkle frontier from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of leaves.
+        let number_of_leaves = u64::read_le(&mut reader)?;
+        // Read the subtree roots, one for each bit set in the number of leaves.
+        let subtrees = (0..number_of_leaves.count_ones())
+            .map(|_| Field::read_le(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle frontier.
+        Self::try_from((number_of_leaves, subtrees)).map_err(|err| error(err.to_string()))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleFrontier<E, DEPTH> {
+    /// Writes the Merkle frontier to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of leaves.
+        self.number_of_leaves.write_le(&mut writer)?;
+        // Write the subtree roots.
+        self.subtrees.iter().try_for_each(|subtree| subtree.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Serialize for MerkleFrontier<E, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerkleFrontier<E, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle frontier")
+    }
+}
+
+/// An append-only Merkle tree, which stores only its right frontier.
+///
+/// Appending a leaf and computing the root take `O(DEPTH)` hashes, and the tree has the same root
+/// as a `MerkleTree` with the same leaves. However, as the inner nodes are not stored,
+/// the tree cannot produce Merkle paths; use a `MerkleTree` when paths are needed.
+#[derive(Clone)]
+pub struct IncrementalMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The right frontier of the Merkle tree.
+    frontier: MerkleFrontier<E, DEPTH>,
+    /// The hashes of the empty perfect subtrees, where the `i`-th hash is of the subtree of height `i`.
+    empty_hashes: Vec<Field<E>>,
+    /// The computed root of the Merkle tree.
+    root: Field<E>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    IncrementalMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Initializes a new, empty incremental Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        Self::from_frontier(leaf_hasher, path_hasher, MerkleFrontier::try_from((0, vec![]))?)
+    }
+
+    /// Initializes an incremental Merkle tree from the given frontier.
+    pub fn from_frontier(leaf_hasher: &LH, path_hasher: &PH, frontier: MerkleFrontier<E, DEPTH>) -> Result<Self> {
+        // Compute the hashes of the empty subtrees, up to the tree depth.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for i in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[i], &empty_hashes[i])?);
+        }
+        // Compute the root of the tree.
+        let root = Self::compute_root(path_hasher, &empty_hashes, &frontier)?;
+
+        Ok(Self { leaf_hasher: leaf_hasher.clone(), path_hasher: path_hasher.clone(), frontier, empty_hashes, root })
+    }
+
+    /// Appends the given leaves to the Merkle tree.
+    pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        // Ensure the new leaves fit in the tree.
+        let max_leaves = (1u128 << DEPTH).min(u64::MAX as u128);
+        ensure!(
+            self.frontier.number_of_leaves as u128 + new_leaves.len() as u128 <= max_leaves,
+            "Merkle tree cannot exceed depth {DEPTH}"
+        );
+
+        // Append each leaf hash to a copy of the frontier, so the tree is not altered in case of failure.
+        let mut frontier = self.frontier.clone();
+        for leaf_hash in self.leaf_hasher.hash_leaves(new_leaves)? {
+            // Merge the perfect subtrees that the leaf completes, from the rightmost subtree.
+            let mut node = leaf_hash;
+            let mut height = 0;
+            while (frontier.number_of_leaves >> height) & 1 == 1 {
+                let left = match frontier.subtrees.pop() {
+                    Some(left) => left,
+                    None => bail!("The Merkle frontier is missing a subtree"),
+                };
+                node = self.path_hasher.hash_children(&left, &node)?;
+                height += 1;
+            }
+            frontier.subtrees.push(node);
+            frontier.number_of_leaves += 1;
+        }
+        // Compute the new root.
+        let root = Self::compute_root(&self.path_hasher, &self.empty_hashes, &frontier)?;
+
+        self.frontier = frontier;
+        self.root = root;
+        Ok(())
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &Field<E> {
+        &self.root
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.frontier.number_of_leaves
+    }
+
+    /// Returns the right frontier of the tree, which suffices to restore it with `from_frontier`.
+    pub const fn frontier(&self) -> &MerkleFrontier<E, DEPTH> {
+        &self.frontier
+    }
+
+    /// Returns the Merkle root for the given frontier.
+    ///
+    /// As in `MerkleTree`, the leaves are padded with empty hashes up to the next power of two,
+    /// and the root of the padded tree is then hashed with the empty hash up to `DEPTH`.
+    fn compute_root(
+        path_hasher: &PH,
+        empty_hashes: &[Field<E>],
+        frontier: &MerkleFrontier<E, DEPTH>,
+    ) -> Result<Field<E>> {
+        let number_of_leaves = frontier.number_of_leaves;
+        // Compute the height of the padded tree.
+        let tree_depth = match number_of_leaves {
+            0 | 1 => 0,
+            _ => 64 - (number_of_leaves - 1).leading_zeros() as u8,
+        };
+
+        // Fold the perfect subtrees, from the rightmost, into the root of the padded tree.
+        let mut subtrees = frontier.subtrees.iter().rev();
+        let mut node = None;
+        for height in 0..tree_depth {
+            let empty_hash = &empty_hashes[height as usize];
+            node = match ((number_of_leaves >> height) & 1 == 1, node) {
+                // If there is a perfect subtree at this height, it is the left child.
+                (true, node) => match subtrees.next() {
+                    Some(left) => Some(path_hasher.hash_children(left, &node.unwrap_or(*empty_hash))?),
+                    None => bail!("The Merkle frontier is missing a subtree"),
+                },
+                // Otherwise, the right child is empty.
+                (false, Some(node)) => Some(path_hasher.hash_children(&node, empty_hash)?),
+                (false, None) => None,
+            };
+        }
+        let mut root = match (node, subtrees.next()) {
+            (Some(node), _) => node,
+            // If the tree is perfect, its root is the only subtree.
+            (None, Some(subtree)) => *subtree,
+            (None, None) => empty_hashes[0],
+        };
+
+        // Hash the root with the empty hash, from the height of the padded tree up to `DEPTH`.
+        for _ in tree_depth..DEPTH {
+            root = path_hasher.hash_children(&root, &empty_hashes[0])?;
+        }
+        Ok(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Checks that the incremental Merkle tree has the same root as the full Merkle tree,
+    /// as the given leaves are appended in batches, and that it can be restored from its frontier.
+    fn check_incremental_merkle_tree<
+        LH: LeafHash<Hash = PH::Hash>,
+        PH: PathHash<Hash = Field<CurrentEnvironment>>,
+        const DEPTH: u8,
+    >(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+    ) -> Result<()> {
+        let mut tree = IncrementalMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+        let expected = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+        assert_eq!(expected.root(), tree.root());
+
+        let (mut num_leaves, mut batch_size) = (0, 1);
+        while num_leaves < leaves.len() {
+            // Append the next batch of leaves, with increasing batch sizes.
+            let end = leaves.len().min(num_leaves + batch_size);
+            batch_size += 1;
+            tree.append(&leaves[num_leaves..end])?;
+            num_leaves = end;
+
+            // Ensure the root matches the full Merkle tree.
+            let expected =
+                MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..end])?;
+            assert_eq!(expected.root(), tree.root(), "Root mismatch with {num_leaves} leaves");
+            assert_eq!(num_leaves as u64, tree.number_of_leaves());
+
+            // Ensure the tree is restored from its serialized frontier.
+            let frontier = MerkleFrontier::<CurrentEnvironment, DEPTH>::from_bytes_le(&tree.frontier().to_bytes_le()?)?;
+            let restored = IncrementalMerkleTree::from_frontier(leaf_hasher, path_hasher, frontier)?;
+            assert_eq!(tree.root(), restored.root());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_merkle_tree_bhp() -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let leaves =
+            (0..40).map(|_| Field::<CurrentEnvironment>::rand(&mut test_rng()).to_bits_le()).collect::<Vec<_>>();
+        check_incremental_merkle_tree::<LH, PH, 10>(&leaf_hasher, &path_hasher, &leaves)
+    }
+
+    #[test]
+    fn test_incremental_merkle_tree_poseidon() -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let leaves = (0..40).map(|_| vec![Uniform::rand(&mut test_rng())]).collect::<Vec<_>>();
+        check_incremental_merkle_tree::<LH, PH, 10>(&leaf_hasher, &path_hasher, &leaves)
+    }
+
+    #[test]
+    fn test_incremental_merkle_tree_full() -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Ensure a full tree has the same root as the full Merkle tree, and rejects another leaf.
+        let leaves = (0..8).map(|_| vec![Uniform::rand(&mut test_rng())]).collect::<Vec<_>>();
+        check_incremental_merkle_tree::<LH, PH, 3>(&leaf_hasher, &path_hasher, &leaves)?;
+
+        let mut tree = IncrementalMerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher)?;
+        tree.append(&leaves)?;
+        let root = *tree.root();
+        assert!(tree.append(&leaves[..1]).is_err());
+        assert_eq!(root, *tree.root());
+
+        // Ensure a malformed frontier is rejected.
+        assert!(MerkleFrontier::<CurrentEnvironment, 3>::try_from((3, vec![root])).is_err());
+        assert!(MerkleFrontier::<CurrentEnvironment, 3>::try_from((16, vec![root])).is_err());
+        Ok(())
+    }
+}
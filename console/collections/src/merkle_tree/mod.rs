@@ -17,6 +17,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod incremental;
+pub use incremental::*;
+
 mod path;
 pub use path::*;
 
@@ -84,10 +87,20 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         while let Some(start) = parent(start_index) {
             // Compute the end index of the current level.
             let end = left_child(start);
-            // Construct the children for each node in the current level.
-            let tuples = (start..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
-            // Compute and store the hashes for each node in the current level.
-            tree[start..end].copy_from_slice(&path_hasher.hash_all_children(&tuples)?);
+            // Split the tree into the current level and the level below it, which holds the children.
+            let (level, children) = tree[start..].split_at_mut(end - start);
+            // Compute and store the hashes for each node in the current level, in place.
+            // If the level has at least 100 nodes, hash the nodes concurrently.
+            match level.len() >= 100 {
+                true => cfg_iter_mut!(level).enumerate().try_for_each(|(i, node)| {
+                    *node = path_hasher.hash_children(&children[2 * i], &children[2 * i + 1])?;
+                    Ok::<_, Error>(())
+                })?,
+                false => level.iter_mut().enumerate().try_for_each(|(i, node)| {
+                    *node = path_hasher.hash_children(&children[2 * i], &children[2 * i + 1])?;
+                    Ok::<_, Error>(())
+                })?,
+            }
             // Update the start index for the next level.
             start_index = start;
         }
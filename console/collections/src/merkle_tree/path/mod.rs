@@ -72,6 +72,61 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
             return false;
         }
 
+        // Check every level of the Merkle path.
+        self.verify_levels(leaf_hasher, path_hasher, root, leaf, DEPTH)
+    }
+
+    /// Returns this Merkle path, padded with zero siblings to a depth of `MAX_DEPTH`,
+    /// so it can be verified with `verify_with_depth` at a depth of `DEPTH`.
+    pub fn pad<const MAX_DEPTH: u8>(&self) -> Result<MerklePath<E, MAX_DEPTH>> {
+        // Ensure the Merkle path fits in the maximum depth.
+        ensure!(DEPTH <= MAX_DEPTH, "Cannot pad a Merkle path of depth {DEPTH} to a depth of {MAX_DEPTH}");
+        // Pad the siblings with zeros.
+        let mut siblings = self.siblings.clone();
+        siblings.resize(MAX_DEPTH as usize, Field::zero());
+        // Return the padded Merkle path.
+        MerklePath::try_from((self.leaf_index, siblings))
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, in a tree of the given depth.
+    /// The siblings after the first `depth` siblings are padding, and are ignored.
+    pub fn verify_with_depth<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        depth: u8,
+    ) -> bool {
+        // Ensure the depth is within the padded depth.
+        if depth == 0 || depth > DEPTH {
+            eprintln!("Found an invalid Merkle tree depth for the Merkle path");
+            return false;
+        }
+        // Ensure the leaf index is within the tree depth.
+        else if (*self.leaf_index as u128) >= (1u128 << depth) {
+            eprintln!("Found an out of bounds Merkle leaf index");
+            return false;
+        }
+        // Ensure the path length matches the padded depth.
+        else if self.siblings.len() != DEPTH as usize {
+            eprintln!("Found an incorrect Merkle path length");
+            return false;
+        }
+
+        // Check the first `depth` levels of the Merkle path.
+        self.verify_levels(leaf_hasher, path_hasher, root, leaf, depth)
+    }
+
+    /// Returns `true` if the first `depth` levels of the Merkle path hash the given leaf to the given root.
+    fn verify_levels<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        depth: u8,
+    ) -> bool {
         // Initialize a tracker for the current hash, by computing the leaf hash to start.
         let mut current_hash = match leaf_hasher.hash_leaf(leaf) {
             Ok(candidate_leaf_hash) => candidate_leaf_hash,
@@ -84,10 +139,10 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         // Compute the ordering of the current hash and sibling hash on each level.
         // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
         // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
-        let indicators = (0..DEPTH).map(|i| ((*self.leaf_index >> i) & 1) == 0);
+        let indicators = (0..depth).map(|i| ((*self.leaf_index >> i) & 1) == 0);
 
         // Check levels between leaf level and root.
-        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
+        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings[..depth as usize]) {
             // Construct the ordering of the left & right child hash for this level.
             let (left, right) = match indicator {
                 true => (current_hash, *sibling_hash),
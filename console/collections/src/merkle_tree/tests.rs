@@ -581,6 +581,40 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
 }
 
 /// Use `cargo test profiler --features timer` to run this test.
+#[test]
+fn test_merkle_path_verify_with_depth() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a depth-3 Merkle tree.
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut test_rng())]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        // Pad the Merkle path to a depth of 8.
+        let path = merkle_tree.prove(leaf_index, leaf)?.pad::<8>()?;
+        assert_eq!(8, path.siblings().len());
+
+        // Ensure the padded Merkle path is valid only at the depth of the tree.
+        let root = merkle_tree.root();
+        assert!(path.verify_with_depth(&leaf_hasher, &path_hasher, root, leaf, 3));
+        assert!(!path.verify_with_depth(&leaf_hasher, &path_hasher, root, leaf, 2));
+        assert!(!path.verify_with_depth(&leaf_hasher, &path_hasher, root, leaf, 4));
+        assert!(!path.verify_with_depth(&leaf_hasher, &path_hasher, root, leaf, 0));
+        assert!(!path.verify_with_depth(&leaf_hasher, &path_hasher, root, leaf, 9));
+        assert!(!path.verify_with_depth(&leaf_hasher, &path_hasher, &Field::one(), leaf, 3));
+        // Ensure the padded Merkle path is not valid at the padded depth.
+        assert!(!path.verify(&leaf_hasher, &path_hasher, root, leaf));
+    }
+
+    // Ensure a Merkle path cannot be padded to a smaller depth.
+    assert!(merkle_tree.prove(0, &leaves[0])?.pad::<2>().is_err());
+    Ok(())
+}
+
 #[ignore]
 #[test]
 fn test_profiler() -> Result<()> {
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod path;
+pub use path::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use std::collections::HashMap;
+
+/// The depth of a sparse Merkle tree, which has a 256-bit keyspace.
+pub const SPARSE_MERKLE_TREE_DEPTH: usize = 256;
+
+/// The identifier of a node, as its height and the bits of the key above its height.
+type NodeID = (u16, [u8; 32]);
+
+/// A sparse Merkle tree over a 256-bit keyspace, where the path to each leaf is the bits of its key.
+///
+/// Only the nodes that differ from the hash of an empty subtree are stored, so the tree size is
+/// proportional to the number of keys times the depth. A Merkle path proves either that a leaf is
+/// stored at a key (membership), or that no leaf is stored at a key (non-membership).
+#[derive(Clone)]
+pub struct SparseMerkleTree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The non-empty nodes of the Merkle tree, including the hashed leaves.
+    nodes: HashMap<NodeID, Field<E>>,
+    /// The hashes of the empty subtrees, where the `i`-th hash is of the subtree of height `i`.
+    empty_hashes: Vec<Field<E>>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>> SparseMerkleTree<E, LH, PH> {
+    /// Initializes a new, empty sparse Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            nodes: Default::default(),
+            empty_hashes: empty_hashes(path_hasher)?,
+        })
+    }
+
+    /// Inserts the given leaf at the given key, replacing the existing leaf, if any.
+    pub fn insert(&mut self, key: &Field<E>, leaf: &LH::Leaf) -> Result<()> {
+        let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
+        self.update(key, leaf_hash)
+    }
+
+    /// Removes the leaf at the given key, if any.
+    pub fn remove(&mut self, key: &Field<E>) -> Result<()> {
+        self.update(key, self.empty_hashes[0])
+    }
+
+    /// Returns `true` if a leaf is stored at the given key.
+    pub fn contains_key(&self, key: &Field<E>) -> Result<bool> {
+        Ok(self.nodes.contains_key(&node_id(&key_to_bytes(key)?, 0)))
+    }
+
+    /// Returns the Merkle path for the given key, which proves the membership of its leaf if one is stored,
+    /// and its non-membership otherwise.
+    pub fn prove(&self, key: &Field<E>) -> Result<SparseMerklePath<E>> {
+        let key = key_to_bytes(key)?;
+        // Collect the sibling hashes from the leaf to the root, omitting the empty hashes.
+        let mut siblings = Vec::new();
+        let mut is_empty = [false; SPARSE_MERKLE_TREE_DEPTH];
+        for (height, is_empty) in is_empty.iter_mut().enumerate() {
+            match self.nodes.get(&sibling_id(&key, height)) {
+                Some(sibling) => siblings.push(*sibling),
+                None => *is_empty = true,
+            }
+        }
+        SparseMerklePath::try_from((is_empty, siblings))
+    }
+
+    /// Returns `true` if the given Merkle path proves the given leaf is stored at the given key.
+    pub fn verify_membership(
+        &self,
+        path: &SparseMerklePath<E>,
+        root: &Field<E>,
+        key: &Field<E>,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        path.verify_membership(&self.leaf_hasher, &self.path_hasher, root, key, leaf)
+    }
+
+    /// Returns `true` if the given Merkle path proves no leaf is stored at the given key.
+    pub fn verify_non_membership(&self, path: &SparseMerklePath<E>, root: &Field<E>, key: &Field<E>) -> bool {
+        path.verify_non_membership(&self.path_hasher, root, key)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub fn root(&self) -> Field<E> {
+        match self.nodes.get(&node_id(&[0u8; 32], SPARSE_MERKLE_TREE_DEPTH)) {
+            Some(root) => *root,
+            None => self.empty_hashes[SPARSE_MERKLE_TREE_DEPTH],
+        }
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub fn number_of_leaves(&self) -> usize {
+        self.nodes.keys().filter(|(height, _)| *height == 0).count()
+    }
+
+    /// Sets the leaf hash at the given key, and updates the nodes from the leaf to the root.
+    fn update(&mut self, key: &Field<E>, leaf_hash: Field<E>) -> Result<()> {
+        let key = key_to_bytes(key)?;
+
+        // Compute the updated nodes, so the tree is not altered in case of failure.
+        let mut updates = Vec::with_capacity(SPARSE_MERKLE_TREE_DEPTH + 1);
+        let mut node = leaf_hash;
+        updates.push((node_id(&key, 0), node));
+        for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+            let sibling = match self.nodes.get(&sibling_id(&key, height)) {
+                Some(sibling) => *sibling,
+                None => self.empty_hashes[height],
+            };
+            node = match bit(&key, height) {
+                false => self.path_hasher.hash_children(&node, &sibling)?,
+                true => self.path_hasher.hash_children(&sibling, &node)?,
+            };
+            updates.push((node_id(&key, height + 1), node));
+        }
+
+        // Store the updated nodes, and remove the nodes that are now empty.
+        for (height, (id, node)) in updates.into_iter().enumerate() {
+            match node == self.empty_hashes[height] {
+                true => self.nodes.remove(&id),
+                false => self.nodes.insert(id, node),
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Returns the hashes of the empty subtrees, where the `i`-th hash is of the subtree of height `i`.
+fn empty_hashes<E: Environment, PH: PathHash<Hash = Field<E>>>(path_hasher: &PH) -> Result<Vec<Field<E>>> {
+    let mut empty_hashes = Vec::with_capacity(SPARSE_MERKLE_TREE_DEPTH + 1);
+    empty_hashes.push(path_hasher.hash_empty()?);
+    for i in 0..SPARSE_MERKLE_TREE_DEPTH {
+        empty_hashes.push(path_hasher.hash_children(&empty_hashes[i], &empty_hashes[i])?);
+    }
+    Ok(empty_hashes)
+}
+
+/// Returns the given key as 32 little-endian bytes.
+fn key_to_bytes<E: Environment>(key: &Field<E>) -> Result<[u8; 32]> {
+    let bytes = key.to_bytes_le()?;
+    ensure!(bytes.len() <= 32, "The sparse Merkle tree key exceeds 256 bits");
+    let mut key = [0u8; 32];
+    key[..bytes.len()].copy_from_slice(&bytes);
+    Ok(key)
+}
+
+/// Returns the bit of the key at the given height, where `false` indicates a left child.
+const fn bit(key: &[u8; 32], height: usize) -> bool {
+    (key[height / 8] >> (height % 8)) & 1 == 1
+}
+
+/// Returns the identifier of the node at the given height on the path of the given key.
+fn node_id(key: &[u8; 32], height: usize) -> NodeID {
+    // Clear the bits of the key below the height.
+    let mut prefix = *key;
+    for (i, byte) in prefix.iter_mut().enumerate() {
+        match (i + 1) * 8 <= height {
+            true => *byte = 0,
+            false if i * 8 < height => *byte &= 0xff << (height % 8),
+            false => break,
+        }
+    }
+    (height as u16, prefix)
+}
+
+/// Returns the identifier of the sibling of the node at the given height on the path of the given key.
+fn sibling_id(key: &[u8; 32], height: usize) -> NodeID {
+    let (height, mut prefix) = node_id(key, height);
+    prefix[height as usize / 8] ^= 1 << (height % 8);
+    (height, prefix)
+}
@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A Merkle path in a sparse Merkle tree, which omits the sibling hashes of empty subtrees.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseMerklePath<E: Environment> {
+    /// For each level from the leaf to the root, `true` if the sibling is the root of an empty subtree.
+    is_empty: [bool; SPARSE_MERKLE_TREE_DEPTH],
+    /// The non-empty sibling hashes, from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment> TryFrom<([bool; SPARSE_MERKLE_TREE_DEPTH], Vec<Field<E>>)> for SparseMerklePath<E> {
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle path.
+    fn try_from((is_empty, siblings): ([bool; SPARSE_MERKLE_TREE_DEPTH], Vec<Field<E>>)) -> Result<Self> {
+        // Ensure there is one sibling hash for each non-empty sibling.
+        ensure!(
+            siblings.len() == is_empty.iter().filter(|is_empty| !**is_empty).count(),
+            "Found an incorrect number of siblings in the sparse Merkle path"
+        );
+        // Return the sparse Merkle path.
+        Ok(Self { is_empty, siblings })
+    }
+}
+
+impl<E: Environment> SparseMerklePath<E> {
+    /// Returns the non-empty sibling hashes, from the leaf to the root.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle path proves the given leaf is stored at the given key in the given root.
+    pub fn verify_membership<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        // Compute the leaf hash.
+        let leaf_hash = match leaf_hasher.hash_leaf(leaf) {
+            Ok(leaf_hash) => leaf_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the sparse Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+        // Ensure the computed root matches the given root.
+        match self.compute_root(path_hasher, key, Some(leaf_hash)) {
+            Ok(candidate_root) => candidate_root == *root,
+            Err(error) => {
+                eprintln!("Failed to verify the sparse Merkle path: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the Merkle path proves no leaf is stored at the given key in the given root.
+    pub fn verify_non_membership<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+    ) -> bool {
+        // Ensure the computed root, from an empty leaf, matches the given root.
+        match self.compute_root(path_hasher, key, None) {
+            Ok(candidate_root) => candidate_root == *root,
+            Err(error) => {
+                eprintln!("Failed to verify the sparse Merkle path: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns the Merkle root for the given key and leaf hash, where `None` is an empty leaf.
+    fn compute_root<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        key: &Field<E>,
+        leaf_hash: Option<Field<E>>,
+    ) -> Result<Field<E>> {
+        let key = key_to_bytes(key)?;
+        let empty_hashes = empty_hashes(path_hasher)?;
+
+        // Initialize the current node, where `None` is the root of an empty subtree.
+        let mut node = leaf_hash;
+        let mut siblings = self.siblings.iter();
+        for (height, is_empty) in self.is_empty.iter().enumerate() {
+            let sibling = match is_empty {
+                true => None,
+                false => match siblings.next() {
+                    Some(sibling) => Some(*sibling),
+                    None => bail!("The sparse Merkle path is missing a sibling"),
+                },
+            };
+            node = match (node, sibling) {
+                // If both children are empty, the parent is empty.
+                (None, None) => None,
+                (node, sibling) => {
+                    let node = node.unwrap_or(empty_hashes[height]);
+                    let sibling = sibling.unwrap_or(empty_hashes[height]);
+                    match bit(&key, height) {
+                        false => Some(path_hasher.hash_children(&node, &sibling)?),
+                        true => Some(path_hasher.hash_children(&sibling, &node)?),
+                    }
+                }
+            };
+        }
+        Ok(node.unwrap_or(empty_hashes[SPARSE_MERKLE_TREE_DEPTH]))
+    }
+}
+
+impl<E: Environment> FromBytes for SparseMerklePath<E> {
+    /// Reads in a sparse Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the bitmask of empty siblings.
+        let mut bitmask = [0u8; SPARSE_MERKLE_TREE_DEPTH / 8];
+        reader.read_exact(&mut bitmask)?;
+        let mut is_empty = [false; SPARSE_MERKLE_TREE_DEPTH];
+        for (height, is_empty) in is_empty.iter_mut().enumerate() {
+            *is_empty = bit(&bitmask, height);
+        }
+        // Read the non-empty sibling hashes.
+        let num_siblings = is_empty.iter().filter(|is_empty| !**is_empty).count();
+        let siblings = (0..num_siblings).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the sparse Merkle path.
+        Self::try_from((is_empty, siblings)).map_err(|err| error(err.to_string()))
+    }
+}
+
+impl<E: Environment> ToBytes for SparseMerklePath<E> {
+    /// Writes the sparse Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the bitmask of empty siblings.
+        let mut bitmask = [0u8; SPARSE_MERKLE_TREE_DEPTH / 8];
+        for (height, is_empty) in self.is_empty.iter().enumerate() {
+            bitmask[height / 8] |= (*is_empty as u8) << (height % 8);
+        }
+        writer.write_all(&bitmask)?;
+        // Write the non-empty sibling hashes.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment> Serialize for SparseMerklePath<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, E: Environment> Deserialize<'de> for SparseMerklePath<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "sparse Merkle path")
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+type LH = Poseidon<CurrentEnvironment, 4>;
+type PH = Poseidon<CurrentEnvironment, 2>;
+
+const ITERATIONS: usize = 10;
+
+/// Returns a new sparse Merkle tree, with its leaf and path hashers.
+fn sample_tree() -> Result<(SparseMerkleTree<CurrentEnvironment, LH, PH>, LH, PH)> {
+    let leaf_hasher = LH::setup("AleoSparseMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoSparseMerkleTreeTest1")?;
+    Ok((SparseMerkleTree::new(&leaf_hasher, &path_hasher)?, leaf_hasher, path_hasher))
+}
+
+#[test]
+fn test_membership_and_non_membership() -> Result<()> {
+    let rng = &mut test_rng();
+    let (mut tree, leaf_hasher, path_hasher) = sample_tree()?;
+
+    // Insert the leaves.
+    let entries = (0..ITERATIONS)
+        .map(|_| (Field::<CurrentEnvironment>::rand(rng), vec![Field::rand(rng)]))
+        .collect::<Vec<_>>();
+    for (key, leaf) in &entries {
+        tree.insert(key, leaf)?;
+    }
+    assert_eq!(ITERATIONS, tree.number_of_leaves());
+    let root = tree.root();
+
+    // Ensure each leaf is proven to be a member, and only with the correct leaf and key.
+    for (key, leaf) in &entries {
+        assert!(tree.contains_key(key)?);
+        let path = tree.prove(key)?;
+        assert!(path.verify_membership(&leaf_hasher, &path_hasher, &root, key, leaf));
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, &root, key, &vec![Field::rand(rng)]));
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, &root, &Field::rand(rng), leaf));
+        assert!(!path.verify_non_membership(&path_hasher, &root, key));
+
+        // Ensure the path round trips through bytes.
+        assert_eq!(path, SparseMerklePath::from_bytes_le(&path.to_bytes_le()?)?);
+    }
+
+    // Ensure absent keys are proven to be non-members.
+    for _ in 0..ITERATIONS {
+        let key = Field::rand(rng);
+        assert!(!tree.contains_key(&key)?);
+        let path = tree.prove(&key)?;
+        assert!(tree.verify_non_membership(&path, &root, &key));
+        assert!(!tree.verify_non_membership(&path, &Field::rand(rng), &key));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_insert_and_remove() -> Result<()> {
+    let rng = &mut test_rng();
+    let (mut tree, _, _) = sample_tree()?;
+    let empty_root = tree.root();
+
+    // Ensure the root is independent of the insertion order.
+    let entries = (0..ITERATIONS)
+        .map(|_| (Field::<CurrentEnvironment>::rand(rng), vec![Field::rand(rng)]))
+        .collect::<Vec<_>>();
+    for (key, leaf) in &entries {
+        tree.insert(key, leaf)?;
+    }
+    let (mut reversed, _, _) = sample_tree()?;
+    for (key, leaf) in entries.iter().rev() {
+        reversed.insert(key, leaf)?;
+    }
+    assert_eq!(tree.root(), reversed.root());
+
+    // Ensure replacing a leaf changes the root, and restoring it reverts the root.
+    let root = tree.root();
+    let (key, leaf) = &entries[0];
+    tree.insert(key, &vec![Field::rand(rng)])?;
+    assert_ne!(root, tree.root());
+    tree.insert(key, leaf)?;
+    assert_eq!(root, tree.root());
+
+    // Ensure removing every leaf restores the empty tree, without leftover nodes.
+    for (key, _) in &entries {
+        tree.remove(key)?;
+        assert!(!tree.contains_key(key)?);
+    }
+    assert_eq!(empty_root, tree.root());
+    assert!(tree.nodes.is_empty());
+    Ok(())
+}
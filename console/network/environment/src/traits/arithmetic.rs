@@ -58,6 +58,26 @@ pub trait DivWrapped<Rhs: ?Sized = Self> {
     fn div_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Ternary operator for left shifting `first`, filling the vacated bits with the upper bits of `second`,
+/// where the rhs is taken modulo the number of bits in self.
+pub trait FunnelShl<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn funnel_shl(first: &Self, second: &Self, rhs: &Rhs) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Ternary operator for right shifting `second`, filling the vacated bits with the lower bits of `first`,
+/// where the rhs is taken modulo the number of bits in self.
+pub trait FunnelShr<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn funnel_shr(first: &Self, second: &Self, rhs: &Rhs) -> Self::Output
+    where
+        Self: Sized;
+}
+
 /// Binary operator for modding two values.
 pub trait Modulo<Rhs: ?Sized = Self> {
     type Output;
@@ -121,6 +141,20 @@ pub trait RemWrapped<Rhs: ?Sized = Self> {
     fn rem_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for left rotating a value, where the rhs is taken modulo the number of bits in self.
+pub trait Rotl<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rotl(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for right rotating a value, where the rhs is taken modulo the number of bits in self.
+pub trait Rotr<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rotr(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for left shifting a value, checking that the rhs is less than the number
 /// of bits in self.
 pub trait ShlChecked<Rhs: ?Sized = Self> {
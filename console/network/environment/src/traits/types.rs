@@ -214,7 +214,11 @@ pub trait StringTrait:
 /// Representation of an integer.
 pub trait IntegerTrait<I: integer_type::IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16>, U32: IntegerCore<u32>>:
     IntegerCore<I>
+    + FunnelShl<U8, Output = Self>
+    + FunnelShr<U8, Output = Self>
     + Pow<U8, Output = Self>
+    + Rotl<U8, Output = Self>
+    + Rotr<U8, Output = Self>
     + Shl<U8, Output = Self>
     + for<'a> Shl<&'a U8, Output = Self>
     + ShlChecked<U8, Output = Self>
@@ -225,7 +229,11 @@ pub trait IntegerTrait<I: integer_type::IntegerType, U8: IntegerCore<u8>, U16: I
     + ShrChecked<U8, Output = Self>
     + ShrWrapped<U8, Output = Self>
     + ShrAssign<U8>
+    + FunnelShl<U16, Output = Self>
+    + FunnelShr<U16, Output = Self>
     + Pow<U16, Output = Self>
+    + Rotl<U16, Output = Self>
+    + Rotr<U16, Output = Self>
     + Shl<U16, Output = Self>
     + for<'a> Shl<&'a U16, Output = Self>
     + ShlChecked<U16, Output = Self>
@@ -236,7 +244,11 @@ pub trait IntegerTrait<I: integer_type::IntegerType, U8: IntegerCore<u8>, U16: I
     + ShrChecked<U16, Output = Self>
     + ShrWrapped<U16, Output = Self>
     + ShrAssign<U16>
+    + FunnelShl<U32, Output = Self>
+    + FunnelShr<U32, Output = Self>
     + Pow<U32, Output = Self>
+    + Rotl<U32, Output = Self>
+    + Rotr<U32, Output = Self>
     + Shl<U32, Output = Self>
     + for<'a> Shl<&'a U32, Output = Self>
     + ShlChecked<U32, Output = Self>
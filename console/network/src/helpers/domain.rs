@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_console_types::{Field, Scalar};
+
+/// A domain tag, which binds a hash or signature to a network and an epoch.
+///
+/// The tag is derived as `HashPSD2(epoch_domain, network ID, epoch)`. A tagged hash is the digest of the input,
+/// hashed again by a Poseidon instance whose domain separator is the tag, and a tagged signature binds the tag
+/// into its challenge preimage, ahead of the nonce commitment. As the tag is never part of the caller's input,
+/// an object produced under one tag does not verify under another, nor as an untagged object, so objects from
+/// a test network or a past epoch can not be replayed, even if key material is reused.
+///
+/// For migration, the domain tag of a block height before `N::DOMAIN_SEPARATION_EPOCH` is untagged,
+/// in which case the input is hashed or signed as is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DomainTag<N: Network> {
+    /// The epoch of the domain tag.
+    epoch: u32,
+    /// The tag, or `None` if the epoch precedes domain separation.
+    tag: Option<Field<N>>,
+}
+
+impl<N: Network> DomainTag<N> {
+    /// Returns the domain tag for the given epoch.
+    pub fn new(epoch: u32) -> Result<Self> {
+        let tag = N::hash_psd2(&[N::epoch_domain(), Field::from_u16(N::ID), Field::from_u32(epoch)])?;
+        Ok(Self { epoch, tag: Some(tag) })
+    }
+
+    /// Returns the domain tag for the given block height, which is untagged before `N::DOMAIN_SEPARATION_EPOCH`.
+    pub fn from_height(height: u32) -> Result<Self> {
+        let epoch = Self::epoch_of(height);
+        match epoch >= N::DOMAIN_SEPARATION_EPOCH {
+            true => Self::new(epoch),
+            false => Ok(Self::untagged(epoch)),
+        }
+    }
+
+    /// Returns the untagged domain for the given epoch, which leaves inputs unchanged.
+    pub const fn untagged(epoch: u32) -> Self {
+        Self { epoch, tag: None }
+    }
+
+    /// Returns the epoch of the given block height.
+    pub const fn epoch_of(height: u32) -> u32 {
+        height / N::BLOCKS_PER_EPOCH
+    }
+}
+
+impl<N: Network> DomainTag<N> {
+    /// Returns the epoch of the domain tag.
+    pub const fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// Returns `true` if the domain is tagged.
+    pub const fn is_tagged(&self) -> bool {
+        self.tag.is_some()
+    }
+
+    /// Returns the tag, or `None` if the domain is untagged.
+    pub const fn to_field(&self) -> Option<Field<N>> {
+        self.tag
+    }
+
+    /// Returns the given digest, hashed under the tag as the domain separator, or as is if the domain is untagged.
+    fn bind(&self, digest: Field<N>) -> Result<Field<N>> {
+        match self.tag {
+            Some(tag) => N::hash_psd2_in_domain(tag, &[digest]),
+            None => Ok(digest),
+        }
+    }
+}
+
+impl<N: Network> DomainTag<N> {
+    /// Returns the BHP hash with an input hasher of 256-bits, in the domain.
+    pub fn hash_bhp256(&self, input: &[bool]) -> Result<Field<N>> {
+        self.bind(N::hash_bhp256(input)?)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits, in the domain.
+    pub fn hash_bhp512(&self, input: &[bool]) -> Result<Field<N>> {
+        self.bind(N::hash_bhp512(input)?)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits, in the domain.
+    pub fn hash_bhp768(&self, input: &[bool]) -> Result<Field<N>> {
+        self.bind(N::hash_bhp768(input)?)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits, in the domain.
+    pub fn hash_bhp1024(&self, input: &[bool]) -> Result<Field<N>> {
+        self.bind(N::hash_bhp1024(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2, in the domain.
+    pub fn hash_psd2(&self, input: &[Field<N>]) -> Result<Field<N>> {
+        self.bind(N::hash_psd2(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4, in the domain.
+    pub fn hash_psd4(&self, input: &[Field<N>]) -> Result<Field<N>> {
+        self.bind(N::hash_psd4(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8, in the domain.
+    pub fn hash_psd8(&self, input: &[Field<N>]) -> Result<Field<N>> {
+        self.bind(N::hash_psd8(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, in the domain.
+    pub fn hash_to_scalar_psd2(&self, input: &[Field<N>]) -> Result<Scalar<N>> {
+        match self.tag {
+            Some(tag) => N::hash_to_scalar_psd2_in_domain(tag, &[N::hash_psd2(input)?]),
+            None => N::hash_to_scalar_psd2(input),
+        }
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field, in the domain.
+    pub fn hash_to_scalar_psd4(&self, input: &[Field<N>]) -> Result<Scalar<N>> {
+        match self.tag {
+            Some(tag) => N::hash_to_scalar_psd2_in_domain(tag, &[N::hash_psd4(input)?]),
+            None => N::hash_to_scalar_psd4(input),
+        }
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field, in the domain.
+    pub fn hash_to_scalar_psd8(&self, input: &[Field<N>]) -> Result<Scalar<N>> {
+        match self.tag {
+            Some(tag) => N::hash_to_scalar_psd2_in_domain(tag, &[N::hash_psd8(input)?]),
+            None => N::hash_to_scalar_psd8(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_domain_tag() -> Result<()> {
+        let rng = &mut test_rng();
+        let input: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(rng)).collect();
+
+        // Ensure the tag is deterministic, and distinct across epochs.
+        let domain = DomainTag::<CurrentNetwork>::new(1)?;
+        assert_eq!(domain, DomainTag::new(1)?);
+        assert_ne!(domain.to_field(), DomainTag::<CurrentNetwork>::new(2)?.to_field());
+
+        // Ensure the tag binds the network ID.
+        let expected = CurrentNetwork::hash_psd2(&[
+            CurrentNetwork::epoch_domain(),
+            Field::from_u16(CurrentNetwork::ID),
+            Field::from_u32(1),
+        ])?;
+        assert_eq!(Some(expected), domain.to_field());
+
+        // Ensure a tagged hash differs from the untagged hash, and from the hash in another epoch.
+        assert_ne!(CurrentNetwork::hash_psd4(&input)?, domain.hash_psd4(&input)?);
+        assert_ne!(DomainTag::<CurrentNetwork>::new(2)?.hash_psd4(&input)?, domain.hash_psd4(&input)?);
+
+        // Ensure a tagged hash differs from the untagged hash of the input prefixed with the tag.
+        let tag = domain.to_field().unwrap();
+        let prefixed = [&[tag], input.as_slice()].concat();
+        assert_ne!(CurrentNetwork::hash_psd4(&prefixed)?, domain.hash_psd4(&input)?);
+        assert_ne!(CurrentNetwork::hash_to_scalar_psd4(&prefixed)?, domain.hash_to_scalar_psd4(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_domain_tag_migration() -> Result<()> {
+        let rng = &mut test_rng();
+        let input: Vec<bool> = (0..64).map(|_| rng.gen()).collect();
+
+        // Ensure the heights before the domain separation epoch are untagged.
+        assert!(CurrentNetwork::DOMAIN_SEPARATION_EPOCH > 0);
+        let first_tagged_height = CurrentNetwork::DOMAIN_SEPARATION_EPOCH * CurrentNetwork::BLOCKS_PER_EPOCH;
+        for height in [0, first_tagged_height - 1] {
+            let domain = DomainTag::<CurrentNetwork>::from_height(height)?;
+            assert!(!domain.is_tagged());
+            assert_eq!(CurrentNetwork::hash_bhp1024(&input)?, domain.hash_bhp1024(&input)?);
+        }
+
+        // Ensure the heights from the domain separation epoch onwards are tagged.
+        let domain = DomainTag::<CurrentNetwork>::from_height(first_tagged_height)?;
+        assert!(domain.is_tagged());
+        assert_eq!(CurrentNetwork::DOMAIN_SEPARATION_EPOCH, domain.epoch());
+        assert_ne!(CurrentNetwork::hash_bhp1024(&input)?, domain.hash_bhp1024(&input)?);
+        Ok(())
+    }
+}
@@ -19,3 +19,9 @@ pub use id::*;
 
 mod object;
 pub use object::*;
+
+mod redacted;
+pub use redacted::*;
+
+mod domain;
+pub use domain::*;
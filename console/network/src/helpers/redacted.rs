@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_console_types::Field;
+
+static FINGERPRINT_DOMAIN: &str = "AleoFingerprint0";
+
+/// The number of bytes of the hash that are shown in a fingerprint.
+const FINGERPRINT_SIZE_IN_BYTES: usize = 4;
+
+/// Returns the fingerprint of the given secret, as the leading bytes (in hex) of its domain-separated hash.
+///
+/// The fingerprint allows two redacted values to be told apart in logs, without revealing the secret.
+pub fn fingerprint<N: Network>(secret: &[Field<N>]) -> Result<String> {
+    // Construct the fingerprint domain separator.
+    let domain = Field::<N>::new_domain_separator(FINGERPRINT_DOMAIN);
+    // Hash the secret.
+    let mut preimage = Vec::with_capacity(secret.len() + 1);
+    preimage.push(domain);
+    preimage.extend_from_slice(secret);
+    let hash = N::hash_psd8(&preimage)?;
+    // Return the leading bytes of the hash, in hex.
+    Ok(hash.to_bytes_le()?.iter().take(FINGERPRINT_SIZE_IN_BYTES).map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Writes the redacted form of a secret, i.e. `name(fingerprint)`.
+pub fn fmt_redacted<N: Network>(f: &mut Formatter, name: &str, secret: &[Field<N>]) -> fmt::Result {
+    write!(f, "{name}(<redacted {}>)", fingerprint::<N>(secret).map_err(|_| fmt::Error)?)
+}
+
+/// A reference to a secret, whose `Debug` and `Display` implementations write the secret in full.
+///
+/// This is returned by `reveal()` on types whose `Debug` implementation is redacted.
+pub struct Revealed<'a, T: Display>(&'a T);
+
+impl<'a, T: Display> Revealed<'a, T> {
+    /// Initializes a revealed reference to the given secret.
+    pub const fn new(secret: &'a T) -> Self {
+        Self(secret)
+    }
+}
+
+impl<T: Display> Debug for Revealed<'_, T> {
+    /// Writes the secret in full.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.0, f)
+    }
+}
+
+impl<T: Display> Display for Revealed<'_, T> {
+    /// Writes the secret in full.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_fingerprint() -> Result<()> {
+        let rng = &mut test_rng();
+
+        let secret = Field::<CurrentNetwork>::rand(rng);
+        let fingerprint = fingerprint::<CurrentNetwork>(&[secret])?;
+        // Ensure the fingerprint is deterministic and of the expected length.
+        assert_eq!(fingerprint, super::fingerprint::<CurrentNetwork>(&[secret])?);
+        assert_eq!(2 * FINGERPRINT_SIZE_IN_BYTES, fingerprint.len());
+        // Ensure the fingerprint does not contain the secret.
+        assert!(!secret.to_string().contains(&fingerprint));
+        Ok(())
+    }
+}
@@ -77,6 +77,9 @@ pub trait Network:
     const MAX_DATA_DEPTH: usize = 32;
     /// The maximum number of values and/or entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
+    /// The maximum number of elements in an array.
+    /// Note: An array of this many field elements must fit within a `u16` number of bits.
+    const MAX_ARRAY_CAPACITY: u32 = 256;
     /// The maximum number of fields in data (must not exceed u16::MAX).
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
 
@@ -92,6 +95,16 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 8;
 
+    /// The number of most recent state roots that a transition input may prove inclusion against.
+    /// Note: This value must be at least 1, as the latest state root is always accepted.
+    const MAX_RECENT_STATE_ROOTS: u32 = 10;
+
+    /// The number of blocks in an epoch.
+    const BLOCKS_PER_EPOCH: u32 = 360;
+    /// The first epoch in which block hashes and signatures are domain-separated by the network and epoch.
+    /// Note: Objects from earlier epochs remain untagged, so the existing ledger continues to verify.
+    const DOMAIN_SEPARATION_EPOCH: u32;
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -128,6 +141,12 @@ pub trait Network:
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the shared secret domain as a constant field element.
+    fn shared_secret_domain() -> Field<Self>;
+
+    /// Returns the epoch domain as a constant field element.
+    fn epoch_domain() -> Field<Self>;
+
     /// Returns a BHP commitment with an input hasher of 256-bits.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>>;
 
@@ -200,6 +219,12 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the Poseidon hash with an input rate of 2, under the given domain separator.
+    fn hash_psd2_in_domain(domain: Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, under the given domain separator.
+    fn hash_to_scalar_psd2_in_domain(domain: Field<Self>, input: &[Field<Self>]) -> Result<Scalar<Self>>;
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
@@ -28,9 +28,15 @@ use snarkvm_console_algorithms::{
     BHP768,
 };
 
+/// The number of bits in each window of the precomputation table for the generator `G`.
+/// Note: The table holds `2^G_TABLE_WINDOW_SIZE` group elements per window.
+const G_TABLE_WINDOW_SIZE: usize = 8;
+
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
     pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    /// The windowed precomputation table for multiplications of the generator `G`.
+    pub static ref GENERATOR_G_TABLE: Vec<Vec<Group<Testnet3>>> = Testnet3::new_window_table(&GENERATOR_G);
 
     /// The Marlin sponge parameters.
     pub static ref MARLIN_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
@@ -47,6 +53,10 @@ lazy_static! {
     pub static ref R_BCM_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoBalanceRandomizer0");
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
+    /// The shared secret domain as a constant field element.
+    pub static ref SHARED_SECRET_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSharedSecret0");
+    /// The epoch domain as a constant field element.
+    pub static ref EPOCH_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoEpochDomain0");
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
@@ -88,6 +98,24 @@ impl Testnet3 {
         }
         g_bases
     }
+
+    /// Initializes the windowed precomputation table for the given powers of a base.
+    /// For the `i`-th window, the `j`-th entry is the sum of the powers selected by the bits of `j`,
+    /// so a scalar multiplication reduces to one addition per window of `G_TABLE_WINDOW_SIZE` bits.
+    fn new_window_table(powers: &[Group<Self>]) -> Vec<Vec<Group<Self>>> {
+        powers
+            .chunks(G_TABLE_WINDOW_SIZE)
+            .map(|window| {
+                let mut table = Vec::with_capacity(1 << window.len());
+                table.push(Group::<Self>::zero());
+                for j in 1..(1usize << window.len()) {
+                    // Add the power of the lowest set bit to the entry without that bit.
+                    table.push(table[j & (j - 1)] + window[j.trailing_zeros() as usize]);
+                }
+                table
+            })
+            .collect()
+    }
 }
 
 impl Environment for Testnet3 {
@@ -125,6 +153,9 @@ impl Network for Testnet3 {
     /// The network name.
     const NAME: &'static str = "Aleo Testnet3";
 
+    /// The first epoch in which block hashes and signatures are domain-separated by the network and epoch.
+    const DOMAIN_SEPARATION_EPOCH: u32 = 1;
+
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>> {
         &GENERATOR_G
@@ -132,12 +163,13 @@ impl Network for Testnet3 {
 
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
-        GENERATOR_G
+        GENERATOR_G_TABLE
             .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
+            .zip_eq(scalar.to_bits_le().chunks(G_TABLE_WINDOW_SIZE))
+            .map(|(table, bits)| {
+                // Look up the entry for the bits of this window.
+                let index = bits.iter().rev().fold(0usize, |index, bit| (index << 1) | *bit as usize);
+                table[index]
             })
             .sum()
     }
@@ -177,6 +209,16 @@ impl Network for Testnet3 {
         *SERIAL_NUMBER_DOMAIN
     }
 
+    /// Returns the shared secret domain as a constant field element.
+    fn shared_secret_domain() -> Field<Self> {
+        *SHARED_SECRET_DOMAIN
+    }
+
+    /// Returns the epoch domain as a constant field element.
+    fn epoch_domain() -> Field<Self> {
+        *EPOCH_DOMAIN
+    }
+
     /// Returns a BHP commitment with an input hasher of 256-bits.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
         BHP_256.commit(input, randomizer)
@@ -297,6 +339,16 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_scalar(input)
     }
 
+    /// Returns the Poseidon hash with an input rate of 2, under the given domain separator.
+    fn hash_psd2_in_domain(domain: Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_2.with_domain(domain).hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, under the given domain separator.
+    fn hash_to_scalar_psd2_in_domain(domain: Field<Self>, input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        POSEIDON_2.with_domain(domain).hash_to_scalar(input)
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
@@ -339,4 +391,30 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_g_scalar_multiply_matches_powers() {
+        let rng = &mut test_rng();
+
+        // Returns the sum of the powers of `G` selected by the bits of the scalar.
+        let expected = |scalar: &Scalar<CurrentNetwork>| -> Group<CurrentNetwork> {
+            CurrentNetwork::g_powers()
+                .iter()
+                .zip_eq(&scalar.to_bits_le())
+                .filter_map(|(base, bit)| match bit {
+                    true => Some(*base),
+                    false => None,
+                })
+                .sum()
+        };
+
+        // Ensure the windowed table agrees with the powers of `G`, including on the edge cases.
+        for scalar in [Scalar::zero(), Scalar::one(), -Scalar::one()] {
+            assert_eq!(expected(&scalar), CurrentNetwork::g_scalar_multiply(&scalar));
+        }
+        for _ in 0..100 {
+            let scalar = Scalar::rand(rng);
+            assert_eq!(expected(&scalar), CurrentNetwork::g_scalar_multiply(&scalar));
+        }
+    }
 }
@@ -37,7 +37,9 @@ impl<N: Network> FromBytes for Literal<N> {
             13 => Self::U128(U128::read_le(&mut reader)?),
             14 => Self::Scalar(Scalar::read_le(&mut reader)?),
             15 => Self::String(StringType::read_le(&mut reader)?),
-            16.. => return Err(error(format!("Failed to decode literal variant {index}"))),
+            16 => Self::I256(I256::read_le(&mut reader)?),
+            17 => Self::U256(U256::read_le(&mut reader)?),
+            18.. => return Err(error(format!("Failed to decode literal variant {index}"))),
         };
         Ok(literal)
     }
@@ -112,6 +114,14 @@ impl<N: Network> ToBytes for Literal<N> {
                 (15 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
+            Self::I256(primitive) => {
+                (16 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
+            Self::U256(primitive) => {
+                (17 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
         }
     }
 }
@@ -173,6 +183,10 @@ mod tests {
             check_bytes(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
             // String
             check_bytes(Literal::<CurrentNetwork>::String(StringType::rand(rng)))?;
+            // I256
+            check_bytes(Literal::<CurrentNetwork>::I256(Uniform::rand(rng)))?;
+            // U256
+            check_bytes(Literal::<CurrentNetwork>::U256(Uniform::rand(rng)))?;
         }
         Ok(())
     }
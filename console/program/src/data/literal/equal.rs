@@ -44,6 +44,8 @@ impl<N: Network> core::hash::Hash for Literal<N> {
             Self::U128(a) => a.hash(state),
             Self::Scalar(a) => a.hash(state),
             Self::String(a) => a.hash(state),
+            Self::I256(a) => a.hash(state),
+            Self::U256(a) => a.hash(state),
         }
     }
 }
@@ -70,6 +72,8 @@ impl<N: Network> Equal for Literal<N> {
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_equal(b),
             _ => Boolean::new(false),
         }
     }
@@ -93,6 +97,8 @@ impl<N: Network> Equal for Literal<N> {
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_not_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_not_equal(b),
             _ => Boolean::new(true),
         }
     }
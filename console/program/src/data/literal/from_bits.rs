@@ -49,7 +49,9 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            16.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
+            16 => Literal::I256(I256::from_bits_le(literal)?),
+            17 => Literal::U256(U256::from_bits_le(literal)?),
+            18.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
         };
         Ok(literal)
     }
@@ -86,7 +88,9 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            16.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
+            16 => Literal::I256(I256::from_bits_be(literal)?),
+            17 => Literal::U256(U256::from_bits_be(literal)?),
+            18.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
         };
         Ok(literal)
     }
@@ -149,6 +153,10 @@ mod tests {
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let string: String = (0..(CurrentNetwork::MAX_STRING_BYTES) / 4).map(|_| rng.gen::<char>()).collect();
             check_serialization(Literal::<CurrentNetwork>::String(StringType::new(&string)))?;
+            // I256
+            check_serialization(Literal::<CurrentNetwork>::I256(Uniform::rand(rng)))?;
+            // U256
+            check_serialization(Literal::<CurrentNetwork>::U256(Uniform::rand(rng)))?;
         }
         Ok(())
     }
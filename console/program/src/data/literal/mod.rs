@@ -64,4 +64,8 @@ pub enum Literal<N: Network> {
     Scalar(Scalar<N>),
     /// The string type.
     String(StringType<N>),
+    /// The 256-bit signed integer type.
+    I256(I256<N>),
+    /// The 256-bit unsigned integer type.
+    U256(U256<N>),
 }
@@ -37,6 +37,8 @@ impl<N: Network> Parser for Literal<N> {
             map(U128::<N>::parse, |literal| Self::U128(literal)),
             map(Scalar::<N>::parse, |literal| Self::Scalar(literal)),
             map(StringType::<N>::parse, |literal| Self::String(literal)),
+            map(I256::<N>::parse, |literal| Self::I256(literal)),
+            map(U256::<N>::parse, |literal| Self::U256(literal)),
         ))(string)
     }
 }
@@ -84,6 +86,8 @@ impl<N: Network> Display for Literal<N> {
             Self::U128(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::I256(literal) => Display::fmt(literal, f),
+            Self::U256(literal) => Display::fmt(literal, f),
         }
     }
 }
@@ -36,6 +36,8 @@ impl<N: Network> Literal<N> {
             LiteralType::U128 => Literal::U128(U128::rand(rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(rng)),
             LiteralType::String => Literal::String(StringType::rand(rng)),
+            LiteralType::I256 => Literal::I256(I256::rand(rng)),
+            LiteralType::U256 => Literal::U256(U256::rand(rng)),
         }
     }
 }
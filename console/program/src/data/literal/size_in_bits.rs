@@ -36,6 +36,8 @@ impl<N: Network> Literal<N> {
             Self::U128(..) => U128::<N>::size_in_bits() as u16,
             Self::Scalar(..) => Scalar::<N>::size_in_bits() as u16,
             Self::String(string) => (string.len() * 8) as u16,
+            Self::I256(..) => I256::<N>::size_in_bits() as u16,
+            Self::U256(..) => U256::<N>::size_in_bits() as u16,
         }
     }
 }
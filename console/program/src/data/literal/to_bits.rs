@@ -48,6 +48,8 @@ impl<N: Network> ToBits for &Literal<N> {
             Literal::U128(literal) => literal.to_bits_le(),
             Literal::Scalar(literal) => literal.to_bits_le(),
             Literal::String(literal) => literal.as_bytes().to_bits_le(),
+            Literal::I256(literal) => literal.to_bits_le(),
+            Literal::U256(literal) => literal.to_bits_le(),
         }
     }
 
@@ -70,6 +72,8 @@ impl<N: Network> ToBits for &Literal<N> {
             Literal::U128(literal) => literal.to_bits_be(),
             Literal::Scalar(literal) => literal.to_bits_be(),
             Literal::String(literal) => literal.as_bytes().to_bits_be(),
+            Literal::I256(literal) => literal.to_bits_be(),
+            Literal::U256(literal) => literal.to_bits_be(),
         }
     }
 }
@@ -36,6 +36,8 @@ impl<N: Network> Literal<N> {
             Self::U128(..) => LiteralType::U128,
             Self::Scalar(..) => LiteralType::Scalar,
             Self::String(..) => LiteralType::String,
+            Self::I256(..) => LiteralType::I256,
+            Self::U256(..) => LiteralType::U256,
         }
     }
 }
@@ -36,6 +36,8 @@ impl<N: Network> Literal<N> {
             Self::U128(..) => 13,
             Self::Scalar(..) => 14,
             Self::String(..) => 15,
+            Self::I256(..) => 16,
+            Self::U256(..) => 17,
         }
     }
 }
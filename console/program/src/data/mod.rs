@@ -27,7 +27,10 @@ mod plaintext;
 pub use plaintext::Plaintext;
 
 mod record;
-pub use record::{Balance, Entry, Owner, Record};
+pub use record::{
+    Balance, DecryptedMemo, Entry, MultiRecipientRecord, Owner, RecipientKey, Record, RecordMemo,
+    MAX_RECORD_RECIPIENTS, MEMO_SIZE_IN_BYTES,
+};
 
 mod register;
 pub use register::Register;
@@ -44,7 +44,22 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the interface.
                 Self::Interface(members, Default::default())
             }
-            2.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            2 => {
+                // Read the array type.
+                let array_type = ArrayType::<N>::read_le(&mut reader)?;
+                // Read the number of elements.
+                let num_elements = u32::read_le(&mut reader)?;
+                // Ensure the number of elements is within the capacity.
+                if num_elements > **array_type.capacity() {
+                    return Err(error(format!("Found {num_elements} elements in an array of type '{array_type}'")));
+                }
+                // Read the elements.
+                let elements =
+                    (0..num_elements).map(|_| Literal::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the array.
+                Self::new_array(array_type, elements).map_err(|e| error(e.to_string()))?
+            }
+            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -75,6 +90,15 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Array(array_type, elements, ..) => {
+                2u8.write_le(&mut writer)?;
+                // Write the array type.
+                array_type.write_le(&mut writer)?;
+                // Write the number of elements.
+                (elements.len() as u32).write_le(&mut writer)?;
+                // Write each element.
+                elements.iter().try_for_each(|element| element.write_le(&mut writer))
+            }
         }
     }
 }
@@ -187,6 +211,10 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
         assert!(Plaintext::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Lastly check an array manually.
+        check_bytes(Plaintext::<CurrentNetwork>::from_str("[] as [u64; 4u32]")?)?;
+        check_bytes(Plaintext::<CurrentNetwork>::from_str("[1u64, 2u64, 3u64] as [u64; 4u32]")?)?;
         Ok(())
     }
 }
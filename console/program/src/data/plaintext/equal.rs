@@ -43,7 +43,12 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(false),
             },
-            (Self::Literal(..), _) | (Self::Interface(..), _) => Boolean::new(false),
+            (Self::Array(type_a, a, _), Self::Array(type_b, b, _)) => match type_a == type_b && a.len() == b.len() {
+                // Check each element for equality.
+                true => a.iter().zip_eq(b.iter()).fold(Boolean::new(true), |equal, (a, b)| equal & a.is_equal(b)),
+                false => Boolean::new(false),
+            },
+            (Self::Literal(..), _) | (Self::Interface(..), _) | (Self::Array(..), _) => Boolean::new(false),
         }
     }
 
@@ -62,7 +67,14 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(true),
             },
-            (Self::Literal(..), _) | (Self::Interface(..), _) => Boolean::new(true),
+            (Self::Array(type_a, a, _), Self::Array(type_b, b, _)) => match type_a == type_b && a.len() == b.len() {
+                // Check each element for inequality.
+                true => {
+                    a.iter().zip_eq(b.iter()).fold(Boolean::new(false), |not_equal, (a, b)| not_equal | a.is_not_equal(b))
+                }
+                false => Boolean::new(true),
+            },
+            (Self::Literal(..), _) | (Self::Interface(..), _) | (Self::Array(..), _) => Boolean::new(true),
         }
     }
 }
@@ -15,6 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::LiteralType;
+
+use num_traits::FromPrimitive;
 
 impl<N: Network> FromBits for Plaintext<N> {
     /// Initializes a new plaintext from a list of little-endian bits *without* trailing zeros.
@@ -74,6 +77,45 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            let element_variant = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            counter += 8;
+
+            let capacity = U32::from_bits_le(&bits_le[counter..counter + 32])?;
+            counter += 32;
+
+            let length = u32::from_bits_le(&bits_le[counter..counter + 32])?;
+            counter += 32;
+
+            let element_type = match LiteralType::from_u8(element_variant) {
+                Some(element_type) => element_type,
+                None => bail!("Unknown array element type '{element_variant}'"),
+            };
+            let array_type = ArrayType::new(element_type, capacity)?;
+            ensure!(length <= *capacity, "Found {length} elements in an array of type '{array_type}'");
+
+            let element_size = array_type.padding().size_in_bits() as usize;
+            let mut elements = Vec::with_capacity(length as usize);
+            for index in 0..*capacity {
+                let element_bits = &bits_le[counter..counter + element_size];
+                counter += element_size;
+
+                match index < length {
+                    true => elements.push(Literal::from_bits_le(element_variant, element_bits)?),
+                    // Ensure the unused capacity is filled with zero bits.
+                    false => ensure!(element_bits.iter().all(|bit| !bit), "Found a nonzero array padding"),
+                }
+            }
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the array.
+                Ok(_) => Ok(Self::Array(array_type, elements, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant.");
@@ -137,6 +179,41 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            let element_variant = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            counter += 8;
+
+            let capacity = U32::from_bits_be(&bits_be[counter..counter + 32])?;
+            counter += 32;
+
+            let length = u32::from_bits_be(&bits_be[counter..counter + 32])?;
+            counter += 32;
+
+            let element_type = match LiteralType::from_u8(element_variant) {
+                Some(element_type) => element_type,
+                None => bail!("Unknown array element type '{element_variant}'"),
+            };
+            let array_type = ArrayType::new(element_type, capacity)?;
+            ensure!(length <= *capacity, "Found {length} elements in an array of type '{array_type}'");
+
+            let element_size = array_type.padding().size_in_bits() as usize;
+            let mut elements = Vec::with_capacity(length as usize);
+            for index in 0..*capacity {
+                let element_bits = &bits_be[counter..counter + element_size];
+                counter += element_size;
+
+                match index < length {
+                    true => elements.push(Literal::from_bits_be(element_variant, element_bits)?),
+                    // Ensure the unused capacity is filled with zero bits.
+                    false => ensure!(element_bits.iter().all(|bit| !bit), "Found a nonzero array padding"),
+                }
+            }
+
+            // Return the array.
+            // Note: The big-endian bits are not cached, as the array only caches its little-endian bits.
+            Ok(Self::Array(array_type, elements, OnceCell::new()))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant.");
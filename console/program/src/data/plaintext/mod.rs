@@ -27,7 +27,7 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal};
+use crate::{ArrayType, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -40,6 +40,29 @@ pub enum Plaintext<N: Network> {
     Literal(Literal<N>, OnceCell<Vec<bool>>),
     /// A interface.
     Interface(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An array, of at most `capacity` literals of the element type.
+    /// Note: The array only caches its little-endian bits.
+    Array(ArrayType<N>, Vec<Literal<N>>, OnceCell<Vec<bool>>),
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a new `Plaintext` array of the given type, from the given elements.
+    pub fn new_array(array_type: ArrayType<N>, elements: Vec<Literal<N>>) -> Result<Self> {
+        // Ensure the number of elements is within the capacity.
+        ensure!(
+            elements.len() <= **array_type.capacity() as usize,
+            "Found {} elements in an array of type '{array_type}'",
+            elements.len()
+        );
+        // Ensure the elements are of the element type.
+        for element in &elements {
+            ensure!(
+                element.to_type() == array_type.element_type(),
+                "Found '{element}' in an array of type '{array_type}'"
+            );
+        }
+        Ok(Self::Array(array_type, elements, OnceCell::new()))
+    }
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -155,4 +178,27 @@ mod tests {
         assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
         Ok(())
     }
+
+    #[test]
+    fn test_array() -> Result<()> {
+        let array_type = ArrayType::<CurrentNetwork>::from_str("[field; 4u32]")?;
+        let element = || Literal::Field(Field::new(Uniform::rand(&mut test_rng())));
+
+        // Ensure an array of up to `capacity` elements round trips through its bits.
+        for length in 0..=4 {
+            let value = Plaintext::<CurrentNetwork>::new_array(array_type, (0..length).map(|_| element()).collect())?;
+            assert_eq!(value, Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?);
+            assert_eq!(value, Plaintext::<CurrentNetwork>::from_bits_be(&value.to_bits_be())?);
+        }
+
+        // Ensure the size of an array is determined by its type.
+        let empty = Plaintext::<CurrentNetwork>::new_array(array_type, vec![])?;
+        let full = Plaintext::<CurrentNetwork>::new_array(array_type, (0..4).map(|_| element()).collect())?;
+        assert_eq!(empty.to_bits_le().len(), full.to_bits_le().len());
+
+        // Ensure an array cannot exceed its capacity, or contain elements of another type.
+        assert!(Plaintext::<CurrentNetwork>::new_array(array_type, (0..5).map(|_| element()).collect()).is_err());
+        assert!(Plaintext::<CurrentNetwork>::new_array(array_type, vec![Literal::from_str("1u8")?]).is_err());
+        Ok(())
+    }
 }
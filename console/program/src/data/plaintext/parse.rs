@@ -62,6 +62,37 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Interface(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a sanitized literal, as an element of an array.
+        fn parse_element<N: Network>(string: &str) -> ParserResult<Literal<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the literal from the string.
+            Literal::parse(string)
+        }
+
+        /// Parses a plaintext as an array: `[literal_0, ..., literal_n] as [element_type; capacity]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = separated_list0(tag(","), parse_element)(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "]" from the string.
+            let (string, _) = tag("]")(string)?;
+            // Parse the " as " from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            let (string, _) = tag("as")(string)?;
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the array type.
+            let (string, plaintext) =
+                map_res(ArrayType::parse, |array_type| Plaintext::new_array(array_type, elements.clone()))(string)?;
+            // Output the plaintext.
+            Ok((string, plaintext))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -70,6 +101,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext interface.
             parse_interface,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -121,15 +154,15 @@ impl<N: Network> Plaintext<N> {
                 // Print the members.
                 interface.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
                     match plaintext {
-                        Self::Literal(literal, ..) => match i == interface.len() - 1 {
+                        Self::Literal(..) | Self::Array(..) => match i == interface.len() - 1 {
                             true => {
                                 // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}", "", indent = (depth + 1) * INDENT)?;
+                                write!(f, "\n{:indent$}{name}: {plaintext}", "", indent = (depth + 1) * INDENT)?;
                                 // Print the closing brace.
                                 write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
                             }
                             // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
+                            false => write!(f, "\n{:indent$}{name}: {plaintext},", "", indent = (depth + 1) * INDENT),
                         },
                         Self::Interface(..) => {
                             // Print the member name.
@@ -147,6 +180,18 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the array, i.e. [1u64, 2u64] as [u64; 4u32]
+            Self::Array(array_type, elements, ..) => {
+                // Print the opening bracket.
+                write!(f, "{:indent$}[", "", indent = depth * INDENT)?;
+                // Print the elements.
+                elements.iter().enumerate().try_for_each(|(i, element)| match i == 0 {
+                    true => write!(f, "{element}"),
+                    false => write!(f, ", {element}"),
+                })?;
+                // Print the closing bracket and the array type.
+                write!(f, "] as {array_type}")
+            }
         }
     }
 }
@@ -213,6 +258,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u64,  2u64, 3u64 ] as [u64; 4u32]")?;
+        assert_eq!("[1u64, 2u64, 3u64] as [u64; 4u32]", candidate.to_string());
+        assert_eq!("", remainder);
+
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[] as [field; 1u32]")?;
+        assert_eq!("[] as [field; 1u32]", candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure an array member is printed within an interface.
+        let expected = r"{
+  foo: [true, false] as [boolean; 2u32],
+  bar: 5u8
+}";
+        let candidate = Plaintext::<CurrentNetwork>::from_str("{ foo: [true, false] as [boolean; 2u32], bar: 5u8 }")?;
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure the elements must match the array type.
+        assert!(Plaintext::<CurrentNetwork>::parse("[1u64, 2u64, 3u64] as [u64; 2u32]").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[1u64, 2u32] as [u64; 4u32]").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[1u64, 2u64,] as [u64; 4u32]").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[1u64, 2u64]").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
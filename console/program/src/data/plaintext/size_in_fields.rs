@@ -23,7 +23,7 @@ impl<N: Network> Visibility for Plaintext<N> {
     fn size_in_fields(&self) -> Result<u16> {
         // Compute the number of bits.
         let num_bits = self.to_bits_le().len() + 1; // 1 extra bit for the terminus indicator.
-        // Compute the ceiling division of the number of bits by the number of bits in a field element.
+                                                    // Compute the ceiling division of the number of bits by the number of bits in a field element.
         let num_fields = (num_bits + Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits();
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
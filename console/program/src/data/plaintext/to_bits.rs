@@ -43,6 +43,23 @@ impl<N: Network> ToBits for Plaintext<N> {
                     bits_le
                 })
                 .clone(),
+            Self::Array(array_type, elements, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![true, false]; // Variant bits.
+                    bits_le.extend(U8::<N>::new(array_type.element_type() as u8).to_bits_le());
+                    bits_le.extend(array_type.capacity().to_bits_le());
+                    bits_le.extend(U32::<N>::new(elements.len() as u32).to_bits_le());
+                    for element in elements {
+                        bits_le.extend(element.to_bits_le());
+                    }
+                    // Fill the unused capacity with zero bits, so the size of the array is determined by its type.
+                    let padding = array_type.padding().to_bits_le();
+                    for _ in elements.len()..**array_type.capacity() as usize {
+                        bits_le.extend(&padding);
+                    }
+                    bits_le
+                })
+                .clone(),
         }
     }
 
@@ -72,6 +89,22 @@ impl<N: Network> ToBits for Plaintext<N> {
                     bits_be
                 })
                 .clone(),
+            // Note: The array only caches its little-endian bits.
+            Self::Array(array_type, elements, _) => {
+                let mut bits_be = vec![true, false]; // Variant bits.
+                bits_be.extend(U8::<N>::new(array_type.element_type() as u8).to_bits_be());
+                bits_be.extend(array_type.capacity().to_bits_be());
+                bits_be.extend(U32::<N>::new(elements.len() as u32).to_bits_be());
+                for element in elements {
+                    bits_be.extend(element.to_bits_be());
+                }
+                // Fill the unused capacity with zero bits, so the size of the array is determined by its type.
+                let padding = array_type.padding().to_bits_be();
+                for _ in elements.len()..**array_type.capacity() as usize {
+                    bits_be.extend(&padding);
+                }
+                bits_be
+            }
         }
     }
 }
@@ -37,6 +37,23 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             ))(string)
         }
 
+        /// Parses an entry as an array: `[literal_0, ..., literal_n] as [element_type; capacity].visibility`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            // Parse the array from the string.
+            let (string, plaintext) = map_res(Plaintext::parse, |plaintext| match plaintext {
+                Plaintext::Array(..) => Ok(plaintext),
+                _ => Err(error("Expected an array")),
+            })(string)?;
+            // Parse the visibility from the string.
+            let (string, mode) = alt((
+                map(tag(".constant"), |_| Mode::Constant),
+                map(tag(".public"), |_| Mode::Public),
+                map(tag(".private"), |_| Mode::Private),
+            ))(string)?;
+            // Output the entry.
+            Ok((string, (plaintext, mode)))
+        }
+
         /// Parses a sanitized pair: `identifier: entry`.
         fn parse_pair<N: Network>(string: &str) -> ParserResult<(Identifier<N>, Plaintext<N>, Mode)> {
             // Parse the whitespace and comments from the string.
@@ -55,6 +72,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_literal,
                 // Parse an interface.
                 parse_interface,
+                // Parse an array.
+                parse_array,
             ))(string)?;
             // Return the identifier, plaintext, and visibility.
             Ok((string, (identifier, plaintext, mode)))
@@ -104,6 +123,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_literal,
             // Parse an interface.
             parse_interface,
+            // Parse an array.
+            parse_array,
         ))(string)?;
 
         // Return the entry.
@@ -163,6 +184,10 @@ impl<N: Network> Entry<N, Plaintext<N>> {
             Plaintext::Literal(literal, ..) => {
                 write!(f, "{:indent$}{literal}.{visibility}", "", indent = depth * INDENT)
             }
+            // Prints the array, i.e. [1u64, 2u64] as [u64; 4u32].public
+            Plaintext::Array(..) => {
+                write!(f, "{:indent$}{plaintext}.{visibility}", "", indent = depth * INDENT)
+            }
             // Prints the interface, i.e. { first: 10i64.private, second: 198u64.private }
             Plaintext::Interface(interface, ..) => {
                 // Print the opening brace.
@@ -171,15 +196,15 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                 interface.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
                     match plaintext {
                         #[rustfmt::skip]
-                        Plaintext::Literal(literal, ..) => match i == interface.len() - 1 {
+                        Plaintext::Literal(..) | Plaintext::Array(..) => match i == interface.len() - 1 {
                             true => {
                                 // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}.{visibility}", "", indent = (depth + 1) * INDENT)?;
+                                write!(f, "\n{:indent$}{name}: {plaintext}.{visibility}", "", indent = (depth + 1) * INDENT)?;
                                 // Print the closing brace.
                                 write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
                             }
                             // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
+                            false => write!(f, "\n{:indent$}{name}: {plaintext}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
                         Plaintext::Interface(..) => {
                             // Print the member name.
@@ -254,6 +279,22 @@ mod tests {
         assert_eq!(expected, candidate.to_string());
         assert_eq!("", remainder);
 
+        // Ensure an array entry is parsed, with its visibility.
+        let expected = "[1u64, 2u64] as [u64; 4u32].public";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(expected)?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [true] as [boolean; 2u32].private,
+  bar: 5u8.private
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: [true] as [boolean; 2u32].private, bar: 5u8.private }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
         Ok(())
     }
 }
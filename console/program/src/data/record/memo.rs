@@ -0,0 +1,339 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_console_account::{PrivateKey, Signature};
+
+/// The domain separator for the encryption of a record memo.
+const MEMO_ENCRYPTION_DOMAIN: &str = "AleoRecordMemoEncryption0";
+/// The domain separator for the authentication tag of a record memo.
+const MEMO_TAG_DOMAIN: &str = "AleoRecordMemoTag0";
+/// The domain separator for the signature of a record memo.
+const MEMO_SIGNATURE_DOMAIN: &str = "AleoRecordMemoSignature0";
+/// The number of bytes in a record memo.
+pub const MEMO_SIZE_IN_BYTES: usize = 64;
+
+/// A fixed-size memo, encrypted under the record view key of the record it accompanies,
+/// so that only the record owner can read it, such as a deposit identifier for an exchange.
+///
+/// The memo is optionally signed by the sender, in which case the signature binds the memo to the record nonce.
+/// The plaintext is packed as `flag || message || signature`, where the signature is zeroed if the memo is unsigned,
+/// so that every memo has the same size, and a signed memo is indistinguishable from an unsigned one.
+///
+/// For the record view key `rvk`, the memo is encrypted as:
+///     ciphertext[i] := plaintext[i] + HashManyPSD8(ENCRYPTION_DOMAIN, rvk)[i]
+///     tag := HashPSD8(TAG_DOMAIN, rvk, ciphertext)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecordMemo<N: Network> {
+    /// The encrypted memo.
+    ciphertext: Vec<Field<N>>,
+    /// The authentication tag of the encrypted memo.
+    tag: Field<N>,
+}
+
+/// A decrypted record memo, with the address of its signer, if it was signed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecryptedMemo<N: Network> {
+    /// The memo message, which is zero-padded to `MEMO_SIZE_IN_BYTES`.
+    message: [u8; MEMO_SIZE_IN_BYTES],
+    /// The address of the signer, if the memo was signed.
+    signer: Option<Address<N>>,
+}
+
+impl<N: Network> DecryptedMemo<N> {
+    /// Returns the memo message, which is zero-padded to `MEMO_SIZE_IN_BYTES`.
+    pub const fn message(&self) -> &[u8; MEMO_SIZE_IN_BYTES] {
+        &self.message
+    }
+
+    /// Returns the address of the signer, if the memo was signed.
+    pub const fn signer(&self) -> Option<Address<N>> {
+        self.signer
+    }
+}
+
+impl<N: Network> RecordMemo<N> {
+    /// Encrypts the given message for the owner of the given record,
+    /// where the randomizer corresponds to the record nonce.
+    pub fn encrypt(record: &Record<N, Plaintext<N>>, randomizer: Scalar<N>, message: &[u8]) -> Result<Self> {
+        Self::encrypt_internal(record, randomizer, message, None)
+    }
+
+    /// Signs the given message with the given private key, binding it to the given record,
+    /// and encrypts the signed message for the owner of the record.
+    pub fn sign_and_encrypt<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        record: &Record<N, Plaintext<N>>,
+        randomizer: Scalar<N>,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self> {
+        let message = Self::pad_message(message)?;
+        let signature = Signature::sign(private_key, &Self::signature_message(&record.nonce, &message)?, rng)?;
+        Self::encrypt_internal(record, randomizer, &message, Some(signature))
+    }
+
+    /// Decrypts the memo with the given view key, where the given record is the record the memo accompanies.
+    /// If the memo was signed, the signature is verified, and the signer is returned with the message.
+    pub fn decrypt(&self, record: &Record<N, Ciphertext<N>>, view_key: &ViewKey<N>) -> Result<DecryptedMemo<N>> {
+        // Ensure the view key is the record owner.
+        ensure!(record.is_owner(&view_key.to_address(), view_key), "The given view key is not the record owner");
+        // Compute the record view key.
+        let record_view_key = (record.nonce * **view_key).to_x_coordinate();
+
+        // Ensure the memo is well-formed, and the tag is valid.
+        ensure!(self.ciphertext.len() == Self::num_fields(), "Invalid record memo size: {}", self.ciphertext.len());
+        ensure!(self.tag == Self::compute_tag(&record_view_key, &self.ciphertext)?, "Invalid record memo tag");
+
+        // Decrypt the memo.
+        let randomizers = Self::randomizers(&record_view_key)?;
+        let mut plaintext = Vec::with_capacity(Self::num_fields() * Self::chunk_size());
+        for (ciphertext, randomizer) in self.ciphertext.iter().zip_eq(&randomizers) {
+            plaintext.extend_from_slice(&(*ciphertext - randomizer).to_bytes_le()?[..Self::chunk_size()]);
+        }
+
+        // Unpack the message.
+        let mut message = [0u8; MEMO_SIZE_IN_BYTES];
+        message.copy_from_slice(&plaintext[1..1 + MEMO_SIZE_IN_BYTES]);
+        // Unpack and verify the signature, if the memo was signed.
+        let signer = match plaintext[0] {
+            0 => None,
+            1 => {
+                let signature = Signature::<N>::read_le(&plaintext[1 + MEMO_SIZE_IN_BYTES..])?;
+                let signer = signature.to_address();
+                ensure!(
+                    signature.verify(&signer, &Self::signature_message(&record.nonce, &message)?),
+                    "Invalid record memo signature"
+                );
+                Some(signer)
+            }
+            flag => bail!("Invalid record memo flag {flag}"),
+        };
+        Ok(DecryptedMemo { message, signer })
+    }
+
+    /// Returns the authentication tag of the encrypted memo.
+    pub const fn tag(&self) -> Field<N> {
+        self.tag
+    }
+}
+
+impl<N: Network> RecordMemo<N> {
+    /// Returns the number of bytes packed into each field element.
+    fn chunk_size() -> usize {
+        Field::<N>::size_in_data_bits() / 8
+    }
+
+    /// Returns the number of bytes in a serialized signature.
+    fn signature_size_in_bytes() -> usize {
+        2 * Scalar::<N>::SIZE_IN_BYTES + 2 * Field::<N>::SIZE_IN_BYTES
+    }
+
+    /// Returns the number of field elements in an encrypted memo.
+    fn num_fields() -> usize {
+        let num_bytes = 1 + MEMO_SIZE_IN_BYTES + Self::signature_size_in_bytes();
+        (num_bytes + Self::chunk_size() - 1) / Self::chunk_size()
+    }
+
+    /// Returns the given message, zero-padded to `MEMO_SIZE_IN_BYTES`.
+    fn pad_message(message: &[u8]) -> Result<[u8; MEMO_SIZE_IN_BYTES]> {
+        ensure!(
+            message.len() <= MEMO_SIZE_IN_BYTES,
+            "A record memo may be at most {MEMO_SIZE_IN_BYTES} bytes, found {} bytes",
+            message.len()
+        );
+        let mut padded = [0u8; MEMO_SIZE_IN_BYTES];
+        padded[..message.len()].copy_from_slice(message);
+        Ok(padded)
+    }
+
+    /// Packs the given bytes into field elements, with `chunk_size` bytes in each field element.
+    fn pack(bytes: &[u8]) -> Result<Vec<Field<N>>> {
+        bytes
+            .chunks(Self::chunk_size())
+            .map(|chunk| {
+                let mut buffer = vec![0u8; Field::<N>::SIZE_IN_BYTES];
+                buffer[..chunk.len()].copy_from_slice(chunk);
+                Field::from_bytes_le(&buffer)
+            })
+            .collect()
+    }
+
+    /// Returns the signed message, as the domain separator, the record nonce, and the memo message.
+    fn signature_message(nonce: &Group<N>, message: &[u8; MEMO_SIZE_IN_BYTES]) -> Result<Vec<Field<N>>> {
+        let mut fields = vec![Field::new_domain_separator(MEMO_SIGNATURE_DOMAIN), nonce.to_x_coordinate()];
+        fields.extend(Self::pack(message)?);
+        Ok(fields)
+    }
+
+    /// Returns the randomizers for the encryption of a memo under the given record view key.
+    fn randomizers(record_view_key: &Field<N>) -> Result<Vec<Field<N>>> {
+        let num_randomizers = u16::try_from(Self::num_fields())?;
+        let preimage = [Field::new_domain_separator(MEMO_ENCRYPTION_DOMAIN), *record_view_key];
+        Ok(N::hash_many_psd8(&preimage, num_randomizers))
+    }
+
+    /// Returns the authentication tag of the given encrypted memo, under the given record view key.
+    fn compute_tag(record_view_key: &Field<N>, ciphertext: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(2 + ciphertext.len());
+        preimage.extend([Field::new_domain_separator(MEMO_TAG_DOMAIN), *record_view_key]);
+        preimage.extend_from_slice(ciphertext);
+        N::hash_psd8(&preimage)
+    }
+
+    /// Encrypts the given message and optional signature for the owner of the given record.
+    fn encrypt_internal(
+        record: &Record<N, Plaintext<N>>,
+        randomizer: Scalar<N>,
+        message: &[u8],
+        signature: Option<Signature<N>>,
+    ) -> Result<Self> {
+        // Ensure the randomizer corresponds to the record nonce.
+        ensure!(
+            record.nonce == N::g_scalar_multiply(&randomizer),
+            "Illegal operation: RecordMemo::encrypt() randomizer does not correspond to the record nonce."
+        );
+        // Compute the record view key.
+        let record_view_key = (**record.owner * randomizer).to_x_coordinate();
+
+        // Pack the plaintext as `flag || message || signature`, where the signature is zeroed if the memo is unsigned.
+        let mut plaintext = Vec::with_capacity(Self::num_fields() * Self::chunk_size());
+        plaintext.push(u8::from(signature.is_some()));
+        plaintext.extend_from_slice(&Self::pad_message(message)?);
+        match signature {
+            Some(signature) => plaintext.extend(signature.to_bytes_le()?),
+            None => plaintext.resize(plaintext.len() + Self::signature_size_in_bytes(), 0u8),
+        }
+
+        // Encrypt the plaintext.
+        let ciphertext = Self::pack(&plaintext)?
+            .into_iter()
+            .zip_eq(Self::randomizers(&record_view_key)?)
+            .map(|(plaintext, randomizer)| plaintext + randomizer)
+            .collect::<Vec<_>>();
+        let tag = Self::compute_tag(&record_view_key, &ciphertext)?;
+        Ok(Self { ciphertext, tag })
+    }
+}
+
+impl<N: Network> FromBytes for RecordMemo<N> {
+    /// Reads the record memo from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid record memo version"));
+        }
+        // Read the ciphertext, which has a fixed size.
+        let ciphertext = (0..Self::num_fields()).map(|_| Field::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the tag.
+        let tag = Field::read_le(&mut reader)?;
+        Ok(Self { ciphertext, tag })
+    }
+}
+
+impl<N: Network> ToBytes for RecordMemo<N> {
+    /// Writes the record memo to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the ciphertext is the fixed size.
+        if self.ciphertext.len() != Self::num_fields() {
+            return Err(error("Invalid record memo size"));
+        }
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+        // Write the ciphertext.
+        for field in &self.ciphertext {
+            field.write_le(&mut writer)?;
+        }
+        // Write the tag.
+        self.tag.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a record with a private owner, for the given owner, and returns its randomizer.
+    fn sample_record<R: Rng + CryptoRng>(
+        owner: Address<CurrentNetwork>,
+        rng: &mut R,
+    ) -> Result<(Record<CurrentNetwork, Plaintext<CurrentNetwork>>, Scalar<CurrentNetwork>)> {
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner: Owner::Private(Plaintext::from(Literal::Address(owner))),
+            gates: Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(rng) >> 12)))),
+            data: IndexMap::new(),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        Ok((record, randomizer))
+    }
+
+    #[test]
+    fn test_memo() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the sender and the recipient.
+        let sender = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let recipient = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let other = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        let (record, randomizer) = sample_record(recipient.to_address(), rng)?;
+        let ciphertext = record.encrypt(randomizer)?;
+
+        // Ensure an unsigned memo decrypts for the recipient only.
+        let memo = RecordMemo::encrypt(&record, randomizer, b"deposit-42")?;
+        let decrypted = memo.decrypt(&ciphertext, &recipient)?;
+        assert_eq!(b"deposit-42", &decrypted.message()[..10]);
+        assert!(decrypted.message()[10..].iter().all(|byte| *byte == 0));
+        assert_eq!(None, decrypted.signer());
+        assert!(memo.decrypt(&ciphertext, &other).is_err());
+
+        // Ensure a signed memo returns the signer, and has the same size as an unsigned memo.
+        let signed = RecordMemo::sign_and_encrypt(&sender, &record, randomizer, b"deposit-42", rng)?;
+        assert_eq!(Some(Address::try_from(&sender)?), signed.decrypt(&ciphertext, &recipient)?.signer());
+        assert_eq!(memo.to_bytes_le()?.len(), signed.to_bytes_le()?.len());
+        assert_eq!(signed, RecordMemo::read_le(&signed.to_bytes_le()?[..])?);
+
+        // Ensure an oversized message is rejected.
+        assert!(RecordMemo::encrypt(&record, randomizer, &[1u8; MEMO_SIZE_IN_BYTES + 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_memo_is_bound_to_record() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let recipient = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let (record, randomizer) = sample_record(recipient.to_address(), rng)?;
+        let (other_record, other_randomizer) = sample_record(recipient.to_address(), rng)?;
+
+        // Ensure a memo does not decrypt alongside another record of the recipient.
+        let memo = RecordMemo::encrypt(&record, randomizer, b"memo")?;
+        assert!(memo.decrypt(&other_record.encrypt(other_randomizer)?, &recipient).is_err());
+
+        // Ensure a tampered memo is rejected.
+        let mut tampered = memo.clone();
+        tampered.ciphertext[0] += Field::one();
+        assert!(tampered.decrypt(&record.encrypt(randomizer)?, &recipient).is_err());
+        Ok(())
+    }
+}
@@ -20,6 +20,12 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::{Balance, Owner};
 
+mod memo;
+pub use memo::{DecryptedMemo, RecordMemo, MEMO_SIZE_IN_BYTES};
+
+mod recipients;
+pub use recipients::{MultiRecipientRecord, RecipientKey, MAX_RECORD_RECIPIENTS};
+
 mod bytes;
 mod decrypt;
 mod encrypt;
@@ -36,7 +42,7 @@ mod to_fields;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
 use snarkvm_console_account::{Address, ViewKey};
-use snarkvm_console_network::prelude::*;
+use snarkvm_console_network::{fmt_redacted, prelude::*, Revealed};
 use snarkvm_console_types::{Boolean, Field, Group, Scalar, U64};
 
 use indexmap::IndexMap;
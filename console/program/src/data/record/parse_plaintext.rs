@@ -145,9 +145,16 @@ impl<N: Network> FromStr for Record<N, Plaintext<N>> {
 }
 
 impl<N: Network> Debug for Record<N, Plaintext<N>> {
-    /// Prints the record as a string.
+    /// Prints the fingerprint of the record, without revealing the record contents.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
+        fmt_redacted::<N>(f, "Record", &self.to_fields().map_err(|_| fmt::Error)?)
+    }
+}
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns a reference to the record, whose `Debug` implementation prints the record contents in full.
+    pub const fn reveal(&self) -> Revealed<Self> {
+        Revealed::new(self)
     }
 }
 
@@ -176,10 +183,13 @@ impl<N: Network> Record<N, Plaintext<N>> {
             write!(f, "\n{:indent$}{identifier}: ", "", indent = (depth + 1) * INDENT)?;
             // Print the entry.
             match entry {
-                // If the entry is a literal, print the entry without indentation.
+                // If the entry is a literal or an array, print the entry without indentation.
                 Entry::Constant(Plaintext::Literal(..))
                 | Entry::Public(Plaintext::Literal(..))
-                | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
+                | Entry::Private(Plaintext::Literal(..))
+                | Entry::Constant(Plaintext::Array(..))
+                | Entry::Public(Plaintext::Array(..))
+                | Entry::Private(Plaintext::Array(..)) => write!(f, "{entry}")?,
                 // If the entry is an interface, print the entry with indentation.
                 Entry::Constant(Plaintext::Interface(..))
                 | Entry::Public(Plaintext::Interface(..))
@@ -264,6 +274,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_debug_is_redacted() -> Result<()> {
+        let given = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 987654321u64.private, foo: 123456789field.private, _nonce: 0group.public }";
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(given)?;
+
+        // Ensure the debug representation does not reveal the record contents.
+        let candidate = format!("{record:?}");
+        assert!(candidate.starts_with("Record(<redacted "));
+        for secret in ["aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah", "987654321", "123456789"] {
+            assert!(!candidate.contains(secret));
+        }
+        // Ensure the revealed representation matches the string representation.
+        assert_eq!(record.to_string(), format!("{:?}", record.reveal()));
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Missing owner.
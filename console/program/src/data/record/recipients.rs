@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_console_account::address::SharedSecret;
+
+/// The domain separator for the wrapping of a record view key.
+const RECIPIENT_KEY_DOMAIN: &str = "AleoRecordRecipientKey0";
+/// The domain separator for the tag of a recipient key.
+const RECIPIENT_TAG_DOMAIN: &str = "AleoRecordRecipientTag0";
+/// The maximum number of additional recipients of a record.
+pub const MAX_RECORD_RECIPIENTS: usize = 16;
+
+/// The record view key of a record, wrapped for one additional recipient, such as an auditor or co-signer.
+///
+/// For a recipient address `A`, the wrapper samples an ephemeral scalar `e`, and computes:
+///     shared_secret := SharedSecret(A, e)
+///     tag := HashPSD2(TAG_DOMAIN, shared_secret)
+///     wrapped_key := record_view_key + HashPSD2(KEY_DOMAIN, shared_secret)
+/// The recipient recomputes the shared secret from the ephemeral key `e * G` with its view key,
+/// finds its recipient key by the tag, and unwraps the record view key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecipientKey<N: Network> {
+    /// The ephemeral key `e * G` of the wrapper.
+    ephemeral_key: Address<N>,
+    /// The tag, which identifies the recipient key to its recipient.
+    tag: Field<N>,
+    /// The wrapped record view key.
+    wrapped_key: Field<N>,
+}
+
+impl<N: Network> RecipientKey<N> {
+    /// Wraps the given record view key for the given recipient address.
+    fn new<R: Rng + CryptoRng>(record_view_key: Field<N>, recipient: &Address<N>, rng: &mut R) -> Result<Self> {
+        let ephemeral = Scalar::rand(rng);
+        let shared_secret = recipient.shared_secret(&ephemeral)?;
+        Ok(Self {
+            ephemeral_key: Address::new(N::g_scalar_multiply(&ephemeral)),
+            tag: N::hash_psd2(&[Field::new_domain_separator(RECIPIENT_TAG_DOMAIN), shared_secret])?,
+            wrapped_key: record_view_key
+                + N::hash_psd2(&[Field::new_domain_separator(RECIPIENT_KEY_DOMAIN), shared_secret])?,
+        })
+    }
+
+    /// Returns the record view key, if this recipient key was wrapped for the given view key.
+    fn unwrap(&self, view_key: &ViewKey<N>) -> Result<Option<Field<N>>> {
+        let shared_secret = view_key.shared_secret(&self.ephemeral_key)?;
+        if self.tag != N::hash_psd2(&[Field::new_domain_separator(RECIPIENT_TAG_DOMAIN), shared_secret])? {
+            return Ok(None);
+        }
+        let mask = N::hash_psd2(&[Field::new_domain_separator(RECIPIENT_KEY_DOMAIN), shared_secret])?;
+        Ok(Some(self.wrapped_key - mask))
+    }
+
+    /// Returns the ephemeral key of the wrapper.
+    pub const fn ephemeral_key(&self) -> Address<N> {
+        self.ephemeral_key
+    }
+
+    /// Returns the tag, which identifies the recipient key to its recipient.
+    pub const fn tag(&self) -> Field<N> {
+        self.tag
+    }
+}
+
+/// A record ciphertext, which is decryptable by the view key of its owner,
+/// and by the view key of each additional recipient, such as an auditor or co-signer.
+///
+/// The record ciphertext is unchanged, and the record view key is wrapped for each additional recipient.
+/// The recipients are not revealed by the recipient keys, and each recipient can only identify its own recipient key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiRecipientRecord<N: Network> {
+    /// The record ciphertext.
+    record: Record<N, Ciphertext<N>>,
+    /// The recipient keys of the additional recipients.
+    recipients: Vec<RecipientKey<N>>,
+}
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Encrypts `self` for the record owner under the given randomizer, and for each of the given recipients.
+    pub fn encrypt_for_recipients<R: Rng + CryptoRng>(
+        &self,
+        randomizer: Scalar<N>,
+        recipients: &[Address<N>],
+        rng: &mut R,
+    ) -> Result<MultiRecipientRecord<N>> {
+        // Ensure the randomizer corresponds to the record nonce.
+        ensure!(
+            self.nonce == N::g_scalar_multiply(&randomizer),
+            "Illegal operation: Record::encrypt_for_recipients() randomizer does not correspond to the record nonce."
+        );
+        // Compute the record view key, and encrypt the record.
+        let record_view_key = (**self.owner * randomizer).to_x_coordinate();
+        let mut record = MultiRecipientRecord { record: self.encrypt_symmetric(&record_view_key)?, recipients: vec![] };
+        // Wrap the record view key for each recipient.
+        for recipient in recipients {
+            record.add_recipient_with_record_view_key(record_view_key, recipient, rng)?;
+        }
+        Ok(record)
+    }
+}
+
+impl<N: Network> MultiRecipientRecord<N> {
+    /// Returns the record ciphertext.
+    pub const fn record(&self) -> &Record<N, Ciphertext<N>> {
+        &self.record
+    }
+
+    /// Returns the recipient keys of the additional recipients.
+    pub fn recipients(&self) -> &[RecipientKey<N>] {
+        &self.recipients
+    }
+
+    /// Returns `true` if the given view key can decrypt the record, as the owner or an additional recipient.
+    pub fn is_recipient(&self, view_key: &ViewKey<N>) -> bool {
+        matches!(self.record_view_key(view_key), Ok(Some(_)))
+    }
+
+    /// Adds the given recipient, where the given view key must be able to decrypt the record.
+    pub fn add_recipient<R: Rng + CryptoRng>(
+        &mut self,
+        view_key: &ViewKey<N>,
+        recipient: &Address<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        match self.record_view_key(view_key)? {
+            Some(record_view_key) => self.add_recipient_with_record_view_key(record_view_key, recipient, rng),
+            None => bail!("The given view key can not decrypt the record"),
+        }
+    }
+
+    /// Decrypts the record with the given view key, as the owner or an additional recipient.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
+        match self.record_view_key(view_key)? {
+            Some(record_view_key) => self.record.decrypt_symmetric(&record_view_key),
+            None => bail!("The given view key is not a recipient of the record"),
+        }
+    }
+
+    /// Returns the record view key for the given view key, if it is the owner or an additional recipient.
+    fn record_view_key(&self, view_key: &ViewKey<N>) -> Result<Option<Field<N>>> {
+        // Check if the view key is the owner.
+        if self.record.is_owner(&view_key.to_address(), view_key) {
+            return Ok(Some((self.record.nonce * **view_key).to_x_coordinate()));
+        }
+        // Check if the view key is an additional recipient.
+        for recipient in &self.recipients {
+            if let Some(record_view_key) = recipient.unwrap(view_key)? {
+                return Ok(Some(record_view_key));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Wraps the given record view key for the given recipient, and adds the recipient key.
+    fn add_recipient_with_record_view_key<R: Rng + CryptoRng>(
+        &mut self,
+        record_view_key: Field<N>,
+        recipient: &Address<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        ensure!(
+            self.recipients.len() < MAX_RECORD_RECIPIENTS,
+            "A record may have at most {MAX_RECORD_RECIPIENTS} additional recipients"
+        );
+        self.recipients.push(RecipientKey::new(record_view_key, recipient, rng)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a view key.
+    fn sample_view_key<R: Rng + CryptoRng>(rng: &mut R) -> Result<ViewKey<CurrentNetwork>> {
+        ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)
+    }
+
+    /// Samples a record with a private owner and private data, for the given owner, and returns its randomizer.
+    fn sample_record<R: Rng + CryptoRng>(
+        owner: Address<CurrentNetwork>,
+        rng: &mut R,
+    ) -> Result<(Record<CurrentNetwork, Plaintext<CurrentNetwork>>, Scalar<CurrentNetwork>)> {
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner: Owner::Private(Plaintext::from(Literal::Address(owner))),
+            gates: Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(rng) >> 12)))),
+            data: IndexMap::from_iter([(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        Ok((record, randomizer))
+    }
+
+    #[test]
+    fn test_encrypt_for_recipients() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the owner, the auditor, the co-signer, and another account.
+        let [owner, auditor, co_signer, other] =
+            [sample_view_key(rng)?, sample_view_key(rng)?, sample_view_key(rng)?, sample_view_key(rng)?];
+
+        // Encrypt the record for the owner and the auditor.
+        let (record, randomizer) = sample_record(owner.to_address(), rng)?;
+        let mut ciphertext = record.encrypt_for_recipients(randomizer, &[auditor.to_address()], rng)?;
+        assert_eq!(1, ciphertext.recipients().len());
+        assert_eq!(record.encrypt(randomizer)?, *ciphertext.record());
+
+        // Ensure the owner and the auditor can decrypt the record, and the others can not.
+        assert_eq!(record, ciphertext.decrypt(&owner)?);
+        assert_eq!(record, ciphertext.decrypt(&auditor)?);
+        assert!(ciphertext.decrypt(&co_signer).is_err());
+        assert!(!ciphertext.is_recipient(&other));
+
+        // Ensure the auditor can add the co-signer, and the other account can not add itself.
+        ciphertext.add_recipient(&auditor, &co_signer.to_address(), rng)?;
+        assert_eq!(record, ciphertext.decrypt(&co_signer)?);
+        assert!(ciphertext.add_recipient(&other, &other.to_address(), rng).is_err());
+        assert_eq!(2, ciphertext.recipients().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_recipients() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let owner = sample_view_key(rng)?;
+        let (record, randomizer) = sample_record(owner.to_address(), rng)?;
+
+        // Ensure the number of recipients is bounded.
+        let recipients = (0..=MAX_RECORD_RECIPIENTS)
+            .map(|_| sample_view_key(rng).map(|view_key| view_key.to_address()))
+            .collect::<Result<Vec<_>>>()?;
+        assert!(record.encrypt_for_recipients(randomizer, &recipients[..MAX_RECORD_RECIPIENTS], rng).is_ok());
+        assert!(record.encrypt_for_recipients(randomizer, &recipients, rng).is_err());
+        Ok(())
+    }
+}
@@ -37,10 +37,13 @@ impl<N: Network> Parser for Register<N> {
                 }
             })(string)?;
         // Return the register.
-        Ok((string, match identifiers.len() {
-            0 => Self::Locator(locator),
-            _ => Self::Member(locator, identifiers),
-        }))
+        Ok((
+            string,
+            match identifiers.len() {
+                0 => Self::Locator(locator),
+                _ => Self::Member(locator, identifiers),
+            },
+        ))
     }
 }
 
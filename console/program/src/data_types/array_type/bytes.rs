@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ArrayType<N> {
+    /// Reads an array type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        let element_type = LiteralType::read_le(&mut reader)?;
+        // Read the capacity.
+        let capacity = U32::read_le(&mut reader)?;
+        // Return the array type.
+        Self::new(element_type, capacity).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ArrayType<N> {
+    /// Writes an array type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        self.element_type.write_le(&mut writer)?;
+        // Write the capacity.
+        self.capacity.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = ArrayType::<CurrentNetwork>::from_str("[u64; 32u32]")?;
+        let candidate = ArrayType::read_le(&expected.to_bytes_le()?[..])?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a capacity beyond `MAX_ARRAY_CAPACITY` fails to deserialize.
+        let mut bytes = LiteralType::U64.to_bytes_le()?;
+        bytes.extend((CurrentNetwork::MAX_ARRAY_CAPACITY + 1).to_bytes_le()?);
+        assert!(ArrayType::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
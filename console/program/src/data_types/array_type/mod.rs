@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+
+use crate::{Literal, LiteralType};
+use snarkvm_console_network::Network;
+use snarkvm_console_types::{prelude::*, Boolean};
+
+/// An `ArrayType` defines the element type and capacity of an array.
+/// The number of elements in an array may vary from zero up to its capacity.
+/// The format of the type is `[<element_type>; <capacity>]`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The literal type of the elements.
+    element_type: LiteralType,
+    /// The maximum number of elements.
+    capacity: U32<N>,
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type.
+    pub fn new(element_type: LiteralType, capacity: U32<N>) -> Result<Self> {
+        // Ensure the elements have a fixed size, so the size of the array is determined by its type.
+        ensure!(element_type != LiteralType::String, "An array cannot contain elements of type '{element_type}'");
+        // Ensure the capacity is within `N::MAX_ARRAY_CAPACITY`.
+        ensure!(
+            *capacity > 0 && *capacity <= N::MAX_ARRAY_CAPACITY,
+            "An array must have a capacity between 1 and {}, found {capacity}",
+            N::MAX_ARRAY_CAPACITY
+        );
+        Ok(Self { element_type, capacity })
+    }
+
+    /// Returns the literal type of the elements.
+    #[inline]
+    pub const fn element_type(&self) -> LiteralType {
+        self.element_type
+    }
+
+    /// Returns the maximum number of elements.
+    #[inline]
+    pub const fn capacity(&self) -> &U32<N> {
+        &self.capacity
+    }
+
+    /// Returns the literal that fills the unused capacity of an array, whose bits are all zero.
+    pub fn padding(&self) -> Literal<N> {
+        match self.element_type {
+            LiteralType::Address => Literal::Address(Address::new(Group::zero())),
+            LiteralType::Boolean => Literal::Boolean(Boolean::new(false)),
+            LiteralType::Field => Literal::Field(Field::zero()),
+            LiteralType::Group => Literal::Group(Group::zero()),
+            LiteralType::I8 => Literal::I8(I8::zero()),
+            LiteralType::I16 => Literal::I16(I16::zero()),
+            LiteralType::I32 => Literal::I32(I32::zero()),
+            LiteralType::I64 => Literal::I64(I64::zero()),
+            LiteralType::I128 => Literal::I128(I128::zero()),
+            LiteralType::U8 => Literal::U8(U8::zero()),
+            LiteralType::U16 => Literal::U16(U16::zero()),
+            LiteralType::U32 => Literal::U32(U32::zero()),
+            LiteralType::U64 => Literal::U64(U64::zero()),
+            LiteralType::U128 => Literal::U128(U128::zero()),
+            LiteralType::Scalar => Literal::Scalar(Scalar::zero()),
+            LiteralType::I256 => Literal::I256(I256::zero()),
+            LiteralType::U256 => Literal::U256(U256::zero()),
+            // Note: This case is unreachable, as an array cannot contain strings.
+            LiteralType::String => Literal::String(StringType::new("")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() {
+        assert!(ArrayType::<CurrentNetwork>::new(LiteralType::U64, U32::new(1)).is_ok());
+        assert!(
+            ArrayType::<CurrentNetwork>::new(LiteralType::Field, U32::new(CurrentNetwork::MAX_ARRAY_CAPACITY)).is_ok()
+        );
+
+        // Ensure the capacity is bounded.
+        assert!(ArrayType::<CurrentNetwork>::new(LiteralType::U64, U32::new(0)).is_err());
+        assert!(
+            ArrayType::<CurrentNetwork>::new(LiteralType::U64, U32::new(CurrentNetwork::MAX_ARRAY_CAPACITY + 1))
+                .is_err()
+        );
+        // Ensure the elements have a fixed size.
+        assert!(ArrayType::<CurrentNetwork>::new(LiteralType::String, U32::new(8)).is_err());
+    }
+
+    #[test]
+    fn test_padding() -> Result<()> {
+        for element_type in [
+            LiteralType::Address,
+            LiteralType::Boolean,
+            LiteralType::Field,
+            LiteralType::Group,
+            LiteralType::I8,
+            LiteralType::I16,
+            LiteralType::I32,
+            LiteralType::I64,
+            LiteralType::I128,
+            LiteralType::U8,
+            LiteralType::U16,
+            LiteralType::U32,
+            LiteralType::U64,
+            LiteralType::U128,
+            LiteralType::Scalar,
+            LiteralType::I256,
+            LiteralType::U256,
+        ] {
+            let padding = ArrayType::<CurrentNetwork>::new(element_type, U32::new(8))?.padding();
+            // Ensure the padding is of the element type, and its bits are all zero.
+            assert_eq!(element_type, padding.to_type());
+            assert!(padding.to_bits_le().iter().all(|bit| !bit));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for ArrayType<N> {
+    /// Parses a string into an array type, i.e. `[u64; 32u32]`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opening bracket '[' from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string.
+        let (string, element_type) = LiteralType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' from the string.
+        let (string, _) = tag(";")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the capacity from the string.
+        let (string, array_type) = map_res(U32::parse, |capacity| Self::new(element_type, capacity))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the closing bracket ']' from the string.
+        let (string, _) = tag("]")(string)?;
+        // Return the array type.
+        Ok((string, array_type))
+    }
+}
+
+impl<N: Network> FromStr for ArrayType<N> {
+    type Err = Error;
+
+    /// Returns an array type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayType<N> {
+    /// Prints the array type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayType<N> {
+    /// Prints the array type as a string, i.e. `[u64; 32u32]`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type, self.capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let expected = ArrayType::<CurrentNetwork>::new(LiteralType::U64, U32::new(32))?;
+        assert_eq!(ArrayType::parse("[u64; 32u32]"), Ok(("", expected)));
+        assert_eq!(ArrayType::parse("[ u64 ;32u32 ]"), Ok(("", expected)));
+        assert_eq!(ArrayType::parse("[u64; 32u32].private"), Ok((".private", expected)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // The capacity must be a `u32`.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 32u8]").is_err());
+        // The capacity must be within bounds.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 0u32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 257u32]").is_err());
+        // The elements must have a fixed size.
+        assert!(ArrayType::<CurrentNetwork>::parse("[string; 32u32]").is_err());
+        // The element type must be a literal type.
+        assert!(ArrayType::<CurrentNetwork>::parse("[token; 32u32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[[u64; 2u32]; 32u32]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[ field ;8u32 ]")?.to_string(), "[field; 8u32]");
+        Ok(())
+    }
+}
@@ -58,6 +58,10 @@ pub enum LiteralType {
     Scalar,
     /// The string type.
     String,
+    /// The 256-bit signed integer type.
+    I256,
+    /// The 256-bit unsigned integer type.
+    U256,
 }
 
 impl LiteralType {
@@ -80,6 +84,8 @@ impl LiteralType {
             Self::U128 => "u128",
             Self::Scalar => "scalar",
             Self::String => "string",
+            Self::I256 => "i256",
+            Self::U256 => "u256",
         }
     }
 }
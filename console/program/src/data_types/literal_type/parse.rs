@@ -38,6 +38,8 @@ impl Parser for LiteralType {
             map(tag("u128"), |_| Self::U128),
             map(tag("scalar"), |_| Self::Scalar),
             map(tag("string"), |_| Self::String),
+            map(tag("i256"), |_| Self::I256),
+            map(tag("u256"), |_| Self::U256),
         ))(string)
     }
 }
@@ -23,7 +23,8 @@ impl<N: Network> FromBytes for PlaintextType<N> {
         match variant {
             0 => Ok(Self::Literal(LiteralType::read_le(&mut reader)?)),
             1 => Ok(Self::Interface(Identifier::read_le(&mut reader)?)),
-            2.. => Err(error(format!("Failed to deserialize annotation variant {variant}"))),
+            2 => Ok(Self::Array(ArrayType::read_le(&mut reader)?)),
+            3.. => Err(error(format!("Failed to deserialize annotation variant {variant}"))),
         }
     }
 }
@@ -40,6 +41,10 @@ impl<N: Network> ToBytes for PlaintextType<N> {
                 u8::write_le(&1u8, &mut writer)?;
                 identifier.write_le(&mut writer)
             }
+            Self::Array(array_type) => {
+                u8::write_le(&2u8, &mut writer)?;
+                array_type.write_le(&mut writer)
+            }
         }
     }
 }
@@ -18,7 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{Identifier, LiteralType};
+use crate::{ArrayType, Identifier, LiteralType};
 use snarkvm_console_network::prelude::*;
 
 /// A `ValueType` defines the type parameter for an entry in an `Interface`.
@@ -30,6 +30,9 @@ pub enum PlaintextType<N: Network> {
     /// An interface type contains its identifier.
     /// The format of the type is `<identifier>`.
     Interface(Identifier<N>),
+    /// An array type contains its element type and capacity.
+    /// The format of the type is `[<element_type>; <capacity>]`.
+    Array(ArrayType<N>),
 }
 
 impl<N: Network> From<LiteralType> for PlaintextType<N> {
@@ -39,6 +42,13 @@ impl<N: Network> From<LiteralType> for PlaintextType<N> {
     }
 }
 
+impl<N: Network> From<ArrayType<N>> for PlaintextType<N> {
+    /// Initializes a plaintext type from an array type.
+    fn from(array: ArrayType<N>) -> Self {
+        PlaintextType::Array(array)
+    }
+}
+
 impl<N: Network> From<Identifier<N>> for PlaintextType<N> {
     /// Initializes a plaintext type from an interface type.
     fn from(interface: Identifier<N>) -> Self {
@@ -24,6 +24,7 @@ impl<N: Network> Parser for PlaintextType<N> {
         alt((
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Interface(identifier)),
+            map(ArrayType::parse, |array| Self::Array(array)),
         ))(string)
     }
 }
@@ -60,6 +61,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Literal(literal) => Display::fmt(literal, f),
             // Prints the interface, i.e. signature
             Self::Interface(interface) => Display::fmt(interface, f),
+            // Prints the array, i.e. [u64; 32u32]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -81,6 +84,10 @@ mod tests {
             PlaintextType::parse("signature"),
             Ok(("", PlaintextType::<CurrentNetwork>::Interface(Identifier::from_str("signature")?)))
         );
+        assert_eq!(
+            PlaintextType::parse("[u64; 32u32]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[u64; 32u32]")?)))
+        );
         Ok(())
     }
 
@@ -153,6 +160,10 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Interface(Identifier::from_str("signature")?).to_string(),
             "signature"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[u64; 32u32]")?).to_string(),
+            "[u64; 32u32]"
+        );
         Ok(())
     }
 }
@@ -25,7 +25,7 @@ mod verify;
 
 use crate::{Identifier, ProgramID, Value, ValueType};
 use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
-use snarkvm_console_network::Network;
+use snarkvm_console_network::{fingerprint, Network, Revealed};
 use snarkvm_console_types::prelude::*;
 
 #[derive(Clone, PartialEq, Eq)]
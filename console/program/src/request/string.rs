@@ -26,9 +26,31 @@ impl<N: Network> FromStr for Request<N> {
 }
 
 impl<N: Network> Debug for Request<N> {
-    /// Prints the request as a JSON-string.
+    /// Prints the public components of the request, and the fingerprint of its inputs and secrets.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
+        // Collect the inputs and secrets of the request.
+        let mut secrets = vec![self.sk_tag, self.tvk, self.tsk.to_field().map_err(|_| fmt::Error)?];
+        for input in self.inputs.iter() {
+            secrets.extend(input.to_fields().map_err(|_| fmt::Error)?);
+        }
+
+        f.debug_struct("Request")
+            .field("caller", &self.caller)
+            .field("network_id", &format_args!("{}", self.network_id))
+            .field("program_id", &self.program_id)
+            .field("function_name", &self.function_name)
+            .field("input_ids", &self.input_ids)
+            .field("signature", &self.signature)
+            .field("tcm", &format_args!("{}", self.tcm))
+            .field("secrets", &format_args!("<redacted {}>", fingerprint::<N>(&secrets).map_err(|_| fmt::Error)?))
+            .finish()
+    }
+}
+
+impl<N: Network> Request<N> {
+    /// Returns a reference to the request, whose `Debug` implementation prints the request as a JSON-string.
+    pub const fn reveal(&self) -> Revealed<Self> {
+        Revealed::new(self)
     }
 }
 
@@ -55,4 +77,24 @@ mod tests {
             assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
         }
     }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        for request in test_helpers::sample_requests().into_iter() {
+            // Ensure the debug representation does not reveal the inputs or the secrets of the request.
+            let candidate = format!("{request:?}");
+            assert!(candidate.starts_with("Request {"));
+            for secret in [request.sk_tag().to_string(), request.tvk().to_string(), request.tsk().to_string()] {
+                assert!(!candidate.contains(&secret));
+            }
+            for input in request.inputs() {
+                assert!(!candidate.contains(&input.to_string()));
+            }
+            // Ensure the public components of the request are shown.
+            assert!(candidate.contains(&request.caller().to_string()));
+            assert!(candidate.contains(&request.tcm().to_string()));
+            // Ensure the revealed representation matches the string representation.
+            assert_eq!(request.to_string(), format!("{:?}", request.reveal()));
+        }
+    }
 }
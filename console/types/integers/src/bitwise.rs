@@ -279,6 +279,58 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
     }
 }
 
+impl<E: Environment, I: IntegerType, M: Magnitude> Rotl<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the left by `n` bits, where `n` is taken modulo the number of bits in `self`.
+    #[inline]
+    fn rotl(&self, n: &Integer<E, M>) -> Self::Output {
+        // Unwrap is safe as we only cast up.
+        Integer::new(self.integer.rotate_left(n.integer.to_u32().unwrap() % I::BITS as u32))
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Rotr<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the right by `n` bits, where `n` is taken modulo the number of bits in `self`.
+    #[inline]
+    fn rotr(&self, n: &Integer<E, M>) -> Self::Output {
+        // Unwrap is safe as we only cast up.
+        Integer::new(self.integer.rotate_right(n.integer.to_u32().unwrap() % I::BITS as u32))
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> FunnelShl<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Shifts `first` to the left by `n` bits, filling the vacated bits with the upper bits of `second`,
+    /// where `n` is taken modulo the number of bits in `first`.
+    #[inline]
+    fn funnel_shl(first: &Self, second: &Self, n: &Integer<E, M>) -> Self::Output {
+        // Unwrap is safe as we only cast up.
+        match n.integer.to_u32().unwrap() % I::BITS as u32 {
+            0 => *first,
+            n => Integer::new(first.integer.wrapping_shl(n) | second.integer.unsigned_shr(I::BITS as u32 - n)),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> FunnelShr<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Shifts `second` to the right by `n` bits, filling the vacated bits with the lower bits of `first`,
+    /// where `n` is taken modulo the number of bits in `second`.
+    #[inline]
+    fn funnel_shr(first: &Self, second: &Self, n: &Integer<E, M>) -> Self::Output {
+        // Unwrap is safe as we only cast up.
+        match n.integer.to_u32().unwrap() % I::BITS as u32 {
+            0 => *second,
+            n => Integer::new(second.integer.unsigned_shr(n) | first.integer.wrapping_shl(I::BITS as u32 - n)),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
     type Boolean = Boolean<E>;
     type Output = Self;
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Fixed<E, FRACTIONAL_BITS> {
+    /// Returns the `sum` of `self` and `other`, or `None` on overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_raw)
+    }
+
+    /// Returns the `difference` of `self` and `other`, or `None` on overflow.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_raw)
+    }
+
+    /// Returns the `product` of `self` and `other`, rounded with the given mode, or `None` on overflow.
+    pub fn checked_mul(&self, other: &Self, mode: RoundingMode) -> Option<Self> {
+        // Note: The product is exact, as the magnitude of the product of two `i64` values is at most 2^126.
+        let product = self.raw as i128 * other.raw as i128;
+        div_round(product, 1 << FRACTIONAL_BITS, mode).map(Self::from_raw)
+    }
+
+    /// Returns the `quotient` of `self` and `other`, rounded with the given mode, or `None` on overflow
+    /// or division by zero.
+    pub fn checked_div(&self, other: &Self, mode: RoundingMode) -> Option<Self> {
+        // Note: The scaled dividend is exact, as its magnitude is at most 2^125.
+        let dividend = (self.raw as i128) << FRACTIONAL_BITS;
+        div_round(dividend, other.raw as i128, mode).map(Self::from_raw)
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Neg for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Self;
+
+    /// Returns the `negation` of `self`.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.raw.checked_neg() {
+            Some(raw) => Self::from_raw(raw),
+            None => E::halt(format!("Fixed-point negation failed on: {self}")),
+        }
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Add<Self> for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Self;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: Self) -> Self::Output {
+        match self.checked_add(&other) {
+            Some(sum) => sum,
+            None => E::halt(format!("Fixed-point addition failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Sub<Self> for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: Self) -> Self::Output {
+        match self.checked_sub(&other) {
+            Some(difference) => difference,
+            None => E::halt(format!("Fixed-point subtraction failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Mul<Self> for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`, rounded towards zero.
+    #[inline]
+    fn mul(self, other: Self) -> Self::Output {
+        match self.checked_mul(&other, RoundingMode::TowardZero) {
+            Some(product) => product,
+            None => E::halt(format!("Fixed-point multiplication failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Div<Self> for Fixed<E, FRACTIONAL_BITS> {
+    type Output = Self;
+
+    /// Returns the `quotient` of `self` and `other`, rounded towards zero.
+    #[inline]
+    fn div(self, other: Self) -> Self::Output {
+        match self.checked_div(&other, RoundingMode::TowardZero) {
+            Some(quotient) => quotient,
+            None => E::halt(format!("Fixed-point division failed on: {self} and {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+    type Fixed16 = Fixed<CurrentEnvironment, 16>;
+
+    const ITERATIONS: u64 = 1000;
+
+    /// Returns the fixed-point number for the given value, which must be exactly representable.
+    fn fixed(value: f64) -> Fixed16 {
+        Fixed16::from_raw((value * 65536.0) as i64)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(fixed(3.75), fixed(1.5) + fixed(2.25));
+        assert_eq!(fixed(-0.75), fixed(1.5) - fixed(2.25));
+        assert_eq!(fixed(3.375), fixed(1.5) * fixed(2.25));
+        assert_eq!(fixed(-3.375), -fixed(1.5) * fixed(2.25));
+        assert_eq!(fixed(0.625), fixed(1.5625) / fixed(2.5));
+        assert_eq!(fixed(-2.5), fixed(6.25) / fixed(-2.5));
+
+        // Ensure the rounding modes apply to the last fractional bit.
+        let epsilon = Fixed16::from_raw(1);
+        let half = fixed(0.5);
+        assert_eq!(Some(Fixed16::zero()), epsilon.checked_mul(&half, RoundingMode::TowardZero));
+        assert_eq!(Some(epsilon), epsilon.checked_mul(&half, RoundingMode::Ceil));
+        assert_eq!(Some(epsilon), epsilon.checked_mul(&half, RoundingMode::NearestTiesAway));
+        assert_eq!(Some(Fixed16::zero()), epsilon.checked_mul(&half, RoundingMode::NearestTiesEven));
+        assert_eq!(Some(-epsilon), (-epsilon).checked_mul(&half, RoundingMode::Floor));
+        assert_eq!(Some(Fixed16::zero()), epsilon.checked_div(&fixed(3.0), RoundingMode::NearestTiesEven));
+        assert_eq!(Some(epsilon), epsilon.checked_div(&fixed(3.0), RoundingMode::Ceil));
+
+        // Ensure overflow and division by zero are detected.
+        assert_eq!(None, Fixed16::MAX.checked_add(&epsilon));
+        assert_eq!(None, Fixed16::MIN.checked_sub(&epsilon));
+        assert_eq!(None, Fixed16::MAX.checked_mul(&fixed(2.0), RoundingMode::Floor));
+        assert_eq!(None, Fixed16::MAX.checked_div(&half, RoundingMode::Floor));
+        assert_eq!(None, Fixed16::one().checked_div(&Fixed16::zero(), RoundingMode::Floor));
+    }
+
+    #[test]
+    fn test_identities() {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let a = Fixed16::from_raw(rng.gen::<i32>() as i64);
+            let b = Fixed16::from_raw(rng.gen::<i32>() as i64);
+
+            // Ensure multiplication and division by one are exact.
+            assert_eq!(a, a * Fixed16::one());
+            assert_eq!(a, a / Fixed16::one());
+
+            // Ensure the floor and ceiling bound the product, and differ by at most the last fractional bit.
+            let floor = a.checked_mul(&b, RoundingMode::Floor).unwrap();
+            let ceil = a.checked_mul(&b, RoundingMode::Ceil).unwrap();
+            assert!(ceil.raw() - floor.raw() <= 1);
+            for mode in [RoundingMode::TowardZero, RoundingMode::NearestTiesAway, RoundingMode::NearestTiesEven] {
+                let candidate = a.checked_mul(&b, mode).unwrap();
+                assert!(floor <= candidate && candidate <= ceil);
+            }
+        }
+    }
+}
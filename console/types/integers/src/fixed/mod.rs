@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod arithmetic;
+
+use super::*;
+
+/// The rounding mode of a fixed-point multiplication or division.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds towards zero.
+    TowardZero,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds to the nearest value, with ties rounded away from zero.
+    NearestTiesAway,
+    /// Rounds to the nearest value, with ties rounded to the even value.
+    NearestTiesEven,
+}
+
+impl RoundingMode {
+    /// Returns `true` if the floor of a quotient is incremented, for the given remainder information.
+    /// Here, `is_negative` is `true` if the quotient is negative, `comparison` is the ordering of twice the remainder
+    /// and the divisor, and `is_floor_odd` is `true` if the floor of the quotient is odd.
+    pub const fn rounds_up(&self, is_negative: bool, is_exact: bool, comparison: Ordering, is_floor_odd: bool) -> bool {
+        match self {
+            Self::TowardZero => !is_exact && is_negative,
+            Self::Floor => false,
+            Self::Ceil => !is_exact,
+            Self::NearestTiesAway => match comparison {
+                Ordering::Less => false,
+                Ordering::Equal => !is_negative,
+                Ordering::Greater => true,
+            },
+            Self::NearestTiesEven => match comparison {
+                Ordering::Less => false,
+                Ordering::Equal => is_floor_odd,
+                Ordering::Greater => true,
+            },
+        }
+    }
+}
+
+/// A signed fixed-point number with `FRACTIONAL_BITS` fractional bits, stored as a 64-bit integer `raw`,
+/// whose value is `raw / 2^FRACTIONAL_BITS`. The number of fractional bits must be less than 63.
+///
+/// Addition and subtraction are exact, and halt on overflow.
+/// Multiplication and division are rounded with the given `RoundingMode`, and halt on overflow.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Fixed<E: Environment, const FRACTIONAL_BITS: u32> {
+    /// The underlying integer, scaled by `2^FRACTIONAL_BITS`.
+    raw: i64,
+    /// PhantomData.
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Fixed<E, FRACTIONAL_BITS> {
+    pub const MAX: Self = Self::from_raw(i64::MAX);
+    pub const MIN: Self = Self::from_raw(i64::MIN);
+
+    /// Initializes a new fixed-point number from its integer representation, scaled by `2^FRACTIONAL_BITS`.
+    pub const fn from_raw(raw: i64) -> Self {
+        Self { raw, _phantom: PhantomData }
+    }
+
+    /// Initializes a new fixed-point number from an integer, or `None` if it is out of range.
+    pub fn from_integer(integer: i64) -> Option<Self> {
+        debug_assert!(FRACTIONAL_BITS < 63, "The number of fractional bits must be less than 63");
+        integer.checked_mul(1 << FRACTIONAL_BITS).map(Self::from_raw)
+    }
+
+    /// Returns the integer representation, scaled by `2^FRACTIONAL_BITS`.
+    pub const fn raw(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the number of fractional bits.
+    pub const fn fractional_bits() -> u32 {
+        FRACTIONAL_BITS
+    }
+}
+
+/// Returns the quotient of `numerator` and `denominator`, rounded with the given mode, or `None` on overflow
+/// or division by zero. Note: The quotient must be an `i64`, and the denominator must not exceed 2^63 in magnitude.
+pub(crate) fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i64> {
+    if denominator == 0 {
+        return None;
+    }
+    // Normalize the denominator to be positive.
+    let (numerator, denominator) = match denominator.is_negative() {
+        true => (-numerator, -denominator),
+        false => (numerator, denominator),
+    };
+    // Compute the floor of the quotient, and the nonnegative remainder.
+    let floor = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+
+    let is_negative = numerator.is_negative();
+    let comparison = (2 * remainder).cmp(&denominator);
+    let is_floor_odd = floor.rem_euclid(2) == 1;
+    match mode.rounds_up(is_negative, remainder == 0, comparison, is_floor_odd) {
+        true => i64::try_from(floor + 1).ok(),
+        false => i64::try_from(floor).ok(),
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Zero for Fixed<E, FRACTIONAL_BITS> {
+    fn zero() -> Self {
+        Self::from_raw(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> One for Fixed<E, FRACTIONAL_BITS> {
+    fn one() -> Self {
+        match Self::from_integer(1) {
+            Some(one) => one,
+            None => E::halt("The number of fractional bits must be less than 63"),
+        }
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Ord for Fixed<E, FRACTIONAL_BITS> {
+    /// Returns the ordering of `self` and `other`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> PartialOrd for Fixed<E, FRACTIONAL_BITS> {
+    /// Returns the ordering of `self` and `other`.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Distribution<Fixed<E, FRACTIONAL_BITS>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fixed<E, FRACTIONAL_BITS> {
+        Fixed::from_raw(rng.gen())
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Debug for Fixed<E, FRACTIONAL_BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment, const FRACTIONAL_BITS: u32> Display for Fixed<E, FRACTIONAL_BITS> {
+    /// Writes the exact decimal expansion of the fixed-point number.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let magnitude = self.raw.unsigned_abs() as u128;
+        let mask = (1u128 << FRACTIONAL_BITS) - 1;
+
+        // Extract the decimal digits of the fraction, which terminate within `FRACTIONAL_BITS` digits.
+        let mut fraction = magnitude & mask;
+        let mut digits = String::new();
+        while fraction != 0 {
+            fraction *= 10;
+            digits.push(char::from(b'0' + (fraction >> FRACTIONAL_BITS) as u8));
+            fraction &= mask;
+        }
+        if digits.is_empty() {
+            digits.push('0');
+        }
+
+        let sign = if self.raw.is_negative() { "-" } else { "" };
+        write!(f, "{sign}{}.{digits}", magnitude >> FRACTIONAL_BITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_div_round() {
+        use RoundingMode::*;
+
+        // Check the rounding of 7 / 2, -7 / 2, 5 / 2, -5 / 2, 5 / 3, and 7 / -2.
+        let cases = [(7, 2), (-7, 2), (5, 2), (-5, 2), (5, 3), (7, -2)];
+        let expected = [
+            (TowardZero, [3, -3, 2, -2, 1, -3]),
+            (Floor, [3, -4, 2, -3, 1, -4]),
+            (Ceil, [4, -3, 3, -2, 2, -3]),
+            (NearestTiesAway, [4, -4, 3, -3, 2, -4]),
+            (NearestTiesEven, [4, -4, 2, -2, 2, -4]),
+        ];
+        for (mode, expected) in expected {
+            for ((numerator, denominator), expected) in cases.iter().zip_eq(expected) {
+                assert_eq!(Some(expected), div_round(*numerator, *denominator, mode), "{numerator} / {denominator}");
+            }
+            assert_eq!(None, div_round(1, 0, mode));
+            assert_eq!(None, div_round(i64::MAX as i128 + 1, 1, mode));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        type Fixed16 = Fixed<CurrentEnvironment, 16>;
+
+        assert_eq!("0.0", Fixed16::zero().to_string());
+        assert_eq!("1.0", Fixed16::one().to_string());
+        assert_eq!("-2.5", Fixed16::from_raw(-5 << 15).to_string());
+        assert_eq!("0.0000152587890625", Fixed16::from_raw(1).to_string());
+        assert_eq!("3.0", Fixed::<CurrentEnvironment, 0>::from_raw(3).to_string());
+    }
+}
@@ -0,0 +1,358 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Integer256<E, SIGNED> {
+    /// Returns the `sum` of `self` and `other`, or `None` on overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let (limbs, carry) = add(&self.limbs, &other.limbs);
+        let sum = Self::new(limbs);
+        match SIGNED {
+            // Overflow occurs if the operands have the same sign, and the sum has a different sign.
+            true => match self.is_negative() == other.is_negative() && sum.is_negative() != self.is_negative() {
+                true => None,
+                false => Some(sum),
+            },
+            false => match carry {
+                true => None,
+                false => Some(sum),
+            },
+        }
+    }
+
+    /// Returns the `difference` of `self` and `other`, or `None` on overflow.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let (limbs, borrow) = sub(&self.limbs, &other.limbs);
+        let difference = Self::new(limbs);
+        match SIGNED {
+            // Overflow occurs if the operands have different signs, and the difference differs in sign from `self`.
+            true => match self.is_negative() != other.is_negative() && difference.is_negative() != self.is_negative() {
+                true => None,
+                false => Some(difference),
+            },
+            false => match borrow {
+                true => None,
+                false => Some(difference),
+            },
+        }
+    }
+
+    /// Returns the `product` of `self` and `other`, or `None` on overflow.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Compute the product of the magnitudes.
+        let product = mul(&self.unsigned_abs().limbs, &other.unsigned_abs().limbs);
+        let (lower, upper) = product.split_at(NUM_LIMBS);
+        if upper.iter().any(|limb| *limb != 0) {
+            return None;
+        }
+        let magnitude = [lower[0], lower[1], lower[2], lower[3]];
+
+        match SIGNED {
+            true => match self.is_negative() != other.is_negative() {
+                // Ensure the magnitude of a negative product is at most 2^255.
+                true => match compare(&magnitude, &Self::MIN.limbs) {
+                    Ordering::Greater => None,
+                    _ => Some(Self::new(negate(&magnitude))),
+                },
+                // Ensure the magnitude of a positive product is less than 2^255.
+                false => match compare(&magnitude, &Self::MIN.limbs) {
+                    Ordering::Less => Some(Self::new(magnitude)),
+                    _ => None,
+                },
+            },
+            false => Some(Self::new(magnitude)),
+        }
+    }
+
+    /// Returns the `quotient` of `self` and `other`, rounded towards zero, or `None` on overflow or division by zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        // Compute the quotient of the magnitudes.
+        let (magnitude, _) = div_rem(&self.unsigned_abs().limbs, &other.unsigned_abs().limbs);
+
+        match SIGNED {
+            true => match self.is_negative() != other.is_negative() {
+                true => Some(Self::new(negate(&magnitude))),
+                // Note: The only overflow is `Self::MIN / -1`, whose quotient has a magnitude of 2^255.
+                false => match Self::new(magnitude).is_negative() {
+                    true => None,
+                    false => Some(Self::new(magnitude)),
+                },
+            },
+            false => Some(Self::new(magnitude)),
+        }
+    }
+
+    /// Returns the `sum` of `self` and `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        Self::new(add(&self.limbs, &other.limbs).0)
+    }
+
+    /// Returns the `difference` of `self` and `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        Self::new(sub(&self.limbs, &other.limbs).0)
+    }
+
+    /// Returns the `product` of `self` and `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(&self, other: &Self) -> Self {
+        // Note: The lower half of the product is the same for signed and unsigned integers.
+        let product = mul(&self.limbs, &other.limbs);
+        Self::new([product[0], product[1], product[2], product[3]])
+    }
+
+    /// Returns the `quotient` of `self` and `other`, wrapping around at the boundary of the type,
+    /// or `None` on division by zero.
+    pub fn wrapping_div(&self, other: &Self) -> Option<Self> {
+        match other.is_zero() {
+            true => None,
+            // Note: The only overflow is `Self::MIN / -1`, which wraps to `Self::MIN`.
+            false => Some(self.checked_div(other).unwrap_or(Self::MIN)),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Neg for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `negation` of `self`.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match SIGNED {
+            true => match Self::zero().checked_sub(&self) {
+                Some(integer) => integer,
+                None => E::halt(format!("Integer negation failed on: {self}")),
+            },
+            false => E::halt("Negation of unsigned integers is not supported."),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Add<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: Self) -> Self::Output {
+        match self.checked_add(&other) {
+            Some(integer) => integer,
+            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Add<&Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: &Self) -> Self::Output {
+        self + *other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> AddWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add_wrapped(&self, other: &Self) -> Self::Output {
+        self.wrapping_add(other)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Sub<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: Self) -> Self::Output {
+        match self.checked_sub(&other) {
+            Some(integer) => integer,
+            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Sub<&Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: &Self) -> Self::Output {
+        self - *other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SubWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub_wrapped(&self, other: &Self) -> Self::Output {
+        self.wrapping_sub(other)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Mul<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul(self, other: Self) -> Self::Output {
+        match self.checked_mul(&other) {
+            Some(integer) => integer,
+            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Mul<&Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul(self, other: &Self) -> Self::Output {
+        self * *other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MulWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul_wrapped(&self, other: &Self) -> Self::Output {
+        self.wrapping_mul(other)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Div<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `quotient` of `self` and `other`.
+    #[inline]
+    fn div(self, other: Self) -> Self::Output {
+        match self.checked_div(&other) {
+            Some(integer) => integer,
+            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Div<&Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `quotient` of `self` and `other`.
+    #[inline]
+    fn div(self, other: &Self) -> Self::Output {
+        self / *other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> DivWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    /// Returns the `quotient` of `self` and `other`.
+    #[inline]
+    fn div_wrapped(&self, other: &Self) -> Self::Output {
+        match self.wrapping_div(other) {
+            Some(integer) => integer,
+            None => E::halt(format!("Integer division by zero: {self} / {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_unsigned_arithmetic() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the operations match the 128-bit operations, where the 128-bit results are exact.
+            let (a, b) = (rng.gen::<u64>() as u128, rng.gen::<u64>() as u128);
+            let (x, y) = (U256::<CurrentEnvironment>::from(a), U256::from(b));
+            assert_eq!(U256::from(a + b), x + y);
+            assert_eq!(U256::from(a * b), x * y);
+            assert_eq!(a.checked_sub(b).map(U256::from), x.checked_sub(&y));
+            assert_eq!(a.checked_div(b).map(U256::from), x.checked_div(&y));
+
+            // Ensure the division satisfies `a = q * b + r`, where `r < b`.
+            let (a, b): (U256<CurrentEnvironment>, U256<CurrentEnvironment>) = (rng.gen(), rng.gen());
+            let (q, r) = div_rem(&a.limbs, &b.limbs);
+            assert_eq!(a, U256::new(q) * b + U256::new(r));
+            assert!(U256::<CurrentEnvironment>::new(r) < b);
+            assert_eq!(U256::new(q), a / b);
+
+            // Ensure the wrapped operations are consistent.
+            assert_eq!(a, a.add_wrapped(&b).sub_wrapped(&b));
+            assert_eq!(a.mul_wrapped(&b), b.mul_wrapped(&a));
+        }
+
+        // Ensure overflows are detected.
+        let max = U256::<CurrentEnvironment>::MAX;
+        let one = U256::<CurrentEnvironment>::one();
+        assert_eq!(None, max.checked_add(&one));
+        assert_eq!(U256::zero(), max.add_wrapped(&one));
+        assert_eq!(None, U256::<CurrentEnvironment>::zero().checked_sub(&one));
+        assert_eq!(max, U256::zero().sub_wrapped(&one));
+        assert_eq!(None, max.checked_mul(&U256::from(2u128)));
+        assert_eq!(max.sub_wrapped(&one), max.mul_wrapped(&U256::from(2u128)));
+        assert_eq!(None, max.checked_div(&U256::zero()));
+        assert_eq!(None, max.wrapping_div(&U256::zero()));
+    }
+
+    #[test]
+    fn test_signed_arithmetic() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the operations match the 128-bit operations, where the 128-bit results are exact.
+            let (a, b) = (rng.gen::<i64>() as i128, rng.gen::<i64>() as i128);
+            let (x, y) = (I256::<CurrentEnvironment>::from(a), I256::from(b));
+            assert_eq!(I256::from(a + b), x + y);
+            assert_eq!(I256::from(a - b), x - y);
+            assert_eq!(I256::from(a * b), x * y);
+            assert_eq!(a.checked_div(b).map(I256::from), x.checked_div(&y));
+            assert_eq!(I256::from(-a), -x);
+        }
+
+        // Ensure overflows are detected.
+        let (min, max) = (I256::<CurrentEnvironment>::MIN, I256::<CurrentEnvironment>::MAX);
+        let one = I256::<CurrentEnvironment>::one();
+        let negative_one = I256::<CurrentEnvironment>::from(-1i128);
+        assert_eq!(None, max.checked_add(&one));
+        assert_eq!(min, max.add_wrapped(&one));
+        assert_eq!(None, min.checked_sub(&one));
+        assert_eq!(max, min.sub_wrapped(&one));
+        assert_eq!(None, min.checked_mul(&negative_one));
+        assert_eq!(Some(min), max.checked_mul(&negative_one).and_then(|x| x.checked_sub(&one)));
+        assert_eq!(None, min.checked_div(&negative_one));
+        assert_eq!(Some(min), min.wrapping_div(&negative_one));
+        assert_eq!(Some(min), I256::new([0, 0, 0, 1 << 62]).checked_mul(&I256::from(-2i128)));
+        assert_eq!(None, I256::<CurrentEnvironment>::new([0, 0, 0, 1 << 62]).checked_mul(&I256::from(2i128)));
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> ToBits for Integer256<E, SIGNED> {
+    /// Returns the little-endian bits of the integer.
+    fn to_bits_le(&self) -> Vec<bool> {
+        self.limbs.iter().flat_map(|limb| (0..64).map(move |i| (limb >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the big-endian bits of the integer.
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits_be = self.to_bits_le();
+        bits_be.reverse();
+        bits_be
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> FromBits for Integer256<E, SIGNED> {
+    /// Initializes a new integer from little-endian bits, where bits beyond the 256th are ignored.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        let mut limbs = [0u64; NUM_LIMBS];
+        for (i, bit) in bits_le.iter().take(Self::size_in_bits()).enumerate() {
+            limbs[i / 64] |= (*bit as u64) << (i % 64);
+        }
+        Ok(Self::new(limbs))
+    }
+
+    /// Initializes a new integer from big-endian bits, where bits before the last 256 are ignored.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Self::from_bits_le(&bits_be.iter().rev().copied().collect::<Vec<_>>())
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> FromBytes for Integer256<E, SIGNED> {
+    /// Reads the integer from a buffer, as little-endian limbs.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut limbs = [0u64; NUM_LIMBS];
+        for limb in limbs.iter_mut() {
+            *limb = u64::read_le(&mut reader)?;
+        }
+        Ok(Self::new(limbs))
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> ToBytes for Integer256<E, SIGNED> {
+    /// Writes the integer to a buffer, as little-endian limbs.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.limbs.iter().try_for_each(|limb| limb.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    fn check_bits_and_bytes<const SIGNED: bool>() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            let expected: Integer256<CurrentEnvironment, SIGNED> = Uniform::rand(&mut test_rng());
+
+            // Ensure the bits round trip.
+            let bits_le = expected.to_bits_le();
+            assert_eq!(Integer256::<CurrentEnvironment, SIGNED>::size_in_bits(), bits_le.len());
+            assert_eq!(expected, Integer256::from_bits_le(&bits_le)?);
+            assert_eq!(expected, Integer256::from_bits_be(&expected.to_bits_be())?);
+
+            // Ensure the bytes round trip.
+            let bytes = expected.to_bytes_le()?;
+            assert_eq!(Integer256::<CurrentEnvironment, SIGNED>::size_in_bytes(), bytes.len());
+            assert_eq!(expected, Integer256::read_le(&bytes[..])?);
+            assert!(Integer256::<CurrentEnvironment, SIGNED>::read_le(&bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bits_and_bytes() -> Result<()> {
+        check_bits_and_bytes::<false>()?;
+        check_bits_and_bytes::<true>()
+    }
+
+    #[test]
+    fn test_bits_match_u128() {
+        let value = u128::rand(&mut test_rng());
+        let bits_le = U256::<CurrentEnvironment>::from(value).to_bits_le();
+        assert_eq!(value.to_bits_le(), bits_le[..128]);
+        assert!(bits_le[128..].iter().all(|bit| !bit));
+    }
+}
@@ -0,0 +1,334 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod arithmetic;
+mod bits;
+mod parse;
+
+use super::*;
+
+pub type I256<E> = Integer256<E, true>;
+pub type U256<E> = Integer256<E, false>;
+
+/// The number of 64-bit limbs in a 256-bit integer.
+const NUM_LIMBS: usize = 4;
+
+/// A 256-bit integer, stored as little-endian 64-bit limbs.
+/// If `SIGNED` is `true`, the limbs are interpreted in two's complement.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Integer256<E: Environment, const SIGNED: bool> {
+    /// The little-endian limbs of the integer.
+    limbs: [u64; NUM_LIMBS],
+    /// PhantomData.
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment, const SIGNED: bool> Integer256<E, SIGNED> {
+    pub const MAX: Self = match SIGNED {
+        true => Self::new([u64::MAX, u64::MAX, u64::MAX, i64::MAX as u64]),
+        false => Self::new([u64::MAX; NUM_LIMBS]),
+    };
+    pub const MIN: Self = match SIGNED {
+        true => Self::new([0, 0, 0, 1 << 63]),
+        false => Self::new([0; NUM_LIMBS]),
+    };
+
+    /// Initializes a new integer from little-endian 64-bit limbs.
+    pub const fn new(limbs: [u64; NUM_LIMBS]) -> Self {
+        Self { limbs, _phantom: PhantomData }
+    }
+
+    /// Returns the little-endian 64-bit limbs of the integer.
+    pub const fn limbs(&self) -> &[u64; NUM_LIMBS] {
+        &self.limbs
+    }
+
+    /// Returns `true` if the integer is signed.
+    pub const fn is_signed() -> bool {
+        SIGNED
+    }
+
+    /// Returns `true` if the integer is negative.
+    pub const fn is_negative(&self) -> bool {
+        SIGNED && self.limbs[NUM_LIMBS - 1] >> 63 == 1
+    }
+
+    /// Returns the absolute value of `self`, as an unsigned integer.
+    /// Note: This is always defined, as the magnitude of `I256::MIN` is representable in a `U256`.
+    pub fn unsigned_abs(&self) -> U256<E> {
+        match self.is_negative() {
+            true => U256::new(negate(&self.limbs)),
+            false => U256::new(self.limbs),
+        }
+    }
+}
+
+impl<E: Environment> From<u128> for U256<E> {
+    /// Initializes an unsigned 256-bit integer from a `u128`.
+    fn from(value: u128) -> Self {
+        Self::new([value as u64, (value >> 64) as u64, 0, 0])
+    }
+}
+
+impl<E: Environment> From<i128> for I256<E> {
+    /// Initializes a signed 256-bit integer from an `i128`, by sign extension.
+    fn from(value: i128) -> Self {
+        let extension = match value.is_negative() {
+            true => u64::MAX,
+            false => 0,
+        };
+        Self::new([value as u64, (value >> 64) as u64, extension, extension])
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> TypeName for Integer256<E, SIGNED> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        match SIGNED {
+            true => "i256",
+            false => "u256",
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Zero for Integer256<E, SIGNED> {
+    fn zero() -> Self {
+        Self::new([0; NUM_LIMBS])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs == [0; NUM_LIMBS]
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> One for Integer256<E, SIGNED> {
+    fn one() -> Self {
+        Self::new([1, 0, 0, 0])
+    }
+
+    fn is_one(&self) -> bool {
+        self.limbs == [1, 0, 0, 0]
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Ord for Integer256<E, SIGNED> {
+    /// Returns the ordering of `self` and `other`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Note: Two's complement integers of the same sign are ordered as their limbs.
+            _ => compare(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> PartialOrd for Integer256<E, SIGNED> {
+    /// Returns the ordering of `self` and `other`.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Equal for Integer256<E, SIGNED> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self == other)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self != other)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Compare<Self> for Integer256<E, SIGNED> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        Boolean::new(self < other)
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Ternary for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match **condition {
+            true => *first,
+            false => *second,
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Distribution<Integer256<E, SIGNED>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Integer256<E, SIGNED> {
+        Integer256::new(rng.gen())
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SizeInBits for Integer256<E, SIGNED> {
+    #[inline]
+    fn size_in_bits() -> usize {
+        NUM_LIMBS * 64
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SizeInBytes for Integer256<E, SIGNED> {
+    #[inline]
+    fn size_in_bytes() -> usize {
+        NUM_LIMBS * 8
+    }
+}
+
+/// Returns the ordering of the given little-endian limbs, as unsigned integers.
+fn compare(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Returns the sum of the given limbs, and the carry bit.
+fn add(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], bool) {
+    let mut limbs = [0u64; NUM_LIMBS];
+    let mut carry = false;
+    for i in 0..NUM_LIMBS {
+        let (sum, carry_0) = a[i].overflowing_add(b[i]);
+        let (sum, carry_1) = sum.overflowing_add(carry as u64);
+        limbs[i] = sum;
+        carry = carry_0 | carry_1;
+    }
+    (limbs, carry)
+}
+
+/// Returns the difference of the given limbs, and the borrow bit.
+fn sub(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], bool) {
+    let mut limbs = [0u64; NUM_LIMBS];
+    let mut borrow = false;
+    for i in 0..NUM_LIMBS {
+        let (difference, borrow_0) = a[i].overflowing_sub(b[i]);
+        let (difference, borrow_1) = difference.overflowing_sub(borrow as u64);
+        limbs[i] = difference;
+        borrow = borrow_0 | borrow_1;
+    }
+    (limbs, borrow)
+}
+
+/// Returns the full 512-bit product of the given limbs.
+fn mul(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> [u64; 2 * NUM_LIMBS] {
+    let mut product = [0u64; 2 * NUM_LIMBS];
+    for i in 0..NUM_LIMBS {
+        let mut carry = 0u128;
+        for j in 0..NUM_LIMBS {
+            // Note: This does not overflow, as (2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1.
+            let term = product[i + j] as u128 + (a[i] as u128) * (b[j] as u128) + carry;
+            product[i + j] = term as u64;
+            carry = term >> 64;
+        }
+        product[i + NUM_LIMBS] = carry as u64;
+    }
+    product
+}
+
+/// Returns the quotient and remainder of the given limbs, as unsigned integers.
+/// Note: The divisor must be nonzero.
+fn div_rem(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], [u64; NUM_LIMBS]) {
+    debug_assert!(b != &[0; NUM_LIMBS], "The divisor must be nonzero");
+
+    let mut quotient = [0u64; NUM_LIMBS];
+    let mut remainder = [0u64; NUM_LIMBS];
+    // Perform binary long division, from the most significant bit.
+    for i in (0..NUM_LIMBS * 64).rev() {
+        // Shift the next bit of `a` into the remainder.
+        let is_overflow = remainder[NUM_LIMBS - 1] >> 63 == 1;
+        for j in (1..NUM_LIMBS).rev() {
+            remainder[j] = (remainder[j] << 1) | (remainder[j - 1] >> 63);
+        }
+        remainder[0] = (remainder[0] << 1) | ((a[i / 64] >> (i % 64)) & 1);
+        // Note: If the shift overflowed, the remainder exceeds `b`, and the wrapped difference is correct.
+        if is_overflow || compare(&remainder, b) != Ordering::Less {
+            remainder = sub(&remainder, b).0;
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+    (quotient, remainder)
+}
+
+/// Returns the two's complement negation of the given limbs.
+fn negate(a: &[u64; NUM_LIMBS]) -> [u64; NUM_LIMBS] {
+    sub(&[0; NUM_LIMBS], a).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(U256::<CurrentEnvironment>::MAX, U256::new([u64::MAX; 4]));
+        assert_eq!(U256::<CurrentEnvironment>::MIN, U256::zero());
+        assert_eq!(I256::<CurrentEnvironment>::MAX, I256::MIN.sub_wrapped(&I256::one()));
+        assert!(I256::<CurrentEnvironment>::MIN.is_negative());
+        assert!(!I256::<CurrentEnvironment>::MAX.is_negative());
+        assert!(!U256::<CurrentEnvironment>::MAX.is_negative());
+        assert_eq!(U256::new([0, 0, 0, 1 << 63]), I256::<CurrentEnvironment>::MIN.unsigned_abs());
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the ordering matches the ordering of the 128-bit integers.
+            let (a, b) = (rng.gen::<u128>(), rng.gen::<u128>());
+            assert_eq!(a.cmp(&b), U256::<CurrentEnvironment>::from(a).cmp(&U256::from(b)));
+            let (a, b) = (rng.gen::<i128>(), rng.gen::<i128>());
+            assert_eq!(a.cmp(&b), I256::<CurrentEnvironment>::from(a).cmp(&I256::from(b)));
+        }
+
+        assert!(I256::<CurrentEnvironment>::MIN < I256::from(-1i128));
+        assert!(I256::<CurrentEnvironment>::from(-1i128) < I256::zero());
+        assert!(I256::<CurrentEnvironment>::zero() < I256::MAX);
+        assert!(U256::<CurrentEnvironment>::from(u128::MAX) < U256::new([0, 0, 1, 0]));
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Parser for Integer256<E, SIGNED> {
+    /// Parses a string into a 256-bit integer.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
+        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        let (string, value) = map_res(tag(Self::type_name()), |_| {
+            Self::from_decimal(negation, &primitive.replace('_', ""))
+                .ok_or_else(|| anyhow!("Failed to parse '{primitive}' as a {}", Self::type_name()))
+        })(string)?;
+
+        Ok((string, value))
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Integer256<E, SIGNED> {
+    /// Returns the integer with the given decimal digits, or `None` if it is out of range.
+    fn from_decimal(is_negative: bool, digits: &str) -> Option<Self> {
+        // Accumulate the magnitude.
+        let mut magnitude = U256::<E>::zero();
+        for digit in digits.chars() {
+            let digit = U256::from(digit.to_digit(10)? as u128);
+            magnitude = magnitude.checked_mul(&U256::from(10u128))?.checked_add(&digit)?;
+        }
+
+        match (SIGNED, is_negative) {
+            // Ensure the magnitude of a negative integer is at most 2^255.
+            (true, true) => match compare(&magnitude.limbs, &Self::MIN.limbs) {
+                Ordering::Greater => None,
+                _ => Some(Self::new(negate(&magnitude.limbs))),
+            },
+            // Ensure the magnitude of a positive integer is less than 2^255.
+            (true, false) => match compare(&magnitude.limbs, &Self::MIN.limbs) {
+                Ordering::Less => Some(Self::new(magnitude.limbs)),
+                _ => None,
+            },
+            (false, true) => None,
+            (false, false) => Some(Self::new(magnitude.limbs)),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> FromStr for Integer256<E, SIGNED> {
+    type Err = Error;
+
+    /// Parses a string into a 256-bit integer.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Debug for Integer256<E, SIGNED> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Display for Integer256<E, SIGNED> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Extract the decimal digits of the magnitude, from least to most significant.
+        let ten = [10, 0, 0, 0];
+        let mut magnitude = self.unsigned_abs().limbs;
+        let mut digits = Vec::new();
+        loop {
+            let (quotient, remainder) = div_rem(&magnitude, &ten);
+            digits.push(char::from(b'0' + remainder[0] as u8));
+            magnitude = quotient;
+            if magnitude == [0; NUM_LIMBS] {
+                break;
+            }
+        }
+        if self.is_negative() {
+            digits.push('-');
+        }
+        write!(f, "{}{}", digits.iter().rev().collect::<String>(), Self::type_name())
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Serialize for Integer256<E, SIGNED> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: Environment, const SIGNED: bool> Deserialize<'de> for Integer256<E, SIGNED> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize(deserializer, "integer", Self::size_in_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_display() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the string representations match the 128-bit integers.
+            let value = rng.gen::<u128>();
+            assert_eq!(format!("{value}u256"), U256::<CurrentEnvironment>::from(value).to_string());
+            let value = rng.gen::<i128>();
+            assert_eq!(format!("{value}i256"), I256::<CurrentEnvironment>::from(value).to_string());
+        }
+
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935u256",
+            U256::<CurrentEnvironment>::MAX.to_string()
+        );
+        assert_eq!(
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968i256",
+            I256::<CurrentEnvironment>::MIN.to_string()
+        );
+        assert_eq!("0i256", I256::<CurrentEnvironment>::zero().to_string());
+    }
+
+    fn check_parse<const SIGNED: bool>() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            let expected: Integer256<CurrentEnvironment, SIGNED> = Uniform::rand(&mut test_rng());
+
+            // Ensure the string representation round trips.
+            let candidate = Integer256::<CurrentEnvironment, SIGNED>::from_str(&expected.to_string())?;
+            assert_eq!(expected, candidate);
+
+            // Ensure the serde representations round trip.
+            assert_eq!(expected, serde_json::from_str(&serde_json::to_string(&expected)?)?);
+            assert_eq!(expected, bincode::deserialize(&bincode::serialize(&expected)?)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        check_parse::<false>()?;
+        check_parse::<true>()?;
+
+        // Ensure out of range integers fail to parse.
+        assert!(U256::<CurrentEnvironment>::from_str(&format!("{}", U256::<CurrentEnvironment>::MAX)).is_ok());
+        assert!(
+            U256::<CurrentEnvironment>::from_str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936u256"
+            )
+            .is_err()
+        );
+        assert!(
+            I256::<CurrentEnvironment>::from_str(
+                "57896044618658097711785492504343953926634992332820282019728792003956564819968i256"
+            )
+            .is_err()
+        );
+        assert!(U256::<CurrentEnvironment>::from_str("-1u256").is_err());
+        assert!(U256::<CurrentEnvironment>::from_str("1i256").is_err());
+        assert_eq!(I256::<CurrentEnvironment>::from(-1_000i128), I256::from_str("-1_000i256")?);
+        Ok(())
+    }
+}
@@ -20,9 +20,11 @@ mod arithmetic;
 mod bitwise;
 mod bytes;
 mod compare;
+mod fixed;
 mod from_bits;
 mod from_field;
 mod from_fields;
+mod integer256;
 mod one;
 mod parse;
 mod random;
@@ -42,6 +44,9 @@ use snarkvm_console_network_environment::traits::integers::*;
 
 use core::marker::PhantomData;
 
+pub use fixed::{Fixed, RoundingMode};
+pub use integer256::{Integer256, I256, U256};
+
 pub type I8<E> = Integer<E, i8>;
 pub type I16<E> = Integer<E, i16>;
 pub type I32<E> = Integer<E, i32>;
@@ -42,7 +42,7 @@ pub use snarkvm_console_types_group::Group;
 #[cfg(feature = "integers")]
 pub use snarkvm_console_types_integers as integers;
 #[cfg(feature = "integers")]
-pub use snarkvm_console_types_integers::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+pub use snarkvm_console_types_integers::{I128, I16, I256, I32, I64, I8, U128, U16, U256, U32, U64, U8};
 
 #[cfg(feature = "scalar")]
 pub use snarkvm_console_types_scalar as scalar;
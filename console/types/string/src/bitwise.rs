@@ -30,15 +30,96 @@ impl<E: Environment> Equal for StringType<E> {
     }
 }
 
+impl<E: Environment> Compare for StringType<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`, in the lexicographic order of their bytes.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        Boolean::new(self.as_bytes() < other.as_bytes())
+    }
+
+    /// Returns `true` if `self` is greater than `other`, in the lexicographic order of their bytes.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`, in the lexicographic order of their bytes.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`, in the lexicographic order of their bytes.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
 impl<E: Environment> Ternary for StringType<E> {
     type Boolean = Boolean<E>;
     type Output = Self;
 
     /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    ///
+    /// As the length of a string determines the shape of its circuit, this halts
+    /// if `first` and `second` do not have the same number of bytes.
     fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Ensure the strings are of the same length.
+        if first.len() != second.len() {
+            E::halt(format!("Ternary requires strings of equal length, found {} and {}", first.len(), second.len()))
+        }
         match **condition {
             true => first.clone(),
             false => second.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_compare() {
+        let mut rng = test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample two random strings.
+            let a: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let b: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Ensure the comparisons match the ordering of `str`.
+            assert_eq!(*a < *b, *a.is_less_than(&b));
+            assert_eq!(*a > *b, *a.is_greater_than(&b));
+            assert_eq!(*a <= *b, *a.is_less_than_or_equal(&b));
+            assert_eq!(*a >= *b, *a.is_greater_than_or_equal(&b));
+        }
+
+        // Ensure a strict prefix is less than the string.
+        let prefix = StringType::<CurrentEnvironment>::new("alice");
+        let string = StringType::<CurrentEnvironment>::new("alice.aleo");
+        assert!(*prefix.is_less_than(&string));
+        assert!(!*string.is_less_than(&prefix));
+        assert!(*string.is_less_than_or_equal(&string));
+        assert!(!*string.is_less_than(&string));
+    }
+
+    #[test]
+    fn test_ternary() {
+        let a = StringType::<CurrentEnvironment>::new("alice");
+        let b = StringType::<CurrentEnvironment>::new("bobby");
+
+        // Ensure the ternary selects the expected string.
+        assert_eq!(a, StringType::ternary(&Boolean::new(true), &a, &b));
+        assert_eq!(b, StringType::ternary(&Boolean::new(false), &a, &b));
+
+        // Ensure the ternary halts on strings of different lengths.
+        let c = StringType::<CurrentEnvironment>::new("carol.aleo");
+        let result = std::panic::catch_unwind(|| StringType::ternary(&Boolean::new(true), &a, &c));
+        assert!(result.is_err());
+    }
+}
@@ -49,6 +49,12 @@ impl<E: Environment> StringType<E> {
             false => E::halt(format!("Attempted to allocate a string of size {num_bytes}")),
         }
     }
+
+    /// Initializes an empty string.
+    #[deprecated(since = "0.1.0", note = "This is used for **testing** purposes")]
+    pub fn zero() -> Self {
+        Self::new("")
+    }
 }
 
 impl<E: Environment> TypeName for StringType<E> {
@@ -79,6 +79,12 @@ pub trait ProjectiveCurve:
         v.into_iter().map(|v| v.into()).collect()
     }
 
+    /// Returns the affine equivalents of a slice of projective elements,
+    /// using a single field inversion.
+    fn batch_normalize(v: &[Self]) -> Vec<Self::Affine> {
+        Self::batch_normalization_into_affine(v.to_vec())
+    }
+
     /// Checks if the point is already "normalized" so that
     /// cheap affine conversion is possible.
     #[must_use]
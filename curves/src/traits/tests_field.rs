@@ -104,6 +104,15 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_batch_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
+    let mut v = (0..ITERATIONS).map(|_| F::rand(rng)).collect::<Vec<_>>();
+    v[ITERATIONS as usize / 2] = F::zero();
+    let expected = v.iter().map(|f| f.inverse().unwrap_or_else(F::zero)).collect::<Vec<_>>();
+
+    F::batch_inverse(&mut v);
+    assert_eq!(v, expected);
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -319,6 +328,7 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
+    random_batch_inversion_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
@@ -92,4 +92,9 @@ pub fn projective_test<G: ProjectiveCurve>(a: G, mut b: G) {
     assert_eq!(a_rand1.mul(fr_rand2), a_rand2.mul(fr_rand1), "(a * r1) * r2 != (a * r2) * r1");
     assert_eq!(a_rand2.mul(fr_rand1), a.mul(fr_rand1 * fr_rand2), "(a * r2) * r1 != a * (r1 * r2)");
     assert_eq!(a_rand1.mul(fr_rand2), a.mul(fr_rand1 * fr_rand2), "(a * r1) * r2 != a * (r1 * r2)");
+
+    // Check that batch normalization matches individual normalization, including the identity.
+    let elements = [a_rand1, zero, a_rand2, a_six];
+    let expected = elements.iter().map(|g| g.to_affine()).collect::<Vec<_>>();
+    assert_eq!(G::batch_normalize(&elements), expected);
 }
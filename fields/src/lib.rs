@@ -65,19 +65,22 @@ impl_field_into_biginteger!(Fp384, BigInteger384, Fp384Parameters);
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
 
-// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
+/// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}.
+/// Zero elements are left unchanged.
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
     batch_inversion_and_mul(v, &F::one());
 }
 
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+/// Zero elements are left unchanged.
 #[cfg(not(feature = "parallel"))]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
 pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     serial_batch_inversion_and_mul(v, coeff);
 }
 
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+/// Zero elements are left unchanged.
 #[cfg(feature = "parallel")]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
 pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     use rayon::prelude::*;
     // Divide the vector v evenly between all available cores
@@ -134,6 +134,12 @@ pub trait Field:
     /// Sets `self` to `self`'s inverse if it exists. Otherwise it is a no-op.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Sets each nonzero element of `v` to its inverse, using a single inversion (Montgomery's trick).
+    /// Zero elements are left unchanged.
+    fn batch_inverse(v: &mut [Self]) {
+        crate::batch_inversion(v)
+    }
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
@@ -41,31 +41,45 @@ pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
     /// with a specific optimization goal.
     fn default_poseidon_parameters<const RATE: usize>() -> Result<PoseidonParameters<Self, RATE, 1>>
+    where
+        Self: PrimeField,
+    {
+        Self::default_poseidon_parameters_with_capacity::<RATE, 1>()
+    }
+
+    /// Obtain the default Poseidon parameters for this rate and capacity, and for this prime field,
+    /// with a specific optimization goal.
+    ///
+    /// The parameters depend only on the state width `RATE + CAPACITY`, so they are the default
+    /// parameters of rate `RATE + CAPACITY - 1` with a capacity of 1.
+    fn default_poseidon_parameters_with_capacity<const RATE: usize, const CAPACITY: usize>()
+    -> Result<PoseidonParameters<Self, RATE, CAPACITY>>
     where
         Self: PrimeField,
     {
         /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
         #[allow(clippy::type_complexity)]
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
+        fn find_poseidon_ark_and_mds<F: PrimeField>(
+            width: usize,
             full_rounds: u64,
             partial_rounds: u64,
             skip_matrices: u64,
         ) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
             let lfsr_time = start_timer!(|| "LFSR Init");
             let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
+                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, width as u64, full_rounds, partial_rounds);
             end_timer!(lfsr_time);
 
             let ark_time = start_timer!(|| "Constructing ARK");
             let mut ark = Vec::with_capacity((full_rounds + partial_rounds) as usize);
             for _ in 0..(full_rounds + partial_rounds) {
-                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
+                ark.push(lfsr.get_field_elements_rejection_sampling(width)?);
             }
             end_timer!(ark_time);
 
             let skip_time = start_timer!(|| "Skipping matrices");
             for _ in 0..skip_matrices {
-                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1))?;
+                let _ = lfsr.get_field_elements_mod_p::<F>(2 * width)?;
             }
             end_timer!(skip_time);
 
@@ -74,26 +88,34 @@ pub trait PoseidonDefaultField {
             // - There is no i and j such that x[i] + y[j] = p.
             // - There resultant MDS passes all three tests.
 
-            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+            let xs = lfsr.get_field_elements_mod_p::<F>(width)?;
+            let ys = lfsr.get_field_elements_mod_p::<F>(width)?;
 
             let mds_time = start_timer!(|| "Construct MDS");
-            let mut mds_flattened = vec![F::zero(); (RATE + 1) * (RATE + 1)];
-            for (x, mds_row_i) in xs.iter().take(RATE + 1).zip_eq(mds_flattened.chunks_mut(RATE + 1)) {
-                for (y, e) in ys.iter().take(RATE + 1).zip_eq(mds_row_i) {
+            let mut mds_flattened = vec![F::zero(); width * width];
+            for (x, mds_row_i) in xs.iter().take(width).zip_eq(mds_flattened.chunks_mut(width)) {
+                for (y, e) in ys.iter().take(width).zip_eq(mds_row_i) {
                     *e = *x + y;
                 }
             }
             serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
-            let mds = mds_flattened.chunks(RATE + 1).map(|row| row.to_vec()).collect();
+            let mds = mds_flattened.chunks(width).map(|row| row.to_vec()).collect();
             end_timer!(mds_time);
 
             Ok((ark, mds))
         }
 
-        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
+        // Ensure the capacity is nonzero.
+        if CAPACITY == 0 {
+            bail!("The Poseidon capacity must be nonzero")
+        }
+
+        // Note: The default parameters are indexed by the rate for a capacity of 1, which is the state width minus 1.
+        let width = RATE + CAPACITY;
+        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate + 1 == width) {
             Some(entry) => {
-                let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
+                let (ark, mds) = find_poseidon_ark_and_mds::<Self>(
+                    width,
                     entry.full_rounds as u64,
                     entry.partial_rounds as u64,
                     entry.skip_matrices as u64,
@@ -106,7 +128,7 @@ pub trait PoseidonDefaultField {
                     mds,
                 })
             }
-            None => bail!("No Poseidon parameters were found for this rate"),
+            None => bail!("No Poseidon parameters were found for rate {RATE} and capacity {CAPACITY}"),
         }
     }
 }
@@ -32,6 +32,9 @@ pub mod macros;
 pub mod errors;
 pub use errors::*;
 
+pub mod store;
+pub use store::*;
+
 pub mod testnet3;
 
 pub mod prelude {
@@ -120,7 +120,8 @@ macro_rules! impl_remote {
         pub struct $name;
 
         impl $name {
-            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+            /// Returns the expected checksum, expected size, versioned filename, and local path of the parameter file.
+            fn metadata() -> (String, usize, String, std::path::PathBuf) {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
                 let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
@@ -138,6 +139,59 @@ macro_rules! impl_remote {
                 file_path.push($local_dir);
                 file_path.push(&filename);
 
+                (expected_checksum, expected_size, filename, file_path)
+            }
+
+            /// Returns the description of the parameter file, for use with a `ParameterStore`.
+            pub fn parameter() -> $crate::Parameter {
+                let (expected_checksum, expected_size, filename, _) = Self::metadata();
+                $crate::Parameter::new(filename, expected_size, expected_checksum, $remote_url)
+            }
+
+            /// Returns the local path of the parameter file, downloading and storing the file if it does not exist.
+            /// The file is checked against the expected size and checksum, without loading it into memory.
+            #[cfg(not(feature = "wasm"))]
+            pub fn load_path() -> Result<std::path::PathBuf, $crate::errors::ParameterError> {
+                use sha2::Digest;
+                use std::io::Read;
+
+                let (expected_checksum, expected_size, filename, file_path) = Self::metadata();
+
+                // Download and store the parameter file, if it does not exist.
+                if !file_path.exists() {
+                    Self::load_bytes()?;
+                }
+                if !file_path.exists() {
+                    return Err($crate::errors::ParameterError::Message(format!("Failed to store \"{}\" locally", filename)));
+                }
+
+                // Ensure the size matches.
+                let mut file = std::fs::File::open(&file_path)?;
+                let size = file.metadata()?.len() as usize;
+                if expected_size != size {
+                    return Err($crate::errors::ParameterError::SizeMismatch(expected_size, size));
+                }
+
+                // Ensure the checksum matches, reading the file in chunks.
+                let mut hasher = sha2::Sha256::new();
+                let mut chunk = vec![0u8; 1 << 20];
+                loop {
+                    match file.read(&mut chunk)? {
+                        0 => break,
+                        num_bytes => hasher.update(&chunk[..num_bytes]),
+                    }
+                }
+                let candidate_checksum = hex::encode(hasher.finalize());
+                if expected_checksum != candidate_checksum {
+                    return checksum_error!(expected_checksum, candidate_checksum)
+                }
+
+                Ok(file_path)
+            }
+
+            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let (expected_checksum, expected_size, filename, file_path) = Self::metadata();
+
                 let buffer = if file_path.exists() {
                     // Attempts to load the parameter file locally with an absolute path.
                     std::fs::read(file_path)?
@@ -305,7 +359,8 @@ macro_rules! impl_remote {
         pub struct $name;
 
         impl $name {
-            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+            /// Returns the expected checksum, expected size, versioned filename, and local path of the parameter file.
+            fn metadata() -> (String, usize, String, std::path::PathBuf) {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
 
                 let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
@@ -323,6 +378,18 @@ macro_rules! impl_remote {
                 file_path.push($local_dir);
                 file_path.push(&filename);
 
+                (expected_checksum, expected_size, filename, file_path)
+            }
+
+            /// Returns the description of the parameter file, for use with a `ParameterStore`.
+            pub fn parameter() -> $crate::Parameter {
+                let (expected_checksum, expected_size, filename, _) = Self::metadata();
+                $crate::Parameter::new(filename, expected_size, expected_checksum, $remote_url)
+            }
+
+            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let (expected_checksum, expected_size, filename, file_path) = Self::metadata();
+
                 let buffer = if file_path.exists() {
                     // Attempts to load the parameter file locally with an absolute path.
                     std::fs::read(file_path)?
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A parameter file, described by its versioned filename, and its expected size and checksum.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Parameter {
+    /// The versioned filename of the parameter file.
+    filename: String,
+    /// The expected size of the parameter file, in bytes.
+    size: usize,
+    /// The expected SHA-256 checksum of the parameter file, as a hex string.
+    checksum: String,
+    /// The URL of the default mirror of the parameter file.
+    remote_url: String,
+}
+
+impl Parameter {
+    /// Initializes a new parameter description.
+    pub fn new(filename: String, size: usize, checksum: String, remote_url: &str) -> Self {
+        Self { filename, size, checksum, remote_url: remote_url.trim_end_matches('/').to_string() }
+    }
+
+    /// Returns the versioned filename of the parameter file.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the expected size of the parameter file, in bytes.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the expected SHA-256 checksum of the parameter file, as a hex string.
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+
+    /// Returns the URL of the default mirror of the parameter file.
+    pub fn remote_url(&self) -> &str {
+        &self.remote_url
+    }
+}
@@ -0,0 +1,312 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::ParameterError, Parameter};
+
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// A callback for download progress, given the parameter, the bytes downloaded, and the total bytes.
+pub type ProgressCallback = Arc<dyn Fn(&Parameter, u64, u64) + Send + Sync>;
+
+/// A store of parameter files, which fetches each file from a list of mirrors, verifies it
+/// against its expected size and checksum, and caches it under a data directory.
+///
+/// Each file is fetched from the configured mirrors in order, and then from its default mirror.
+/// A download is written to a temporary file, and only moved into the data directory once it is
+/// verified, so the data directory never contains a partial or corrupted file.
+#[derive(Clone)]
+pub struct ParameterStore {
+    /// The data directory of the parameter files.
+    directory: PathBuf,
+    /// The base URLs of the mirrors, which are tried before the default mirror of each file.
+    mirrors: Vec<String>,
+    /// The callback for download progress, if any.
+    progress: Option<ProgressCallback>,
+}
+
+impl Default for ParameterStore {
+    /// Returns a parameter store in the default Aleo resources directory, with the default mirrors.
+    fn default() -> Self {
+        Self::new(aleo_std::aleo_dir().join("resources"))
+    }
+}
+
+impl ParameterStore {
+    /// Initializes a parameter store in the given data directory, with the default mirrors.
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self { directory: directory.into(), mirrors: Vec::new(), progress: None }
+    }
+
+    /// Sets the base URLs of the mirrors, which are tried in order before the default mirror of each file.
+    pub fn with_mirrors<S: Into<String>>(mut self, mirrors: impl IntoIterator<Item = S>) -> Self {
+        self.mirrors = mirrors.into_iter().map(|mirror| mirror.into().trim_end_matches('/').to_string()).collect();
+        self
+    }
+
+    /// Sets the callback for download progress.
+    pub fn with_progress(mut self, progress: impl Fn(&Parameter, u64, u64) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Returns the data directory of the parameter files.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the base URLs of the configured mirrors.
+    pub fn mirrors(&self) -> &[String] {
+        &self.mirrors
+    }
+
+    /// Returns the path of the given parameter file in the data directory.
+    pub fn path(&self, parameter: &Parameter) -> PathBuf {
+        self.directory.join(parameter.filename())
+    }
+
+    /// Returns `true` if the given parameter file is cached with the expected size.
+    /// Note: This does not verify the checksum; use `verify` for a full check.
+    pub fn contains(&self, parameter: &Parameter) -> bool {
+        match std::fs::metadata(self.path(parameter)) {
+            Ok(metadata) => metadata.len() == parameter.size() as u64,
+            Err(_) => false,
+        }
+    }
+
+    /// Ensures the cached parameter file has the expected size and checksum.
+    pub fn verify(&self, parameter: &Parameter) -> Result<(), ParameterError> {
+        verify_file(parameter, &self.path(parameter))
+    }
+
+    /// Returns the path of the given parameter file, fetching and caching the file if it is missing or corrupted.
+    pub fn fetch(&self, parameter: &Parameter) -> Result<PathBuf, ParameterError> {
+        let path = self.path(parameter);
+        // Return the cached file, if it is valid.
+        if self.contains(parameter) && self.verify(parameter).is_ok() {
+            return Ok(path);
+        }
+
+        // Ensure the data directory exists.
+        std::fs::create_dir_all(&self.directory)?;
+
+        // Try each mirror in order, and then the default mirror.
+        let mut errors = Vec::new();
+        for mirror in self.mirrors.iter().map(|mirror| mirror.as_str()).chain([parameter.remote_url()]) {
+            let url = format!("{mirror}/{}", parameter.filename());
+            match self.download(parameter, &url, &path) {
+                Ok(()) => return Ok(path),
+                Err(error) => errors.push(format!("{url}: {error}")),
+            }
+        }
+        Err(ParameterError::Message(format!(
+            "Failed to fetch \"{}\" from every mirror ({})",
+            parameter.filename(),
+            errors.join(", ")
+        )))
+    }
+
+    /// Returns the bytes of the given parameter file, fetching and caching the file if it is missing or corrupted.
+    pub fn load_bytes(&self, parameter: &Parameter) -> Result<Vec<u8>, ParameterError> {
+        let path = self.fetch(parameter)?;
+        let bytes = std::fs::read(path)?;
+        // Ensure the size matches, as the file may have changed since it was verified.
+        match bytes.len() == parameter.size() {
+            true => Ok(bytes),
+            false => Err(ParameterError::SizeMismatch(parameter.size(), bytes.len())),
+        }
+    }
+
+    /// Downloads the given parameter file from the given URL, and moves it to the given path once it is verified.
+    fn download(&self, parameter: &Parameter, url: &str, path: &Path) -> Result<(), ParameterError> {
+        let partial_path = path.with_file_name(format!("{}.partial", parameter.filename()));
+        let result = self.download_to(parameter, url, &partial_path);
+        match result {
+            Ok(()) => Ok(std::fs::rename(&partial_path, path)?),
+            Err(error) => {
+                let _ = std::fs::remove_file(&partial_path);
+                Err(error)
+            }
+        }
+    }
+
+    /// Downloads the given parameter file from the given URL to the given path, and verifies it.
+    fn download_to(&self, parameter: &Parameter, url: &str, path: &Path) -> Result<(), ParameterError> {
+        let mut file = File::create(path)?;
+        let mut hasher = Sha256::new();
+        let mut size = 0usize;
+        let mut write_error = None;
+
+        let mut easy = curl::easy::Easy::new();
+        easy.url(url)?;
+        easy.fail_on_error(true)?;
+        easy.progress(self.progress.is_some())?;
+        let result = {
+            let mut transfer = easy.transfer();
+            if let Some(progress) = &self.progress {
+                transfer.progress_function(|total, downloaded, _, _| {
+                    progress(parameter, downloaded as u64, total as u64);
+                    true
+                })?;
+            }
+            transfer.write_function(|data| {
+                // Abort the transfer if the file exceeds the expected size.
+                size += data.len();
+                if size > parameter.size() {
+                    return Ok(0);
+                }
+                hasher.update(data);
+                match file.write_all(data) {
+                    Ok(()) => Ok(data.len()),
+                    Err(error) => {
+                        write_error = Some(error);
+                        Ok(0)
+                    }
+                }
+            })?;
+            transfer.perform()
+        };
+        // Ensure the file was written, and did not exceed the expected size, before checking the transfer.
+        if let Some(error) = write_error {
+            return Err(error.into());
+        }
+        if size > parameter.size() {
+            return Err(ParameterError::SizeMismatch(parameter.size(), size));
+        }
+        result?;
+        file.flush()?;
+
+        // Ensure the size and checksum match.
+        if size != parameter.size() {
+            return Err(ParameterError::SizeMismatch(parameter.size(), size));
+        }
+        let checksum = hex::encode(hasher.finalize());
+        if checksum != parameter.checksum() {
+            return checksum_error!(parameter.checksum().to_string(), checksum);
+        }
+        Ok(())
+    }
+}
+
+/// Ensures the file at the given path has the expected size and checksum of the given parameter file.
+fn verify_file(parameter: &Parameter, path: &Path) -> Result<(), ParameterError> {
+    let mut file = File::open(path)?;
+    let size = file.metadata()?.len() as usize;
+    if size != parameter.size() {
+        return Err(ParameterError::SizeMismatch(parameter.size(), size));
+    }
+
+    // Compute the checksum, reading the file in chunks.
+    let mut hasher = Sha256::new();
+    let mut chunk = vec![0u8; 1 << 20];
+    loop {
+        match file.read(&mut chunk)? {
+            0 => break,
+            num_bytes => hasher.update(&chunk[..num_bytes]),
+        }
+    }
+    let checksum = hex::encode(hasher.finalize());
+    if checksum != parameter.checksum() {
+        return checksum_error!(parameter.checksum().to_string(), checksum);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// Returns a fresh temporary directory with the given name.
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("parameter_store_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    /// Writes a sample parameter file to the given mirror directory, and returns its description.
+    fn sample_parameter(mirror: &Path, remote_url: &str) -> Parameter {
+        let bytes = (0..100_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let parameter =
+            Parameter::new("sample.prover.0123456".to_string(), bytes.len(), checksum!(&bytes), remote_url);
+        std::fs::write(mirror.join(parameter.filename()), &bytes).unwrap();
+        parameter
+    }
+
+    #[test]
+    fn test_fetch_from_mirror() {
+        let (mirror, directory) = (temp_dir("mirror"), temp_dir("cache"));
+        let parameter = sample_parameter(&mirror, "file:///nonexistent");
+
+        // Ensure the file is fetched from the mirror, with progress updates.
+        let num_updates = Arc::new(AtomicU64::new(0));
+        let progress = num_updates.clone();
+        let store = ParameterStore::new(&directory)
+            .with_mirrors([format!("file://{}", mirror.display())])
+            .with_progress(move |_, _, _| {
+                progress.fetch_add(1, Ordering::SeqCst);
+            });
+        assert!(!store.contains(&parameter));
+        let path = store.fetch(&parameter).unwrap();
+        assert_eq!(path, directory.join(parameter.filename()));
+        assert!(store.contains(&parameter));
+        assert!(store.verify(&parameter).is_ok());
+        assert_eq!(parameter.size(), store.load_bytes(&parameter).unwrap().len());
+        assert!(num_updates.load(Ordering::SeqCst) > 0);
+
+        // Ensure a corrupted cached file is detected, and fetched again.
+        std::fs::write(&path, vec![0u8; parameter.size()]).unwrap();
+        assert!(store.contains(&parameter));
+        assert!(store.verify(&parameter).is_err());
+        store.fetch(&parameter).unwrap();
+        assert!(store.verify(&parameter).is_ok());
+
+        let _ = std::fs::remove_dir_all(mirror);
+        let _ = std::fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn test_fetch_falls_back() {
+        let (mirror, directory) = (temp_dir("fallback_mirror"), temp_dir("fallback_cache"));
+        // The default mirror has the file, but the configured mirror does not.
+        let parameter = sample_parameter(&mirror, &format!("file://{}", mirror.display()));
+        let store = ParameterStore::new(&directory).with_mirrors(["file:///nonexistent"]);
+        assert!(store.fetch(&parameter).is_ok());
+
+        // Ensure a mismatched checksum is rejected, and no file is cached.
+        let invalid = Parameter::new(
+            parameter.filename().to_string(),
+            parameter.size(),
+            "00".repeat(32),
+            parameter.remote_url(),
+        );
+        let store = ParameterStore::new(temp_dir("fallback_invalid"));
+        assert!(store.fetch(&invalid).is_err());
+        assert!(!store.path(&invalid).exists());
+        assert!(!store.directory().join(format!("{}.partial", invalid.filename())).exists());
+
+        let _ = std::fs::remove_dir_all(mirror);
+        let _ = std::fs::remove_dir_all(directory);
+        let _ = std::fs::remove_dir_all(store.directory());
+    }
+}
@@ -99,3 +99,68 @@ lazy_static! {
         map
     };
 }
+
+lazy_static! {
+    pub static ref TESTNET3_CREDITS_VERIFYING_KEYS: indexmap::IndexMap<String, Vec<u8>> = {
+        macro_rules! insert_verifying_key {
+            ($map: ident, $vname: ident, $fname: tt) => {
+                $map.insert($fname.to_string(), $vname::load_bytes().expect("Failed to load verifying key"));
+            };
+        }
+        let mut map = indexmap::IndexMap::new();
+        insert_verifying_key!(map, CombineVerifier, "combine");
+        insert_verifying_key!(map, FeeVerifier, "fee");
+        insert_verifying_key!(map, GenesisVerifier, "genesis");
+        insert_verifying_key!(map, MintVerifier, "mint");
+        insert_verifying_key!(map, SplitVerifier, "split");
+        insert_verifying_key!(map, TransferVerifier, "transfer");
+        map
+    };
+}
+
+/// Returns the local path of the proving key for the given credits function,
+/// downloading and storing the proving key if it does not exist.
+#[cfg(not(feature = "wasm"))]
+pub fn credits_proving_key_path(function_name: &str) -> Result<std::path::PathBuf, crate::errors::ParameterError> {
+    match function_name {
+        "combine" => CombineProver::load_path(),
+        "fee" => FeeProver::load_path(),
+        "genesis" => GenesisProver::load_path(),
+        "mint" => MintProver::load_path(),
+        "split" => SplitProver::load_path(),
+        "transfer" => TransferProver::load_path(),
+        _ => Err(crate::errors::ParameterError::Message(format!("Unknown credits function '{function_name}'"))),
+    }
+}
+
+/// Returns the description of every remote parameter file, for use with a `ParameterStore`.
+pub fn remote_parameters() -> Vec<crate::Parameter> {
+    vec![
+        Degree16::parameter(),
+        Degree17::parameter(),
+        Degree18::parameter(),
+        Degree19::parameter(),
+        Degree20::parameter(),
+        Degree21::parameter(),
+        Degree22::parameter(),
+        Degree23::parameter(),
+        Degree24::parameter(),
+        Degree25::parameter(),
+        Degree26::parameter(),
+        Degree27::parameter(),
+        Degree28::parameter(),
+        TrialSRS::parameter(),
+        CombineProver::parameter(),
+        CombineVerifier::parameter(),
+        FeeProver::parameter(),
+        FeeVerifier::parameter(),
+        GenesisProver::parameter(),
+        GenesisVerifier::parameter(),
+        MintProver::parameter(),
+        MintVerifier::parameter(),
+        SplitProver::parameter(),
+        SplitVerifier::parameter(),
+        TransferProver::parameter(),
+        TransferVerifier::parameter(),
+    ]
+}
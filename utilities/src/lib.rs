@@ -73,6 +73,11 @@ pub use self::rand::*;
 pub mod serialize;
 pub use serialize::*;
 
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub use stream::*;
+
 #[cfg(not(feature = "std"))]
 pub mod io;
 
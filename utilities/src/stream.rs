@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A chunked framing for streaming `ToBytes` objects over a socket, without materializing
+//! the entire byte vector of the object.
+//!
+//! The bytes of an object are split into frames of the form `length || payload || checksum`,
+//! where `length` is the payload size as a `u32`, and `checksum` is the CRC-32 of the payload as a `u32`.
+//! The stream is terminated by an empty frame.
+
+use crate::{error, io::Result as IoResult, FromBytes, ToBytes};
+
+use std::io::{Read, Write};
+
+/// The default size of a frame payload, in bytes (64 KiB).
+pub const DEFAULT_FRAME_SIZE: usize = 64 * 1024;
+/// The maximum size of a frame payload accepted by a `FrameReader`, in bytes (16 MiB).
+pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// Writes the given object to the given writer, in frames of `DEFAULT_FRAME_SIZE` bytes.
+pub fn write_framed<T: ToBytes, W: Write>(object: &T, writer: W) -> IoResult<W> {
+    let mut writer = FrameWriter::new(writer);
+    object.write_le(&mut writer)?;
+    writer.finish()
+}
+
+/// Reads an object from the given framed reader, and ensures the stream ends after the object.
+pub fn read_framed<T: FromBytes, R: Read>(reader: R) -> IoResult<T> {
+    let mut reader = FrameReader::new(reader);
+    let object = T::read_le(&mut reader)?;
+    reader.finish()?;
+    Ok(object)
+}
+
+/// A writer, which splits the written bytes into checksummed frames.
+///
+/// `FrameWriter::finish` must be called to write the remaining bytes and terminate the stream.
+pub struct FrameWriter<W: Write> {
+    /// The underlying writer.
+    writer: W,
+    /// The bytes of the pending frame.
+    buffer: Vec<u8>,
+    /// The size of a frame payload, in bytes.
+    frame_size: usize,
+}
+
+impl<W: Write> FrameWriter<W> {
+    /// Initializes a new frame writer, with frames of `DEFAULT_FRAME_SIZE` bytes.
+    pub fn new(writer: W) -> Self {
+        Self::with_frame_size(writer, DEFAULT_FRAME_SIZE)
+    }
+
+    /// Initializes a new frame writer, with frames of the given size, which is clamped to `1..=MAX_FRAME_SIZE`.
+    pub fn with_frame_size(writer: W, frame_size: usize) -> Self {
+        let frame_size = frame_size.clamp(1, MAX_FRAME_SIZE);
+        Self { writer, buffer: Vec::with_capacity(frame_size), frame_size }
+    }
+
+    /// Writes the pending frame, and the empty frame that terminates the stream, and returns the underlying writer.
+    pub fn finish(mut self) -> IoResult<W> {
+        // Write the pending frame, if it is not empty.
+        if !self.buffer.is_empty() {
+            self.write_frame()?;
+        }
+        // Write the empty frame.
+        self.write_frame()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Writes the pending bytes as a frame, which is empty if there are no pending bytes.
+    fn write_frame(&mut self) -> IoResult<()> {
+        (self.buffer.len() as u32).write_le(&mut self.writer)?;
+        self.writer.write_all(&self.buffer)?;
+        crc32fast::hash(&self.buffer).write_le(&mut self.writer)?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for FrameWriter<W> {
+    fn write(&mut self, bytes: &[u8]) -> IoResult<usize> {
+        // Write a frame, if the pending frame is full.
+        if self.buffer.len() == self.frame_size {
+            self.write_frame()?;
+        }
+        // Append as many bytes as fit in the pending frame.
+        let num_bytes = bytes.len().min(self.frame_size - self.buffer.len());
+        self.buffer.extend_from_slice(&bytes[..num_bytes]);
+        Ok(num_bytes)
+    }
+
+    /// Flushes the underlying writer. The pending frame is not written,
+    /// so the frames are independent of the calls to `flush`.
+    fn flush(&mut self) -> IoResult<()> {
+        self.writer.flush()
+    }
+}
+
+/// A reader, which reads the bytes of checksummed frames, until the empty frame that terminates the stream.
+pub struct FrameReader<R: Read> {
+    /// The underlying reader.
+    reader: R,
+    /// The payload of the current frame.
+    buffer: Vec<u8>,
+    /// The number of bytes read from the current frame.
+    position: usize,
+    /// If `true`, the empty frame that terminates the stream was read.
+    is_finished: bool,
+}
+
+impl<R: Read> FrameReader<R> {
+    /// Initializes a new frame reader.
+    pub fn new(reader: R) -> Self {
+        Self { reader, buffer: Vec::new(), position: 0, is_finished: false }
+    }
+
+    /// Ensures the stream ends after the bytes read so far, and returns the underlying reader.
+    pub fn finish(mut self) -> IoResult<R> {
+        // Ensure there are no unread bytes in the current frame.
+        if self.position < self.buffer.len() {
+            return Err(error("Found trailing bytes in the framed stream"));
+        }
+        // Ensure the next frame terminates the stream.
+        if !self.is_finished {
+            self.read_frame()?;
+            if !self.is_finished {
+                return Err(error("Found trailing frames in the framed stream"));
+            }
+        }
+        Ok(self.reader)
+    }
+
+    /// Reads the next frame, and verifies its checksum.
+    fn read_frame(&mut self) -> IoResult<()> {
+        // Read the length.
+        let length = u32::read_le(&mut self.reader)? as usize;
+        if length > MAX_FRAME_SIZE {
+            return Err(error(format!("The frame size {length} exceeds the maximum of {MAX_FRAME_SIZE} bytes")));
+        }
+        // Read the payload.
+        self.buffer.resize(length, 0u8);
+        self.reader.read_exact(&mut self.buffer)?;
+        self.position = 0;
+        // Ensure the checksum is valid.
+        if u32::read_le(&mut self.reader)? != crc32fast::hash(&self.buffer) {
+            return Err(error("Invalid checksum in the framed stream"));
+        }
+        // An empty frame terminates the stream.
+        self.is_finished = length == 0;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for FrameReader<R> {
+    fn read(&mut self, bytes: &mut [u8]) -> IoResult<usize> {
+        // Read the next frame, if the current frame is exhausted.
+        while self.position == self.buffer.len() {
+            if self.is_finished {
+                return Ok(0);
+            }
+            self.read_frame()?;
+        }
+        // Copy as many bytes as are available in the current frame.
+        let num_bytes = bytes.len().min(self.buffer.len() - self.position);
+        bytes[..num_bytes].copy_from_slice(&self.buffer[self.position..self.position + num_bytes]);
+        self.position += num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_rng, Uniform};
+
+    /// Returns a framed stream of the given values, with frames of the given size.
+    fn sample_stream(values: &[u64], frame_size: usize) -> Vec<u8> {
+        let mut writer = FrameWriter::with_frame_size(vec![], frame_size);
+        values.iter().try_for_each(|value| value.write_le(&mut writer)).unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn test_framed_round_trip() {
+        let mut rng = test_rng();
+        let values = (0..1_000).map(|_| u64::rand(&mut rng)).collect::<Vec<_>>();
+
+        for frame_size in [1, 7, 64, DEFAULT_FRAME_SIZE] {
+            let stream = sample_stream(&values, frame_size);
+            // Ensure the values are read back in order, and the stream terminates.
+            let mut reader = FrameReader::new(&stream[..]);
+            for expected in &values {
+                assert_eq!(*expected, u64::read_le(&mut reader).unwrap());
+            }
+            assert!(reader.finish().unwrap().is_empty());
+        }
+
+        // Ensure a single object round trips.
+        let stream = write_framed(&values[0], vec![]).unwrap();
+        assert_eq!(values[0], read_framed::<u64, _>(&stream[..]).unwrap());
+    }
+
+    #[test]
+    fn test_framed_rejects_invalid_streams() {
+        let stream = sample_stream(&[1, 2, 3], 8);
+
+        // Ensure a corrupted payload fails the checksum.
+        let mut candidate = stream.clone();
+        candidate[4] ^= 1;
+        assert!(read_framed::<u64, _>(&candidate[..]).is_err());
+
+        // Ensure a truncated stream is rejected.
+        assert!(read_framed::<u64, _>(&stream[..stream.len() - 1]).is_err());
+
+        // Ensure trailing values are rejected.
+        assert!(read_framed::<u64, _>(&stream[..]).is_err());
+
+        // Ensure an oversized frame is rejected.
+        let mut candidate = (MAX_FRAME_SIZE as u32 + 1).to_le_bytes().to_vec();
+        candidate.extend_from_slice(&stream);
+        assert!(read_framed::<u64, _>(&candidate[..]).is_err());
+    }
+}
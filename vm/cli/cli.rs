@@ -18,6 +18,7 @@ use crate::cli::Updater;
 
 use anyhow::Result;
 use clap::Parser;
+use std::path::PathBuf;
 
 #[derive(Debug, Parser)]
 #[clap(name = "snarkVM", author = "The Aleo Team <hello@aleo.org>", setting = clap::AppSettings::ColoredHelp)]
@@ -41,6 +42,15 @@ pub enum Command {
         #[clap(short = 'q', long)]
         quiet: bool,
     },
+    /// Download and verify the parameters
+    Fetch {
+        /// The base URL of a mirror, which is tried before the default mirror (may be repeated)
+        #[clap(short = 'm', long)]
+        mirror: Vec<String>,
+        /// The directory to store the parameters in [default: ~/.aleo/resources]
+        #[clap(short = 'd', long)]
+        directory: Option<PathBuf>,
+    },
 }
 
 impl Command {
@@ -71,7 +81,27 @@ impl Command {
                         Ok("".to_string())
                     }
                 }
-            }, // _ => Err(anyhow!("\nUnknown command\n")),
+            },
+            Command::Fetch { mirror, directory } => {
+                use snarkvm_parameters::ParameterStore;
+                use std::io::Write;
+
+                let store = match directory {
+                    Some(directory) => ParameterStore::new(directory),
+                    None => ParameterStore::default(),
+                };
+                let store = store.with_mirrors(mirror.clone()).with_progress(|parameter, downloaded, total| {
+                    print!("\r{} - {downloaded} of {total} bytes", parameter.filename());
+                    let _ = std::io::stdout().flush();
+                });
+
+                // Fetch and verify each parameter file.
+                for parameter in snarkvm_parameters::testnet3::remote_parameters() {
+                    let path = store.fetch(&parameter)?;
+                    println!("\r{} - Verified {}", parameter.filename(), path.display());
+                }
+                Ok(format!("\nThe parameters are stored in {}", store.directory().display()))
+            } // _ => Err(anyhow!("\nUnknown command\n")),
         }
     }
 }
@@ -21,7 +21,10 @@ mod string;
 mod to_bits;
 mod to_hash;
 
-use console::{network::prelude::*, types::Field};
+use console::{
+    network::{prelude::*, DomainTag},
+    types::Field,
+};
 
 use core::marker::PhantomData;
 
@@ -17,15 +17,37 @@
 use super::*;
 
 impl<N: Network> Metadata<N> {
-    /// Returns the metadata hash.
+    /// Returns the metadata hash, in the network and epoch domain of the block height.
     pub fn to_hash(&self) -> Result<Field<N>> {
         // Construct the metadata bits (the last leaf in the Merkle tree).
         let metadata_bits = self.to_bits_le(); // 304 bits
         // Ensure the metadata bits is the correct size.
         ensure!(metadata_bits.len() == 304, "Incorrect metadata size");
-        // Hash the metadata bits.
-        let metadata_hash = N::hash_bhp512(&metadata_bits)?;
+        // Hash the metadata bits, in the network and epoch domain of the block.
+        let metadata_hash = DomainTag::from_height(self.height)?.hash_bhp512(&metadata_bits)?;
         // Return the metadata hash.
         Ok(metadata_hash)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_hash_in_domain() -> Result<()> {
+        // Ensure the metadata hash before the domain separation epoch is untagged.
+        let metadata = Metadata::<CurrentNetwork>::new(CurrentNetwork::ID, 1, 1, 1, 1, 1)?;
+        assert_eq!(CurrentNetwork::hash_bhp512(&metadata.to_bits_le())?, metadata.to_hash()?);
+
+        // Ensure the metadata hash from the domain separation epoch onwards is tagged.
+        let height = CurrentNetwork::DOMAIN_SEPARATION_EPOCH * CurrentNetwork::BLOCKS_PER_EPOCH;
+        let metadata = Metadata::<CurrentNetwork>::new(CurrentNetwork::ID, 1, height, 1, 1, 1)?;
+        assert_ne!(CurrentNetwork::hash_bhp512(&metadata.to_bits_le())?, metadata.to_hash()?);
+        assert_eq!(DomainTag::from_height(height)?.hash_bhp512(&metadata.to_bits_le())?, metadata.to_hash()?);
+        Ok(())
+    }
+}
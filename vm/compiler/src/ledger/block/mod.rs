@@ -20,6 +20,9 @@ pub use header::*;
 mod transactions;
 pub use transactions::*;
 
+mod weight;
+pub use weight::*;
+
 mod bytes;
 mod genesis;
 mod serialize;
@@ -31,7 +34,7 @@ use crate::{
 };
 use console::{
     account::{Address, PrivateKey, Signature},
-    network::prelude::*,
+    network::{prelude::*, DomainTag},
     program::Value,
     types::{Field, Group},
 };
@@ -61,14 +64,20 @@ impl<N: Network> Block<N> {
     ) -> Result<Self> {
         // Ensure the block is not empty.
         ensure!(!transactions.is_empty(), "Cannot create block with no transactions");
+        // Retrieve the network and epoch domain of the block.
+        let domain = DomainTag::from_height(header.height())?;
         // Compute the block hash.
-        let block_hash = N::hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())?;
+        let block_hash = Self::compute_hash(&domain, previous_hash, &header)?;
         // Sign the block hash.
-        let signature = private_key.sign(&[block_hash], rng)?;
+        let signature = private_key.sign_with_domain(&domain, &[block_hash], rng)?;
         // Derive the signer address.
         let address = Address::try_from(private_key)?;
         // Ensure the signature is valid.
-        ensure!(signature.verify(&address, &[block_hash]), "Invalid signature for block {}", header.height());
+        ensure!(
+            signature.verify_with_domain(&address, &domain, &[block_hash]),
+            "Invalid signature for block {}",
+            header.height()
+        );
         // Construct the block.
         Ok(Self { block_hash: block_hash.into(), previous_hash, header, transactions, signature })
     }
@@ -82,15 +91,26 @@ impl<N: Network> Block<N> {
     ) -> Result<Self> {
         // Ensure the block is not empty.
         ensure!(!transactions.is_empty(), "Cannot create block with no transactions");
+        // Retrieve the network and epoch domain of the block.
+        let domain = DomainTag::from_height(header.height())?;
         // Compute the block hash.
-        let block_hash = N::hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())?;
+        let block_hash = Self::compute_hash(&domain, previous_hash, &header)?;
         // Derive the signer address.
         let address = signature.to_address();
         // Ensure the signature is valid.
-        ensure!(signature.verify(&address, &[block_hash]), "Invalid signature for block {}", header.height());
+        ensure!(
+            signature.verify_with_domain(&address, &domain, &[block_hash]),
+            "Invalid signature for block {}",
+            header.height()
+        );
         // Construct the block.
         Ok(Self { block_hash: block_hash.into(), previous_hash, header, transactions, signature })
     }
+
+    /// Returns the block hash for the given previous hash and header, in the given network and epoch domain.
+    pub fn compute_hash(domain: &DomainTag<N>, previous_hash: N::BlockHash, header: &Header<N>) -> Result<Field<N>> {
+        domain.hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::ledger::{TransactionWeight, PROOF_VERIFICATION_COST};
+
+/// The weight of a block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockWeight<N: Network> {
+    /// The block hash.
+    hash: N::BlockHash,
+    /// The size of the serialized block, in bytes.
+    size_in_bytes: usize,
+    /// The number of proofs and certificates in the block.
+    num_proofs: usize,
+    /// The estimated cost of verifying the block.
+    verification_cost: u64,
+    /// The weight of each transaction in the block.
+    transactions: Vec<TransactionWeight<N>>,
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the weight of the block.
+    ///
+    /// The verification cost of a block is the sum of the cost of its transactions,
+    /// and the cost of verifying the block signature.
+    pub fn weight(&self) -> Result<BlockWeight<N>> {
+        // Compute the weight of each transaction.
+        let transactions = self.transactions.transactions().map(Transaction::weight).collect::<Result<Vec<_>>>()?;
+
+        Ok(BlockWeight {
+            hash: self.block_hash,
+            size_in_bytes: self.to_bytes_le()?.len(),
+            num_proofs: transactions.iter().map(TransactionWeight::num_proofs).sum(),
+            verification_cost: PROOF_VERIFICATION_COST
+                + transactions.iter().map(TransactionWeight::verification_cost).sum::<u64>(),
+            transactions,
+        })
+    }
+}
+
+impl<N: Network> BlockWeight<N> {
+    /// Returns the block hash.
+    pub const fn hash(&self) -> &N::BlockHash {
+        &self.hash
+    }
+
+    /// Returns the size of the serialized block, in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of proofs and certificates in the block.
+    pub const fn num_proofs(&self) -> usize {
+        self.num_proofs
+    }
+
+    /// Returns the estimated cost of verifying the block.
+    pub const fn verification_cost(&self) -> u64 {
+        self.verification_cost
+    }
+
+    /// Returns the weight of each transaction in the block.
+    pub fn transactions(&self) -> &[TransactionWeight<N>] {
+        &self.transactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight() -> Result<()> {
+        let block = crate::ledger::test_helpers::sample_genesis_block();
+        let weight = block.weight()?;
+
+        assert_eq!(block.hash(), *weight.hash());
+        assert_eq!(block.to_bytes_le()?.len(), weight.size_in_bytes());
+        assert_eq!(block.transactions().len(), weight.transactions().len());
+
+        // Ensure the block weight covers the weight of its transactions.
+        let transaction_size = weight.transactions().iter().map(TransactionWeight::size_in_bytes).sum::<usize>();
+        let transaction_cost = weight.transactions().iter().map(TransactionWeight::verification_cost).sum::<u64>();
+        assert!(weight.size_in_bytes() > transaction_size);
+        assert_eq!(weight.verification_cost(), PROOF_VERIFICATION_COST + transaction_cost);
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::program::Value;
+
+/// The policy for selecting the records that are spent, which determines the change returned to the caller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChangePolicy {
+    /// Spends the smallest record that covers the amount, which minimizes the change.
+    MinimizeChange,
+    /// Spends the largest record, which keeps the smaller records for later transactions.
+    MaximizeChange,
+}
+
+impl Default for ChangePolicy {
+    /// Returns the default change policy, which minimizes the change.
+    fn default() -> Self {
+        Self::MinimizeChange
+    }
+}
+
+/// A builder for a `credits.aleo/transfer` transaction, which selects the records to spend,
+/// and returns the authorized and executed transaction in one call.
+///
+/// The records are selected from the unspent records of the caller in the ledger,
+/// or from the records given with `TransactionBuilder::input`. The transfer consumes one record,
+/// and the additional fee (if any) consumes another, where the change of each is returned to the caller.
+#[derive(Clone)]
+pub struct TransactionBuilder<N: Network> {
+    /// The private key of the caller.
+    private_key: PrivateKey<N>,
+    /// The records that may be spent, or empty to select from the unspent records in the ledger.
+    inputs: Vec<Record<N, Plaintext<N>>>,
+    /// The recipients, and their amounts (in gates).
+    recipients: Vec<(Address<N>, u64)>,
+    /// The policy for selecting the records that are spent.
+    change_policy: ChangePolicy,
+    /// The additional fee (in gates), if any.
+    fee: Option<u64>,
+}
+
+impl<N: Network> TransactionBuilder<N> {
+    /// Initializes a new transaction builder, for the given caller.
+    pub fn new(private_key: PrivateKey<N>) -> Self {
+        Self { private_key, inputs: vec![], recipients: vec![], change_policy: Default::default(), fee: None }
+    }
+
+    /// Adds a record that may be spent, instead of selecting from the unspent records in the ledger.
+    pub fn input(mut self, record: Record<N, Plaintext<N>>) -> Self {
+        self.inputs.push(record);
+        self
+    }
+
+    /// Adds a recipient of the given amount (in gates).
+    pub fn recipient(mut self, address: Address<N>, amount: u64) -> Self {
+        self.recipients.push((address, amount));
+        self
+    }
+
+    /// Sets the policy for selecting the records that are spent.
+    pub const fn change_policy(mut self, change_policy: ChangePolicy) -> Self {
+        self.change_policy = change_policy;
+        self
+    }
+
+    /// Sets the additional fee (in gates).
+    pub const fn fee(mut self, fee: u64) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Selects the records to spend, and returns the authorized and executed transfer transaction.
+    pub fn build<B: BlockStorage<N>, P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        ledger: &Ledger<N, B, P>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure there is exactly one recipient, as a transfer has a single recipient.
+        let (address, amount) = match self.recipients.as_slice() {
+            [recipient] => *recipient,
+            [] => bail!("The transaction does not have a recipient"),
+            _ => bail!("The transaction has {} recipients, but a transfer supports 1", self.recipients.len()),
+        };
+        ensure!(amount > 0, "The amount for '{address}' must be nonzero");
+
+        // Select the record for the transfer.
+        let mut records = self.spendable_records(ledger)?;
+        let record = self
+            .select(&mut records, amount)
+            .ok_or_else(|| anyhow!("There is no spendable record that covers {amount} gates for '{address}'"))?;
+        // Select the record for the additional fee, if any.
+        let additional_fee = match self.fee {
+            Some(fee) => match self.select(&mut records, fee) {
+                Some(credits) => Some((credits, fee)),
+                None => bail!("There is no other spendable record that covers the fee of {fee} gates"),
+            },
+            None => None,
+        };
+
+        // Construct the transfer transaction.
+        let inputs =
+            [Value::Record(record), Value::from_str(&address.to_string())?, Value::from_str(&format!("{amount}u64"))?];
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+        Transaction::execute(ledger.vm(), &self.private_key, &program_id, function_name, &inputs, additional_fee, rng)
+    }
+
+    /// Returns the records that may be spent, ordered from the smallest to the largest.
+    fn spendable_records<B: BlockStorage<N>, P: ProgramStorage<N>>(
+        &self,
+        ledger: &Ledger<N, B, P>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let mut records = match self.inputs.is_empty() {
+            true => ledger
+                .find_records(&ViewKey::try_from(&self.private_key)?, RecordsFilter::Unspent)?
+                .map(|(_, record)| record)
+                .collect::<Vec<_>>(),
+            false => self.inputs.clone(),
+        };
+        records.retain(|record| !record.gates().is_zero());
+        records.sort_by_key(|record| ***record.gates());
+        Ok(records)
+    }
+
+    /// Removes and returns the record that covers the given amount (in gates), according to the change policy.
+    fn select(&self, records: &mut Vec<Record<N, Plaintext<N>>>, amount: u64) -> Option<Record<N, Plaintext<N>>> {
+        let position = match self.change_policy {
+            ChangePolicy::MinimizeChange => records.iter().position(|record| ***record.gates() >= amount)?,
+            ChangePolicy::MaximizeChange => match records.last() {
+                Some(record) if ***record.gates() >= amount => records.len() - 1,
+                _ => return None,
+            },
+        };
+        Some(records.remove(position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::test_helpers;
+    use console::network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_transaction_builder() {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = test_helpers::sample_genesis_private_key();
+        let ledger = test_helpers::sample_genesis_ledger();
+
+        // Sample a recipient.
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Build a transfer to the recipient.
+        for change_policy in [ChangePolicy::MinimizeChange, ChangePolicy::MaximizeChange] {
+            let transaction = TransactionBuilder::new(private_key)
+                .recipient(recipient, 1_000)
+                .change_policy(change_policy)
+                .build(&ledger, rng)
+                .unwrap();
+            assert!(ledger.vm().verify(&transaction));
+            assert_eq!(1, transaction.transitions().count());
+        }
+    }
+
+    #[test]
+    fn test_transaction_builder_fails() {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = test_helpers::sample_genesis_private_key();
+        let ledger = test_helpers::sample_genesis_ledger();
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Ensure a transaction without exactly one recipient is rejected.
+        assert!(TransactionBuilder::new(private_key).build(&ledger, rng).is_err());
+        let builder = TransactionBuilder::new(private_key).recipient(recipient, 1).recipient(recipient, 1);
+        assert!(builder.build(&ledger, rng).is_err());
+        // Ensure a zero or uncovered amount is rejected.
+        assert!(TransactionBuilder::new(private_key).recipient(recipient, 0).build(&ledger, rng).is_err());
+        assert!(TransactionBuilder::new(private_key).recipient(recipient, u64::MAX).build(&ledger, rng).is_err());
+        // Ensure a fee is rejected, as the genesis account has a single record.
+        let builder = TransactionBuilder::new(private_key).recipient(recipient, 1).fee(1);
+        assert!(builder.build(&ledger, rng).is_err());
+    }
+}
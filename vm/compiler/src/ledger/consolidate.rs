@@ -0,0 +1,319 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A record that is consumed by a step of a consolidation plan.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlannedRecord<N: Network> {
+    /// An unspent record, with the given commitment.
+    Unspent(Field<N>),
+    /// The output record of the step at the given index in the plan.
+    Output(usize),
+}
+
+/// A self-transfer transaction, which merges its input records into one.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConsolidationStep<N: Network> {
+    /// The input records.
+    inputs: Vec<PlannedRecord<N>>,
+    /// The number of gates in the output record, after the fee is paid.
+    gates: u64,
+}
+
+impl<N: Network> ConsolidationStep<N> {
+    /// Returns the input records.
+    pub fn inputs(&self) -> &[PlannedRecord<N>] {
+        &self.inputs
+    }
+
+    /// Returns the number of gates in the output record, after the fee is paid.
+    pub const fn gates(&self) -> u64 {
+        self.gates
+    }
+}
+
+/// A sequence of self-transfer transactions that consolidates many small records into one.
+///
+/// The records are merged in groups of up to `max_inputs` in rounds, smallest first, so the steps within a round
+/// are independent of one another, and the plan completes in `log_{max_inputs}(n)` rounds of blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsolidationPlan<N: Network> {
+    /// The consolidation steps, in the order they must be executed.
+    steps: Vec<ConsolidationStep<N>>,
+    /// The index of the round each step belongs to.
+    rounds: Vec<usize>,
+    /// The commitments of the records in groups that are worth less than the fee, and are left untouched.
+    dust: Vec<Field<N>>,
+    /// The total fee paid by the plan (in gates).
+    total_fee: u64,
+}
+
+impl<N: Network> ConsolidationPlan<N> {
+    /// Returns a plan to consolidate the given `(commitment, gates)` records, with at most `max_inputs` records
+    /// per transaction and at most `max_executions` steps, where each step pays `fee_per_execution` gates
+    /// from its output record.
+    ///
+    /// In the first round, the records in a group whose combined value cannot cover the fee are left as dust.
+    /// In a later round, the records in such a group are carried into the next round, and the plan ends
+    /// once a round has no group that can cover the fee. If `max_executions` is reached, the remaining
+    /// records are left out of the plan.
+    pub fn new(
+        records: &[(Field<N>, u64)],
+        fee_per_execution: u64,
+        max_inputs: usize,
+        max_executions: usize,
+    ) -> Result<Self> {
+        // Ensure a transaction merges at least two records, and no more than the maximum number of inputs.
+        ensure!(max_inputs >= 2, "A consolidation step must merge at least two records");
+        ensure!(max_inputs <= N::MAX_INPUTS, "A consolidation step cannot exceed {} inputs", N::MAX_INPUTS);
+        // Ensure the commitments are unique.
+        ensure!(!has_duplicates(records.iter().map(|(commitment, _)| commitment)), "Found a duplicate record");
+
+        // Sort the records, smallest first.
+        let mut records = records.to_vec();
+        records.sort_by_key(|(_, gates)| *gates);
+
+        // Initialize the queue of records to merge.
+        let mut queue: Vec<_> =
+            records.into_iter().map(|(commitment, gates)| (PlannedRecord::Unspent(commitment), gates)).collect();
+
+        let mut steps = Vec::new();
+        let mut rounds = Vec::new();
+        let mut dust = Vec::new();
+        let mut total_fee = 0u64;
+
+        // Merge the records in groups, until a single record remains.
+        let mut round = 0;
+        'rounds: while queue.len() > 1 {
+            let mut next_queue = Vec::with_capacity(queue.len());
+            let mut is_merged = false;
+            for group in queue.chunks(max_inputs) {
+                // Stop once the maximum number of steps is reached.
+                if steps.len() == max_executions {
+                    break 'rounds;
+                }
+                // Carry an unpaired record into the next round.
+                if group.len() == 1 {
+                    next_queue.extend_from_slice(group);
+                    continue;
+                }
+
+                // Compute the combined value of the group.
+                let combined = group
+                    .iter()
+                    .try_fold(0u64, |sum, (_, gates)| sum.checked_add(*gates))
+                    .ok_or_else(|| anyhow!("Overflow while computing the consolidated record balance"))?;
+                // Compute the gates of the output record, after the fee is paid.
+                match combined.checked_sub(fee_per_execution) {
+                    Some(gates) if gates > 0 => {
+                        total_fee = total_fee.saturating_add(fee_per_execution);
+
+                        // Add the step, and carry its output record into the next round.
+                        next_queue.push((PlannedRecord::Output(steps.len()), gates));
+                        let inputs = group.iter().map(|(record, _)| *record).collect();
+                        steps.push(ConsolidationStep { inputs, gates });
+                        rounds.push(round);
+                        is_merged = true;
+                    }
+                    // Leave the unspent records of a group that cannot cover the fee as dust.
+                    _ if round == 0 => dust.extend(group.iter().filter_map(|(record, _)| match record {
+                        PlannedRecord::Unspent(commitment) => Some(*commitment),
+                        PlannedRecord::Output(_) => None,
+                    })),
+                    // Carry the records of a group that cannot cover the fee into the next round.
+                    _ => next_queue.extend_from_slice(group),
+                }
+            }
+            // Stop once a later round has no group that can cover the fee.
+            if round > 0 && !is_merged {
+                break;
+            }
+            queue = next_queue;
+            round += 1;
+        }
+
+        Ok(Self { steps, rounds, dust, total_fee })
+    }
+
+    /// Returns the consolidation steps, in the order they must be executed.
+    pub fn steps(&self) -> &[ConsolidationStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the consolidation steps in the given round, which may be executed independently of one another.
+    pub fn steps_in_round(&self, round: usize) -> impl '_ + Iterator<Item = &ConsolidationStep<N>> {
+        self.steps.iter().zip_eq(&self.rounds).filter(move |(_, r)| **r == round).map(|(step, _)| step)
+    }
+
+    /// Returns the number of rounds in the plan.
+    pub fn num_rounds(&self) -> usize {
+        self.rounds.last().map_or(0, |round| round + 1)
+    }
+
+    /// Returns the commitments of the records in groups that are worth less than the fee, and are left untouched.
+    pub fn dust(&self) -> &[Field<N>] {
+        &self.dust
+    }
+
+    /// Returns the total fee paid by the plan (in gates).
+    pub const fn total_fee(&self) -> u64 {
+        self.total_fee
+    }
+
+    /// Returns the number of gates in the output record of the last step, if the plan is not empty.
+    pub fn consolidated_gates(&self) -> Option<u64> {
+        self.steps.last().map(|step| step.gates)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
+    /// Returns a plan to consolidate the unspent credits records of the given view key, with at most
+    /// `max_inputs` records per transaction and at most `max_executions` steps, where each step pays
+    /// `fee_per_execution` gates.
+    pub fn plan_consolidation(
+        &self,
+        view_key: &ViewKey<N>,
+        fee_per_execution: u64,
+        max_inputs: usize,
+        max_executions: usize,
+    ) -> Result<ConsolidationPlan<N>> {
+        // Prepare the credits program ID.
+        let credits_program_id = ProgramID::from_str("credits.aleo")?;
+
+        // Collect the unspent credits records, as records without any data entries,
+        // that are output by a transition of the credits program.
+        let mut records = Vec::new();
+        for (commitment, record) in self.find_records(view_key, RecordsFilter::Unspent)? {
+            // Skip the records with data entries.
+            if !record.data().is_empty() {
+                continue;
+            }
+            // Retrieve the program ID of the transition that output the record.
+            let transition_id = self.transitions.find_transition_id(&commitment)?;
+            if self.transitions.get_program_id(&transition_id)? == Some(credits_program_id) {
+                records.push((commitment, ***record.gates()));
+            }
+        }
+        // Plan the consolidation.
+        ConsolidationPlan::new(&records, fee_per_execution, max_inputs, max_executions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the given balances as `(commitment, gates)` records.
+    fn sample_records(balances: &[u64]) -> Vec<(Field<CurrentNetwork>, u64)> {
+        balances.iter().enumerate().map(|(i, gates)| (Field::from_u64(i as u64), *gates)).collect()
+    }
+
+    #[test]
+    fn test_consolidation_plan() {
+        let records = sample_records(&[10, 20, 30, 40, 50]);
+
+        let plan = ConsolidationPlan::new(&records, 1, 2, usize::MAX).unwrap();
+        // Ensure the plan merges `n` records in `n - 1` steps and `log2(n)` rounds.
+        assert_eq!(4, plan.steps().len());
+        assert_eq!(3, plan.num_rounds());
+        assert_eq!(2, plan.steps_in_round(0).count());
+        assert_eq!(4, plan.total_fee());
+        // Ensure the consolidated record holds every gate, minus the fees.
+        assert_eq!(Some(150 - 4), plan.consolidated_gates());
+        // Ensure every step only consumes records that exist before it.
+        for (index, step) in plan.steps().iter().enumerate() {
+            assert_eq!(2, step.inputs().len());
+            for record in step.inputs() {
+                if let PlannedRecord::Output(output) = record {
+                    assert!(*output < index);
+                }
+            }
+        }
+
+        // Ensure a larger number of inputs per transaction merges the records in fewer steps and rounds.
+        let plan = ConsolidationPlan::new(&records, 1, 4, usize::MAX).unwrap();
+        assert_eq!(2, plan.steps().len());
+        assert_eq!(2, plan.num_rounds());
+        assert_eq!(4, plan.steps()[0].inputs().len());
+        assert_eq!(Some(150 - 2), plan.consolidated_gates());
+
+        // Ensure the number of inputs per transaction is bounded.
+        assert!(ConsolidationPlan::new(&records, 1, 1, usize::MAX).is_err());
+        assert!(ConsolidationPlan::new(&records, 1, CurrentNetwork::MAX_INPUTS + 1, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_consolidation_plan_dust_and_limit() {
+        let records = sample_records(&[1, 100, 3, 200, 300, 400]);
+
+        // Ensure the records in a group worth less than the fee are left as dust.
+        let plan = ConsolidationPlan::new(&records, 5, 2, usize::MAX).unwrap();
+        assert_eq!(&[records[0].0, records[2].0], plan.dust());
+        assert_eq!(3, plan.steps().len());
+
+        // Ensure the records in a group are merged if their combined value covers the fee,
+        // even if each record is worth less than the fee.
+        let plan = ConsolidationPlan::new(&records, 3, 2, usize::MAX).unwrap();
+        assert!(plan.dust().is_empty());
+        assert_eq!(
+            vec![PlannedRecord::Unspent(records[0].0), PlannedRecord::Unspent(records[2].0)],
+            plan.steps()[0].inputs()
+        );
+        assert_eq!(1, plan.steps()[0].gates());
+
+        // Ensure the smallest records are consolidated first, when the number of steps is limited.
+        let plan = ConsolidationPlan::new(&records, 5, 2, 1).unwrap();
+        assert_eq!(1, plan.steps().len());
+        assert_eq!(
+            vec![PlannedRecord::Unspent(records[1].0), PlannedRecord::Unspent(records[3].0)],
+            plan.steps()[0].inputs()
+        );
+        assert_eq!(Some(295), plan.consolidated_gates());
+
+        // Ensure the plan ends once a later round has no group that covers the fee.
+        let plan = ConsolidationPlan::new(&sample_records(&[6, 6, 6, 6]), 10, 2, usize::MAX).unwrap();
+        assert_eq!(2, plan.steps().len());
+        assert_eq!(1, plan.num_rounds());
+        assert!(plan.dust().is_empty());
+
+        // Ensure a single record results in an empty plan.
+        let plan = ConsolidationPlan::new(&records[1..2], 5, 2, usize::MAX).unwrap();
+        assert!(plan.steps().is_empty());
+        assert_eq!(None, plan.consolidated_gates());
+
+        // Ensure duplicate records are rejected.
+        assert!(ConsolidationPlan::new(&[records[1], records[1]], 5, 2, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_plan_consolidation() {
+        // Sample the genesis private key.
+        let private_key = crate::ledger::test_helpers::sample_genesis_private_key();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        // Sample the genesis ledger.
+        let ledger = crate::ledger::test_helpers::sample_genesis_ledger();
+
+        // Ensure the single genesis record results in an empty plan.
+        assert_eq!(1, ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().count());
+        let plan = ledger.plan_consolidation(&view_key, 1, 2, usize::MAX).unwrap();
+        assert!(plan.steps().is_empty());
+        assert!(plan.dust().is_empty());
+    }
+}
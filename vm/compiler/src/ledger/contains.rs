@@ -17,11 +17,9 @@
 use super::*;
 
 impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
-    /// Returns `true` if the given state root exists.
-    pub fn contains_state_root(&self, _state_root: &Field<N>) -> bool {
-        todo!()
-        // state_root == self.latest_state_root()
-        //     || self.headers.values().any(|h| Header::previous_state_root(&h) == state_root)
+    /// Returns `true` if the given state root is one of the `N::MAX_RECENT_STATE_ROOTS` most recent state roots.
+    pub fn contains_state_root(&self, state_root: &Field<N>) -> Result<bool> {
+        Ok(self.recent_state_roots()?.contains(state_root))
     }
 
     /// Returns `true` if the given block hash exists.
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexSet;
+use std::collections::BTreeSet;
+
+/// A node of the dependency graph, where transactions are referred to by their index in the graph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Node {
+    /// The transactions that produce a record consumed by this transaction.
+    dependencies: IndexSet<usize>,
+    /// The transactions that can not be included together with this transaction.
+    conflicts: IndexSet<usize>,
+}
+
+/// The dependency graph of a set of transactions, for block producers to decide which transactions to include.
+///
+/// A transaction depends on another if it consumes a record (by commitment origin) that the other produces.
+/// Two transactions conflict if they spend the same record (i.e. share a serial number or tag),
+/// or if they produce the same commitment. A set of dependencies may form a cycle, in which case
+/// none of the transactions in (or depending on) the cycle can be included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph<N: Network> {
+    /// The nodes of the graph, keyed by transaction ID, in the given order of the transactions.
+    nodes: IndexMap<N::TransactionID, Node>,
+    /// The acyclic transactions, in topological order.
+    order: Vec<usize>,
+}
+
+impl<N: Network> DependencyGraph<N> {
+    /// Returns the dependency graph of the given transactions.
+    pub fn build(transactions: &[Transaction<N>]) -> Result<Self> {
+        Self::from_parts(transactions.iter().map(|transaction| {
+            // Retrieve the serial numbers and tags of the records spent by the transaction.
+            let spent = transaction.serial_numbers().chain(transaction.tags()).copied().collect();
+            // Retrieve the commitments of the records produced by the transaction.
+            let produced = transaction.commitments().copied().collect();
+            // Retrieve the commitments of the records consumed by the transaction.
+            let consumed = transaction
+                .origins()
+                .filter_map(|origin| match origin {
+                    Origin::Commitment(commitment) => Some(*commitment),
+                    Origin::StateRoot(_) => None,
+                })
+                .collect();
+            (transaction.id(), spent, produced, consumed)
+        }))
+    }
+
+    /// Returns the dependency graph of the given `(transaction ID, spent, produced, consumed)` transactions.
+    fn from_parts(
+        transactions: impl Iterator<Item = (N::TransactionID, Vec<Field<N>>, Vec<Field<N>>, Vec<Field<N>>)>,
+    ) -> Result<Self> {
+        let mut nodes = IndexMap::<N::TransactionID, Node>::new();
+        // The transactions that spend each serial number or tag.
+        let mut spenders = IndexMap::<Field<N>, Vec<usize>>::new();
+        // The transactions that produce each commitment.
+        let mut producers = IndexMap::<Field<N>, Vec<usize>>::new();
+        // The commitments consumed by each transaction.
+        let mut consumed_by = Vec::new();
+
+        for (index, (id, spent, produced, consumed)) in transactions.enumerate() {
+            // Ensure the transaction is unique.
+            if nodes.insert(id, Node::default()).is_some() {
+                bail!("Found a duplicate transaction '{id}' in the dependency graph");
+            }
+            spent.into_iter().for_each(|field| spenders.entry(field).or_default().push(index));
+            produced.into_iter().for_each(|field| producers.entry(field).or_default().push(index));
+            consumed_by.push(consumed);
+        }
+
+        // Link the transactions that spend the same record, or produce the same commitment.
+        for indices in spenders.values().chain(producers.values()) {
+            for (i, first) in indices.iter().enumerate() {
+                for second in indices[i + 1..].iter().filter(|second| *second != first) {
+                    nodes[*first].conflicts.insert(*second);
+                    nodes[*second].conflicts.insert(*first);
+                }
+            }
+        }
+
+        // Link each transaction to the transactions that produce the records it consumes.
+        for (index, consumed) in consumed_by.into_iter().enumerate() {
+            for commitment in consumed {
+                for producer in producers.get(&commitment).into_iter().flatten().filter(|producer| **producer != index)
+                {
+                    nodes[index].dependencies.insert(*producer);
+                }
+            }
+        }
+
+        // Sort the transactions topologically, where ties are broken by the given order of the transactions.
+        let mut dependents = vec![Vec::new(); nodes.len()];
+        let mut num_pending = Vec::with_capacity(nodes.len());
+        for (index, node) in nodes.values().enumerate() {
+            node.dependencies.iter().for_each(|dependency| dependents[*dependency].push(index));
+            num_pending.push(node.dependencies.len());
+        }
+        let mut ready = (0..nodes.len()).filter(|index| num_pending[*index] == 0).collect::<BTreeSet<_>>();
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some(index) = ready.iter().next().copied() {
+            ready.remove(&index);
+            order.push(index);
+            for dependent in &dependents[index] {
+                num_pending[*dependent] -= 1;
+                if num_pending[*dependent] == 0 {
+                    ready.insert(*dependent);
+                }
+            }
+        }
+
+        Ok(Self { nodes, order })
+    }
+}
+
+impl<N: Network> DependencyGraph<N> {
+    /// Returns the number of transactions in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph has no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the IDs of the transactions that produce a record consumed by the given transaction.
+    pub fn dependencies(&self, transaction_id: &N::TransactionID) -> Result<Vec<N::TransactionID>> {
+        match self.nodes.get(transaction_id) {
+            Some(node) => Ok(node.dependencies.iter().map(|index| self.id(*index)).collect()),
+            None => bail!("Transaction '{transaction_id}' is not in the dependency graph"),
+        }
+    }
+
+    /// Returns the pairs of conflicting transactions, in the given order of the transactions.
+    pub fn conflicts(&self) -> Vec<(N::TransactionID, N::TransactionID)> {
+        self.nodes
+            .values()
+            .enumerate()
+            .flat_map(|(index, node)| {
+                node.conflicts.iter().filter(move |other| index < **other).map(move |other| (index, *other))
+            })
+            .sorted()
+            .map(|(first, second)| (self.id(first), self.id(second)))
+            .collect()
+    }
+
+    /// Returns the IDs of the transactions that are in a dependency cycle, or depend on one.
+    pub fn cyclic(&self) -> Vec<N::TransactionID> {
+        let acyclic = self.order.iter().collect::<IndexSet<_>>();
+        (0..self.nodes.len()).filter(|index| !acyclic.contains(index)).map(|index| self.id(index)).collect()
+    }
+
+    /// Returns the IDs of the acyclic transactions, in topological order,
+    /// where ties are broken by the given order of the transactions.
+    pub fn topological_order(&self) -> Vec<N::TransactionID> {
+        self.order.iter().map(|index| self.id(*index)).collect()
+    }
+
+    /// Returns a maximal subset of non-conflicting transactions, in topological order.
+    ///
+    /// The transactions are selected greedily, in the topological order, so among conflicting transactions,
+    /// the one that is given first is preferred. A transaction is excluded if it conflicts with a selected
+    /// transaction, is in (or depends on) a cycle, or depends on an excluded transaction.
+    /// As no excluded transaction can be added to the subset, the subset is maximal.
+    pub fn select(&self) -> Vec<N::TransactionID> {
+        let mut selected = IndexSet::with_capacity(self.order.len());
+        for index in &self.order {
+            let node = &self.nodes[*index];
+            // Ensure the dependencies are selected, and the conflicts are not.
+            if node.dependencies.iter().all(|dependency| selected.contains(dependency))
+                && !node.conflicts.iter().any(|conflict| selected.contains(conflict))
+            {
+                selected.insert(*index);
+            }
+        }
+        selected.into_iter().map(|index| self.id(index)).collect()
+    }
+
+    /// Returns the ID of the transaction at the given index.
+    fn id(&self, index: usize) -> N::TransactionID {
+        *self.nodes.get_index(index).map(|(id, _)| id).expect("The transaction index is in the graph")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    type Parts = (
+        <CurrentNetwork as Network>::TransactionID,
+        Vec<Field<CurrentNetwork>>,
+        Vec<Field<CurrentNetwork>>,
+        Vec<Field<CurrentNetwork>>,
+    );
+
+    /// Returns the parts of a transaction with the given ID, which spends, produces, and consumes the given fields.
+    fn sample_parts(id: u64, spent: &[u64], produced: &[u64], consumed: &[u64]) -> Parts {
+        let fields = |values: &[u64]| -> Vec<_> { values.iter().map(|value| Field::from_u64(*value)).collect() };
+        (Field::<CurrentNetwork>::from_u64(id).into(), fields(spent), fields(produced), fields(consumed))
+    }
+
+    /// Returns the transaction ID for the given value.
+    fn id(value: u64) -> <CurrentNetwork as Network>::TransactionID {
+        Field::<CurrentNetwork>::from_u64(value).into()
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        // Transaction 2 consumes the records of transactions 0 and 1, and transaction 3 double spends with 1.
+        let graph = DependencyGraph::<CurrentNetwork>::from_parts(
+            [
+                sample_parts(2, &[20], &[], &[100, 101]),
+                sample_parts(0, &[1], &[100], &[]),
+                sample_parts(1, &[10], &[101], &[]),
+                sample_parts(3, &[10], &[102], &[]),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        assert_eq!(4, graph.len());
+
+        assert_eq!(vec![id(0), id(1)], graph.dependencies(&id(2)).unwrap());
+        assert!(graph.dependencies(&id(4)).is_err());
+        assert_eq!(vec![(id(1), id(3))], graph.conflicts());
+        assert!(graph.cyclic().is_empty());
+
+        // Ensure the dependencies are ordered first, and the conflicting transaction is excluded.
+        assert_eq!(vec![id(0), id(1), id(2), id(3)], graph.topological_order());
+        assert_eq!(vec![id(0), id(1), id(2)], graph.select());
+    }
+
+    #[test]
+    fn test_dependency_graph_cycle() {
+        // Transactions 0 and 1 consume each other's records, and transaction 2 depends on the cycle.
+        let graph = DependencyGraph::<CurrentNetwork>::from_parts(
+            [
+                sample_parts(0, &[1], &[100], &[101]),
+                sample_parts(1, &[2], &[101], &[100]),
+                sample_parts(2, &[3], &[102], &[101]),
+                sample_parts(3, &[4], &[103], &[]),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(vec![id(0), id(1), id(2)], graph.cyclic());
+        assert_eq!(vec![id(3)], graph.topological_order());
+        assert_eq!(vec![id(3)], graph.select());
+    }
+
+    #[test]
+    fn test_dependency_graph_build() {
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction();
+
+        // Ensure a single transaction is selected.
+        let graph = DependencyGraph::build(&[transaction.clone()]).unwrap();
+        assert_eq!(vec![transaction.id()], graph.select());
+
+        // Ensure a duplicate transaction is rejected.
+        assert!(DependencyGraph::build(&[transaction.clone(), transaction]).is_err());
+    }
+}
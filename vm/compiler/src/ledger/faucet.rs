@@ -0,0 +1,343 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::program::Value;
+
+use indexmap::IndexSet;
+
+/// The default maximum amount of a single funding (in gates).
+const DEFAULT_MAX_AMOUNT: u64 = 100_000_000;
+/// The default number of blocks before the same address may be funded again.
+const DEFAULT_COOLDOWN_IN_BLOCKS: u32 = 10;
+
+/// The records of the faucet, as a list of `(commitment, record)`.
+type FaucetRecords<N> = Vec<(Field<N>, Record<N, Plaintext<N>>)>;
+
+/// A faucet that funds accounts on a private network (e.g. a devnet or an integration environment),
+/// by transferring credits from the records of the account that signed the genesis block.
+///
+/// Each funding is a `credits.aleo/transfer` execution, which is added to the memory pool of the ledger.
+/// As a transfer consumes a whole record, the faucet can fund at most one address per spendable record
+/// in each block. `Faucet::replenish` splits the spendable records, to fund more addresses per block.
+///
+/// To limit abuse, each funding is capped at `max_amount` gates, and an address may be funded
+/// at most once every `cooldown` blocks.
+#[derive(Clone)]
+pub struct Faucet<N: Network> {
+    /// The private key of the faucet.
+    private_key: PrivateKey<N>,
+    /// The view key of the faucet.
+    view_key: ViewKey<N>,
+    /// The maximum amount of a single funding (in gates).
+    max_amount: u64,
+    /// The number of blocks before the same address may be funded again.
+    cooldown: u32,
+    /// The block height at which each address was last funded.
+    last_funded: IndexMap<Address<N>, u32>,
+    /// The commitments of the records consumed by transactions that are not yet in a block.
+    pending: IndexSet<Field<N>>,
+}
+
+impl<N: Network> Faucet<N> {
+    /// Initializes a new faucet, for the given private key.
+    /// The private key must belong to the account that signed the genesis block of the ledger.
+    pub fn new<B: BlockStorage<N>, P: ProgramStorage<N>>(
+        private_key: PrivateKey<N>,
+        ledger: &Ledger<N, B, P>,
+    ) -> Result<Self> {
+        // Ensure the faucet is authorized by the genesis block.
+        let address = Address::try_from(&private_key)?;
+        let genesis_signer = ledger.get_signature(0)?.to_address();
+        ensure!(address == genesis_signer, "The faucet account '{address}' did not sign the genesis block");
+
+        Ok(Self {
+            private_key,
+            view_key: ViewKey::try_from(private_key)?,
+            max_amount: DEFAULT_MAX_AMOUNT,
+            cooldown: DEFAULT_COOLDOWN_IN_BLOCKS,
+            last_funded: Default::default(),
+            pending: Default::default(),
+        })
+    }
+
+    /// Sets the maximum amount of a single funding (in gates).
+    pub const fn max_amount(mut self, max_amount: u64) -> Self {
+        self.max_amount = max_amount;
+        self
+    }
+
+    /// Sets the number of blocks before the same address may be funded again.
+    pub const fn cooldown(mut self, cooldown: u32) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Funds the given address with the given amount (in gates), and returns the transfer transaction,
+    /// which is added to the memory pool of the ledger.
+    pub fn fund<B: BlockStorage<N>, P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &mut Ledger<N, B, P>,
+        address: Address<N>,
+        amount: u64,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        self.fund_many(ledger, &[(address, amount)], rng)?
+            .pop()
+            .ok_or_else(|| anyhow!("Failed to fund the address '{address}'"))
+    }
+
+    /// Funds each of the given addresses with its amount (in gates), and returns the transfer transactions,
+    /// which are added to the memory pool of the ledger.
+    ///
+    /// The batch is funded entirely, or not at all, so the faucet must have a spendable record
+    /// that covers each amount in the batch.
+    pub fn fund_many<B: BlockStorage<N>, P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &mut Ledger<N, B, P>,
+        fundings: &[(Address<N>, u64)],
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        let height = ledger.latest_height();
+
+        // Ensure each funding is within the rate limits.
+        let mut addresses = IndexSet::with_capacity(fundings.len());
+        for (address, amount) in fundings {
+            ensure!(addresses.insert(*address), "The address '{address}' is funded more than once in the batch");
+            ensure!(*amount > 0, "The funding amount for '{address}' must be nonzero");
+            ensure!(
+                *amount <= self.max_amount,
+                "The funding amount {amount} for '{address}' exceeds the maximum of {} gates",
+                self.max_amount
+            );
+            if let Some(last_height) = self.last_funded.get(address) {
+                let next_height = last_height.saturating_add(self.cooldown);
+                ensure!(
+                    height >= next_height,
+                    "The address '{address}' may not be funded again until block {next_height}"
+                );
+            }
+        }
+
+        // Assign a spendable record to each funding, largest amount first, using the smallest record that covers it.
+        let mut records = self.spendable_records(ledger)?;
+        let mut order = (0..fundings.len()).collect::<Vec<_>>();
+        order.sort_by_key(|index| std::cmp::Reverse(fundings[*index].1));
+        let mut assignments = vec![None; fundings.len()];
+        for index in order {
+            let (address, amount) = fundings[index];
+            let position = match records.iter().position(|(_, record)| ***record.gates() >= amount) {
+                Some(position) => position,
+                None => bail!(
+                    "The faucet has no spendable record that covers {amount} gates for '{address}' \
+                     (call 'Faucet::replenish' and wait for the next block)"
+                ),
+            };
+            assignments[index] = Some(records.remove(position));
+        }
+
+        // Construct the transfer transactions.
+        let mut transactions = Vec::with_capacity(fundings.len());
+        for ((address, amount), assignment) in fundings.iter().zip_eq(assignments) {
+            let (commitment, record) = assignment.ok_or_else(|| anyhow!("Missing a record for '{address}'"))?;
+            let inputs = [
+                Value::Record(record),
+                Value::from_str(&address.to_string())?,
+                Value::from_str(&format!("{amount}u64"))?,
+            ];
+            let transaction = self.execute(ledger, "transfer", &inputs, rng)?;
+            transactions.push((commitment, transaction));
+        }
+
+        // Add the transactions to the memory pool, and update the rate limits.
+        for ((address, _), (commitment, transaction)) in fundings.iter().zip_eq(&transactions) {
+            ledger.add_to_memory_pool(transaction.clone())?;
+            self.pending.insert(*commitment);
+            self.last_funded.insert(*address, height);
+        }
+        Ok(transactions.into_iter().map(|(_, transaction)| transaction).collect())
+    }
+
+    /// Splits each spendable record of the faucet in half, so the faucet can fund twice as many addresses
+    /// per block, once the split transactions are in a block. Returns the split transactions,
+    /// which are added to the memory pool of the ledger.
+    pub fn replenish<B: BlockStorage<N>, P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &mut Ledger<N, B, P>,
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        let mut transactions = Vec::new();
+        for (commitment, record) in self.spendable_records(ledger)? {
+            // Skip the records that can not be split.
+            let gates = ***record.gates();
+            if gates < 2 {
+                continue;
+            }
+            let inputs = [Value::Record(record), Value::from_str(&format!("{}u64", gates / 2))?];
+            let transaction = self.execute(ledger, "split", &inputs, rng)?;
+            ledger.add_to_memory_pool(transaction.clone())?;
+            self.pending.insert(commitment);
+            transactions.push(transaction);
+        }
+        Ok(transactions)
+    }
+
+    /// Returns the total balance of the spendable records of the faucet (in gates).
+    pub fn balance<B: BlockStorage<N>, P: ProgramStorage<N>>(&mut self, ledger: &Ledger<N, B, P>) -> Result<u64> {
+        Ok(self.spendable_records(ledger)?.iter().map(|(_, record)| ***record.gates()).sum())
+    }
+
+    /// Returns the address of the faucet.
+    pub fn address(&self) -> Address<N> {
+        self.view_key.to_address()
+    }
+
+    /// Returns the maximum amount of a single funding (in gates).
+    pub const fn get_max_amount(&self) -> u64 {
+        self.max_amount
+    }
+
+    /// Returns the number of blocks before the same address may be funded again.
+    pub const fn get_cooldown(&self) -> u32 {
+        self.cooldown
+    }
+
+    /// Returns the unspent records of the faucet that are not consumed by a pending transaction,
+    /// ordered from the smallest to the largest.
+    fn spendable_records<B: BlockStorage<N>, P: ProgramStorage<N>>(
+        &mut self,
+        ledger: &Ledger<N, B, P>,
+    ) -> Result<FaucetRecords<N>> {
+        let unspent = ledger
+            .find_records(&self.view_key, RecordsFilter::Unspent)?
+            .filter(|(_, record)| !record.gates().is_zero())
+            .collect::<Vec<_>>();
+        // Forget the pending records that have been spent in a block.
+        self.pending.retain(|commitment| unspent.iter().any(|(candidate, _)| candidate == commitment));
+        // Filter out the pending records.
+        let mut records =
+            unspent.into_iter().filter(|(commitment, _)| !self.pending.contains(commitment)).collect::<Vec<_>>();
+        records.sort_by_key(|(_, record)| ***record.gates());
+        Ok(records)
+    }
+
+    /// Returns a transaction that executes the given `credits.aleo` function, signed by the faucet.
+    fn execute<B: BlockStorage<N>, P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        ledger: &Ledger<N, B, P>,
+        function_name: &str,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str(function_name)?;
+        Transaction::execute(ledger.vm(), &self.private_key, &program_id, function_name, inputs, None, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::test_helpers;
+    use console::network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the total balance of the unspent records owned by the given private key (in gates).
+    fn balance_of<B: BlockStorage<CurrentNetwork>, P: ProgramStorage<CurrentNetwork>>(
+        ledger: &Ledger<CurrentNetwork, B, P>,
+        private_key: &PrivateKey<CurrentNetwork>,
+    ) -> u64 {
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().map(|(_, record)| ***record.gates()).sum()
+    }
+
+    #[test]
+    fn test_faucet_requires_genesis_authorization() {
+        let rng = &mut test_crypto_rng();
+        let ledger = test_helpers::sample_genesis_ledger();
+
+        // Ensure the genesis account is authorized.
+        assert!(Faucet::new(test_helpers::sample_genesis_private_key(), &ledger).is_ok());
+        // Ensure any other account is not authorized.
+        assert!(Faucet::new(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), &ledger).is_err());
+    }
+
+    #[test]
+    fn test_faucet_fund() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the faucet.
+        let private_key = test_helpers::sample_genesis_private_key();
+        let mut ledger = test_helpers::sample_genesis_ledger();
+        let mut faucet = Faucet::new(private_key, &ledger).unwrap().max_amount(1_000).cooldown(2);
+
+        // Sample a recipient.
+        let recipient = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&recipient).unwrap();
+
+        // Ensure a funding above the maximum amount is rejected.
+        assert!(faucet.fund(&mut ledger, address, 1_001, rng).is_err());
+
+        // Fund the recipient, and add the next block.
+        faucet.fund(&mut ledger, address, 1_000, rng).unwrap();
+        assert_eq!(1, ledger.memory_pool().len());
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+        assert_eq!(1_000, balance_of(&ledger, &recipient));
+
+        // Ensure the recipient can not be funded again within the cooldown.
+        assert!(faucet.fund(&mut ledger, address, 1_000, rng).is_err());
+    }
+
+    #[test]
+    fn test_faucet_fund_many() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the faucet.
+        let private_key = test_helpers::sample_genesis_private_key();
+        let mut ledger = test_helpers::sample_genesis_ledger();
+        let mut faucet = Faucet::new(private_key, &ledger).unwrap();
+        let balance = faucet.balance(&ledger).unwrap();
+
+        // Sample the recipients.
+        let recipients = (0..2).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+        let fundings =
+            recipients.iter().map(|recipient| (Address::try_from(recipient).unwrap(), 500)).collect::<Vec<_>>();
+
+        // Ensure the batch is rejected, as the genesis account has a single record.
+        assert!(faucet.fund_many(&mut ledger, &fundings, rng).is_err());
+        assert!(ledger.memory_pool().is_empty());
+        // Ensure a batch that funds the same address twice is rejected.
+        assert!(faucet.fund_many(&mut ledger, &[fundings[0], fundings[0]], rng).is_err());
+
+        // Replenish the faucet, and add the next block.
+        assert_eq!(1, faucet.replenish(&mut ledger, rng).unwrap().len());
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+        assert_eq!(balance, faucet.balance(&ledger).unwrap());
+
+        // Fund the batch, and add the next block.
+        assert_eq!(2, faucet.fund_many(&mut ledger, &fundings, rng).unwrap().len());
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+        for recipient in &recipients {
+            assert_eq!(500, balance_of(&ledger, recipient));
+        }
+        assert_eq!(balance - 1_000, faucet.balance(&ledger).unwrap());
+    }
+}
@@ -18,8 +18,25 @@ use super::*;
 
 impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
     /// Returns the latest state root.
-    pub const fn latest_state_root(&self) -> &Field<N> {
-        self.block_tree.root()
+    pub fn latest_state_root(&self) -> Result<Field<N>> {
+        self.blocks.tree_store().root()
+    }
+
+    /// Returns the `N::MAX_RECENT_STATE_ROOTS` most recent state roots, starting from the latest state root.
+    pub fn recent_state_roots(&self) -> Result<Vec<Field<N>>> {
+        // Initialize the list with the latest state root.
+        let mut state_roots = Vec::with_capacity(N::MAX_RECENT_STATE_ROOTS as usize);
+        state_roots.push(self.latest_state_root()?);
+
+        // Add the state root preceding each recent block, which is recorded in its header.
+        // Note: The genesis block is skipped, as there is no state root preceding it.
+        for height in (1..=self.current_height).rev() {
+            if state_roots.len() >= N::MAX_RECENT_STATE_ROOTS as usize {
+                break;
+            }
+            state_roots.push(*self.get_header(height)?.previous_state_root());
+        }
+        Ok(state_roots)
     }
 
     /// Returns the latest block.
@@ -17,6 +17,21 @@
 mod block;
 pub use block::*;
 
+mod builder;
+pub use builder::*;
+
+mod consolidate;
+pub use consolidate::*;
+
+mod dependency;
+pub use dependency::*;
+
+mod faucet;
+pub use faucet::*;
+
+mod scanner;
+pub use scanner::*;
+
 pub mod map;
 pub use map::*;
 
@@ -45,7 +60,7 @@ use crate::program::Program;
 use console::{
     account::{Address, GraphKey, PrivateKey, Signature, ViewKey},
     collections::merkle_tree::MerklePath,
-    network::{prelude::*, BHPMerkleTree},
+    network::{prelude::*, BHPMerkleTree, DomainTag},
     program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
@@ -64,6 +79,8 @@ const BLOCKS_DEPTH: u8 = 32;
 
 /// The Merkle tree for the block state.
 pub type BlockTree<N> = BHPMerkleTree<N, BLOCKS_DEPTH>;
+/// The storage-backed Merkle tree for the block state.
+pub type BlockTreeStore<N, T> = TreeStore<N, T, BLOCKS_DEPTH>;
 /// The Merkle path for the state tree blocks.
 pub type BlockPath<N> = MerklePath<N, BLOCKS_DEPTH>;
 
@@ -89,8 +106,6 @@ pub struct Ledger<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> {
     current_height: u32,
     /// The current round number.
     current_round: u64,
-    /// The current block tree.
-    block_tree: BlockTree<N>,
     /// The block store.
     blocks: BlockStore<N, B>,
     /// The transaction store.
@@ -104,6 +119,8 @@ pub struct Ledger<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> {
     memory_pool: IndexMap<N::TransactionID, Transaction<N>>,
     /// The VM state.
     vm: VM<N, P>,
+    /// The options for verifying transactions and blocks.
+    verification_options: VerificationOptions,
     // /// The mapping of program IDs to their global state.
     // states: MemoryMap<ProgramID<N>, IndexMap<Identifier<N>, Plaintext<N>>>,
 }
@@ -133,13 +150,13 @@ impl<N: Network> Ledger<N, BlockMemory<N>, ProgramMemory<N>> {
             current_hash: Default::default(),
             current_height: 0,
             current_round: 0,
-            block_tree: N::merkle_tree_bhp(&[])?,
             transactions: blocks.transaction_store().clone(),
             transitions: blocks.transition_store().clone(),
             blocks,
             // TODO (howardwu): Update this to retrieve from a validators store.
             validators: [(address, ())].into_iter().collect(),
             vm,
+            verification_options: Default::default(),
             memory_pool: Default::default(),
         };
 
@@ -172,7 +189,6 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             current_hash: Default::default(),
             current_height: 0,
             current_round: 0,
-            block_tree: N::merkle_tree_bhp(&[])?,
             transactions: blocks.transaction_store().clone(),
             transitions: blocks.transition_store().clone(),
             blocks,
@@ -184,6 +200,7 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             .into_iter()
             .collect(),
             vm,
+            verification_options: Default::default(),
             memory_pool: Default::default(),
         };
 
@@ -209,26 +226,57 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         ledger.current_height = block.height();
         ledger.current_round = block.round();
 
-        // TODO (howardwu): Improve the performance here by using iterators.
-        // Generate the block tree.
-        let hashes: Vec<_> =
-            (0..=latest_height).map(|height| ledger.get_hash(height).map(|hash| hash.to_bits_le())).try_collect()?;
-        ledger.block_tree.append(&hashes)?;
+        // Safety check the block tree contains a leaf for every block.
+        let number_of_leaves = ledger.blocks.tree_store().number_of_leaves()?;
+        if number_of_leaves != latest_height as u64 + 1 {
+            bail!("The block tree has {number_of_leaves} leaves, but the latest block height is {latest_height}")
+        }
 
-        // Safety check the existence of every block.
-        (0..=latest_height).into_par_iter().try_for_each(|height| {
-            ledger.get_block(height)?;
-            Ok::<_, Error>(())
-        })?;
+        // Safety check the block tree ends with the latest block, and has a state root.
+        // Note: This check is constant-time, so startup does not scale with the ledger size.
+        // To check every block against the block tree, call `Ledger::verify_block_tree`.
+        if !ledger.blocks.tree_store().contains_leaf(latest_height as u64, &block.hash().to_bits_le())? {
+            bail!("The block tree does not contain the hash of the latest block {latest_height}")
+        }
+        ledger.latest_state_root()?;
 
         Ok(ledger)
     }
 
+    /// Checks the existence of every block, and that its hash is in the block tree.
+    /// This check takes time linear in the ledger size, and is not performed when the ledger is opened.
+    pub fn verify_block_tree(&self) -> Result<()> {
+        (0..=self.current_height).into_par_iter().try_for_each(|height| {
+            let block = self.get_block(height)?;
+            if !self.blocks.tree_store().contains_leaf(height as u64, &block.hash().to_bits_le())? {
+                bail!("The block tree does not contain the hash of block {height}")
+            }
+            Ok::<_, Error>(())
+        })
+    }
+
     /// Returns the VM.
     pub fn vm(&self) -> &VM<N, P> {
         &self.vm
     }
 
+    /// Returns the options for verifying transactions and blocks.
+    pub const fn verification_options(&self) -> &VerificationOptions {
+        &self.verification_options
+    }
+
+    /// Sets the options for verifying transactions and blocks.
+    pub fn set_verification_options(&mut self, options: VerificationOptions) -> Result<()> {
+        // Ensure the options are well-formed.
+        let options = options.build()?;
+        // If the proving keys are not retained, remove them from the VM.
+        if !options.get_retain_proving_keys() {
+            self.vm.remove_proving_keys()?;
+        }
+        self.verification_options = options;
+        Ok(())
+    }
+
     /// Appends the given transaction to the memory pool.
     pub fn add_to_memory_pool(&mut self, transaction: Transaction<N>) -> Result<()> {
         // Ensure the transaction does not already exist.
@@ -245,6 +293,12 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             }
         }
 
+        // Ensure the given transaction does not reference a state root.
+        // Note: Inclusion proofs against a state root are not yet verified, so these origins are rejected.
+        if transaction.origins().any(|origin| matches!(origin, Origin::StateRoot(..))) {
+            bail!("State roots are currently not supported (yet)")
+        }
+
         // Ensure the ledger does not already contain a given serial numbers.
         for serial_number in transaction.serial_numbers() {
             if self.contains_serial_number(serial_number)? {
@@ -310,7 +364,7 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
         // Fetch the latest block and state root.
         let block = self.latest_block()?;
-        let state_root = self.latest_state_root();
+        let state_root = self.latest_state_root()?;
 
         // TODO (raychu86): Establish the correct round, coinbase target, and proof target.
         let round = block.round() + 1;
@@ -328,7 +382,7 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         )?;
 
         // Construct the header.
-        let header = Header::from(*state_root, transactions.to_root()?, metadata)?;
+        let header = Header::from(state_root, transactions.to_root()?, metadata)?;
 
         // Construct the new block.
         Block::new(private_key, block.hash(), header, transactions, rng)
@@ -442,6 +496,9 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
         /* Block Hash */
 
+        // Retrieve the network and epoch domain of the block.
+        let domain = DomainTag::from_height(block.height())?;
+
         // Compute the Merkle root of the block header.
         let header_root = match block.header().to_root() {
             Ok(root) => root,
@@ -449,7 +506,7 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         };
 
         // Check the block hash.
-        match N::hash_bhp1024(&[block.previous_hash().to_bits_le(), header_root.to_bits_le()].concat()) {
+        match domain.hash_bhp1024(&[block.previous_hash().to_bits_le(), header_root.to_bits_le()].concat()) {
             Ok(candidate_hash) => {
                 // Ensure the block hash matches the one in the block.
                 if candidate_hash != *block.hash() {
@@ -472,7 +529,7 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         }
 
         // Check the signature.
-        if !block.signature().verify(&signer, &[*block.hash()]) {
+        if !block.signature().verify_with_domain(&signer, &domain, &[*block.hash()]) {
             bail!("Invalid signature for block {} ({})", block.height(), block.hash());
         }
 
@@ -504,9 +561,13 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             bail!("Cannot validate a block with more than {} transactions", Transactions::<N>::MAX_TRANSACTIONS);
         }
 
-        // Ensure each transaction is well-formed.
-        if !block.transactions().par_iter().all(|(_, transaction)| self.vm.verify(transaction)) {
-            bail!("Invalid transaction found in the transactions list");
+        // Ensure each transaction is well-formed, verifying at most `max_concurrent_transactions` at a time.
+        let transactions = block.transactions().values().collect::<Vec<_>>();
+        let batch_size = self.verification_options.get_max_concurrent_transactions().unwrap_or(transactions.len());
+        for batch in transactions.chunks(batch_size) {
+            if !batch.par_iter().all(|transaction| self.vm.verify(transaction)) {
+                bail!("Invalid transaction found in the transactions list");
+            }
         }
 
         /* Fees */
@@ -533,6 +594,19 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         Ok(())
     }
 
+    /// Reads the next block from the given reader, and adds it as the next block in the chain.
+    /// The block is deserialized directly from the reader, and at most `max_block_size` bytes are read.
+    pub fn add_next_block_from_reader<R: Read>(&mut self, reader: R) -> Result<Block<N>> {
+        // Read the block, up to the maximum block size.
+        let block = match self.verification_options.get_max_block_size() {
+            Some(max_block_size) => Block::read_le(reader.take(max_block_size))?,
+            None => Block::read_le(reader)?,
+        };
+        // Add the block to the ledger.
+        self.add_next_block(&block)?;
+        Ok(block)
+    }
+
     /// Adds the given block as the next block in the chain.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
         // Ensure the given block is a valid next block.
@@ -548,7 +622,6 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             ledger.current_hash = block.hash();
             ledger.current_height = block.height();
             ledger.current_round = block.round();
-            ledger.block_tree.append(&[block.hash().to_bits_le()])?;
             ledger.blocks.insert(block)?;
 
             // Update the VM.
@@ -565,13 +638,13 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
                 current_hash: ledger.current_hash,
                 current_height: ledger.current_height,
                 current_round: ledger.current_round,
-                block_tree: ledger.block_tree,
                 blocks: ledger.blocks,
                 transactions: ledger.transactions,
                 transitions: ledger.transitions,
                 validators: ledger.validators,
                 vm: ledger.vm,
                 memory_pool: ledger.memory_pool,
+                verification_options: ledger.verification_options,
             };
         }
 
@@ -579,8 +652,8 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
     }
 
     /// Returns the block tree.
-    pub const fn block_tree(&self) -> &BlockTree<N> {
-        &self.block_tree
+    pub fn block_tree(&self) -> &BlockTreeStore<N, B::TreeStorage> {
+        self.blocks.tree_store()
     }
 
     /// Returns the memory pool.
@@ -644,8 +717,8 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         let header_path = block_header.to_path(&header_leaf)?;
 
         // Construct the state root and block path.
-        let state_root = *self.block_tree.root();
-        let block_path = self.block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+        let state_root = self.block_tree().root()?;
+        let block_path = self.block_tree().prove(block.height() as u64, &block.hash().to_bits_le())?;
 
         StatePath::new(
             state_root.into(),
@@ -942,6 +1015,7 @@ mod tests {
         assert_eq!(ledger.latest_height(), genesis.height());
         assert_eq!(ledger.latest_round(), genesis.round());
         assert_eq!(ledger.latest_block().unwrap(), genesis);
+        ledger.verify_block_tree().unwrap();
 
         // Initialize the ledger with the genesis block.
         let ledger = CurrentLedger::new_with_genesis(&genesis, address).unwrap();
@@ -965,6 +1039,35 @@ mod tests {
         let _state_path = ledger.to_state_path(commitment).unwrap();
     }
 
+    #[test]
+    fn test_recent_state_roots() {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger();
+
+        // Ensure the genesis ledger only accepts the latest state root.
+        let genesis_state_root = ledger.latest_state_root().unwrap();
+        assert_eq!(ledger.recent_state_roots().unwrap(), vec![genesis_state_root]);
+        assert!(ledger.contains_state_root(&genesis_state_root).unwrap());
+        assert!(!ledger.contains_state_root(&Uniform::rand(rng)).unwrap());
+
+        // Add a block to the ledger.
+        let transaction = crate::ledger::vm::test_helpers::sample_deployment_transaction();
+        ledger.add_to_memory_pool(transaction).unwrap();
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Ensure the ledger accepts both the latest and the preceding state root.
+        let latest_state_root = ledger.latest_state_root().unwrap();
+        assert_ne!(latest_state_root, genesis_state_root);
+        assert_eq!(ledger.recent_state_roots().unwrap(), vec![latest_state_root, genesis_state_root]);
+        assert!(ledger.contains_state_root(&latest_state_root).unwrap());
+        assert!(ledger.contains_state_root(&genesis_state_root).unwrap());
+    }
+
     #[test]
     #[traced_test]
     fn test_ledger_deploy() {
@@ -1022,6 +1125,37 @@ mod tests {
         assert!(ledger.add_to_memory_pool(transaction).is_err());
     }
 
+    #[test]
+    fn test_ledger_bounded_verification() {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key();
+        // Sample the genesis ledger, and propose the next block.
+        let mut ledger = test_helpers::sample_genesis_ledger();
+        ledger.add_to_memory_pool(crate::ledger::vm::test_helpers::sample_execution_transaction()).unwrap();
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        let next_block_bytes = next_block.to_bytes_le().unwrap();
+
+        // Initialize a follower ledger with its own VM, in bounded-memory mode.
+        let genesis = test_helpers::sample_genesis_block();
+        let address = Address::try_from(&private_key).unwrap();
+        let mut follower = CurrentLedger::new_with_genesis(&genesis, address).unwrap();
+        follower.set_verification_options(VerificationOptions::bounded()).unwrap();
+
+        // Ensure the follower adds the next block from the reader.
+        assert_eq!(next_block, follower.add_next_block_from_reader(&next_block_bytes[..]).unwrap());
+        assert_eq!(follower.latest_height(), 1);
+        assert_eq!(follower.latest_hash(), next_block.hash());
+
+        // Ensure a block that exceeds the maximum block size is rejected.
+        let mut follower = CurrentLedger::new_with_genesis(&genesis, address).unwrap();
+        let options = VerificationOptions::bounded().max_block_size(Some(next_block_bytes.len() as u64 - 1));
+        follower.set_verification_options(options).unwrap();
+        assert!(follower.add_next_block_from_reader(&next_block_bytes[..]).is_err());
+        assert_eq!(follower.latest_height(), 0);
+    }
+
     #[test]
     #[traced_test]
     fn test_ledger_execute_many() {
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use core::ops::Range;
+
+/// The number of blocks fetched from the source before they are trial decrypted, in `Scanner::sync`.
+const BLOCKS_PER_BATCH: u32 = 64;
+
+/// The records found by a scanner, as a list of `(commitment, record)`.
+pub type ScannedRecords<N> = Vec<(Field<N>, Record<N, Plaintext<N>>)>;
+
+/// A source of blocks, such as a ledger or a client of a remote node.
+pub trait BlockSource<N: Network> {
+    /// Returns the block for the given block height.
+    fn get_block(&self, height: u32) -> Result<Block<N>>;
+
+    /// Returns the latest block height.
+    fn latest_height(&self) -> Result<u32>;
+}
+
+impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> BlockSource<N> for Ledger<N, B, P> {
+    /// Returns the block for the given block height.
+    fn get_block(&self, height: u32) -> Result<Block<N>> {
+        Ledger::get_block(self, height)
+    }
+
+    /// Returns the latest block height.
+    fn latest_height(&self) -> Result<u32> {
+        Ok(Ledger::latest_height(self))
+    }
+}
+
+impl<N: Network> BlockSource<N> for [Block<N>] {
+    /// Returns the block for the given block height.
+    fn get_block(&self, height: u32) -> Result<Block<N>> {
+        match self.iter().find(|block| block.height() == height) {
+            Some(block) => Ok(block.clone()),
+            None => bail!("Block {height} does not exist in the given blocks"),
+        }
+    }
+
+    /// Returns the latest block height.
+    fn latest_height(&self) -> Result<u32> {
+        match self.iter().map(Block::height).max() {
+            Some(height) => Ok(height),
+            None => bail!("The given blocks are empty"),
+        }
+    }
+}
+
+/// The progress of a scanner, from which a scan can be resumed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScanCheckpoint<N: Network> {
+    /// The height of the next block to scan.
+    height: u32,
+    /// The hash of the last scanned block, or `None` if no block was scanned.
+    hash: Option<N::BlockHash>,
+}
+
+impl<N: Network> ScanCheckpoint<N> {
+    /// Returns the checkpoint from the given height of the next block to scan, and the hash of the last scanned block.
+    pub const fn new(height: u32, hash: Option<N::BlockHash>) -> Self {
+        Self { height, hash }
+    }
+
+    /// Returns the height of the next block to scan.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the hash of the last scanned block, or `None` if no block was scanned.
+    pub const fn hash(&self) -> Option<N::BlockHash> {
+        self.hash
+    }
+}
+
+impl<N: Network> Default for ScanCheckpoint<N> {
+    /// Returns the checkpoint of a scan that starts at the genesis block.
+    fn default() -> Self {
+        Self { height: 0, hash: None }
+    }
+}
+
+/// A scanner that finds the records owned by a view key, in the blocks of a block source.
+///
+/// The records of each batch of blocks are trial decrypted in parallel. `Scanner::sync` advances the checkpoint
+/// after every batch, so a wallet may persist the checkpoint, and resume scanning from it later.
+/// On resumption, the scanner ensures the next block extends the last scanned block,
+/// so a reorganization of the source is reported, instead of silently skipping records.
+#[derive(Clone)]
+pub struct Scanner<N: Network> {
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The address of the account.
+    address: Address<N>,
+    /// The progress of the scanner.
+    checkpoint: ScanCheckpoint<N>,
+}
+
+impl<N: Network> Scanner<N> {
+    /// Initializes a new scanner for the given view key, starting at the genesis block.
+    pub fn new(view_key: ViewKey<N>) -> Self {
+        Self::from_checkpoint(view_key, ScanCheckpoint::default())
+    }
+
+    /// Initializes a new scanner for the given view key, resuming from the given checkpoint.
+    pub fn from_checkpoint(view_key: ViewKey<N>, checkpoint: ScanCheckpoint<N>) -> Self {
+        Self { view_key, address: view_key.to_address(), checkpoint }
+    }
+
+    /// Returns the progress of the scanner.
+    pub const fn checkpoint(&self) -> ScanCheckpoint<N> {
+        self.checkpoint
+    }
+
+    /// Returns the records owned by the view key, in the blocks of the given range of heights,
+    /// as a tuple of `(commitment, record)`. This does not advance the checkpoint.
+    pub fn scan<S: BlockSource<N> + ?Sized>(&self, source: &S, heights: Range<u32>) -> Result<ScannedRecords<N>> {
+        let blocks = heights.map(|height| source.get_block(height)).collect::<Result<Vec<_>>>()?;
+        Ok(self.trial_decrypt(&blocks))
+    }
+
+    /// Returns the records owned by the view key, in the blocks from the checkpoint to the latest block of the source,
+    /// as a tuple of `(commitment, record)`, and advances the checkpoint past the latest block.
+    pub fn sync<S: BlockSource<N> + ?Sized>(&mut self, source: &S) -> Result<ScannedRecords<N>> {
+        let end_height = source.latest_height()?.saturating_add(1);
+
+        let mut records = Vec::new();
+        while self.checkpoint.height < end_height {
+            // Fetch the next batch of blocks.
+            let start = self.checkpoint.height;
+            let end = start.saturating_add(BLOCKS_PER_BATCH).min(end_height);
+            let blocks = (start..end).map(|height| source.get_block(height)).collect::<Result<Vec<_>>>()?;
+
+            // Ensure the blocks extend the last scanned block.
+            let mut previous_hash = self.checkpoint.hash;
+            for block in &blocks {
+                if let Some(previous_hash) = previous_hash {
+                    ensure!(
+                        block.previous_hash() == previous_hash,
+                        "Block {} does not extend the last scanned block '{previous_hash}'",
+                        block.height()
+                    );
+                }
+                previous_hash = Some(block.hash());
+            }
+
+            // Trial decrypt the records, and advance the checkpoint.
+            records.extend(self.trial_decrypt(&blocks));
+            self.checkpoint = ScanCheckpoint::new(end, previous_hash);
+        }
+        Ok(records)
+    }
+
+    /// Returns the records owned by the view key in the given blocks, as a tuple of `(commitment, record)`.
+    fn trial_decrypt(&self, blocks: &[Block<N>]) -> ScannedRecords<N> {
+        // Collect the output records of the blocks.
+        let records = blocks
+            .iter()
+            .flat_map(|block| block.transitions().flat_map(Transition::output_records))
+            .collect::<Vec<_>>();
+        // Decrypt the records owned by the view key.
+        records
+            .into_par_iter()
+            .filter_map(|(commitment, record)| match record.is_owner(&self.address, &self.view_key) {
+                true => record.decrypt(&self.view_key).ok().map(|record| (*commitment, record)),
+                false => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::test_helpers;
+    use console::network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_scan() {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the genesis ledger, and the genesis view key.
+        let ledger = test_helpers::sample_genesis_ledger();
+        let view_key = ViewKey::try_from(test_helpers::sample_genesis_private_key()).unwrap();
+
+        // Ensure the genesis record is found.
+        let expected = ledger.find_records(&view_key, RecordsFilter::All).unwrap().collect::<Vec<_>>();
+        assert_eq!(1, expected.len());
+        assert_eq!(expected, Scanner::new(view_key).scan(&ledger, 0..1).unwrap());
+
+        // Ensure no records are found for another view key.
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(Scanner::new(view_key).scan(&ledger, 0..1).unwrap().is_empty());
+        // Ensure a missing block is an error.
+        assert!(Scanner::new(view_key).scan(&ledger, 0..2).is_err());
+    }
+
+    #[test]
+    fn test_sync() {
+        // Sample the genesis ledger, and the genesis view key.
+        let ledger = test_helpers::sample_genesis_ledger();
+        let view_key = ViewKey::try_from(test_helpers::sample_genesis_private_key()).unwrap();
+
+        // Ensure the scanner finds the genesis record, and advances the checkpoint.
+        let mut scanner = Scanner::new(view_key);
+        assert_eq!(1, scanner.sync(&ledger).unwrap().len());
+        assert_eq!(ScanCheckpoint::new(1, Some(ledger.latest_hash())), scanner.checkpoint());
+
+        // Ensure a resumed scanner does not find the record again.
+        let mut scanner = Scanner::from_checkpoint(view_key, scanner.checkpoint());
+        assert!(scanner.sync(&ledger).unwrap().is_empty());
+        assert_eq!(1, scanner.checkpoint().height());
+
+        // Ensure the blocks of a slice can be scanned.
+        let blocks = vec![ledger.get_block(0).unwrap()];
+        assert_eq!(1, Scanner::new(view_key).sync(blocks.as_slice()).unwrap().len());
+    }
+
+    #[test]
+    fn test_sync_detects_reorganization() {
+        // Sample the genesis ledger, and the genesis view key.
+        let ledger = test_helpers::sample_genesis_ledger();
+        let view_key = ViewKey::try_from(test_helpers::sample_genesis_private_key()).unwrap();
+
+        // Ensure a checkpoint whose last scanned block is not the parent of the next block is rejected.
+        let genesis = ledger.get_block(0).unwrap();
+        let checkpoint = ScanCheckpoint::new(0, Some(genesis.hash()));
+        let mut scanner = Scanner::from_checkpoint(view_key, checkpoint);
+        assert!(scanner.sync(&ledger).is_err());
+        // Ensure the checkpoint is not advanced.
+        assert_eq!(checkpoint, scanner.checkpoint());
+    }
+}
@@ -27,11 +27,15 @@ use crate::{
             TransitionMemory,
             TransitionStorage,
             TransitionStore,
+            TreeMemory,
+            TreeStorage,
+            TreeStore,
         },
         Block,
         Header,
         Signature,
         Transactions,
+        BLOCKS_DEPTH,
     },
 };
 use console::network::prelude::*;
@@ -65,6 +69,8 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
     type TransitionStorage: TransitionStorage<N>;
     /// The mapping of `block hash` to `block signature`.
     type SignatureMap: for<'a> Map<'a, N::BlockHash, Signature<N>>;
+    /// The block tree storage.
+    type TreeStorage: TreeStorage<N>;
 
     /// Initializes the block storage.
     fn open() -> Result<Self>;
@@ -83,6 +89,8 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
     /// Returns the signature map.
     fn signature_map(&self) -> &Self::SignatureMap;
+    /// Returns the block tree store.
+    fn tree_store(&self) -> &TreeStore<N, Self::TreeStorage, BLOCKS_DEPTH>;
 
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
@@ -93,6 +101,7 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
         self.reverse_transactions_map().start_atomic();
         self.transaction_store().start_atomic();
         self.signature_map().start_atomic();
+        self.tree_store().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -104,6 +113,7 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
             || self.reverse_transactions_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
             || self.signature_map().is_atomic_in_progress()
+            || self.tree_store().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -115,6 +125,7 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
         self.reverse_transactions_map().abort_atomic();
         self.transaction_store().abort_atomic();
         self.signature_map().abort_atomic();
+        self.tree_store().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -125,11 +136,18 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
         self.transactions_map().finish_atomic()?;
         self.reverse_transactions_map().finish_atomic()?;
         self.transaction_store().finish_atomic()?;
-        self.signature_map().finish_atomic()
+        self.signature_map().finish_atomic()?;
+        self.tree_store().finish_atomic()
     }
 
     /// Stores the given `block` into storage.
     fn insert(&self, block: &Block<N>) -> Result<()> {
+        // Ensure the block is the next leaf of the block tree.
+        let number_of_leaves = self.tree_store().number_of_leaves()?;
+        if number_of_leaves != block.height() as u64 {
+            bail!("Failed to insert block {}: the block tree has {number_of_leaves} leaves", block.height())
+        }
+
         atomic_write_batch!(self, {
             // Store the block hash.
             self.id_map().insert(block.height(), block.hash())?;
@@ -152,6 +170,9 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
             // Store the block signature.
             self.signature_map().insert(block.hash(), *block.signature())?;
 
+            // Append the block hash to the block tree.
+            self.tree_store().append(&block.hash().to_bits_le())?;
+
             Ok(())
         });
 
@@ -170,6 +191,10 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
             Some(transaction_ids) => transaction_ids,
             None => bail!("Failed to remove block: missing transactions for block '{height}' ('{block_hash}')"),
         };
+        // Ensure the block is the last leaf of the block tree.
+        if self.tree_store().number_of_leaves()? != height as u64 + 1 {
+            bail!("Failed to remove block: block '{height}' ('{block_hash}') is not the latest block")
+        }
 
         atomic_write_batch!(self, {
             // Remove the block hash.
@@ -193,6 +218,9 @@ pub trait BlockStorage<N: Network>: Clone + Sync {
             // Remove the block signature.
             self.signature_map().remove(block_hash)?;
 
+            // Remove the block hash from the block tree.
+            self.tree_store().remove_last()?;
+
             Ok(())
         });
 
@@ -326,6 +354,8 @@ pub struct BlockMemory<N: Network> {
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
     /// The signature map.
     signature_map: MemoryMap<N::BlockHash, Signature<N>>,
+    /// The block tree store.
+    tree_store: TreeStore<N, TreeMemory<N>, BLOCKS_DEPTH>,
 }
 
 #[rustfmt::skip]
@@ -338,6 +368,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
     type SignatureMap = MemoryMap<N::BlockHash, Signature<N>>;
+    type TreeStorage = TreeMemory<N>;
 
     /// Initializes the block storage.
     fn open() -> Result<Self> {
@@ -345,6 +376,8 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         let transition_store = TransitionStore::<N, TransitionMemory<N>>::open()?;
         // Initialize the transaction store.
         let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
+        // Initialize the block tree store.
+        let tree_store = TreeStore::<N, TreeMemory<N>, BLOCKS_DEPTH>::open()?;
         // Return the block storage.
         Ok(Self {
             id_map: MemoryMap::default(),
@@ -354,6 +387,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             reverse_transactions_map: MemoryMap::default(),
             transaction_store,
             signature_map: MemoryMap::default(),
+            tree_store,
         })
     }
 
@@ -391,6 +425,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn signature_map(&self) -> &Self::SignatureMap {
         &self.signature_map
     }
+
+    /// Returns the block tree store.
+    fn tree_store(&self) -> &TreeStore<N, Self::TreeStorage, BLOCKS_DEPTH> {
+        &self.tree_store
+    }
 }
 
 /// The block store.
@@ -436,6 +475,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.transaction_store().transition_store()
     }
 
+    /// Returns the block tree store.
+    pub fn tree_store(&self) -> &TreeStore<N, B::TreeStorage, BLOCKS_DEPTH> {
+        self.storage.tree_store()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -26,6 +26,9 @@ pub use transaction::*;
 mod transition;
 pub use transition::*;
 
+mod tree;
+pub use tree::*;
+
 #[macro_export]
 macro_rules! cow_to_copied {
     ($cow:expr) => {
@@ -0,0 +1,461 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    atomic_write_batch,
+    cow_to_copied,
+    ledger::map::{memory_map::MemoryMap, Map, MapRead},
+};
+use console::{
+    collections::merkle_tree::MerklePath,
+    network::prelude::*,
+    types::{Field, U64},
+};
+
+use anyhow::Result;
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+/// A trait for Merkle tree storage.
+pub trait TreeStorage<N: Network>: Clone + Sync {
+    /// The mapping of `(level, index)` to `node hash`, where level `0` holds the leaf hashes.
+    type NodeMap: for<'a> Map<'a, (u8, u64), Field<N>>;
+
+    /// Initializes the tree storage.
+    fn open() -> Result<Self>;
+
+    /// Returns the node map.
+    fn node_map(&self) -> &Self::NodeMap;
+
+    /// Starts an atomic batch write operation.
+    fn start_atomic(&self) {
+        self.node_map().start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.node_map().is_atomic_in_progress()
+    }
+
+    /// Aborts an atomic batch write operation.
+    fn abort_atomic(&self) {
+        self.node_map().abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    fn finish_atomic(&self) -> Result<()> {
+        self.node_map().finish_atomic()
+    }
+}
+
+/// An in-memory Merkle tree storage.
+#[derive(Clone)]
+pub struct TreeMemory<N: Network> {
+    /// The node map.
+    node_map: MemoryMap<(u8, u64), Field<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> TreeStorage<N> for TreeMemory<N> {
+    type NodeMap = MemoryMap<(u8, u64), Field<N>>;
+
+    /// Initializes the tree storage.
+    fn open() -> Result<Self> {
+        Ok(Self { node_map: MemoryMap::default() })
+    }
+
+    /// Returns the node map.
+    fn node_map(&self) -> &Self::NodeMap {
+        &self.node_map
+    }
+}
+
+/// A BHP Merkle tree of depth `DEPTH`, whose nodes are read from and written to storage.
+///
+/// The tree produces the same roots and paths as `BHPMerkleTree<N, DEPTH>`, however only the
+/// nodes along the path of each append are touched, so appends and path queries are `O(DEPTH)`
+/// and opening the tree does not require loading the leaves into memory.
+#[derive(Clone)]
+pub struct TreeStore<N: Network, T: TreeStorage<N>, const DEPTH: u8> {
+    /// The tree storage.
+    storage: T,
+    /// The hashes of the empty subtrees, indexed by level.
+    empty_hashes: Arc<Vec<Field<N>>>,
+    /// The root of the empty tree.
+    empty_root: Field<N>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, T: TreeStorage<N>, const DEPTH: u8> TreeStore<N, T, DEPTH> {
+    /// Initializes the tree store.
+    pub fn open() -> Result<Self> {
+        // Initialize the tree storage.
+        let storage = T::open()?;
+        // Return the tree store.
+        Self::from(storage)
+    }
+
+    /// Initializes a tree store from storage.
+    pub fn from(storage: T) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the empty hash, which is the hash of an empty leaf.
+        let empty_hash = hash_children::<N>(&Field::zero(), &Field::zero())?;
+        // Compute the hash of an empty subtree for each level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(empty_hash);
+        for level in 0..DEPTH as usize {
+            empty_hashes.push(hash_children::<N>(&empty_hashes[level], &empty_hashes[level])?);
+        }
+        // Compute the root of the empty tree.
+        let empty_root = pad_root::<N>(empty_hash, 0, DEPTH, &empty_hash)?;
+
+        Ok(Self { storage, empty_hashes: Arc::new(empty_hashes), empty_root, _phantom: PhantomData })
+    }
+
+    /// Appends the given leaf to the tree.
+    pub fn append(&self, leaf: &[bool]) -> Result<()> {
+        // Retrieve the index of the new leaf.
+        let leaf_index = self.number_of_leaves()?;
+        // Ensure the tree is not full.
+        ensure!((leaf_index as u128) < (1u128 << DEPTH), "Merkle tree cannot exceed depth {DEPTH}");
+        // Compute the depth of the subtree holding all of the leaves, including the new leaf.
+        let subtree_depth = subtree_depth(leaf_index + 1);
+
+        // Compute the leaf hash.
+        let leaf_hash = hash_leaf::<N>(leaf)?;
+        // Compute the updated nodes along the path, from the leaf to the top of the subtree.
+        let nodes = self.compute_path_nodes(leaf_index, leaf_hash, subtree_depth)?;
+        // Compute the new root.
+        let root = pad_root::<N>(nodes[nodes.len() - 1].1, subtree_depth, DEPTH, &self.empty_hashes[0])?;
+
+        atomic_write_batch!(self, {
+            // Store the updated nodes.
+            for (key, node) in nodes.iter() {
+                self.storage.node_map().insert(*key, *node)?;
+            }
+            // Store the root.
+            self.storage.node_map().insert((DEPTH, 0), root)?;
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Removes the last leaf from the tree.
+    pub fn remove_last(&self) -> Result<()> {
+        // Retrieve the number of leaves.
+        let number_of_leaves = self.number_of_leaves()?;
+        // Ensure the tree is not empty.
+        ensure!(number_of_leaves > 0, "Cannot remove a leaf from an empty Merkle tree");
+
+        // Retrieve the index of the last leaf.
+        let leaf_index = number_of_leaves - 1;
+        // Compute the depth of the subtree before and after the removal.
+        let old_depth = subtree_depth(number_of_leaves);
+        let new_depth = subtree_depth(leaf_index);
+
+        // Compute the updated nodes along the path, with the last leaf replaced by the empty hash.
+        let nodes = self.compute_path_nodes(leaf_index, self.empty_hashes[0], old_depth)?;
+        // Retrieve the root of the new subtree, which is off the path if the remaining leaves fill it.
+        let subtree_root = match nodes[new_depth as usize] {
+            ((_, 0), node) => node,
+            _ => self.get_node(new_depth, 0)?,
+        };
+        // Compute the new root.
+        let root = match leaf_index {
+            0 => self.empty_root,
+            _ => pad_root::<N>(subtree_root, new_depth, DEPTH, &self.empty_hashes[0])?,
+        };
+
+        atomic_write_batch!(self, {
+            for (level, ((_, index), node)) in nodes.iter().enumerate() {
+                // Remove the node if it is the root of an empty subtree, or if it is above the new subtree.
+                match (index << level) >= leaf_index || level > new_depth as usize {
+                    true => self.storage.node_map().remove(&(level as u8, *index))?,
+                    false => self.storage.node_map().insert((level as u8, *index), *node)?,
+                }
+            }
+            // Store the root.
+            match leaf_index {
+                0 => self.storage.node_map().remove(&(DEPTH, 0))?,
+                _ => self.storage.node_map().insert((DEPTH, 0), root)?,
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Returns the Merkle path for the given leaf index and leaf.
+    pub fn prove(&self, leaf_index: u64, leaf: &[bool]) -> Result<MerklePath<N, DEPTH>> {
+        // Ensure the leaf index is valid.
+        ensure!(leaf_index < self.number_of_leaves()?, "The given Merkle leaf index is out of bounds");
+        // Ensure the leaf hash matches the one in the tree.
+        ensure!(
+            self.get_node(0, leaf_index)? == hash_leaf::<N>(leaf)?,
+            "The given Merkle leaf does not match the one in the Merkle tree"
+        );
+
+        // Compute the depth of the subtree holding all of the leaves.
+        let subtree_depth = subtree_depth(self.number_of_leaves()?);
+
+        // Collect the sibling hashes within the subtree, from the leaf to the top of the subtree.
+        let mut path = (0..subtree_depth)
+            .map(|level| self.get_node(level, (leaf_index >> level) ^ 1))
+            .collect::<Result<Vec<_>>>()?;
+        // Pad the path with the empty hash, for the levels above the subtree.
+        path.resize(DEPTH as usize, self.empty_hashes[0]);
+
+        // Return the Merkle path.
+        MerklePath::try_from((U64::new(leaf_index), path))
+    }
+
+    /// Returns `true` if the tree contains the given leaf at the given leaf index.
+    pub fn contains_leaf(&self, leaf_index: u64, leaf: &[bool]) -> Result<bool> {
+        match self.storage.node_map().get(&(0, leaf_index))? {
+            Some(node) => Ok(cow_to_copied!(node) == hash_leaf::<N>(leaf)?),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub fn root(&self) -> Result<Field<N>> {
+        match self.storage.node_map().get(&(DEPTH, 0))? {
+            Some(root) => Ok(cow_to_copied!(root)),
+            None => Ok(self.empty_root),
+        }
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub fn number_of_leaves(&self) -> Result<u64> {
+        // As the leaves are contiguous, binary search for the first missing leaf index.
+        let (mut low, mut high) = (0u64, 1u64);
+        // Find an upper bound on the number of leaves.
+        while self.storage.node_map().contains_key(&(0, high - 1))? {
+            low = high;
+            high = match high.checked_mul(2) {
+                Some(high) => high,
+                None => return Ok(u64::MAX),
+            };
+        }
+        // Find the number of leaves within the bounds.
+        while low + 1 < high {
+            let middle = low + (high - low) / 2;
+            match self.storage.node_map().contains_key(&(0, middle - 1))? {
+                true => low = middle,
+                false => high = middle,
+            }
+        }
+        Ok(low)
+    }
+
+    /// Starts an atomic batch write operation.
+    pub fn start_atomic(&self) {
+        self.storage.start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    pub fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
+    /// Aborts an atomic batch write operation.
+    pub fn abort_atomic(&self) {
+        self.storage.abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    pub fn finish_atomic(&self) -> Result<()> {
+        self.storage.finish_atomic()
+    }
+}
+
+impl<N: Network, T: TreeStorage<N>, const DEPTH: u8> TreeStore<N, T, DEPTH> {
+    /// Returns the node at the given level and index, defaulting to the empty subtree hash.
+    fn get_node(&self, level: u8, index: u64) -> Result<Field<N>> {
+        match self.storage.node_map().get(&(level, index))? {
+            Some(node) => Ok(cow_to_copied!(node)),
+            None => Ok(self.empty_hashes[level as usize]),
+        }
+    }
+
+    /// Returns the nodes from the given leaf up to the top of a subtree of the given depth,
+    /// where the leaf at `leaf_index` is set to `leaf_hash`.
+    #[allow(clippy::type_complexity)]
+    fn compute_path_nodes(
+        &self,
+        leaf_index: u64,
+        leaf_hash: Field<N>,
+        depth: u8,
+    ) -> Result<Vec<((u8, u64), Field<N>)>> {
+        let mut nodes = Vec::with_capacity(depth as usize + 1);
+        nodes.push(((0, leaf_index), leaf_hash));
+
+        let (mut index, mut current) = (leaf_index, leaf_hash);
+        for level in 0..depth {
+            // Retrieve the sibling hash.
+            let sibling = self.get_node(level, index ^ 1)?;
+            // Compute the parent hash.
+            current = match index & 1 == 0 {
+                true => hash_children::<N>(&current, &sibling)?,
+                false => hash_children::<N>(&sibling, &current)?,
+            };
+            index >>= 1;
+            nodes.push(((level + 1, index), current));
+        }
+        Ok(nodes)
+    }
+}
+
+/// Returns the depth of the smallest power-of-two subtree that holds the given number of leaves.
+fn subtree_depth(number_of_leaves: u64) -> u8 {
+    number_of_leaves.next_power_of_two().trailing_zeros() as u8
+}
+
+/// Returns the root, by hashing the given subtree root with the empty hash up to `DEPTH`.
+fn pad_root<N: Network>(
+    subtree_root: Field<N>,
+    subtree_depth: u8,
+    depth: u8,
+    empty_hash: &Field<N>,
+) -> Result<Field<N>> {
+    let mut root = subtree_root;
+    for _ in subtree_depth..depth {
+        root = hash_children::<N>(&root, empty_hash)?;
+    }
+    Ok(root)
+}
+
+/// Returns the BHP leaf hash, matching the leaf hasher of `BHPMerkleTree`.
+fn hash_leaf<N: Network>(leaf: &[bool]) -> Result<Field<N>> {
+    // Prepend the leaf with a `false` bit.
+    let mut input = vec![false];
+    input.extend(leaf);
+    // Hash the input.
+    N::hash_bhp1024(&input)
+}
+
+/// Returns the BHP path hash, matching the path hasher of `BHPMerkleTree`.
+fn hash_children<N: Network>(left: &Field<N>, right: &Field<N>) -> Result<Field<N>> {
+    // Prepend the nodes with a `true` bit.
+    let mut input = vec![true];
+    input.extend(left.to_bits_le());
+    input.extend(right.to_bits_le());
+    // Hash the input.
+    N::hash_bhp512(&input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const DEPTH: u8 = 8;
+
+    #[test]
+    fn test_append_matches_merkle_tree() -> Result<()> {
+        let rng = &mut test_rng();
+
+        // Initialize the tree store.
+        let tree = TreeStore::<CurrentNetwork, TreeMemory<_>, DEPTH>::open()?;
+        // Ensure the empty tree matches.
+        let expected = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&[])?;
+        assert_eq!(expected.root(), &tree.root()?);
+        assert_eq!(0, tree.number_of_leaves()?);
+
+        let mut leaves = vec![];
+        for i in 0..10u64 {
+            // Append a new leaf.
+            let leaf = Field::<CurrentNetwork>::rand(rng).to_bits_le();
+            tree.append(&leaf)?;
+            leaves.push(leaf);
+            assert_eq!(i + 1, tree.number_of_leaves()?);
+
+            // Ensure the root matches the in-memory Merkle tree.
+            let expected = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&leaves)?;
+            assert_eq!(expected.root(), &tree.root()?);
+
+            // Ensure every path matches the in-memory Merkle tree, and verifies.
+            for (index, leaf) in leaves.iter().enumerate() {
+                let path = tree.prove(index as u64, leaf)?;
+                assert_eq!(expected.prove(index, leaf)?, path);
+                assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &tree.root()?, leaf));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_last() -> Result<()> {
+        let rng = &mut test_rng();
+
+        // Initialize the tree store.
+        let tree = TreeStore::<CurrentNetwork, TreeMemory<_>, DEPTH>::open()?;
+
+        // Append the leaves.
+        let leaves: Vec<_> = (0..9).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect();
+        for leaf in leaves.iter() {
+            tree.append(leaf)?;
+        }
+
+        // Remove the leaves, one at a time.
+        for i in (0..leaves.len()).rev() {
+            tree.remove_last()?;
+            assert_eq!(i as u64, tree.number_of_leaves()?);
+            // Ensure the root matches the in-memory Merkle tree.
+            let expected = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&leaves[..i])?;
+            assert_eq!(expected.root(), &tree.root()?);
+        }
+        // Ensure the empty tree cannot be removed from.
+        assert!(tree.remove_last().is_err());
+
+        // Ensure the tree can be appended to again.
+        tree.append(&leaves[0])?;
+        let expected = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&leaves[..1])?;
+        assert_eq!(expected.root(), &tree.root()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prove_invalid() -> Result<()> {
+        let rng = &mut test_rng();
+
+        // Initialize the tree store.
+        let tree = TreeStore::<CurrentNetwork, TreeMemory<_>, DEPTH>::open()?;
+        let leaf = Field::<CurrentNetwork>::rand(rng).to_bits_le();
+
+        // Ensure a path cannot be produced for a missing leaf.
+        assert!(tree.prove(0, &leaf).is_err());
+
+        // Ensure a path cannot be produced for an incorrect leaf.
+        tree.append(&leaf)?;
+        assert!(tree.prove(0, &Field::<CurrentNetwork>::rand(rng).to_bits_le()).is_err());
+        assert!(tree.prove(0, &leaf).is_ok());
+
+        // Ensure the tree only contains the leaf at its own index.
+        assert!(tree.contains_leaf(0, &leaf)?);
+        assert!(!tree.contains_leaf(1, &leaf)?);
+        assert!(!tree.contains_leaf(0, &Field::<CurrentNetwork>::rand(rng).to_bits_le())?);
+        Ok(())
+    }
+}
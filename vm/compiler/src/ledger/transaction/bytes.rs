@@ -137,4 +137,22 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_framed_bytes() -> Result<()> {
+        use snarkvm_utilities::{read_framed, FrameWriter};
+
+        for expected in [
+            crate::ledger::vm::test_helpers::sample_deployment_transaction(),
+            crate::ledger::vm::test_helpers::sample_execution_transaction(),
+        ] {
+            // Stream the transaction in small frames, and ensure it reads back from the stream.
+            let mut writer = FrameWriter::with_frame_size(vec![], 1_024);
+            expected.write_le(&mut writer)?;
+            let stream = writer.finish()?;
+            assert!(stream.len() > expected.to_bytes_le()?.len());
+            assert_eq!(expected, read_framed::<Transaction<CurrentNetwork>, _>(&stream[..])?);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Transaction<N> {
+    /// Returns the transaction for the given bytes, if the bytes are its canonical encoding.
+    pub fn from_bytes_canonical(bytes: &[u8]) -> Result<Self> {
+        let transaction = Self::from_bytes_le(bytes)?;
+        transaction.check_canonical(bytes)?;
+        Ok(transaction)
+    }
+
+    /// Ensures the given bytes are the canonical encoding of the transaction, and that the transaction is well-formed,
+    /// without verifying its proofs. This allows a memory pool to cheaply enforce a unique encoding per transaction ID.
+    ///
+    /// This rejects non-canonical field and group encodings and trailing bytes, as the encoding must be
+    /// reproduced exactly, and reordered or duplicated transitions, as the IDs must match their contents.
+    pub fn check_canonical(&self, bytes: &[u8]) -> Result<()> {
+        // Ensure the bytes are the canonical encoding of the transaction.
+        ensure!(self.to_bytes_le()? == bytes, "Transaction '{}' is not canonically encoded", self.id());
+
+        // Ensure the number of transitions is within the allowed range.
+        let num_transitions = self.transitions().count();
+        ensure!(num_transitions > 0, "Transaction '{}' does not contain any transitions", self.id());
+        ensure!(
+            num_transitions <= Self::MAX_TRANSITIONS,
+            "Transaction '{}' contains {num_transitions} transitions, exceeding the maximum of {}",
+            self.id(),
+            Self::MAX_TRANSITIONS
+        );
+
+        // Ensure each transition ID matches the contents of its transition.
+        for transition in self.transitions() {
+            ensure!(**transition.id() == transition.to_root()?, "Transition '{}' has an incorrect ID", transition.id());
+        }
+        // Ensure the transaction ID matches the transitions, in order.
+        ensure!(*self.id() == self.to_root()?, "Transaction '{}' has an incorrect ID", self.id());
+
+        // Ensure there are no duplicate transition IDs, transition public keys, serial numbers, or commitments.
+        ensure!(!has_duplicates(self.transition_ids()), "Transaction '{}' has duplicate transitions", self.id());
+        ensure!(
+            !has_duplicates(self.transition_public_keys()),
+            "Transaction '{}' has duplicate transition public keys",
+            self.id()
+        );
+        ensure!(!has_duplicates(self.serial_numbers()), "Transaction '{}' has duplicate serial numbers", self.id());
+        ensure!(!has_duplicates(self.commitments()), "Transaction '{}' has duplicate commitments", self.id());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_canonical() -> Result<()> {
+        for transaction in [
+            crate::ledger::vm::test_helpers::sample_deployment_transaction(),
+            crate::ledger::vm::test_helpers::sample_execution_transaction(),
+        ] {
+            // Ensure the canonical encoding is accepted.
+            let bytes = transaction.to_bytes_le()?;
+            transaction.check_canonical(&bytes)?;
+            assert_eq!(transaction, Transaction::from_bytes_canonical(&bytes)?);
+
+            // Ensure trailing bytes are rejected.
+            let mut candidate = bytes.clone();
+            candidate.push(0);
+            assert!(transaction.check_canonical(&candidate).is_err());
+            assert!(Transaction::<console::network::Testnet3>::from_bytes_canonical(&candidate).is_err());
+        }
+
+        // Ensure a duplicated transition is rejected.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction();
+        let (execution, transition) = match &transaction {
+            Transaction::Execute(_, execution, _) => (execution.clone(), execution.peek()?),
+            Transaction::Deploy(..) => unreachable!(),
+        };
+        let candidate = Transaction::from_execution(execution, Some(transition))?;
+        assert!(candidate.check_canonical(&candidate.to_bytes_le()?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{ledger::Ledger, BlockStorage};
+
+/// The estimated size of a transition, excluding its inputs and outputs, in bytes, which is dominated by the proof.
+pub const TRANSITION_SIZE_IN_BYTES: usize = 1_024;
+/// The estimated size of each transition input or output, in bytes.
+pub const PUBLIC_INPUT_SIZE_IN_BYTES: usize = 128;
+/// The estimated size of the verifying key and certificate of each function in a deployment, in bytes.
+pub const FUNCTION_KEY_SIZE_IN_BYTES: usize = 2_048;
+/// The estimated cost of executing each finalize command.
+pub const FINALIZE_COMMAND_COST: u64 = 50;
+
+/// A prospective transaction, which has not been authorized or proven yet.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Executable<N: Network> {
+    /// The deployment of a program, which is paid for with an additional fee.
+    Deploy(Box<Program<N>>),
+    /// A call to a program function, which is paid for with an additional fee if `true`.
+    Execute(ProgramID<N>, Identifier<N>, bool),
+}
+
+/// The estimated fee of a prospective transaction, in the units of `TransactionWeight`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeEstimate<N: Network> {
+    /// The estimated size of the serialized transaction, in bytes.
+    size_in_bytes: usize,
+    /// The number of constraints proven by the caller.
+    num_constraints: usize,
+    /// The estimated cost of verifying the transaction.
+    verification_cost: u64,
+    /// The estimated cost of executing the finalize logic of the transaction.
+    finalize_cost: u64,
+    /// The estimate of each transition in the transaction.
+    transitions: Vec<TransitionEstimate<N>>,
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the estimated fee of the given prospective transaction, without authorizing or proving it,
+    /// so the fee is known before the (expensive) proofs are produced.
+    ///
+    /// The verification cost matches `Transaction::weight` once the transaction is proven.
+    /// The size is an estimate, as the size of each input and output depends on its value.
+    /// For a deployment, the constraints of the deployed functions are not counted,
+    /// as their keys are synthesized when the deployment is verified.
+    pub fn estimate_fee<B: BlockStorage<N>, P: ProgramStorage<N>>(
+        executable: &Executable<N>,
+        ledger: &Ledger<N, B, P>,
+    ) -> Result<FeeEstimate<N>> {
+        // Retrieve the additional fee function.
+        let credits = ProgramID::from_str("credits.aleo")?;
+        let fee = Identifier::from_str("fee")?;
+
+        // Estimate the transitions.
+        let transitions = match executable {
+            Executable::Deploy(program) => {
+                // Ensure the program does not already exist.
+                ensure!(!ledger.vm().contains_program(program.id()), "Program '{}' already exists", program.id());
+                ledger.vm().estimate_transitions(&credits, &fee)?
+            }
+            Executable::Execute(program_id, function_name, with_additional_fee) => {
+                let mut transitions = ledger.vm().estimate_transitions(program_id, function_name)?;
+                if *with_additional_fee {
+                    transitions.extend(ledger.vm().estimate_transitions(&credits, &fee)?);
+                }
+                transitions
+            }
+        };
+
+        // Estimate the size, constraints, and costs of the transitions.
+        let mut size_in_bytes = 0;
+        let mut num_constraints = 0;
+        let mut verification_cost = 0;
+        let mut finalize_cost = 0;
+        for transition in &transitions {
+            let num_public_inputs = transition.num_inputs() + transition.num_outputs();
+            size_in_bytes += TRANSITION_SIZE_IN_BYTES + num_public_inputs * PUBLIC_INPUT_SIZE_IN_BYTES;
+            num_constraints += transition.num_constraints();
+            verification_cost += PROOF_VERIFICATION_COST + num_public_inputs as u64 * PUBLIC_INPUT_COST;
+            finalize_cost += transition.num_finalize_commands() as u64 * FINALIZE_COMMAND_COST;
+        }
+
+        // Add the size and verification cost of the deployment, as in `Transaction::weight`.
+        if let Executable::Deploy(program) = executable {
+            size_in_bytes += program.to_bytes_le()?.len();
+            for function in program.functions().values() {
+                size_in_bytes += FUNCTION_KEY_SIZE_IN_BYTES;
+                verification_cost += PROOF_VERIFICATION_COST
+                    + function.instructions().len() as u64 * INSTRUCTION_SYNTHESIS_COST
+                    + (function.inputs().len() + function.outputs().len()) as u64 * PUBLIC_INPUT_COST;
+            }
+        }
+
+        Ok(FeeEstimate { size_in_bytes, num_constraints, verification_cost, finalize_cost, transitions })
+    }
+}
+
+impl<N: Network> FeeEstimate<N> {
+    /// Returns the estimated size of the serialized transaction, in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of constraints proven by the caller.
+    pub const fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// Returns the estimated cost of verifying the transaction.
+    pub const fn verification_cost(&self) -> u64 {
+        self.verification_cost
+    }
+
+    /// Returns the estimated cost of executing the finalize logic of the transaction.
+    pub const fn finalize_cost(&self) -> u64 {
+        self.finalize_cost
+    }
+
+    /// Returns the estimated total cost of the transaction, which is the verification and finalize cost.
+    pub const fn total_cost(&self) -> u64 {
+        self.verification_cost + self.finalize_cost
+    }
+
+    /// Returns the estimate of each transition in the transaction.
+    pub fn transitions(&self) -> &[TransitionEstimate<N>] {
+        &self.transitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_fee() -> Result<()> {
+        let ledger = crate::ledger::test_helpers::sample_genesis_ledger();
+
+        // Ensure the estimate of an execution matches the weight of the proven transaction.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction();
+        let transition = transaction.transitions().next().unwrap();
+        let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+        let executable = Executable::Execute(program_id, function_name, false);
+        let estimate = Transaction::estimate_fee(&executable, &ledger)?;
+        assert_eq!(transaction.transitions().count(), estimate.transitions().len());
+        assert_eq!(transaction.weight()?.verification_cost(), estimate.verification_cost());
+        assert_eq!(0, estimate.finalize_cost());
+        assert!(estimate.num_constraints() > 0);
+
+        // Ensure the additional fee adds a transition.
+        let executable = Executable::Execute(program_id, function_name, true);
+        let with_fee = Transaction::estimate_fee(&executable, &ledger)?;
+        assert_eq!(estimate.transitions().len() + 1, with_fee.transitions().len());
+        assert!(with_fee.size_in_bytes() > estimate.size_in_bytes());
+        assert!(with_fee.total_cost() > estimate.total_cost());
+
+        // Ensure the estimate of a deployment matches the weight of the proven transaction.
+        let transaction = crate::ledger::vm::test_helpers::sample_deployment_transaction();
+        let program = match &transaction {
+            Transaction::Deploy(_, deployment, _) => deployment.program().clone(),
+            Transaction::Execute(..) => unreachable!(),
+        };
+        let estimate = Transaction::estimate_fee(&Executable::Deploy(Box::new(program)), &ledger)?;
+        assert_eq!(transaction.weight()?.verification_cost(), estimate.verification_cost());
+        assert_eq!(1, estimate.transitions().len());
+
+        // Ensure a missing function is rejected.
+        let executable = Executable::Execute(program_id, Identifier::from_str("missing")?, false);
+        assert!(Transaction::estimate_fee(&executable, &ledger).is_err());
+        Ok(())
+    }
+}
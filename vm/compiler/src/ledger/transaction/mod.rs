@@ -14,19 +14,29 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod estimate;
+pub use estimate::*;
+
 mod leaf;
 pub use leaf::*;
 
 mod merkle;
 pub use merkle::*;
 
+mod priority;
+pub use priority::*;
+
+mod weight;
+pub use weight::*;
+
 mod bytes;
+mod canonical;
 mod serialize;
 mod string;
 
 use crate::{
     ledger::{vm::VM, Origin, Transition},
-    process::{Authorization, Deployment, Execution},
+    process::{Authorization, Deployment, Execution, TransitionEstimate},
     program::Program,
     ProgramStorage,
 };
@@ -139,6 +149,42 @@ impl<N: Network> Transaction<N> {
         // Initialize the transaction.
         Self::execute_authorization_with_additional_fee(vm, private_key, authorization, additional_fee, rng)
     }
+
+    /// Initializes a new execution transaction, which bundles the executions of the given authorizations,
+    /// and pays a single additional fee for the bundle.
+    ///
+    /// The bundle is verified and finalized as a whole, so its transitions are accepted or rejected together.
+    /// Each authorization is executed independently, so an output of one execution can not be
+    /// an input to another execution in the same bundle.
+    pub fn execute_bundle<P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        authorizations: Vec<Authorization<N>>,
+        additional_fee: Option<(Record<N, Plaintext<N>>, u64)>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        ensure!(!authorizations.is_empty(), "A bundle must contain at least one execution");
+
+        // Compute the executions, and concatenate their transitions, in order.
+        let mut transitions = Vec::new();
+        for authorization in authorizations {
+            let (_, execution) = vm.execute(authorization, rng)?;
+            transitions.extend(execution.into_transitions());
+        }
+        let execution = Execution::from(N::EDITION, &transitions)?;
+        // Ensure the bundle is within the size of a transaction.
+        Self::check_execution_size(&execution)?;
+
+        // Compute the additional fee, if it is present.
+        let additional_fee = match additional_fee {
+            Some((credits, additional_fee_in_gates)) => {
+                Some(vm.execute_additional_fee(private_key, credits, additional_fee_in_gates, rng)?.1)
+            }
+            None => None,
+        };
+        // Initialize the transaction.
+        Self::from_execution(execution, additional_fee)
+    }
 }
 
 impl<N: Network> Transaction<N> {
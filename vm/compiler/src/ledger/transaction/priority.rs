@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::ledger::Transactions;
+
+use core::cmp::Ordering;
+use std::collections::HashSet;
+
+/// The priority of a transaction, for ordering a mempool, where a higher priority is included first.
+///
+/// The priority is the fee per unit of weight, where the weight of a transaction is:
+///     size_in_bytes + verification_cost + finalize_cost
+/// Transactions with an equal fee rate are ordered by the larger fee, and then by the smaller size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Priority {
+    /// The fee of the transaction, in gates.
+    fee: u64,
+    /// The size of the serialized transaction, in bytes.
+    size_in_bytes: usize,
+    /// The weight of the transaction.
+    weight: u64,
+}
+
+impl Priority {
+    /// Returns the fee of the transaction, in gates.
+    pub const fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// Returns the size of the serialized transaction, in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the weight of the transaction.
+    pub const fn weight(&self) -> u64 {
+        self.weight
+    }
+}
+
+impl Ord for Priority {
+    /// Orders the priorities by the fee rate, then by the fee, and then by the smaller size.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare `self.fee / self.weight` to `other.fee / other.weight`, without division.
+        let rate = (self.fee as u128 * other.weight as u128).cmp(&(other.fee as u128 * self.weight as u128));
+        rate.then(self.fee.cmp(&other.fee)).then(other.size_in_bytes.cmp(&self.size_in_bytes))
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the priority of the transaction, for ordering a mempool.
+    ///
+    /// The fee is the sum of the transition fees, where a negative sum counts as no fee.
+    /// The finalize cost is estimated as `FINALIZE_COMMAND_COST` for each finalize input,
+    /// as the finalize logic of a function is not part of the transaction.
+    pub fn priority(&self) -> Result<Priority> {
+        // Compute the weight of the transaction.
+        let weight = self.weight()?;
+        // Compute the fee of the transaction.
+        let fee = u64::try_from(self.fees().sum::<i64>()).unwrap_or(0);
+        // Estimate the finalize cost of the transaction.
+        let num_finalize_inputs =
+            self.transitions().filter_map(|transition| transition.finalize().as_ref()).map(Vec::len);
+        let finalize_cost = num_finalize_inputs.sum::<usize>() as u64 * FINALIZE_COMMAND_COST;
+
+        Ok(Priority {
+            fee,
+            size_in_bytes: weight.size_in_bytes(),
+            weight: (weight.size_in_bytes() as u64)
+                .saturating_add(weight.verification_cost())
+                .saturating_add(finalize_cost),
+        })
+    }
+}
+
+/// Returns the transactions from the given mempool to include in a block of at most `max_block_size` bytes,
+/// in the order of inclusion.
+///
+/// The transactions are selected greedily by priority, which approximates the knapsack of the highest total fee.
+/// A transaction is skipped if it does not fit in the remaining space, if the block is full,
+/// or if it spends a serial number that a selected transaction already spends.
+pub fn select_transactions<'a, N: Network>(
+    mempool: impl IntoIterator<Item = &'a Transaction<N>>,
+    max_block_size: usize,
+) -> Result<Vec<&'a Transaction<N>>> {
+    // Compute the priority of each transaction, and sort by descending priority.
+    let mut candidates = mempool
+        .into_iter()
+        .map(|transaction| Ok((transaction.priority()?, transaction)))
+        .collect::<Result<Vec<_>>>()?;
+    candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    // Select the transactions that fit.
+    let mut selected = Vec::new();
+    let mut remaining = max_block_size;
+    let mut serial_numbers: HashSet<&Field<N>> = HashSet::new();
+    for (priority, transaction) in candidates {
+        // Ensure the block is not full.
+        if selected.len() == Transactions::<N>::MAX_TRANSACTIONS {
+            break;
+        }
+        // Ensure the transaction fits in the remaining space.
+        if priority.size_in_bytes() > remaining {
+            continue;
+        }
+        // Ensure the transaction does not spend a serial number twice.
+        let transaction_serial_numbers = transaction.serial_numbers().collect::<Vec<_>>();
+        if transaction_serial_numbers.iter().any(|serial_number| serial_numbers.contains(*serial_number)) {
+            continue;
+        }
+        serial_numbers.extend(transaction_serial_numbers);
+        remaining -= priority.size_in_bytes();
+        selected.push(transaction);
+    }
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_ordering() {
+        let priority = |fee, size_in_bytes, weight| Priority { fee, size_in_bytes, weight };
+
+        // Ensure a higher fee rate takes priority, even with a lower fee.
+        assert!(priority(10, 100, 100) > priority(15, 200, 200));
+        // Ensure an equal fee rate is ordered by the larger fee, and then by the smaller size.
+        assert!(priority(20, 200, 200) > priority(10, 100, 100));
+        assert!(priority(10, 50, 100) > priority(10, 60, 100));
+        // Ensure a transaction without a fee has the lowest priority.
+        assert!(priority(0, 10, 10) < priority(1, 1_000, 1_000_000));
+    }
+
+    #[test]
+    fn test_select_transactions() -> Result<()> {
+        let deployment = crate::ledger::vm::test_helpers::sample_deployment_transaction();
+        let execution = crate::ledger::vm::test_helpers::sample_execution_transaction();
+        let execution_size = execution.to_bytes_le()?.len();
+
+        // Ensure a transaction is selected only if it fits.
+        assert_eq!(vec![&execution], select_transactions([&execution], execution_size)?);
+        assert!(select_transactions([&execution], execution_size - 1)?.is_empty());
+
+        // Ensure only the transaction with the higher priority is selected, as both spend the same genesis record.
+        let selected = select_transactions([&deployment, &execution], usize::MAX)?;
+        assert_eq!(1, selected.len());
+        assert_eq!(deployment.priority()?.max(execution.priority()?), selected[0].priority()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The estimated cost of verifying a single proof or certificate.
+pub const PROOF_VERIFICATION_COST: u64 = 1_000;
+/// The estimated cost of each transition input or output, which is a public input to the transition proof.
+pub const PUBLIC_INPUT_COST: u64 = 10;
+/// The estimated cost of synthesizing each instruction of a function, when verifying a deployment.
+pub const INSTRUCTION_SYNTHESIS_COST: u64 = 20;
+
+/// The weight of a transition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransitionWeight<N: Network> {
+    /// The transition ID.
+    id: N::TransitionID,
+    /// The size of the serialized transition, in bytes.
+    size_in_bytes: usize,
+    /// The number of transition inputs and outputs.
+    num_public_inputs: usize,
+    /// The estimated cost of verifying the transition.
+    verification_cost: u64,
+}
+
+/// The weight of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionWeight<N: Network> {
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The size of the serialized transaction, in bytes.
+    size_in_bytes: usize,
+    /// The number of proofs and certificates in the transaction.
+    num_proofs: usize,
+    /// The estimated cost of verifying the transaction.
+    verification_cost: u64,
+    /// The weight of each transition in the transaction.
+    transitions: Vec<TransitionWeight<N>>,
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns the weight of the transition, where the verification cost is:
+    ///     PROOF_VERIFICATION_COST + (num_inputs + num_outputs) * PUBLIC_INPUT_COST
+    pub fn weight(&self) -> Result<TransitionWeight<N>> {
+        let num_public_inputs = self.inputs().len() + self.outputs().len();
+        Ok(TransitionWeight {
+            id: *self.id(),
+            size_in_bytes: self.to_bytes_le()?.len(),
+            num_public_inputs,
+            verification_cost: PROOF_VERIFICATION_COST + num_public_inputs as u64 * PUBLIC_INPUT_COST,
+        })
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the weight of the transaction.
+    ///
+    /// The verification cost of an execution is the sum of the cost of its transitions.
+    /// The verification cost of a deployment additionally includes, for each function, the cost of
+    /// synthesizing the function circuit and verifying its certificate.
+    pub fn weight(&self) -> Result<TransactionWeight<N>> {
+        // Compute the weight of each transition.
+        let transitions = self.transitions().map(Transition::weight).collect::<Result<Vec<_>>>()?;
+
+        // Compute the number of proofs and the verification cost of the transitions.
+        let mut num_proofs = transitions.len();
+        let mut verification_cost = transitions.iter().map(TransitionWeight::verification_cost).sum::<u64>();
+
+        // Add the number of certificates and the verification cost of the deployment.
+        if let Self::Deploy(_, deployment, _) = self {
+            num_proofs += deployment.verifying_keys().len();
+            for function in deployment.program().functions().values() {
+                verification_cost += PROOF_VERIFICATION_COST
+                    + function.instructions().len() as u64 * INSTRUCTION_SYNTHESIS_COST
+                    + (function.inputs().len() + function.outputs().len()) as u64 * PUBLIC_INPUT_COST;
+            }
+        }
+
+        Ok(TransactionWeight {
+            id: self.id(),
+            size_in_bytes: self.to_bytes_le()?.len(),
+            num_proofs,
+            verification_cost,
+            transitions,
+        })
+    }
+}
+
+impl<N: Network> TransitionWeight<N> {
+    /// Returns the transition ID.
+    pub const fn id(&self) -> &N::TransitionID {
+        &self.id
+    }
+
+    /// Returns the size of the serialized transition, in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of proofs in the transition.
+    pub const fn num_proofs(&self) -> usize {
+        1
+    }
+
+    /// Returns the number of transition inputs and outputs.
+    pub const fn num_public_inputs(&self) -> usize {
+        self.num_public_inputs
+    }
+
+    /// Returns the estimated cost of verifying the transition.
+    pub const fn verification_cost(&self) -> u64 {
+        self.verification_cost
+    }
+}
+
+impl<N: Network> TransactionWeight<N> {
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> &N::TransactionID {
+        &self.id
+    }
+
+    /// Returns the size of the serialized transaction, in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of proofs and certificates in the transaction.
+    pub const fn num_proofs(&self) -> usize {
+        self.num_proofs
+    }
+
+    /// Returns the estimated cost of verifying the transaction.
+    pub const fn verification_cost(&self) -> u64 {
+        self.verification_cost
+    }
+
+    /// Returns the weight of each transition in the transaction.
+    pub fn transitions(&self) -> &[TransitionWeight<N>] {
+        &self.transitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight() -> Result<()> {
+        for transaction in [
+            crate::ledger::vm::test_helpers::sample_deployment_transaction(),
+            crate::ledger::vm::test_helpers::sample_execution_transaction(),
+        ] {
+            let weight = transaction.weight()?;
+            assert_eq!(transaction.id(), *weight.id());
+            assert_eq!(transaction.to_bytes_le()?.len(), weight.size_in_bytes());
+            assert_eq!(transaction.transitions().count(), weight.transitions().len());
+
+            // Ensure the transaction weight covers the weight of its transitions.
+            let transition_cost = weight.transitions().iter().map(TransitionWeight::verification_cost).sum::<u64>();
+            let transition_size = weight.transitions().iter().map(TransitionWeight::size_in_bytes).sum::<usize>();
+            assert!(weight.verification_cost() >= transition_cost);
+            assert!(weight.size_in_bytes() > transition_size);
+
+            match &transaction {
+                Transaction::Deploy(_, deployment, _) => {
+                    assert_eq!(weight.num_proofs(), 1 + deployment.verifying_keys().len());
+                    assert!(weight.verification_cost() > transition_cost);
+                }
+                Transaction::Execute(..) => {
+                    assert_eq!(weight.num_proofs(), weight.transitions().len());
+                    assert_eq!(weight.verification_cost(), transition_cost);
+                }
+            }
+        }
+        Ok(())
+    }
+}
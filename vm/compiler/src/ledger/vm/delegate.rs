@@ -0,0 +1,335 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::types::Field;
+
+use std::collections::HashSet;
+
+/// A request to prove an authorized execution, which is assembled by the caller with its private key,
+/// and proven by a (possibly untrusted) prover.
+///
+/// The request contains the signed requests of the execution, and optionally of the additional fee.
+/// As each transition commitment is signed by the caller, the prover is unable to alter the calls,
+/// and the caller checks the proven transitions against its requests in `VM::attach_proof`.
+///
+/// Note: The prover does not learn the private key of the caller. However, it does learn the inputs,
+/// and the tag secret key `sk_tag` of the caller, which allows it to link the tags of the caller's records.
+#[derive(Clone)]
+pub struct ProvingRequest<N: Network> {
+    /// The authorization of the execution.
+    authorization: Authorization<N>,
+    /// The authorization of the additional fee, if one is paid.
+    additional_fee: Option<Authorization<N>>,
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Initializes a new proving request, from the authorizations of the execution and the additional fee.
+    pub fn new(authorization: Authorization<N>, additional_fee: Option<Authorization<N>>) -> Result<Self> {
+        // Ensure the execution is authorized.
+        ensure!(!authorization.is_empty(), "The proving request does not contain any requests");
+        // Ensure the additional fee is a single call to 'credits.aleo/fee'.
+        if let Some(additional_fee) = &additional_fee {
+            ensure!(additional_fee.len() == 1, "The additional fee must contain exactly 1 request");
+            let request = additional_fee.peek_next()?;
+            ensure!(
+                request.program_id() == &ProgramID::from_str("credits.aleo")?
+                    && request.function_name() == &Identifier::from_str("fee")?,
+                "The additional fee must be a call to 'credits.aleo/fee'"
+            );
+        }
+        Ok(Self { authorization, additional_fee })
+    }
+
+    /// Returns the authorization of the execution.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the authorization of the additional fee, if one is paid.
+    pub const fn additional_fee(&self) -> &Option<Authorization<N>> {
+        &self.additional_fee
+    }
+}
+
+impl<N: Network> FromBytes for ProvingRequest<N> {
+    /// Reads the proving request from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid proving request version"));
+        }
+
+        // Read the number of requests.
+        let num_requests = u16::read_le(&mut reader)?;
+        // Read the requests.
+        let requests = (0..num_requests).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the additional fee request, if it is present.
+        let additional_fee = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Authorization::new(&[FromBytes::read_le(&mut reader)?])),
+            variant => return Err(error(format!("Invalid additional fee variant '{variant}'"))),
+        };
+
+        Self::new(Authorization::new(&requests), additional_fee).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ProvingRequest<N> {
+    /// Writes the proving request to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+
+        // Retrieve the requests.
+        let requests = self.authorization.to_vec_deque();
+        // Write the number of requests.
+        u16::try_from(requests.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the requests.
+        for request in &requests {
+            request.write_le(&mut writer)?;
+        }
+        // Write the additional fee request, if it is present.
+        match &self.additional_fee {
+            None => 0u8.write_le(&mut writer),
+            Some(additional_fee) => {
+                1u8.write_le(&mut writer)?;
+                additional_fee.peek_next().map_err(|e| error(e.to_string()))?.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+/// The proven transitions of a proving request, which are returned by the prover to the caller.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingResponse<N: Network> {
+    /// The execution.
+    execution: Execution<N>,
+    /// The additional fee, if one is paid.
+    additional_fee: Option<AdditionalFee<N>>,
+}
+
+impl<N: Network> ProvingResponse<N> {
+    /// Returns the execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+
+    /// Returns the additional fee, if one is paid.
+    pub const fn additional_fee(&self) -> &Option<AdditionalFee<N>> {
+        &self.additional_fee
+    }
+}
+
+impl<N: Network> FromBytes for ProvingResponse<N> {
+    /// Reads the proving response from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid proving response version"));
+        }
+
+        // Read the execution.
+        let execution = FromBytes::read_le(&mut reader)?;
+        // Read the additional fee, if it is present.
+        let additional_fee = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(FromBytes::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid additional fee variant '{variant}'"))),
+        };
+        Ok(Self { execution, additional_fee })
+    }
+}
+
+impl<N: Network> ToBytes for ProvingResponse<N> {
+    /// Writes the proving response to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+
+        // Write the execution.
+        self.execution.write_le(&mut writer)?;
+        // Write the additional fee, if it is present.
+        match &self.additional_fee {
+            None => 0u8.write_le(&mut writer),
+            Some(additional_fee) => {
+                1u8.write_le(&mut writer)?;
+                additional_fee.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
+    /// Assembles a proving request for a call to the program function for the given inputs,
+    /// and optionally an additional fee, given the credits record and the additional fee amount (in gates).
+    ///
+    /// This step requires the private key of the caller, and is cheap, as no proofs are produced.
+    #[inline]
+    pub fn assemble_proving_request<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        additional_fee: Option<(Record<N, Plaintext<N>>, u64)>,
+        rng: &mut R,
+    ) -> Result<ProvingRequest<N>> {
+        // Authorize the execution.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Authorize the additional fee, if it is present.
+        let additional_fee = match additional_fee {
+            Some((credits, additional_fee_in_gates)) => {
+                let inputs = [Value::Record(credits), Value::from_str(&format!("{additional_fee_in_gates}u64"))?];
+                let program_id = ProgramID::from_str("credits.aleo")?;
+                Some(self.authorize(private_key, &program_id, Identifier::from_str("fee")?, &inputs, rng)?)
+            }
+            None => None,
+        };
+        ProvingRequest::new(authorization, additional_fee)
+    }
+
+    /// Proves the given proving request, returning the proven transitions.
+    ///
+    /// This step does not require the private key of the caller, and may be performed by an untrusted prover.
+    #[inline]
+    pub fn prove<R: Rng + CryptoRng>(&self, request: &ProvingRequest<N>, rng: &mut R) -> Result<ProvingResponse<N>> {
+        // Compute the execution.
+        let (_, execution) = self.execute(request.authorization.replicate(), rng)?;
+        // Compute the additional fee, if it is present.
+        let additional_fee = match &request.additional_fee {
+            Some(authorization) => {
+                let (_, additional_fee) = self.execute(authorization.replicate(), rng)?;
+                // Ensure the additional fee contains 1 transition.
+                ensure!(additional_fee.len() == 1, "The additional fee does not contain 1 transition");
+                Some(additional_fee.peek()?)
+            }
+            None => None,
+        };
+        Ok(ProvingResponse { execution, additional_fee })
+    }
+
+    /// Returns a transaction, given the proving request and the proven transitions from the prover.
+    ///
+    /// This ensures the proven transitions are exactly those the caller signed, and that the transaction is valid.
+    #[inline]
+    pub fn attach_proof(&self, request: &ProvingRequest<N>, response: ProvingResponse<N>) -> Result<Transaction<N>> {
+        let ProvingResponse { execution, additional_fee } = response;
+
+        // Ensure the execution proves each of the signed requests, once.
+        let expected = request.authorization.to_vec_deque().iter().map(|r| *r.tcm()).collect::<HashSet<Field<N>>>();
+        let candidate = execution.iter().map(|transition| *transition.tcm()).collect::<HashSet<Field<N>>>();
+        ensure!(
+            execution.len() == request.authorization.len()
+                && candidate.len() == execution.len()
+                && expected == candidate,
+            "The execution does not match the signed requests"
+        );
+
+        // Ensure the additional fee proves the signed fee request.
+        match (&request.additional_fee, &additional_fee) {
+            (Some(authorization), Some(additional_fee)) => ensure!(
+                authorization.peek_next()?.tcm() == additional_fee.tcm(),
+                "The additional fee does not match the signed fee request"
+            ),
+            (None, None) => (),
+            _ => bail!("The additional fee does not match the proving request"),
+        }
+
+        // Initialize the transaction.
+        let transaction = Transaction::from_execution(execution, additional_fee)?;
+        // Ensure the transaction is valid.
+        ensure!(self.verify(&transaction), "The proven transaction '{}' is invalid", transaction.id());
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ledger::vm::test_helpers::sample_vm, RecordsFilter};
+    use console::{
+        account::{Address, ViewKey},
+        network::Testnet3,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_delegated_proving() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::ledger::test_helpers::sample_genesis_private_key();
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Fetch the unspent records.
+        let ledger = crate::ledger::test_helpers::sample_genesis_ledger();
+        let records = ledger
+            .find_records(&caller_view_key, RecordsFilter::SlowUnspent(caller_private_key))
+            .unwrap()
+            .filter(|(_, record)| !record.gates().is_zero())
+            .collect::<indexmap::IndexMap<_, _>>();
+        let record = records.values().next().unwrap().clone();
+
+        // The caller assembles the proving request.
+        let vm = sample_vm();
+        let request = vm
+            .assemble_proving_request(
+                &caller_private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("transfer").unwrap(),
+                &[
+                    Value::<CurrentNetwork>::Record(record),
+                    Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                    Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+                ],
+                None,
+                rng,
+            )
+            .unwrap();
+
+        // The prover receives the proving request as bytes, and proves it.
+        let request_bytes = request.to_bytes_le().unwrap();
+        let prover_request = ProvingRequest::<CurrentNetwork>::from_bytes_le(&request_bytes).unwrap();
+        assert_eq!(request_bytes, prover_request.to_bytes_le().unwrap());
+        let response = sample_vm().prove(&prover_request, rng).unwrap();
+
+        // The caller receives the proven transitions as bytes, and attaches them.
+        let response_bytes = response.to_bytes_le().unwrap();
+        let response = ProvingResponse::<CurrentNetwork>::from_bytes_le(&response_bytes).unwrap();
+        let transaction = vm.attach_proof(&request, response.clone()).unwrap();
+        assert!(vm.verify(&transaction));
+
+        // Ensure the proven transitions are rejected for a different proving request.
+        let other_request = sample_vm()
+            .assemble_proving_request(
+                &caller_private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("transfer").unwrap(),
+                &request.authorization().peek_next().unwrap().inputs().to_vec(),
+                None,
+                rng,
+            )
+            .unwrap();
+        assert!(vm.attach_proof(&other_request, response).is_err());
+    }
+}
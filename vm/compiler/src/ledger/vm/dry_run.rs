@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
+    /// Evaluates every transition in the given authorization, without proving,
+    /// and returns the outputs and finalize inputs of each transition, with the estimated shape of the execution.
+    #[inline]
+    pub fn dry_run(&self, authorization: Authorization<N>) -> Result<DryRun<N>> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+
+                // Evaluate the transitions.
+                let dry_run = $process.dry_run::<$aleo>(authorization.clone())?;
+
+                // Return the dry run.
+                Ok(cast_ref!(dry_run as DryRun<N>).clone())
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::vm::test_helpers::sample_vm;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_dry_run() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+
+        // Authorize a call to mint.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let authorization = vm
+            .authorize(
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("mint").unwrap(),
+                &[Value::from_str(&address.to_string()).unwrap(), Value::from_str("100u64").unwrap()],
+                rng,
+            )
+            .unwrap();
+
+        // Dry run the call.
+        let dry_run = vm.dry_run(authorization.replicate()).unwrap();
+        assert_eq!(1, dry_run.transitions().len());
+        assert_eq!(None, dry_run.transitions()[0].finalize());
+        assert!(dry_run.num_constraints().unwrap() > 0);
+
+        // Ensure the minted record matches the record from the execution.
+        let (response, _) = vm.execute(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), dry_run.outputs());
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
+    /// Returns the estimated shape of each transition in a call to the given program function.
+    #[inline]
+    pub fn estimate_transitions(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<TransitionEstimate<N>>> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the program ID and function name.
+                let program_id = cast_ref!(&program_id as ProgramID<$network>);
+                let function_name = cast_ref!(&function_name as Identifier<$network>);
+
+                // Estimate the transitions.
+                let estimates = $process.get_stack(program_id)?.estimate_transitions(function_name)?;
+
+                // Return the estimates.
+                Ok(cast_ref!(estimates as Vec<TransitionEstimate<N>>).clone())
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+}
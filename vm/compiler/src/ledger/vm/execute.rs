@@ -44,6 +44,37 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
         process!(self, logic)
     }
 
+    /// Executes the given independent authorizations, returning the response and execution of each, in order.
+    #[inline]
+    pub fn execute_batch<R: Rng + CryptoRng>(
+        &self,
+        authorizations: Vec<Authorization<N>>,
+        rng: &mut R,
+    ) -> Result<Vec<(Response<N>, Execution<N>)>> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorizations.
+                let authorizations = cast_ref!(authorizations as Vec<Authorization<$network>>);
+
+                // Execute the calls.
+                let results = $process.execute_batch::<$aleo, _>(authorizations.clone(), rng)?;
+
+                // Prepare the return.
+                results
+                    .into_iter()
+                    .map(|(response, execution)| {
+                        let response = cast_ref!(response as Response<N>).clone();
+                        let execution = cast_ref!(execution as Execution<N>).clone();
+                        Ok((response, execution))
+                    })
+                    .collect()
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+
     /// Returns an additional fee for the given private key, credits record, and additional fee amount (in gates).
     #[inline]
     pub fn execute_additional_fee<R: Rng + CryptoRng>(
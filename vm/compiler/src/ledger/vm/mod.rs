@@ -18,10 +18,26 @@ mod helpers;
 
 mod authorize;
 mod deploy;
+mod dry_run;
+mod estimate;
 mod execute;
 mod finalize;
 mod verify;
 
+mod delegate;
+pub use delegate::*;
+
+mod options;
+pub use options::*;
+
+mod partial;
+pub use partial::*;
+
+#[cfg(feature = "async")]
+mod pool;
+#[cfg(feature = "async")]
+pub use pool::*;
+
 use crate::{
     cast_ref,
     ledger::{
@@ -30,7 +46,7 @@ use crate::{
         Transaction,
     },
     process,
-    process::{Authorization, Deployment, Execution, Process},
+    process::{Authorization, Deployment, DryRun, Execution, Process, TransitionEstimate},
     program::Program,
 };
 use console::{
@@ -117,6 +133,20 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
             }
         }
     }
+
+    /// Removes the proving keys from the process, as they are only needed to produce proofs.
+    #[inline]
+    pub fn remove_proving_keys(&self) -> Result<()> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                $process.remove_proving_keys();
+                Ok(())
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
 }
 
 #[cfg(test)]
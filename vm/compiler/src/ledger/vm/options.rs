@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+/// The maximum size of a block read in bounded-memory mode, in bytes (32 MiB).
+const BOUNDED_MAX_BLOCK_SIZE: u64 = 32 * 1024 * 1024;
+
+/// The options for verifying transactions and blocks.
+///
+/// The defaults favor throughput: every transaction in a block is verified concurrently,
+/// and the circuit proving keys are retained so the node can also produce transactions and blocks.
+///
+/// `VerificationOptions::bounded` caps the peak memory of a node that only follows the chain,
+/// such as a validator on a single-board computer:
+///  - blocks are deserialized directly from the reader, and rejected once they exceed 32 MiB,
+///  - transactions are verified one proof at a time, instead of all at once,
+///  - the proving keys are dropped, as only the verifying keys are needed to verify.
+///
+/// In this mode, block verification time grows linearly with the number of transactions,
+/// instead of being divided across the available cores. Also, as the proving keys are dropped,
+/// producing a transaction or block requires re-synthesizing the proving key of each function,
+/// which is slow and memory intensive; a node in this mode should not propose blocks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationOptions {
+    /// The maximum number of transactions verified concurrently, or `None` for no limit.
+    max_concurrent_transactions: Option<usize>,
+    /// The maximum size of a block read from a reader, in bytes, or `None` for no limit.
+    max_block_size: Option<u64>,
+    /// If `true`, the proving keys are retained in the process.
+    retain_proving_keys: bool,
+}
+
+impl Default for VerificationOptions {
+    /// Returns the default verification options.
+    fn default() -> Self {
+        Self { max_concurrent_transactions: None, max_block_size: None, retain_proving_keys: true }
+    }
+}
+
+impl VerificationOptions {
+    /// Returns the verification options for a resource-constrained node, which cap the peak memory.
+    pub fn bounded() -> Self {
+        Self::default()
+            .max_concurrent_transactions(Some(1))
+            .max_block_size(Some(BOUNDED_MAX_BLOCK_SIZE))
+            .retain_proving_keys(false)
+    }
+
+    /// Sets the maximum number of transactions verified concurrently.
+    pub const fn max_concurrent_transactions(mut self, max_concurrent_transactions: Option<usize>) -> Self {
+        self.max_concurrent_transactions = max_concurrent_transactions;
+        self
+    }
+
+    /// Sets the maximum size of a block read from a reader, in bytes.
+    pub const fn max_block_size(mut self, max_block_size: Option<u64>) -> Self {
+        self.max_block_size = max_block_size;
+        self
+    }
+
+    /// Sets whether the proving keys are retained in the process.
+    pub const fn retain_proving_keys(mut self, retain_proving_keys: bool) -> Self {
+        self.retain_proving_keys = retain_proving_keys;
+        self
+    }
+
+    /// Ensures the verification options are well-formed, and returns them.
+    pub fn build(self) -> Result<Self> {
+        // Ensure at least one transaction is verified at a time.
+        if let Some(max_concurrent_transactions) = self.max_concurrent_transactions {
+            ensure!(max_concurrent_transactions > 0, "The maximum number of concurrent transactions must be nonzero");
+        }
+        // Ensure the maximum block size is nonzero.
+        if let Some(max_block_size) = self.max_block_size {
+            ensure!(max_block_size > 0, "The maximum block size must be nonzero");
+        }
+        Ok(self)
+    }
+}
+
+impl VerificationOptions {
+    /// Returns the maximum number of transactions verified concurrently, or `None` for no limit.
+    pub const fn get_max_concurrent_transactions(&self) -> Option<usize> {
+        self.max_concurrent_transactions
+    }
+
+    /// Returns the maximum size of a block read from a reader, in bytes, or `None` for no limit.
+    pub const fn get_max_block_size(&self) -> Option<u64> {
+        self.max_block_size
+    }
+
+    /// Returns `true` if the proving keys are retained in the process.
+    pub const fn get_retain_proving_keys(&self) -> bool {
+        self.retain_proving_keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        let options = VerificationOptions::default().build().unwrap();
+        assert_eq!(None, options.get_max_concurrent_transactions());
+        assert_eq!(None, options.get_max_block_size());
+        assert!(options.get_retain_proving_keys());
+
+        let options = VerificationOptions::bounded().build().unwrap();
+        assert_eq!(Some(1), options.get_max_concurrent_transactions());
+        assert_eq!(Some(BOUNDED_MAX_BLOCK_SIZE), options.get_max_block_size());
+        assert!(!options.get_retain_proving_keys());
+    }
+
+    #[test]
+    fn test_build_fails() {
+        assert!(VerificationOptions::default().max_concurrent_transactions(Some(0)).build().is_err());
+        assert!(VerificationOptions::default().max_block_size(Some(0)).build().is_err());
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::account::Address;
+
+/// A call in the kernel of a partial authorization, which must be authorized by its signer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KernelCall<N: Network> {
+    /// The address of the signer.
+    signer: Address<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+}
+
+impl<N: Network> KernelCall<N> {
+    /// Initializes a new kernel call, for the given signer and program function.
+    pub const fn new(signer: Address<N>, program_id: ProgramID<N>, function_name: Identifier<N>) -> Self {
+        Self { signer, program_id, function_name }
+    }
+
+    /// Returns the address of the signer.
+    pub const fn signer(&self) -> &Address<N> {
+        &self.signer
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+}
+
+/// An in-progress authorization of a transaction, which is passed between the signers of its calls,
+/// such as a user who signs an execution, and an exchange that pays its additional fee.
+///
+/// The kernel lists the calls of the transaction, where the first is the execution, and the second (if any)
+/// is the additional fee. Each signer authorizes its call with `PartialAuthorization::sign`, and the partial
+/// authorizations of the signers are combined with `PartialAuthorization::merge`. Once every call is signed,
+/// `PartialAuthorization::finalize` returns the proving request for the transaction.
+#[derive(Clone)]
+pub struct PartialAuthorization<N: Network> {
+    /// The calls of the transaction.
+    kernel: Vec<KernelCall<N>>,
+    /// The authorization of each call, if it is signed.
+    authorizations: Vec<Option<Authorization<N>>>,
+}
+
+impl<N: Network> PartialAuthorization<N> {
+    /// Initializes a new partial authorization, for the given execution,
+    /// and the signer who pays the additional fee (if any).
+    pub fn new(execution: KernelCall<N>, additional_fee_signer: Option<Address<N>>) -> Result<Self> {
+        let mut kernel = vec![execution];
+        if let Some(signer) = additional_fee_signer {
+            kernel.push(KernelCall::new(signer, ProgramID::from_str("credits.aleo")?, Identifier::from_str("fee")?));
+        }
+        Ok(Self { authorizations: vec![None; kernel.len()], kernel })
+    }
+
+    /// Returns the calls of the transaction.
+    pub fn kernel(&self) -> &[KernelCall<N>] {
+        &self.kernel
+    }
+
+    /// Returns the signers who have not yet authorized their calls.
+    pub fn missing_signers(&self) -> Vec<Address<N>> {
+        self.kernel
+            .iter()
+            .zip_eq(&self.authorizations)
+            .filter(|(_, authorization)| authorization.is_none())
+            .map(|(call, _)| call.signer)
+            .collect()
+    }
+
+    /// Returns `true` if every call is authorized.
+    pub fn is_complete(&self) -> bool {
+        self.authorizations.iter().all(Option::is_some)
+    }
+
+    /// Authorizes each unsigned call of the given signer, with the given inputs.
+    /// For the additional fee, the inputs are the credits record and the additional fee amount (in gates).
+    pub fn sign<P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<()> {
+        let signer = Address::try_from(private_key)?;
+
+        // Find the unsigned calls of the signer.
+        let indices = (0..self.kernel.len())
+            .filter(|index| self.kernel[*index].signer == signer && self.authorizations[*index].is_none())
+            .collect::<Vec<_>>();
+        ensure!(!indices.is_empty(), "The partial authorization has no unsigned calls for '{signer}'");
+
+        for index in indices {
+            let call = self.kernel[index];
+            let authorization = vm.authorize(private_key, &call.program_id, call.function_name, inputs, rng)?;
+            self.authorizations[index] = Some(authorization);
+        }
+        Ok(())
+    }
+
+    /// Adds the signatures of the given partial authorization, which must have the same kernel.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        ensure!(self.kernel == other.kernel, "Cannot merge partial authorizations with different kernels");
+
+        for (index, candidate) in other.authorizations.iter().enumerate() {
+            match (&self.authorizations[index], candidate) {
+                // Ensure the call is not signed twice, with different requests.
+                (Some(existing), Some(candidate)) => ensure!(
+                    existing.to_vec_deque() == candidate.to_vec_deque(),
+                    "Cannot merge conflicting authorizations for call {index}"
+                ),
+                (None, Some(candidate)) => {
+                    Self::check_authorization(&self.kernel[index], candidate)?;
+                    self.authorizations[index] = Some(candidate.replicate());
+                }
+                (_, None) => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the proving request for the transaction, once every call is authorized.
+    pub fn finalize(self) -> Result<ProvingRequest<N>> {
+        // Ensure every call is authorized.
+        let missing_signers = self.missing_signers();
+        ensure!(missing_signers.is_empty(), "The partial authorization is missing signers: {missing_signers:?}");
+
+        // Ensure each authorization is for its call.
+        for (call, authorization) in self.kernel.iter().zip_eq(&self.authorizations) {
+            if let Some(authorization) = authorization {
+                Self::check_authorization(call, authorization)?;
+            }
+        }
+
+        let mut authorizations = self.authorizations.into_iter().flatten();
+        match (authorizations.next(), authorizations.next()) {
+            (Some(execution), additional_fee) => ProvingRequest::new(execution, additional_fee),
+            (None, _) => bail!("The partial authorization does not have an execution"),
+        }
+    }
+
+    /// Ensures the given authorization is for the given call, and is signed by its signer.
+    fn check_authorization(call: &KernelCall<N>, authorization: &Authorization<N>) -> Result<()> {
+        let request = authorization.peek_next()?;
+        ensure!(
+            request.program_id() == &call.program_id && request.function_name() == &call.function_name,
+            "The authorization is not for '{}/{}'",
+            call.program_id,
+            call.function_name
+        );
+        ensure!(
+            authorization.to_vec_deque().iter().all(|request| request.caller() == &call.signer),
+            "The authorization is not signed by '{}'",
+            call.signer
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for PartialAuthorization<N> {
+    /// Reads the partial authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid partial authorization version"));
+        }
+
+        // Read the number of calls.
+        let num_calls = u8::read_le(&mut reader)?;
+        if !(1..=2).contains(&num_calls) {
+            return Err(error(format!("Invalid number of calls ({num_calls}) in the partial authorization")));
+        }
+
+        let mut kernel = Vec::with_capacity(num_calls as usize);
+        let mut authorizations = Vec::with_capacity(num_calls as usize);
+        for _ in 0..num_calls {
+            // Read the call.
+            let signer = FromBytes::read_le(&mut reader)?;
+            let program_id = FromBytes::read_le(&mut reader)?;
+            let function_name = FromBytes::read_le(&mut reader)?;
+            let call = KernelCall::new(signer, program_id, function_name);
+
+            // Read the authorization, if it is present.
+            let authorization = match u8::read_le(&mut reader)? {
+                0 => None,
+                1 => {
+                    let num_requests = u16::read_le(&mut reader)?;
+                    let requests =
+                        (0..num_requests).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                    let authorization = Authorization::new(&requests);
+                    Self::check_authorization(&call, &authorization).map_err(|e| error(e.to_string()))?;
+                    Some(authorization)
+                }
+                variant => return Err(error(format!("Invalid authorization variant '{variant}'"))),
+            };
+
+            kernel.push(call);
+            authorizations.push(authorization);
+        }
+        Ok(Self { kernel, authorizations })
+    }
+}
+
+impl<N: Network> ToBytes for PartialAuthorization<N> {
+    /// Writes the partial authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+
+        // Write the number of calls.
+        (self.kernel.len() as u8).write_le(&mut writer)?;
+        for (call, authorization) in self.kernel.iter().zip_eq(&self.authorizations) {
+            // Write the call.
+            call.signer.write_le(&mut writer)?;
+            call.program_id.write_le(&mut writer)?;
+            call.function_name.write_le(&mut writer)?;
+
+            // Write the authorization, if it is present.
+            match authorization {
+                None => 0u8.write_le(&mut writer)?,
+                Some(authorization) => {
+                    1u8.write_le(&mut writer)?;
+                    let requests = authorization.to_vec_deque();
+                    u16::try_from(requests.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                    for request in &requests {
+                        request.write_le(&mut writer)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ledger::vm::test_helpers::sample_vm, RecordsFilter};
+    use console::{account::ViewKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_partial_authorization() {
+        let rng = &mut test_crypto_rng();
+        let vm = sample_vm();
+
+        // Initialize the user, who owns the genesis record, and the fee payer.
+        let user_private_key = crate::ledger::test_helpers::sample_genesis_private_key();
+        let user = Address::try_from(&user_private_key).unwrap();
+        let payer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let payer = Address::try_from(&payer_private_key).unwrap();
+
+        // Fetch the record of the user.
+        let ledger = crate::ledger::test_helpers::sample_genesis_ledger();
+        let record = ledger
+            .find_records(&ViewKey::try_from(&user_private_key).unwrap(), RecordsFilter::SlowUnspent(user_private_key))
+            .unwrap()
+            .map(|(_, record)| record)
+            .find(|record| !record.gates().is_zero())
+            .unwrap();
+
+        // Initialize the partial authorization of a transfer, with a fee paid by the payer.
+        let execution = KernelCall::new(
+            user,
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("transfer").unwrap(),
+        );
+        let partial = PartialAuthorization::new(execution, Some(payer)).unwrap();
+        assert_eq!(vec![user, payer], partial.missing_signers());
+
+        // The user signs the execution, and passes the partial authorization as bytes.
+        let mut user_partial = partial.clone();
+        let inputs =
+            [Value::Record(record), Value::from_str(&payer.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        user_partial.sign(&vm, &user_private_key, &inputs, rng).unwrap();
+        assert_eq!(vec![payer], user_partial.missing_signers());
+        let bytes = user_partial.to_bytes_le().unwrap();
+        let user_partial = PartialAuthorization::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(bytes, user_partial.to_bytes_le().unwrap());
+
+        // Ensure the partial authorization can not be finalized with a missing signer.
+        assert!(user_partial.clone().finalize().is_err());
+        // Ensure the payer only signs the additional fee, so the transfer inputs are rejected.
+        let mut candidate = partial.clone();
+        assert!(candidate.sign(&vm, &payer_private_key, &inputs, rng).is_err());
+        assert_eq!(vec![user, payer], candidate.missing_signers());
+
+        // The payer merges the signature of the user, and signs the additional fee with its own record.
+        let mut payer_partial = partial;
+        payer_partial.merge(&user_partial).unwrap();
+        assert!(payer_partial.merge(&user_partial).is_ok());
+        assert_eq!(vec![payer], payer_partial.missing_signers());
+        let nonce = CurrentNetwork::g_scalar_multiply(&Uniform::rand(rng));
+        let payer_record =
+            Value::from_str(&format!("{{ owner: {payer}.private, gates: 100u64.private, _nonce: {nonce}.public }}"))
+                .unwrap();
+        payer_partial.sign(&vm, &payer_private_key, &[payer_record, Value::from_str("1u64").unwrap()], rng).unwrap();
+        assert!(payer_partial.is_complete());
+
+        // Ensure the finalized proving request contains the signature of each signer.
+        let request = payer_partial.finalize().unwrap();
+        assert_eq!(user, *request.authorization().peek_next().unwrap().caller());
+        assert_eq!(payer, *request.additional_fee().as_ref().unwrap().peek_next().unwrap().caller());
+    }
+}
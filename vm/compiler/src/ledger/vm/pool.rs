@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use futures::channel::oneshot;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll},
+};
+
+/// The phase of an execution on a `ProvingPool`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExecutionPhase {
+    /// The execution is waiting for a thread in the pool.
+    Queued,
+    /// The transitions of the authorization are being proven.
+    Executing,
+    /// The additional fee is being proven.
+    ExecutingAdditionalFee,
+    /// The transaction is being verified.
+    Verifying,
+    /// The transaction is ready.
+    Completed,
+    /// The execution was cancelled, or failed.
+    Aborted,
+}
+
+/// A token to cooperatively cancel an execution on a `ProvingPool`.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Requests the cancellation of the execution.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the cancellation of the execution was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// A handle to an execution on a `ProvingPool`, which resolves to the executed transaction.
+pub struct ExecutionHandle<N: Network> {
+    /// The current phase of the execution.
+    phase: Arc<RwLock<ExecutionPhase>>,
+    /// The cancellation token of the execution.
+    token: CancellationToken,
+    /// The receiver of the executed transaction.
+    receiver: oneshot::Receiver<Result<Transaction<N>>>,
+}
+
+impl<N: Network> ExecutionHandle<N> {
+    /// Returns the current phase of the execution.
+    pub fn phase(&self) -> ExecutionPhase {
+        *self.phase.read()
+    }
+
+    /// Requests the cancellation of the execution.
+    pub fn cancel(&self) {
+        self.token.cancel()
+    }
+
+    /// Returns the cancellation token of the execution.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+}
+
+impl<N: Network> Future for ExecutionHandle<N> {
+    type Output = Result<Transaction<N>>;
+
+    /// Polls for the executed transaction.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or_else(|_| Err(anyhow!("The execution was dropped by the proving pool"))))
+    }
+}
+
+/// A dedicated pool of threads, which executes transactions without blocking the caller.
+///
+/// Each execution is checked for cancellation before each phase, so a cancelled execution stops
+/// once its current phase completes, as a proof in progress can not be interrupted.
+/// Dropping the `ExecutionHandle` also cancels the execution.
+#[derive(Clone)]
+pub struct ProvingPool {
+    /// The thread pool.
+    pool: Arc<rayon::ThreadPool>,
+}
+
+impl ProvingPool {
+    /// Initializes a new proving pool, with the given number of threads.
+    pub fn new(num_threads: usize) -> Result<Self> {
+        let pool =
+            rayon::ThreadPoolBuilder::new().num_threads(num_threads).thread_name(|i| format!("proving-{i}")).build()?;
+        Ok(Self { pool: Arc::new(pool) })
+    }
+
+    /// Executes the given authorization and additional fee on the pool, and returns a handle to the execution.
+    pub fn execute<N: Network, P: 'static + ProgramStorage<N> + Send, R: Rng + CryptoRng>(
+        &self,
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        authorization: Authorization<N>,
+        additional_fee: Option<(Record<N, Plaintext<N>>, u64)>,
+        rng: &mut R,
+    ) -> ExecutionHandle<N> {
+        // Seed an RNG for the execution.
+        let seed = rng.gen::<[u8; 32]>();
+
+        let phase = Arc::new(RwLock::new(ExecutionPhase::Queued));
+        let token = CancellationToken::default();
+        let (sender, receiver) = oneshot::channel();
+
+        let (vm, private_key, task_phase, task_token) = (vm.clone(), *private_key, phase.clone(), token.clone());
+        self.pool.spawn(move || {
+            // Checks the execution is not cancelled, and enters the given phase.
+            let enter = |next: ExecutionPhase| {
+                ensure!(!task_token.is_cancelled() && !sender.is_canceled(), "The execution was cancelled");
+                *task_phase.write() = next;
+                Ok(())
+            };
+            let result =
+                Self::run(&vm, &private_key, authorization, additional_fee, enter, &mut StdRng::from_seed(seed));
+
+            // Update the phase, and send the result.
+            *task_phase.write() = match result.is_ok() {
+                true => ExecutionPhase::Completed,
+                false => ExecutionPhase::Aborted,
+            };
+            // Note: The handle may be dropped, in which case the result is discarded.
+            let _ = sender.send(result);
+        });
+
+        ExecutionHandle { phase, token, receiver }
+    }
+
+    /// Executes the given authorization and additional fee, entering each phase with the given closure.
+    fn run<N: Network, P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        authorization: Authorization<N>,
+        additional_fee: Option<(Record<N, Plaintext<N>>, u64)>,
+        enter: impl Fn(ExecutionPhase) -> Result<()>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the execution.
+        enter(ExecutionPhase::Executing)?;
+        let (_, execution) = vm.execute(authorization, rng)?;
+        // Compute the additional fee, if it is present.
+        let additional_fee = match additional_fee {
+            Some((credits, additional_fee_in_gates)) => {
+                enter(ExecutionPhase::ExecutingAdditionalFee)?;
+                Some(vm.execute_additional_fee(private_key, credits, additional_fee_in_gates, rng)?.1)
+            }
+            None => None,
+        };
+
+        // Initialize and verify the transaction.
+        enter(ExecutionPhase::Verifying)?;
+        let transaction = Transaction::from_execution(execution, additional_fee)?;
+        ensure!(vm.verify(&transaction), "The executed transaction '{}' is invalid", transaction.id());
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ledger::vm::test_helpers::sample_vm, RecordsFilter};
+    use console::{
+        account::{Address, ViewKey},
+        network::Testnet3,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns an authorization of a transfer from the genesis account to itself.
+    fn sample_authorization(
+        vm: &VM<CurrentNetwork, crate::ProgramMemory<CurrentNetwork>>,
+    ) -> Authorization<CurrentNetwork> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = crate::ledger::test_helpers::sample_genesis_private_key();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        let ledger = crate::ledger::test_helpers::sample_genesis_ledger();
+        let record = ledger
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .unwrap()
+            .map(|(_, record)| record)
+            .find(|record| !record.gates().is_zero())
+            .unwrap();
+
+        let inputs =
+            [Value::Record(record), Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        vm.authorize(&private_key, &program_id, function_name, &inputs, rng).unwrap()
+    }
+
+    #[test]
+    fn test_proving_pool() {
+        let rng = &mut test_crypto_rng();
+        let pool = ProvingPool::new(1).unwrap();
+        let vm = sample_vm();
+
+        // Execute the authorization on the pool.
+        let private_key = crate::ledger::test_helpers::sample_genesis_private_key();
+        let handle = pool.execute(&vm, &private_key, sample_authorization(&vm), None, rng);
+        let phase = handle.phase.clone();
+        let transaction = futures::executor::block_on(handle).unwrap();
+        assert_eq!(ExecutionPhase::Completed, *phase.read());
+        assert!(vm.verify(&transaction));
+    }
+
+    #[test]
+    fn test_proving_pool_cancel() {
+        let rng = &mut test_crypto_rng();
+        let pool = ProvingPool::new(1).unwrap();
+        let vm = sample_vm();
+
+        // Cancel the execution, which stops before its next phase.
+        let private_key = crate::ledger::test_helpers::sample_genesis_private_key();
+        let handle = pool.execute(&vm, &private_key, sample_authorization(&vm), None, rng);
+        handle.cancel();
+        let phase = handle.phase.clone();
+        assert!(futures::executor::block_on(handle).is_err());
+        assert_eq!(ExecutionPhase::Aborted, *phase.read());
+    }
+}
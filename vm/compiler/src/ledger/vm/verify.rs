@@ -213,4 +213,39 @@ mod tests {
         // Ensure the deployment is valid.
         assert!(vm.verify_deployment(&deployment));
     }
+
+    #[test]
+    fn test_verify_bundle() {
+        use crate::{ledger::Transaction, process::Execution};
+        use console::{
+            account::{Address, PrivateKey},
+            network::{prelude::*, Testnet3},
+            program::{Identifier, ProgramID, Value},
+        };
+
+        let rng = &mut test_crypto_rng();
+        let vm = crate::ledger::vm::test_helpers::sample_vm();
+
+        // Authorize two independent calls to mint.
+        let private_key = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let authorizations = ["1u64", "2u64"]
+            .iter()
+            .map(|amount| {
+                let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str(amount).unwrap()];
+                let program_id = ProgramID::from_str("credits.aleo").unwrap();
+                vm.authorize(&private_key, &program_id, Identifier::from_str("mint").unwrap(), &inputs, rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the bundle verifies.
+        let transaction = Transaction::execute_bundle(&vm, &private_key, authorizations, None, rng).unwrap();
+        assert_eq!(2, transaction.transitions().count());
+        assert!(vm.verify(&transaction));
+
+        // Ensure a bundle that repeats a transition is rejected.
+        let transition = transaction.transitions().next().unwrap().clone();
+        let execution = Execution::from(Testnet3::EDITION, &[transition.clone(), transition]).unwrap();
+        assert!(!vm.verify(&Transaction::from_execution(execution, None).unwrap()));
+    }
 }
@@ -24,6 +24,8 @@ impl<N: Network> Process<N> {
         program: &Program<N>,
         rng: &mut R,
     ) -> Result<Deployment<N>> {
+        // Ensure the program can be deployed in the registry, if set.
+        self.check_registry(program)?;
         // Compute the stack.
         let stack = Stack::new(self, program)?;
         // Return the deployment.
@@ -41,6 +43,8 @@ impl<N: Network> Process<N> {
         let program_id = deployment.program().id();
         // Ensure the program does not already exist in the process.
         ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program can be deployed in the registry, if set.
+        self.check_registry(deployment.program())?;
 
         // Ensure the program is well-formed, by computing the stack.
         let stack = Stack::new(self, deployment.program())?;
@@ -61,6 +65,8 @@ impl<N: Network> Process<N> {
 
         // Compute the program stack.
         let stack = Stack::new(self, deployment.program())?;
+        // Ensure the program can be deployed in the registry, if set.
+        let digest = self.check_registry(deployment.program())?;
         // Insert the verifying keys.
         for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
             stack.insert_verifying_key(function_name, verifying_key.clone())?;
@@ -73,8 +79,12 @@ impl<N: Network> Process<N> {
             store.initialize_mapping(program_id, mapping.name())?;
         }
 
+        // Deploy the program in the registry, if set.
+        if let Some(registry) = &mut self.registry {
+            registry.deploy_with_digest(deployment.program(), digest)?;
+        }
         // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        self.insert_stack(stack, digest);
         Ok(())
     }
 
@@ -84,12 +94,31 @@ impl<N: Network> Process<N> {
     pub(crate) fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         // Compute the program stack.
         let stack = Stack::new(self, deployment.program())?;
+        // Ensure the program can be deployed in the registry, if set.
+        let digest = self.check_registry(deployment.program())?;
         // Insert the verifying keys.
         for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
             stack.insert_verifying_key(function_name, verifying_key.clone())?;
         }
+        // Deploy the program in the registry, if set.
+        if let Some(registry) = &mut self.registry {
+            registry.deploy_with_digest(deployment.program(), digest)?;
+        }
         // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        self.insert_stack(stack, digest);
         Ok(())
     }
+
+    /// Ensures the given program can be deployed in the registry of the process, if set,
+    /// and returns the program digest.
+    #[inline]
+    fn check_registry(&self, program: &Program<N>) -> Result<Field<N>> {
+        // Compute the program digest.
+        let digest = ProgramRegistry::digest(program)?;
+        // Ensure the program ID is not deployed with a different program, and its imports are deployed.
+        if let Some(registry) = &self.registry {
+            registry.check_deployment(program, &digest)?;
+        }
+        Ok(digest)
+    }
 }
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+/// A deterministic RNG for proving, so an authorization or execution is byte-identical for the same seed and inputs,
+/// e.g. to compare proofs in continuous integration, in differential tests against other implementations,
+/// or when reproducing a bug.
+///
+/// **Warning:** This RNG is NOT safe for production. Anyone who knows the seed can recompute the randomness
+/// of the signatures, commitments, and proofs, and recover the private key and the private inputs from them.
+/// Only use it with burner accounts and test inputs.
+#[derive(Clone, Debug)]
+pub struct UnsafeDeterministicRng(StdRng);
+
+impl UnsafeDeterministicRng {
+    /// Initializes a new deterministic RNG from the given seed.
+    ///
+    /// **Warning:** This RNG is NOT safe for production.
+    pub fn from_seed(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl RngCore for UnsafeDeterministicRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+// Note: This allows the RNG to be passed to the prover, which requires a `CryptoRng`, as it is intended for testing.
+impl CryptoRng for UnsafeDeterministicRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::test_helpers::sample_process, Process, Program};
+    use circuit::network::AleoV0;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{Identifier, Value},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_deterministic_execution() -> Result<()> {
+        // Initialize a program with a function, and a program that calls it.
+        let program0 = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 0u64 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )?;
+        let program1 = Program::<CurrentNetwork>::from_str(
+            r"
+import token.aleo;
+
+program minter.aleo;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    call token.aleo/mint r0 r1 into r2;
+    output r2 as token.aleo/token.record;",
+        )?;
+        let mut process = sample_process(&program0);
+        process.add_program(&program1)?;
+
+        // Authorizes and executes the call to `minter.aleo/mint`, with a deterministic RNG from the given seed.
+        let execute = |process: &Process<CurrentNetwork>, seed: u64| -> Result<_> {
+            let rng = &mut UnsafeDeterministicRng::from_seed(seed);
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&Address::try_from(&private_key)?.to_string())?,
+                Value::<CurrentNetwork>::from_str("100u64")?,
+            ];
+            let function_name = Identifier::from_str("mint")?;
+            let authorization =
+                process.authorize::<CurrentAleo, _>(&private_key, program1.id(), function_name, &inputs, rng)?;
+            let (_response, execution) = process.execute::<CurrentAleo, _>(authorization, rng)?;
+            Ok(execution.to_bytes_le()?)
+        };
+
+        // Ensure the execution, including the nested call, is byte-identical for the same seed.
+        let expected = execute(&process, 1)?;
+        assert_eq!(expected, execute(&process, 1)?);
+        assert_ne!(expected, execute(&process, 2)?);
+        Ok(())
+    }
+}
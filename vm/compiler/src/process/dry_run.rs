@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A transition, which was evaluated without proving.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DryRunTransition<N: Network> {
+    /// The request of the transition.
+    request: Request<N>,
+    /// The response of the transition.
+    response: Response<N>,
+    /// The inputs to the 'finalize' scope of the function, if it has one.
+    finalize: Option<Vec<Value<N>>>,
+}
+
+impl<N: Network> DryRunTransition<N> {
+    /// Returns the request of the transition.
+    pub const fn request(&self) -> &Request<N> {
+        &self.request
+    }
+
+    /// Returns the response of the transition.
+    pub const fn response(&self) -> &Response<N> {
+        &self.response
+    }
+
+    /// Returns the outputs of the transition.
+    pub fn outputs(&self) -> &[Value<N>] {
+        self.response.outputs()
+    }
+
+    /// Returns the inputs to the 'finalize' scope of the function, if it has one.
+    pub fn finalize(&self) -> Option<&[Value<N>]> {
+        self.finalize.as_deref()
+    }
+}
+
+/// The result of a dry run, which evaluates every transition of an execution without proving.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DryRun<N: Network> {
+    /// The evaluated transitions, in the order of the requests in the authorization.
+    transitions: Vec<DryRunTransition<N>>,
+    /// The estimated shape of each transition, or `None` if a verifying key has not been synthesized.
+    estimates: Option<Vec<TransitionEstimate<N>>>,
+}
+
+impl<N: Network> DryRun<N> {
+    /// Returns the evaluated transitions, in the order of the requests in the authorization.
+    pub fn transitions(&self) -> &[DryRunTransition<N>] {
+        &self.transitions
+    }
+
+    /// Returns the outputs of the main transition.
+    pub fn outputs(&self) -> &[Value<N>] {
+        // Note: A dry run contains at least one transition, as the authorization is not empty.
+        self.transitions.first().map_or(&[], |transition| transition.outputs())
+    }
+
+    /// Returns the estimated shape of each transition, or `None` if a verifying key has not been synthesized.
+    pub fn estimates(&self) -> Option<&[TransitionEstimate<N>]> {
+        self.estimates.as_deref()
+    }
+
+    /// Returns the number of constraints the execution would prove,
+    /// or `None` if a verifying key has not been synthesized.
+    pub fn num_constraints(&self) -> Option<usize> {
+        self.estimates.as_ref().map(|estimates| estimates.iter().map(|estimate| estimate.num_constraints()).sum())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Evaluates every transition in the given authorization, without synthesizing or proving the circuits,
+    /// and returns the outputs and finalize inputs of each transition, with the estimated shape of the execution.
+    ///
+    /// This runs the same checks on the requests and instructions as `Process::execute`, in a fraction of the time,
+    /// so a developer can test a program, and a wallet can preview the result of a call before proving it.
+    /// Note: The 'finalize' scope is not applied, as it would mutate the program store.
+    #[inline]
+    pub fn dry_run<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<DryRun<N>> {
+        // Retrieve the requests, where the first request is the main request.
+        let requests = Vec::from(authorization.to_vec_deque());
+        ensure!(!requests.is_empty(), "The authorization is empty");
+
+        // Evaluate each transition, where each request is followed by the requests of the functions it calls.
+        let transitions = (0..requests.len())
+            .map(|index| {
+                let request = &requests[index];
+                let call_stack = CallStack::evaluate(Authorization::new(&requests[index..]))?;
+                let (response, finalize) =
+                    self.get_stack(request.program_id())?.evaluate_transition::<A>(call_stack)?;
+                Ok(DryRunTransition { request: request.clone(), response, finalize })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Estimate the transitions, if the verifying keys are available.
+        let estimates =
+            self.get_stack(requests[0].program_id())?.estimate_transitions(requests[0].function_name()).ok();
+
+        Ok(DryRun { transitions, estimates })
+    }
+}
@@ -16,6 +16,12 @@
 
 use super::*;
 
+use indexmap::IndexSet;
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
     #[inline]
@@ -30,6 +36,9 @@ impl<N: Network> Process<N> {
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executing '{}/{}'...", request.program_id(), request.function_name()).dimmed());
 
+        // Ensure the program matches its pinned digests, if the process has a registry.
+        self.check_pins(request.program_id())?;
+
         // Initialize the execution.
         let execution = Arc::new(RwLock::new(Execution::new()));
         // Retrieve the stack.
@@ -44,6 +53,49 @@ impl<N: Network> Process<N> {
         Ok((response, execution))
     }
 
+    /// Executes the given independent authorizations, returning the response and execution of each, in order.
+    ///
+    /// The first authorization of each function is executed first, so each missing proving key is
+    /// synthesized once, and reused by the remaining executions, which are proven concurrently.
+    /// Each execution samples from its own RNG, seeded from the given RNG, so the results do not
+    /// depend on the scheduling of the executions.
+    #[inline]
+    pub fn execute_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorizations: Vec<Authorization<N>>,
+        rng: &mut R,
+    ) -> Result<Vec<(Response<N>, Execution<N>)>> {
+        // Seed an RNG for each execution.
+        let tasks =
+            authorizations.into_iter().map(|authorization| (authorization, rng.gen::<[u8; 32]>())).collect::<Vec<_>>();
+
+        // Split the executions into the first execution of each function, and the remaining executions.
+        let mut functions = IndexSet::with_capacity(tasks.len());
+        let mut first = Vec::new();
+        let mut remaining = Vec::with_capacity(tasks.len());
+        for (index, (authorization, seed)) in tasks.into_iter().enumerate() {
+            let request = authorization.peek_next()?;
+            match functions.insert((*request.program_id(), *request.function_name())) {
+                true => first.push((index, authorization, seed)),
+                false => remaining.push((index, authorization, seed)),
+            }
+        }
+
+        // Executes the given authorization, with an RNG from the given seed.
+        let execute = |(index, authorization, seed): (usize, Authorization<N>, [u8; 32])| {
+            self.execute::<A, _>(authorization, &mut StdRng::from_seed(seed)).map(|result| (index, result))
+        };
+
+        // Execute the first execution of each function, to synthesize any missing proving keys once.
+        let mut results = first.into_iter().map(execute).collect::<Result<Vec<_>>>()?;
+        // Execute the remaining executions concurrently.
+        results.extend(cfg_into_iter!(remaining).map(execute).collect::<Result<Vec<_>>>()?);
+
+        // Return the results in the order of the authorizations.
+        results.sort_unstable_by_key(|(index, _)| *index);
+        Ok(results.into_iter().map(|(_, result)| result).collect())
+    }
+
     /// Verifies the given execution is valid.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
@@ -55,23 +107,13 @@ impl<N: Network> Process<N> {
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
-        // Ensure the number of transitions matches the program function.
-        {
-            // Retrieve the transition (without popping it).
-            let transition = execution.peek()?;
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-            ensure!(
-                number_of_calls == execution.len(),
-                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
-            );
-        }
+        // Ensure the number of transitions matches the program functions.
+        self.check_number_of_transitions(execution)?;
 
         // Replicate the execution stack for verification.
         let mut queue = execution.clone();
+        // Initialize the verifying key, verifier inputs, and proof of each transition.
+        let mut instances = Vec::with_capacity(execution.len());
 
         // Verify each transition.
         while let Ok(transition) = queue.pop() {
@@ -114,6 +156,8 @@ impl<N: Network> Process<N> {
             // [Inputs] Extend the verifier inputs with the input IDs.
             inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
 
+            // Ensure the program matches its pinned digests, if the process has a registry.
+            self.check_pins(transition.program_id())?;
             // Retrieve the stack.
             let stack = self.get_stack(transition.program_id())?;
             // Retrieve the function from the stack.
@@ -180,12 +224,12 @@ impl<N: Network> Process<N> {
 
             // Retrieve the verifying key.
             let verifying_key = self.get_verifying_key(transition.program_id(), transition.function_name())?;
-            // Ensure the proof is valid.
-            ensure!(
-                verifying_key.verify(transition.function_name(), &inputs, transition.proof()),
-                "Transition is invalid"
-            );
+            instances.push((verifying_key, inputs, transition.proof().clone()));
         }
+
+        // Ensure the proofs are valid, where their pairing checks are batched together.
+        let instances = instances.iter().map(|(vk, inputs, proof)| (vk, inputs.as_slice(), proof)).collect::<Vec<_>>();
+        ensure!(VerifyingKey::batch_verify(&instances), "A transition in the execution is invalid");
         Ok(())
     }
 
@@ -200,20 +244,8 @@ impl<N: Network> Process<N> {
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
-        // Ensure the number of transitions matches the program function.
-        {
-            // Retrieve the transition (without popping it).
-            let transition = execution.peek()?;
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-            ensure!(
-                number_of_calls == execution.len(),
-                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
-            );
-        }
+        // Ensure the number of transitions matches the program functions.
+        self.check_number_of_transitions(execution)?;
 
         // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
         //  Currently this loop assumes a linearly execution stack.
@@ -272,4 +304,31 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Ensures the given execution is a sequence of one or more call trees, where the transitions of each
+    /// call tree are contiguous, and the root transition is the last transition of its call tree.
+    ///
+    /// A single execution has one call tree. A bundle of executions, which are verified and finalized
+    /// as a whole, has one call tree for each execution in the bundle.
+    fn check_number_of_transitions(&self, execution: &Execution<N>) -> Result<()> {
+        // Walk the call trees from the last transition, which is the root of the last call tree.
+        let mut end = execution.len();
+        while end > 0 {
+            // Retrieve the root transition of the call tree.
+            let transition = execution.get(end - 1)?;
+            // Retrieve the stack.
+            let stack = self.get_stack(transition.program_id())?;
+            // Ensure the call tree fits in the remaining transitions.
+            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
+            ensure!(
+                number_of_calls <= end,
+                "The number of transitions in the execution is incorrect. Expected {number_of_calls} for \
+                 '{}/{}', but found {end}",
+                transition.program_id(),
+                transition.function_name()
+            );
+            end -= number_of_calls;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Differential fuzzing of the console evaluator against the circuit synthesizer.
+//!
+//! Each iteration generates a random function over literal registers, evaluates it in console mode,
+//! synthesizes it in circuit mode, and asserts the two modes produce the same outputs,
+//! and that the synthesized witness satisfies the circuit.
+
+use super::*;
+use console::{
+    account::Address,
+    program::{Literal, LiteralType},
+};
+
+use snarkvm_r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+
+type CurrentNetwork = console::network::Testnet3;
+type CurrentAleo = circuit::network::AleoV0;
+
+/// The number of random programs to check.
+const ITERATIONS: usize = 25;
+/// The maximum number of instructions in a random program.
+const MAX_INSTRUCTIONS: usize = 16;
+
+/// The literal types exercised by the fuzzer.
+const LITERAL_TYPES: [LiteralType; 10] = [
+    LiteralType::Boolean,
+    LiteralType::Field,
+    LiteralType::I8,
+    LiteralType::I16,
+    LiteralType::I32,
+    LiteralType::I64,
+    LiteralType::U8,
+    LiteralType::U16,
+    LiteralType::U32,
+    LiteralType::U64,
+];
+
+/// The shape of an instruction, in terms of its operand and destination types.
+#[derive(Copy, Clone)]
+enum Shape {
+    /// `T => T`
+    Unary,
+    /// `(T, T) => T`
+    Binary,
+    /// `(T, T) => boolean`
+    Compare,
+    /// `(boolean, T, T) => T`
+    Ternary,
+}
+
+/// Returns the opcodes that are total over the given literal type, as the fuzzer does not exercise halting.
+fn opcodes(literal_type: LiteralType) -> Vec<(&'static str, Shape)> {
+    let mut opcodes = match literal_type {
+        LiteralType::Boolean => vec![
+            ("and", Shape::Binary),
+            ("or", Shape::Binary),
+            ("xor", Shape::Binary),
+            ("nand", Shape::Binary),
+            ("nor", Shape::Binary),
+            ("not", Shape::Unary),
+        ],
+        LiteralType::Field => vec![
+            ("add", Shape::Binary),
+            ("sub", Shape::Binary),
+            ("mul", Shape::Binary),
+            ("double", Shape::Unary),
+            ("square", Shape::Unary),
+            ("neg", Shape::Unary),
+        ],
+        _ => vec![
+            ("add.w", Shape::Binary),
+            ("sub.w", Shape::Binary),
+            ("mul.w", Shape::Binary),
+            ("and", Shape::Binary),
+            ("or", Shape::Binary),
+            ("xor", Shape::Binary),
+            ("not", Shape::Unary),
+        ],
+    };
+    opcodes.extend([("is.eq", Shape::Compare), ("is.neq", Shape::Compare), ("ternary", Shape::Ternary)]);
+    opcodes
+}
+
+/// A generator of random programs over literal registers.
+struct Generator {
+    /// The literal type of each register, where the register locator is the index.
+    registers: Vec<LiteralType>,
+}
+
+impl Generator {
+    /// Returns a random visibility.
+    fn visibility<R: Rng>(rng: &mut R) -> &'static str {
+        match rng.gen::<bool>() {
+            true => "public",
+            false => "private",
+        }
+    }
+
+    /// Returns a random register of the given literal type.
+    fn register<R: Rng>(&self, literal_type: LiteralType, rng: &mut R) -> String {
+        let candidates = (0..self.registers.len()).filter(|i| self.registers[*i] == literal_type).collect::<Vec<_>>();
+        format!("r{}", candidates[rng.gen_range(0..candidates.len())])
+    }
+
+    /// Returns a random operand of the given literal type, which is either a register or a constant.
+    fn operand<R: Rng + CryptoRng>(&self, literal_type: LiteralType, rng: &mut R) -> String {
+        match rng.gen_range(0..4) {
+            0 => Literal::<CurrentNetwork>::sample(literal_type, rng).to_string(),
+            _ => self.register(literal_type, rng),
+        }
+    }
+
+    /// Returns a random instruction over the given literal type, and adds its destination register.
+    fn instruction<R: Rng + CryptoRng>(&mut self, literal_type: LiteralType, rng: &mut R) -> String {
+        let opcodes = opcodes(literal_type);
+        let (opcode, shape) = opcodes[rng.gen_range(0..opcodes.len())];
+
+        // Note: The first operand is always a register, so the instruction is not folded into a constant.
+        let (operands, destination_type) = match shape {
+            Shape::Unary => (vec![self.register(literal_type, rng)], literal_type),
+            Shape::Binary => (vec![self.register(literal_type, rng), self.operand(literal_type, rng)], literal_type),
+            Shape::Compare => {
+                (vec![self.register(literal_type, rng), self.operand(literal_type, rng)], LiteralType::Boolean)
+            }
+            Shape::Ternary => (
+                vec![
+                    self.register(LiteralType::Boolean, rng),
+                    self.operand(literal_type, rng),
+                    self.operand(literal_type, rng),
+                ],
+                literal_type,
+            ),
+        };
+
+        let destination = self.registers.len();
+        self.registers.push(destination_type);
+        format!("{opcode} {} into r{destination};", operands.join(" "))
+    }
+
+    /// Returns a random program, with a function `compute` over the given literal type.
+    fn program<R: Rng + CryptoRng>(literal_type: LiteralType, rng: &mut R) -> String {
+        let mut generator = Self { registers: vec![literal_type, literal_type, LiteralType::Boolean] };
+
+        let mut lines = vec![
+            "program fuzz.aleo;".to_string(),
+            "function compute:".to_string(),
+            format!("    input r0 as {literal_type}.{};", Self::visibility(rng)),
+            format!("    input r1 as {literal_type}.{};", Self::visibility(rng)),
+            format!("    input r2 as boolean.{};", Self::visibility(rng)),
+        ];
+
+        // Generate the instructions.
+        let num_instructions = rng.gen_range(1..=MAX_INSTRUCTIONS);
+        for _ in 0..num_instructions {
+            lines.push(format!("    {}", generator.instruction(literal_type, rng)));
+        }
+
+        // Output the most recent destination registers.
+        let num_registers = generator.registers.len();
+        let num_outputs = num_instructions.min(CurrentNetwork::MAX_OUTPUTS);
+        for register in num_registers - num_outputs..num_registers {
+            let output_type = generator.registers[register];
+            lines.push(format!("    output r{register} as {output_type}.{};", Self::visibility(rng)));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Checks that the console evaluator and the circuit synthesizer agree on the given program,
+/// and that the synthesized witness satisfies the circuit.
+fn check_program<R: Rng + CryptoRng>(process: &Process<CurrentNetwork>, source: &str, rng: &mut R) -> Result<()> {
+    // Initialize the stack.
+    let program = Program::<CurrentNetwork>::from_str(source)?;
+    let stack = Stack::new(process, &program)?;
+    let function_name = Identifier::from_str("compute")?;
+
+    // Sample the caller and the inputs.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+    let address = Address::try_from(&private_key)?;
+    let inputs = stack
+        .get_function(&function_name)?
+        .input_types()
+        .iter()
+        .map(|input_type| stack.sample_value(&address, input_type, rng))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Authorize the function call.
+    let authorization = stack.authorize::<CurrentAleo, _>(&private_key, function_name, &inputs, rng)?;
+    let request = authorization.peek_next()?;
+
+    // Evaluate the function in console mode.
+    let expected = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate())?)?;
+
+    // Synthesize the function in circuit mode.
+    let assignments = Assignments::<CurrentNetwork>::default();
+    let call_stack = CallStack::CheckDeployment(vec![request], private_key, assignments.clone());
+    let candidate = stack.execute_function::<CurrentAleo, _>(call_stack, rng)?;
+
+    // Ensure the outputs match.
+    ensure!(
+        expected.outputs() == candidate.outputs(),
+        "Output mismatch: console {:?}, circuit {:?}",
+        expected.outputs(),
+        candidate.outputs()
+    );
+    ensure!(expected.output_ids() == candidate.output_ids(), "Output ID mismatch");
+
+    // Ensure the witness satisfies the circuit.
+    let assignment = match assignments.read().last() {
+        Some(assignment) => assignment.clone(),
+        None => bail!("The assignment for '{function_name}' is missing"),
+    };
+    let mut cs = TestConstraintSystem::new();
+    assignment.generate_constraints(&mut cs)?;
+    if let Some(constraint) = cs.which_is_unsatisfied() {
+        bail!("The witness does not satisfy the circuit, at constraint '{constraint}'")
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fuzz_console_and_circuit_agree() -> Result<()> {
+    let rng = &mut test_crypto_rng();
+    let process = Process::<CurrentNetwork>::load()?;
+
+    for i in 0..ITERATIONS {
+        // Generate a random program.
+        let literal_type = LITERAL_TYPES[rng.gen_range(0..LITERAL_TYPES.len())];
+        let source = Generator::program(literal_type, rng);
+        // Check the program, printing the program on failure for reproduction.
+        if let Err(error) = check_program(&process, &source, rng) {
+            bail!("Iteration {i} failed: {error}\n\n{source}")
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fuzz_generator_is_well_formed() -> Result<()> {
+    let rng = &mut test_crypto_rng();
+    let process = Process::<CurrentNetwork>::load()?;
+
+    // Ensure the generator produces programs that parse and type check, for every literal type.
+    for literal_type in LITERAL_TYPES {
+        let source = Generator::program(literal_type, rng);
+        let program = Program::<CurrentNetwork>::from_str(&source)?;
+        Stack::new(&process, &program)?;
+    }
+    Ok(())
+}
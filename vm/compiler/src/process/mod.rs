@@ -17,12 +17,30 @@
 mod stack;
 pub use stack::*;
 
+mod deterministic;
+pub use deterministic::*;
+
+mod dry_run;
+pub use dry_run::*;
+
 mod additional_fee;
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 
+#[cfg(test)]
+mod fuzz;
+
+mod registry;
+pub use registry::*;
+
+mod session;
+pub use session::*;
+
+mod synthesis;
+pub use synthesis::*;
+
 use crate::{
     ledger::{ProgramStorage, ProgramStore},
     AdditionalFee,
@@ -33,11 +51,13 @@ use crate::{
     UniversalSRS,
     VerifyingKey,
 };
+#[cfg(not(target_family = "wasm"))]
+use crate::LazyProvingKey;
 use console::{
     account::PrivateKey,
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Record, Request, Response, Value},
-    types::{I64, U64},
+    types::{Field, I64, U64},
 };
 
 use indexmap::IndexMap;
@@ -53,6 +73,10 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The mapping of program IDs to program digests, which are computed when the program is added.
+    digests: IndexMap<ProgramID<N>, Field<N>>,
+    /// The registry of pinned program digests, which executions are checked against, if set.
+    registry: Option<ProgramRegistry<N>>,
 }
 
 impl<N: Network> Process<N> {
@@ -60,7 +84,12 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn setup<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            digests: IndexMap::new(),
+            registry: None,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -73,7 +102,7 @@ impl<N: Network> Process<N> {
         }
 
         // Add the 'credits.aleo' stack to the process.
-        process.stacks.insert(*program.id(), stack);
+        process.add_stack(stack)?;
         // Return the process.
         Ok(process)
     }
@@ -85,10 +114,27 @@ impl<N: Network> Process<N> {
         // Compute the program stack.
         let stack = Stack::new(self, program)?;
         // Add the stack to the process.
-        self.stacks.insert(*program.id(), stack);
+        self.add_stack(stack)?;
         // Return success.
         Ok(())
     }
+
+    /// Adds the given stack to the process, and caches the digest of its program.
+    #[inline]
+    fn add_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Compute the program digest.
+        let digest = ProgramRegistry::digest(stack.program())?;
+        // Add the stack to the process.
+        self.insert_stack(stack, digest);
+        Ok(())
+    }
+
+    /// Adds the given stack to the process, with the given digest of its program.
+    #[inline]
+    fn insert_stack(&mut self, stack: Stack<N>, digest: Field<N>) {
+        self.digests.insert(*stack.program_id(), digest);
+        self.stacks.insert(*stack.program_id(), stack);
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -96,7 +142,12 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            digests: IndexMap::new(),
+            registry: None,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -106,18 +157,31 @@ impl<N: Network> Process<N> {
         // Synthesize the 'credits.aleo' circuit keys.
         for function_name in program.functions().keys() {
             // TODO (howardwu): Abstract this into the `Network` trait.
-            // Load the proving and verifying key bytes.
-            let (proving_key, verifying_key) = snarkvm_parameters::testnet3::TESTNET3_CREDITS_PROGRAM
+            // Load the verifying key bytes.
+            let verifying_key = snarkvm_parameters::testnet3::TESTNET3_CREDITS_VERIFYING_KEYS
                 .get(&function_name.to_string())
-                .ok_or_else(|| anyhow!("Circuit keys for credits.aleo/{function_name}' not found"))?;
-
-            // Insert the proving and verifying key.
-            stack.insert_proving_key(function_name, ProvingKey::from_bytes_le(proving_key)?)?;
+                .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))?;
+
+            // Insert the proving key, which is only read and deserialized on first use.
+            #[cfg(not(target_family = "wasm"))]
+            {
+                let path = snarkvm_parameters::testnet3::credits_proving_key_path(&function_name.to_string())?;
+                stack.insert_lazy_proving_key(function_name, LazyProvingKey::open(path)?)?;
+            }
+            // Insert the proving key.
+            #[cfg(target_family = "wasm")]
+            {
+                let proving_key = snarkvm_parameters::testnet3::TESTNET3_CREDITS_PROGRAM
+                    .get(&function_name.to_string())
+                    .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))?;
+                stack.insert_proving_key(function_name, ProvingKey::from_bytes_le(&proving_key.0)?)?;
+            }
+            // Insert the verifying key.
             stack.insert_verifying_key(function_name, VerifyingKey::from_bytes_le(verifying_key)?)?;
         }
 
         // Add the stack to the process.
-        process.stacks.insert(*program.id(), stack);
+        process.add_stack(stack)?;
         // Return the process.
         Ok(process)
     }
@@ -151,6 +215,12 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id).map(Stack::program)
     }
 
+    /// Returns the program digest for the given program ID.
+    #[inline]
+    pub fn get_program_digest(&self, program_id: &ProgramID<N>) -> Result<Field<N>> {
+        self.digests.get(program_id).copied().ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))
+    }
+
     /// Returns the proving key for the given program ID and function name.
     #[inline]
     pub fn get_proving_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
@@ -191,6 +261,17 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.insert_verifying_key(function_name, verifying_key)
     }
 
+    /// Removes the proving keys of every program, as they are only needed to produce proofs.
+    /// Note: A proving key that is needed again is re-synthesized on demand.
+    #[inline]
+    pub fn remove_proving_keys(&self) {
+        for stack in self.stacks.values() {
+            for function_name in stack.program().functions().keys() {
+                stack.remove_proving_key(function_name);
+            }
+        }
+    }
+
     /// Synthesizes the proving and verifying key for the given program ID and function name.
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -202,6 +283,18 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Returns the constraint report for the given program ID and function name.
+    #[inline]
+    pub fn constraint_report<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<ConstraintReport<N>> {
+        // Retrieve the constraint report.
+        self.get_stack(program_id)?.constraint_report::<A, R>(function_name, rng)
+    }
 }
 
 #[cfg(test)]
@@ -455,6 +548,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_execute_batch() {
+        // Initialize the RNG.
+        let rng = &mut test_crypto_rng();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Construct the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+
+        // Authorize independent calls to 'genesis' and 'mint'.
+        let authorizations = ["genesis", "mint", "genesis", "mint"]
+            .iter()
+            .enumerate()
+            .map(|(index, function_name)| {
+                let inputs = [
+                    Value::<CurrentNetwork>::from_str(&format!("{caller}")).unwrap(),
+                    Value::<CurrentNetwork>::from_str(&format!("{}u64", index + 1)).unwrap(),
+                ];
+                let function_name = Identifier::from_str(function_name).unwrap();
+                process.authorize::<CurrentAleo, _>(&caller_private_key, &program_id, function_name, &inputs, rng)
+            })
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let requests = authorizations.iter().map(|authorization| authorization.peek_next().unwrap()).collect_vec();
+
+        // Execute the batch.
+        let results = process.execute_batch::<CurrentAleo, _>(authorizations, rng).unwrap();
+        assert_eq!(requests.len(), results.len());
+
+        // Ensure each execution is valid, and is returned in the order of its authorization.
+        for (request, (_, execution)) in requests.iter().zip_eq(&results) {
+            assert!(process.verify_execution(execution).is_ok());
+            assert_eq!(request.tcm(), execution.peek().unwrap().tcm());
+        }
+    }
+
     #[test]
     fn test_process_circuit_key() {
         // Initialize a new program.
@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A content-addressed registry of programs.
+///
+/// Programs are stored by digest. When a program is deployed, the digests of its imports are
+/// resolved and pinned, so that an execution can verify it runs against the exact programs
+/// the deployment was made against, rather than any program that shares the same ID.
+#[derive(Clone)]
+pub struct ProgramRegistry<N: Network> {
+    /// The mapping of program digests to programs.
+    programs: IndexMap<Field<N>, Program<N>>,
+    /// The mapping of program IDs to the digest of the deployed program.
+    deployments: IndexMap<ProgramID<N>, Field<N>>,
+    /// The mapping of program IDs to the pinned digests of their imports.
+    pins: IndexMap<ProgramID<N>, IndexMap<ProgramID<N>, Field<N>>>,
+}
+
+impl<N: Network> Default for ProgramRegistry<N> {
+    /// Initializes a new, empty registry.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ProgramRegistry<N> {
+    /// Initializes a new, empty registry.
+    pub fn new() -> Self {
+        Self { programs: IndexMap::new(), deployments: IndexMap::new(), pins: IndexMap::new() }
+    }
+
+    /// Returns the digest of the given program.
+    pub fn digest(program: &Program<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&program.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Stores the given program by digest, and returns the digest.
+    pub fn insert(&mut self, program: &Program<N>) -> Result<Field<N>> {
+        // Compute the program digest.
+        let digest = Self::digest(program)?;
+        // Store the program, if it does not already exist.
+        self.programs.entry(digest).or_insert_with(|| program.clone());
+        // Return the digest.
+        Ok(digest)
+    }
+
+    /// Deploys the given program, pinning the digests of its deployed imports, and returns the program digest.
+    pub fn deploy(&mut self, program: &Program<N>) -> Result<Field<N>> {
+        // Compute the program digest.
+        let digest = Self::digest(program)?;
+        // Deploy the program.
+        self.deploy_with_digest(program, digest)?;
+        Ok(digest)
+    }
+
+    /// Deploys the given program with the given digest, pinning the digests of its deployed imports.
+    pub(crate) fn deploy_with_digest(&mut self, program: &Program<N>, digest: Field<N>) -> Result<()> {
+        // Ensure the program can be deployed, and resolve the digest of each import.
+        let pins = match self.check_deployment(program, &digest)? {
+            Some(pins) => pins,
+            // If the program is already deployed, redeploying it is a no-op.
+            None => return Ok(()),
+        };
+
+        // Store the program, and record the deployment and its pins.
+        self.programs.entry(digest).or_insert_with(|| program.clone());
+        self.deployments.insert(*program.id(), digest);
+        self.pins.insert(*program.id(), pins);
+        Ok(())
+    }
+
+    /// Ensures the given program can be deployed with the given digest, and returns the digest of each import,
+    /// or `None` if the program is already deployed.
+    pub(crate) fn check_deployment(
+        &self,
+        program: &Program<N>,
+        digest: &Field<N>,
+    ) -> Result<Option<IndexMap<ProgramID<N>, Field<N>>>> {
+        // Ensure the program ID is not deployed with a different program.
+        if let Some(deployed) = self.deployments.get(program.id()) {
+            ensure!(deployed == digest, "Program '{}' is already deployed with digest '{deployed}'", program.id());
+            return Ok(None);
+        }
+
+        // Resolve the digest of each import.
+        program
+            .imports()
+            .keys()
+            .map(|import_id| match self.deployments.get(import_id) {
+                Some(import_digest) => Ok((*import_id, *import_digest)),
+                None => bail!("Program '{}' imports '{import_id}', which is not deployed", program.id()),
+            })
+            .collect::<Result<IndexMap<_, _>>>()
+            .map(Some)
+    }
+
+    /// Returns `true` if the registry contains a program with the given digest.
+    pub fn contains(&self, digest: &Field<N>) -> bool {
+        self.programs.contains_key(digest)
+    }
+
+    /// Returns the program with the given digest.
+    pub fn get(&self, digest: &Field<N>) -> Result<&Program<N>> {
+        self.programs.get(digest).ok_or_else(|| anyhow!("Program with digest '{digest}' does not exist"))
+    }
+
+    /// Returns the digest of the deployed program with the given ID.
+    pub fn get_deployment(&self, program_id: &ProgramID<N>) -> Result<Field<N>> {
+        self.deployments.get(program_id).copied().ok_or_else(|| anyhow!("Program '{program_id}' is not deployed"))
+    }
+
+    /// Returns the pinned import digests of the deployed program with the given ID.
+    pub fn get_pins(&self, program_id: &ProgramID<N>) -> Result<&IndexMap<ProgramID<N>, Field<N>>> {
+        self.pins.get(program_id).ok_or_else(|| anyhow!("Program '{program_id}' is not deployed"))
+    }
+
+    /// Ensures the program with the given ID, and every program it transitively imports, matches its pinned digest,
+    /// where `get_digest` returns the digest of the program with the given ID.
+    pub fn verify(
+        &self,
+        program_id: &ProgramID<N>,
+        get_digest: &dyn Fn(&ProgramID<N>) -> Result<Field<N>>,
+    ) -> Result<()> {
+        // Ensure the program matches its deployment.
+        let digest = get_digest(program_id)?;
+        let deployed = self.get_deployment(program_id)?;
+        ensure!(digest == deployed, "Program '{program_id}' does not match its deployed digest '{deployed}'");
+
+        // Ensure each import matches its pinned digest, and recursively verify the import.
+        for (import_id, pinned) in self.get_pins(program_id)? {
+            let candidate = get_digest(import_id)?;
+            ensure!(
+                candidate == *pinned,
+                "Program '{program_id}' pinned '{import_id}' at '{pinned}', but found '{candidate}'"
+            );
+            self.verify(import_id, get_digest)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Sets the registry of pinned program digests, which executions are checked against.
+    /// Once set, every program that is executed or verified must be deployed in the registry,
+    /// and every program deployed to the process is also deployed in the registry.
+    #[inline]
+    pub fn set_registry(&mut self, registry: ProgramRegistry<N>) {
+        self.registry = Some(registry);
+    }
+
+    /// Returns the registry of pinned program digests, if set.
+    #[inline]
+    pub const fn registry(&self) -> Option<&ProgramRegistry<N>> {
+        self.registry.as_ref()
+    }
+
+    /// Ensures the program with the given ID, and every program it imports, matches the pinned digests in the registry.
+    #[inline]
+    pub fn verify_pins(&self, registry: &ProgramRegistry<N>, program_id: &ProgramID<N>) -> Result<()> {
+        registry.verify(program_id, &|program_id| self.get_program_digest(program_id))
+    }
+
+    /// Ensures the program with the given ID matches the pinned digests in the registry of the process, if set.
+    #[inline]
+    pub(crate) fn check_pins(&self, program_id: &ProgramID<N>) -> Result<()> {
+        match &self.registry {
+            Some(registry) => self.verify_pins(registry, program_id),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::test_helpers::sample_process;
+    use circuit::network::AleoV0;
+    use console::{account::PrivateKey, network::Testnet3, program::Value};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns a program with the given ID, function name, and imports.
+    fn sample_program(id: &str, function: &str, imports: &[&str]) -> Program<CurrentNetwork> {
+        let imports: String = imports.iter().map(|import| format!("import {import};\n")).collect();
+        Program::from_str(&format!(
+            r"{imports}
+program {id};
+
+function {function}:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;"
+        ))
+        .unwrap()
+    }
+
+    /// Returns a closure that resolves the program digests from the given list.
+    fn resolver(
+        programs: Vec<Program<CurrentNetwork>>,
+    ) -> impl Fn(&ProgramID<CurrentNetwork>) -> Result<Field<CurrentNetwork>> {
+        move |program_id| match programs.iter().find(|program| program.id() == program_id) {
+            Some(program) => ProgramRegistry::digest(program),
+            None => bail!("Program '{program_id}' not found"),
+        }
+    }
+
+    #[test]
+    fn test_deploy_and_verify() {
+        let token = sample_program("token.aleo", "compute", &[]);
+        let app = sample_program("app.aleo", "compute", &["token.aleo"]);
+
+        let mut registry = ProgramRegistry::new();
+        // Ensure a program cannot be deployed before its imports.
+        assert!(registry.deploy(&app).is_err());
+
+        // Deploy the programs.
+        let token_digest = registry.deploy(&token).unwrap();
+        let app_digest = registry.deploy(&app).unwrap();
+        assert!(registry.contains(&token_digest));
+        assert_eq!(&app, registry.get(&app_digest).unwrap());
+        assert_eq!(Some(&token_digest), registry.get_pins(app.id()).unwrap().get(token.id()));
+
+        // Ensure the deployed programs verify.
+        registry.verify(app.id(), &resolver(vec![token.clone(), app.clone()])).unwrap();
+
+        // Ensure a substituted import fails to verify.
+        let substitute = sample_program("token.aleo", "substitute", &[]);
+        assert!(registry.verify(app.id(), &resolver(vec![substitute.clone(), app.clone()])).is_err());
+        // Ensure a substituted program cannot be deployed under the same ID.
+        assert!(registry.deploy(&substitute).is_err());
+        // Ensure redeploying the same program is a no-op.
+        assert_eq!(token_digest, registry.deploy(&token).unwrap());
+    }
+
+    #[test]
+    fn test_process_rejects_mismatched_pins() {
+        let token = sample_program("token.aleo", "compute", &[]);
+        let app = sample_program("app.aleo", "compute", &["token.aleo"]);
+        let substitute = sample_program("token.aleo", "substitute", &[]);
+
+        // Deploy the programs in the registry.
+        let mut registry = ProgramRegistry::new();
+        registry.deploy(&token).unwrap();
+        registry.deploy(&app).unwrap();
+
+        let rng = &mut test_crypto_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+        let inputs = [Value::from_str("1u32").unwrap(), Value::from_str("2u32").unwrap()];
+
+        // Execute the program against the pinned import.
+        let mut process = sample_process(&token);
+        process.add_program(&app).unwrap();
+        process.set_registry(registry.clone());
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, app.id(), function_name, &inputs, rng).unwrap();
+        let (_, execution) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+
+        // Construct a process with a substituted import, under the same program ID.
+        let mut process = sample_process(&substitute);
+        process.add_program(&app).unwrap();
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, app.id(), function_name, &inputs, rng).unwrap();
+        // Ensure the substituted import does not match the pinned digest.
+        assert!(process.verify_pins(&registry, app.id()).is_err());
+
+        // Ensure the execution and its verification are rejected against the registry.
+        process.set_registry(registry);
+        let error = process.execute::<CurrentAleo, _>(authorization, rng).unwrap_err();
+        assert!(error.to_string().contains("pinned"), "{error}");
+        let error = process.verify_execution(&execution).unwrap_err();
+        assert!(error.to_string().contains("pinned"), "{error}");
+    }
+    #[test]
+    fn test_process_deploy_with_registry() {
+        let token = sample_program("token.aleo", "compute", &[]);
+        let app = sample_program("app.aleo", "compute", &["token.aleo"]);
+        let rng = &mut test_crypto_rng();
+
+        // Ensure a program cannot be deployed before its imports are deployed in the registry.
+        let mut process = sample_process(&token);
+        process.set_registry(ProgramRegistry::new());
+        assert!(process.deploy::<CurrentAleo, _>(&app, rng).is_err());
+
+        // Deploy the import in the registry, and deploy the program to the process.
+        let mut registry = ProgramRegistry::new();
+        let token_digest = registry.deploy(&token).unwrap();
+        process.set_registry(registry);
+        let deployment = process.deploy::<CurrentAleo, _>(&app, rng).unwrap();
+        process.load_deployment(&deployment).unwrap();
+
+        // Ensure the program is deployed in the registry, under its cached digest, with its import pinned.
+        let registry = process.registry().unwrap();
+        assert_eq!(process.get_program_digest(app.id()).unwrap(), registry.get_deployment(app.id()).unwrap());
+        assert_eq!(Some(&token_digest), registry.get_pins(app.id()).unwrap().get(token.id()));
+        process.verify_pins(registry, app.id()).unwrap();
+    }
+}
@@ -0,0 +1,363 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::CallOperator;
+use console::{
+    account::Address,
+    program::{Entry, Literal, Locator, PlaintextType, Register, RegisterType},
+};
+
+use core::marker::PhantomData;
+use indexmap::IndexSet;
+
+/// The name of the program that wraps each instruction snippet.
+const SNIPPET_PROGRAM: &str = "session.aleo";
+/// The name of the function that wraps each instruction snippet.
+const SNIPPET_FUNCTION: &str = "snippet";
+
+/// The result of a command in a session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionOutput<N: Network> {
+    /// The program was added to the session.
+    Program(ProgramID<N>),
+    /// The registers were assigned the given values.
+    Registers(Vec<(Register<N>, Value<N>)>),
+}
+
+/// An interactive session, which evaluates Aleo instruction snippets and function calls
+/// against an in-memory process, and keeps the resulting values and records in session registers.
+///
+/// Note: A session evaluates without proving, and is intended for notebooks and debugging tools.
+pub struct VmSession<N: Network, A: circuit::Aleo<Network = N>> {
+    /// The in-memory process.
+    process: Process<N>,
+    /// The private key of the caller.
+    private_key: PrivateKey<N>,
+    /// The session registers, by locator.
+    registers: IndexMap<u64, Value<N>>,
+    /// PhantomData.
+    _phantom: PhantomData<A>,
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> VmSession<N, A> {
+    /// Initializes a new session for the given caller, with the `credits.aleo` program loaded.
+    pub fn new(private_key: PrivateKey<N>) -> Result<Self> {
+        Ok(Self { process: Process::load()?, private_key, registers: IndexMap::new(), _phantom: PhantomData })
+    }
+
+    /// Returns the in-memory process.
+    pub const fn process(&self) -> &Process<N> {
+        &self.process
+    }
+
+    /// Returns the private key of the caller.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns the session registers, by locator.
+    pub const fn registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.registers
+    }
+
+    /// Returns the value in the given session register.
+    pub fn get(&self, register: &Register<N>) -> Result<Value<N>> {
+        // Retrieve the value in the register.
+        let value = match self.registers.get(&register.locator()) {
+            Some(value) => value,
+            None => bail!("Register '{register}' is not set in the session"),
+        };
+        // Retrieve the member, if the register is a member.
+        match (register, value) {
+            (Register::Locator(..), value) => Ok(value.clone()),
+            (Register::Member(_, path), Value::Plaintext(plaintext)) => Ok(Value::Plaintext(plaintext.find(path)?)),
+            (Register::Member(_, path), Value::Record(record)) => match record.find(path)? {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                    Ok(Value::Plaintext(plaintext))
+                }
+            },
+        }
+    }
+
+    /// Assigns the given value to the given session register.
+    pub fn set(&mut self, register: &Register<N>, value: Value<N>) -> Result<()> {
+        match register {
+            Register::Locator(locator) => {
+                self.registers.insert(*locator, value);
+                Ok(())
+            }
+            Register::Member(..) => bail!("Cannot assign to the register member '{register}'"),
+        }
+    }
+
+    /// Returns an iterator over the session registers that contain records.
+    pub fn records(&self) -> impl '_ + Iterator<Item = (Register<N>, &Record<N, Plaintext<N>>)> {
+        self.registers.iter().filter_map(|(locator, value)| match value {
+            Value::Record(record) => Some((Register::Locator(*locator), record)),
+            Value::Plaintext(..) => None,
+        })
+    }
+
+    /// Clears the session registers.
+    pub fn clear(&mut self) {
+        self.registers.clear();
+    }
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> VmSession<N, A> {
+    /// Runs the given command, which is either a program to add to the session, or a single instruction.
+    ///
+    /// An instruction may read any session register as an operand, and its destinations are assigned
+    /// to the session registers of the same name. A `call` instruction evaluates a function of a program
+    /// in the session, and any other instruction is evaluated as a snippet over literal operands.
+    pub fn run<R: Rng + CryptoRng>(&mut self, command: &str, rng: &mut R) -> Result<SessionOutput<N>> {
+        let command = command.trim();
+        match command.starts_with("program ") {
+            // Add the program to the session.
+            true => {
+                let program = Program::from_str(command)?;
+                self.process.add_program(&program)?;
+                Ok(SessionOutput::Program(*program.id()))
+            }
+            // Evaluate the instruction.
+            false => {
+                let instruction = Instruction::from_str(command)?;
+                let outputs = match &instruction {
+                    Instruction::Call(call) => {
+                        // Retrieve the function locator.
+                        let locator = match call.operator() {
+                            CallOperator::Locator(locator) => locator,
+                            CallOperator::Resource(..) => bail!("A call in a session must name the program"),
+                        };
+                        // Load the inputs.
+                        let inputs =
+                            call.operands().iter().map(|operand| self.load(operand)).collect::<Result<Vec<_>>>()?;
+                        // Evaluate the function.
+                        self.call(locator, &inputs, rng)?
+                    }
+                    _ => self.evaluate_snippet(&instruction, rng)?,
+                };
+                // Assign the outputs to the destination registers.
+                let destinations = instruction.destinations();
+                ensure!(
+                    destinations.len() == outputs.len(),
+                    "Expected {} outputs, found {}",
+                    destinations.len(),
+                    outputs.len()
+                );
+                for (register, value) in destinations.iter().zip_eq(&outputs) {
+                    self.set(register, value.clone())?;
+                }
+                Ok(SessionOutput::Registers(destinations.into_iter().zip_eq(outputs).collect()))
+            }
+        }
+    }
+
+    /// Evaluates the function at the given locator on the given inputs, and returns the outputs.
+    pub fn call<R: Rng + CryptoRng>(
+        &self,
+        locator: &Locator<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Authorize the call.
+        let authorization = self.process.authorize::<A, R>(
+            &self.private_key,
+            locator.program_id(),
+            *locator.resource(),
+            inputs,
+            rng,
+        )?;
+        // Evaluate the call.
+        Ok(self.process.evaluate::<A>(authorization)?.outputs().to_vec())
+    }
+
+    /// Returns the value of the given operand in the session.
+    fn load(&self, operand: &Operand<N>) -> Result<Value<N>> {
+        match operand {
+            Operand::Literal(literal) => Ok(Value::Plaintext(literal.clone().into())),
+            Operand::Register(register) => self.get(register),
+            Operand::ProgramID(program_id) => Ok(Value::Plaintext(Literal::Address(program_id.to_address()?).into())),
+            Operand::Caller => Ok(Value::Plaintext(Literal::Address(Address::try_from(&self.private_key)?).into())),
+        }
+    }
+
+    /// Evaluates the given instruction, by wrapping it in a function whose inputs are the session registers
+    /// read by the instruction, and whose outputs are the destinations of the instruction.
+    fn evaluate_snippet<R: Rng + CryptoRng>(&self, instruction: &Instruction<N>, rng: &mut R) -> Result<Vec<Value<N>>> {
+        // Retrieve the session registers read by the instruction, in order.
+        let input_locators: IndexSet<_> = instruction
+            .operands()
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .collect();
+
+        // Load the inputs, and their types.
+        let mut inputs = Vec::with_capacity(input_locators.len());
+        let mut input_types = Vec::with_capacity(input_locators.len());
+        for locator in &input_locators {
+            let value = self.get(&Register::Locator(*locator))?;
+            match &value {
+                Value::Plaintext(Plaintext::Literal(literal, ..)) => {
+                    input_types.push(PlaintextType::Literal(literal.to_type()))
+                }
+                _ => bail!("Instruction snippets only support literal registers, found 'r{locator}'"),
+            }
+            inputs.push(value);
+        }
+
+        // Rename the registers of the instruction, such that the inputs are 'r0, r1, ...',
+        // and the destinations follow the inputs.
+        let num_inputs = input_types.len() as u64;
+        let mut num_destinations = 0;
+        let mut is_destination = false;
+        let mut tokens = Vec::new();
+        for token in instruction.to_string().trim_end_matches(';').split_whitespace() {
+            match Register::<N>::from_str(token) {
+                // Rename the destination register.
+                Ok(Register::Locator(..)) if is_destination => {
+                    tokens.push(format!("r{}", num_inputs + num_destinations));
+                    num_destinations += 1;
+                }
+                // Rename the input register.
+                Ok(register) => {
+                    let locator = input_locators
+                        .get_index_of(&register.locator())
+                        .ok_or_else(|| anyhow!("Register '{register}' is not an input"))?;
+                    tokens.push(match register {
+                        Register::Locator(..) => Register::<N>::Locator(locator as u64).to_string(),
+                        Register::Member(_, path) => Register::Member(locator as u64, path).to_string(),
+                    });
+                }
+                Err(..) => {
+                    is_destination |= token == "into";
+                    tokens.push(token.to_string());
+                }
+            }
+        }
+        let snippet = format!("{};", tokens.join(" "));
+
+        // Construct the function without outputs, to determine the types of the destinations.
+        let mut source = format!("program {SNIPPET_PROGRAM};\n\nfunction {SNIPPET_FUNCTION}:\n");
+        for (index, input_type) in input_types.iter().enumerate() {
+            source.push_str(&format!("    input r{index} as {input_type}.private;\n"));
+        }
+        source.push_str(&format!("    {snippet}\n"));
+        let stack = Stack::new(&self.process, &Program::from_str(&source)?)?;
+
+        // Append the outputs to the function.
+        let register_types = input_types.iter().cloned().map(RegisterType::Plaintext).collect::<Vec<_>>();
+        for (index, output_type) in
+            Instruction::from_str(&snippet)?.output_types(&stack, &register_types)?.iter().enumerate()
+        {
+            match output_type {
+                RegisterType::Plaintext(plaintext_type) => source
+                    .push_str(&format!("    output r{} as {plaintext_type}.private;\n", num_inputs + index as u64)),
+                _ => bail!("Instruction snippets only support literal destinations, found '{output_type}'"),
+            }
+        }
+        let stack = Stack::new(&self.process, &Program::from_str(&source)?)?;
+
+        // Evaluate the function.
+        let function_name = Identifier::from_str(SNIPPET_FUNCTION)?;
+        let authorization = stack.authorize::<A, R>(&self.private_key, function_name, &inputs, rng)?;
+        Ok(stack.evaluate_function::<A>(CallStack::evaluate(authorization)?)?.outputs().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    fn sample_session() -> VmSession<CurrentNetwork, CurrentAleo> {
+        let rng = &mut test_crypto_rng();
+        VmSession::new(PrivateKey::new(rng).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_session_snippet() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+        let mut session = sample_session();
+
+        // Evaluate a snippet over literals.
+        let output = session.run("add 1u32 2u32 into r0;", rng)?;
+        let expected = Value::from_str("3u32")?;
+        assert_eq!(output, SessionOutput::Registers(vec![(Register::Locator(0), expected.clone())]));
+        assert_eq!(session.get(&Register::Locator(0))?, expected);
+
+        // Evaluate a snippet over a session register, into a non-consecutive register.
+        session.run("mul r0 r0 into r7;", rng)?;
+        assert_eq!(session.get(&Register::Locator(7))?, Value::from_str("9u32")?);
+
+        // Evaluate a snippet that overwrites its own input.
+        session.run("sub r7 r0 into r0;", rng)?;
+        assert_eq!(session.get(&Register::Locator(0))?, Value::from_str("6u32")?);
+
+        // Ensure a snippet over an unset register fails.
+        assert!(session.run("add r3 1u32 into r4;", rng).is_err());
+        // Ensure a failing snippet does not modify the session.
+        assert!(session.run("div r0 0u32 into r1;", rng).is_err());
+        assert_eq!(session.registers().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_call() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+        let mut session = sample_session();
+
+        // Add a program to the session.
+        let program = r"
+program session_test.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller 0u64 r0 into r1 as token.record;
+    output r1 as token.record;
+
+function double:
+    input r0 as token.record;
+    add r0.amount r0.amount into r1;
+    cast r0.owner r0.gates r1 into r2 as token.record;
+    output r2 as token.record;";
+        let output = session.run(program, rng)?;
+        assert_eq!(output, SessionOutput::Program(ProgramID::from_str("session_test.aleo")?));
+
+        // Call a function, and keep its record in the session.
+        session.run("add 2u64 3u64 into r0;", rng)?;
+        session.run("call session_test.aleo/mint r0 into r1;", rng)?;
+        assert_eq!(session.records().count(), 1);
+
+        // Pass the record to another function, and read a member of the output.
+        session.run("call session_test.aleo/double r1 into r2;", rng)?;
+        assert_eq!(session.get(&Register::from_str("r2.amount")?)?, Value::from_str("10u64")?);
+        assert_eq!(session.records().count(), 2);
+        Ok(())
+    }
+}
@@ -15,6 +15,12 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::process::synthesis::synthesize_in_pool;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 impl<N: Network> Stack<N> {
     /// Deploys the given program ID, if it does not exist.
@@ -23,13 +29,24 @@ impl<N: Network> Stack<N> {
         // Ensure the program contains functions.
         ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
 
+        // Seed an RNG for each function, so the keys do not depend on the scheduling of the functions.
+        let tasks = self
+            .program
+            .functions()
+            .keys()
+            .map(|function_name| (function_name, rng.gen::<[u8; 32]>()))
+            .collect::<Vec<_>>();
+        // Synthesize the proving and verifying key of each function concurrently, as the circuits are independent.
+        synthesize_in_pool(|| {
+            cfg_into_iter!(tasks)
+                .map(|(function_name, seed)| self.synthesize_key::<A, _>(function_name, &mut StdRng::from_seed(seed)))
+                .collect::<Result<Vec<_>>>()
+        })??;
+
         // Initialize a mapping for the bundle.
         let mut bundle = IndexMap::with_capacity(self.program.functions().len());
 
         for function_name in self.program.functions().keys() {
-            // Synthesize the proving and verifying key.
-            self.synthesize_key::<A, R>(function_name, rng)?;
-
             // Retrieve the proving key.
             let proving_key = self.get_proving_key(function_name)?;
             // Retrieve the verifying key.
@@ -94,8 +111,25 @@ impl<N: Network> Stack<N> {
             }
         }
 
-        // Iterate through the program functions.
-        for (function, (verifying_key, certificate)) in program.functions().values().zip_eq(verifying_keys.values()) {
+        // Seed an RNG for each function, so the checks do not depend on the scheduling of the functions.
+        let tasks = program
+            .functions()
+            .values()
+            .zip_eq(verifying_keys.values())
+            .map(|(function, (verifying_key, certificate))| {
+                (function, verifying_key, certificate, rng.gen::<[u8; 32]>())
+            })
+            .collect::<Vec<_>>();
+
+        // Checks the given function on its verifying key and certificate, with an RNG from the given seed.
+        let check_function = |(function, verifying_key, certificate, seed): (
+            &Function<N>,
+            &VerifyingKey<N>,
+            &Certificate<N>,
+            [u8; 32],
+        )| {
+            let rng = &mut StdRng::from_seed(seed);
+
             // Initialize a burner private key.
             let burner_private_key = PrivateKey::new(rng)?;
             // Compute the burner address.
@@ -124,7 +158,7 @@ impl<N: Network> Stack<N> {
             // Initialize the call stack.
             let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
             // Synthesize the circuit.
-            let _response = self.execute_function::<A, R>(call_stack, rng)?;
+            let _response = self.execute_function::<A, _>(call_stack, rng)?;
             // Check the certificate.
             match assignments.read().last() {
                 None => bail!("The assignment for function '{}' is missing in '{program_id}'", function.name()),
@@ -135,7 +169,11 @@ impl<N: Network> Stack<N> {
                     }
                 }
             };
-        }
+            Ok(())
+        };
+
+        // Check each function concurrently, as the circuits are independent.
+        synthesize_in_pool(|| cfg_into_iter!(tasks).map(check_function).collect::<Result<Vec<_>>>())??;
 
         Ok(())
     }
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The estimated shape of a transition, which is known before the transition is proven.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionEstimate<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of constraints in the function circuit.
+    num_constraints: usize,
+    /// The number of transition inputs.
+    num_inputs: usize,
+    /// The number of transition outputs.
+    num_outputs: usize,
+    /// The number of commands in the finalize logic, if any.
+    num_finalize_commands: usize,
+}
+
+impl<N: Network> TransitionEstimate<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of constraints in the function circuit.
+    pub const fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// Returns the number of transition inputs.
+    pub const fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of transition outputs.
+    pub const fn num_outputs(&self) -> usize {
+        self.num_outputs
+    }
+
+    /// Returns the number of commands in the finalize logic, if any.
+    pub const fn num_finalize_commands(&self) -> usize {
+        self.num_finalize_commands
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the estimated shape of each transition in a call to the given function name,
+    /// starting with the function itself, followed by the functions it calls.
+    ///
+    /// The number of constraints is read from the verifying key of each function,
+    /// so the estimate does not require synthesizing or proving the circuits.
+    #[inline]
+    pub fn estimate_transitions(&self, function_name: &Identifier<N>) -> Result<Vec<TransitionEstimate<N>>> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Retrieve the verifying key.
+        let verifying_key = self.get_verifying_key(function_name)?;
+
+        // Estimate the transition for this function.
+        let mut estimates = vec![TransitionEstimate {
+            program_id: *self.program_id(),
+            function_name: *function_name,
+            num_constraints: verifying_key.circuit_info.num_constraints,
+            num_inputs: function.inputs().len(),
+            num_outputs: function.outputs().len(),
+            num_finalize_commands: function.finalize_logic().map_or(0, |finalize| finalize.commands().len()),
+        }];
+
+        // Estimate the transitions for each function call.
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine if this is a function call.
+                if call.is_function_call(self)? {
+                    estimates.extend(match call.operator() {
+                        CallOperator::Locator(locator) => {
+                            self.get_external_stack(locator.program_id())?.estimate_transitions(locator.resource())?
+                        }
+                        CallOperator::Resource(resource) => self.estimate_transitions(resource)?,
+                    });
+                }
+            }
+        }
+
+        Ok(estimates)
+    }
+}
@@ -16,6 +16,9 @@
 
 use super::*;
 
+/// The response of a transition, along with the inputs to its 'finalize' scope, if the function has one.
+type TransitionOutput<N> = (Response<N>, Option<Vec<Value<N>>>);
+
 impl<N: Network> Stack<N> {
     /// Evaluates a program closure on the given inputs.
     ///
@@ -71,6 +74,19 @@ impl<N: Network> Stack<N> {
     /// This method will halt if the given inputs are not the same length as the input statements.
     #[inline]
     pub fn evaluate_function<A: circuit::Aleo<Network = N>>(&self, call_stack: CallStack<N>) -> Result<Response<N>> {
+        Ok(self.evaluate_transition::<A>(call_stack)?.0)
+    }
+
+    /// Evaluates a program function on the given inputs, and returns the response,
+    /// along with the inputs to its 'finalize' scope, if the function has one.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn evaluate_transition<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+    ) -> Result<TransitionOutput<N>> {
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
             CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
@@ -140,7 +156,7 @@ impl<N: Network> Stack<N> {
             .collect::<Result<Vec<_>>>()?;
 
         // Compute the response.
-        Response::new(
+        let response = Response::new(
             self.program.id(),
             request.inputs().len(),
             request.tvk(),
@@ -148,6 +164,33 @@ impl<N: Network> Stack<N> {
             outputs,
             &function.output_types(),
             output_registers,
-        )
+        )?;
+
+        // If this function has the finalize command, then load the finalize inputs.
+        let finalize = match function.finalize_command() {
+            Some(command) => {
+                let inputs = command
+                    .operands()
+                    .iter()
+                    .map(|operand| {
+                        // Retrieve the finalize input.
+                        let value = registers.load(self, operand)?;
+                        // Ensure the value is a literal (for now), as in `Stack::execute_function`.
+                        match value {
+                            Value::Plaintext(Plaintext::Literal(..)) => Ok(value),
+                            _ => bail!(
+                                "'{}/{}' attempts to pass a non-literal into 'finalize'",
+                                self.program_id(),
+                                function.name()
+                            ),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Some(inputs)
+            }
+            None => None,
+        };
+
+        Ok((response, finalize))
     }
 }
@@ -138,6 +138,8 @@ impl<N: Network> Stack<N> {
 
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
+        // Seed the RNG for function calls from the given RNG, so the execution is reproducible from the given RNG.
+        registers.set_rng_seed(rng.gen());
 
         use circuit::{Eject, Inject};
 
@@ -161,6 +163,9 @@ impl<N: Network> Stack<N> {
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>("Request");
 
+        // Retrieve the metrics of the circuit after the request.
+        let request_metrics = A::metrics();
+
         // Retrieve the number of public variables in the circuit.
         let num_public = A::num_public();
 
@@ -178,6 +183,9 @@ impl<N: Network> Stack<N> {
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
+        // Initialize the metrics of each instruction.
+        let mut instruction_metrics = Vec::with_capacity(function.instructions().len());
+
         // Execute the instructions.
         for instruction in function.instructions() {
             // If the circuit is in execute mode, then evaluate the instructions.
@@ -188,8 +196,12 @@ impl<N: Network> Stack<N> {
                 }
             }
 
+            // Retrieve the metrics of the circuit before the instruction.
+            let metrics = A::metrics();
             // Execute the instruction.
             instruction.execute(self, &mut registers)?;
+            // Record the metrics of the instruction.
+            instruction_metrics.push((instruction.to_string(), A::metrics() - metrics));
 
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
@@ -210,6 +222,9 @@ impl<N: Network> Stack<N> {
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>(format!("Function '{}()'", function.name()));
 
+        // Retrieve the metrics of the circuit after the function.
+        let function_metrics = A::metrics();
+
         // If the function does not contain function calls, ensure no new public variables were injected.
         if !contains_function_call {
             // Ensure the number of public variables remains the same.
@@ -230,6 +245,9 @@ impl<N: Network> Stack<N> {
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>("Response");
 
+        // Retrieve the metrics of the circuit after the response.
+        let response_metrics = A::metrics();
+
         // If the circuit is in `Execute` mode, then prepare the 'finalize' scope if it exists.
         let finalize = if matches!(registers.call_stack(), CallStack::Synthesize(..))
             || matches!(registers.call_stack(), CallStack::CheckDeployment(..))
@@ -267,6 +285,13 @@ impl<N: Network> Stack<N> {
                                 function.name()
                             );
                         }
+                        circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => {
+                            bail!(
+                                "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                                self.program_id(),
+                                function.name()
+                            );
+                        }
                         circuit::Value::Record(..) => {
                             bail!(
                                 "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -301,6 +326,9 @@ impl<N: Network> Stack<N> {
             None
         };
 
+        // Retrieve the metrics of the circuit after the finalize inputs.
+        let finalize_metrics = A::metrics();
+
         use circuit::{ToField, Zero};
 
         let mut i64_gates = circuit::I64::zero();
@@ -369,6 +397,23 @@ impl<N: Network> Stack<N> {
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>("Complete");
 
+        // Record the constraint report for the function.
+        self.insert_constraint_report(
+            function.name(),
+            ConstraintReport::new(
+                *self.program_id(),
+                *function.name(),
+                vec![
+                    ("request", request_metrics),
+                    ("function", function_metrics - request_metrics),
+                    ("response", response_metrics - function_metrics),
+                    ("finalize", finalize_metrics - response_metrics),
+                    ("fee", A::metrics() - finalize_metrics),
+                ],
+                instruction_metrics,
+            ),
+        );
+
         // Eject the fee.
         let fee = i64_gates.eject_value();
         // Eject the response.
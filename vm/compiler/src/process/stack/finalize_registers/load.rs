@@ -28,6 +28,7 @@ impl<N: Network> FinalizeRegisters<N> {
         match self.load(stack, operand)? {
             Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
             Value::Plaintext(Plaintext::Interface(..)) => bail!("Operand must be a literal"),
+            Value::Plaintext(Plaintext::Array(..)) => bail!("Operand must be a literal"),
             Value::Record(..) => bail!("Operand must be a literal"),
         }
     }
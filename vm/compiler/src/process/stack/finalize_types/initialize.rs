@@ -102,7 +102,7 @@ impl<N: Network> FinalizeTypes<N> {
     fn check_input(&mut self, stack: &Stack<N>, register: &Register<N>, register_type: &RegisterType<N>) -> Result<()> {
         // Ensure the register type is defined in the program.
         match register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+            RegisterType::Plaintext(PlaintextType::Literal(..)) | RegisterType::Plaintext(PlaintextType::Array(..)) => (),
             RegisterType::Plaintext(PlaintextType::Interface(interface_name)) => {
                 // Ensure the interface is defined in the program.
                 if !stack.program().contains_interface(interface_name) {
@@ -148,7 +148,7 @@ impl<N: Network> FinalizeTypes<N> {
 
         // Ensure the register type is defined in the program.
         match register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+            RegisterType::Plaintext(PlaintextType::Literal(..)) | RegisterType::Plaintext(PlaintextType::Array(..)) => (),
             RegisterType::Plaintext(PlaintextType::Interface(interface_name)) => {
                 // Ensure the interface is defined in the program.
                 if !stack.program().contains_interface(interface_name) {
@@ -232,12 +232,17 @@ impl<N: Network> FinalizeTypes<N> {
                     | LiteralType::U16
                     | LiteralType::U32
                     | LiteralType::U64
-                    | LiteralType::U128 => {}
+                    | LiteralType::U128
+                    | LiteralType::I256
+                    | LiteralType::U256 => {}
                 }
             }
             RegisterType::Plaintext(PlaintextType::Interface(..)) => {
                 bail!("Decrement cannot decrement by an 'interface' (found at '{decrement}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Decrement cannot decrement by an 'array' (found at '{decrement}')")
+            }
             RegisterType::Record(..) => bail!("Decrement cannot decrement by a 'record' (found at '{decrement}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Decrement cannot decrement by an 'external record' (found at '{decrement}')")
@@ -288,12 +293,17 @@ impl<N: Network> FinalizeTypes<N> {
                     | LiteralType::U16
                     | LiteralType::U32
                     | LiteralType::U64
-                    | LiteralType::U128 => {}
+                    | LiteralType::U128
+                    | LiteralType::I256
+                    | LiteralType::U256 => {}
                 }
             }
             RegisterType::Plaintext(PlaintextType::Interface(..)) => {
                 bail!("Increment cannot increment by an 'interface' (found at '{increment}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Increment cannot increment by an 'array' (found at '{increment}')")
+            }
             RegisterType::Record(..) => bail!("Increment cannot increment by a 'record' (found at '{increment}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Increment cannot increment by an 'external record' (found at '{increment}')")
@@ -358,6 +368,24 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Array(opcode) => {
+                // Ensure the instruction belongs to the defined set.
+                if !["array.get", "array.len"].contains(&opcode) {
+                    bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
+                }
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "array.get" => ensure!(
+                        matches!(instruction, Instruction::ArrayGet(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "array.len" => ensure!(
+                        matches!(instruction, Instruction::ArrayLen(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
             Opcode::Assert(opcode) => {
                 // Ensure the instruction belongs to the defined set.
                 if !["assert.eq", "assert.neq"].contains(&opcode) {
@@ -407,6 +435,10 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the interface.
                         self.matches_interface(stack, instruction.operands(), &interface)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Unsupported operation: Cannot cast to a record (yet).")
                         // // Ensure the record type is defined in the program.
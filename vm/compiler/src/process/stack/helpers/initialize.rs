@@ -28,7 +28,10 @@ impl<N: Network> Stack<N> {
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
+            #[cfg(not(target_family = "wasm"))]
+            lazy_proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            constraint_reports: Default::default(),
         };
 
         // Add all of the imports into the stack.
@@ -211,6 +211,8 @@ impl<N: Network> Stack<N> {
                 }
                 // If `plaintext` is an interface, this is a mismatch.
                 Plaintext::Interface(..) => bail!("'{plaintext_type}' is invalid: expected literal, found interface"),
+                // If `plaintext` is an array, this is a mismatch.
+                Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
             },
             PlaintextType::Interface(interface_name) => {
                 // Ensure the interface name is valid.
@@ -231,6 +233,7 @@ impl<N: Network> Stack<N> {
                 let members = match plaintext {
                     Plaintext::Literal(..) => bail!("'{interface_name}' is invalid: expected interface, found literal"),
                     Plaintext::Interface(members, ..) => members,
+                    Plaintext::Array(..) => bail!("'{interface_name}' is invalid: expected interface, found array"),
                 };
 
                 // Ensure the number of interface members does not exceed the maximum.
@@ -263,6 +266,35 @@ impl<N: Network> Stack<N> {
                     self.matches_plaintext_internal(member, expected_type, depth + 1)?;
                 }
 
+                Ok(())
+            }
+            PlaintextType::Array(array_type) => {
+                // Retrieve the array type and elements.
+                let (candidate_type, elements) = match plaintext {
+                    Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected array, found literal"),
+                    Plaintext::Interface(..) => bail!("'{plaintext_type}' is invalid: expected array, found interface"),
+                    Plaintext::Array(candidate_type, elements, ..) => (candidate_type, elements),
+                };
+
+                // Ensure the array type matches.
+                if candidate_type != array_type {
+                    bail!("'{plaintext_type}' is invalid: expected {array_type}, found {candidate_type}")
+                }
+
+                // Ensure the number of elements does not exceed the capacity.
+                ensure!(
+                    elements.len() <= **array_type.capacity() as usize,
+                    "'{plaintext_type}' cannot exceed {} elements",
+                    array_type.capacity()
+                );
+
+                // Ensure the elements match the element type.
+                for element in elements {
+                    if element.to_type() != array_type.element_type() {
+                        bail!("'{plaintext_type}' is invalid: expected {}, found {element}", array_type.element_type())
+                    }
+                }
+
                 Ok(())
             }
         }
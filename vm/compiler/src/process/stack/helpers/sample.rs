@@ -179,6 +179,15 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Interface(members, Default::default())
             }
+            // Sample an array.
+            PlaintextType::Array(array_type) => {
+                // Sample the number of elements.
+                let length = rng.gen_range(0..=**array_type.capacity());
+                // Sample each element of the array.
+                let elements = (0..length).map(|_| Literal::sample(array_type.element_type(), rng)).collect();
+
+                Plaintext::new_array(*array_type, elements)?
+            }
         };
         // Return the plaintext.
         Ok(plaintext)
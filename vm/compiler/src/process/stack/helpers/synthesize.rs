@@ -29,6 +29,29 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
+        // Compute the request, with a burner private key.
+        let (burner_private_key, request) = self.sample_burner_request(function_name, rng)?;
+        // Initialize the authorization.
+        let authorization = Authorization::new(&[request.clone()]);
+        // Initialize the call stack.
+        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
+        // Synthesize the circuit.
+        let _response = self.execute_function::<A, R>(call_stack, rng)?;
+
+        // Ensure the proving key exists.
+        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
+        // Ensure the verifying key exists.
+        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+        Ok(())
+    }
+
+    /// Returns a burner private key, and a request for the given function name on sampled inputs, signed by it.
+    #[inline]
+    pub(crate) fn sample_burner_request<R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(PrivateKey<N>, Request<N>)> {
         // Retrieve the program ID.
         let program_id = self.program_id();
         // Retrieve the function input types.
@@ -54,18 +77,7 @@ impl<N: Network> Stack<N> {
 
         // Compute the request, with a burner private key.
         let request = Request::sign(&burner_private_key, *program_id, *function_name, &inputs, &input_types, rng)?;
-        // Initialize the authorization.
-        let authorization = Authorization::new(&[request.clone()]);
-        // Initialize the call stack.
-        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
-        // Synthesize the circuit.
-        let _response = self.execute_function::<A, R>(call_stack, rng)?;
-
-        // Ensure the proving key exists.
-        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
-        // Ensure the verifying key exists.
-        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
-        Ok(())
+        Ok((burner_private_key, request))
     }
 
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
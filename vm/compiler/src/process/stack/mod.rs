@@ -20,6 +20,9 @@ pub use authorization::*;
 mod deployment;
 pub use deployment::*;
 
+mod estimate;
+pub use estimate::*;
+
 mod execution;
 pub use execution::*;
 
@@ -35,6 +38,9 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod report;
+pub use report::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -55,6 +61,8 @@ use crate::{
     UniversalSRS,
     VerifyingKey,
 };
+#[cfg(not(target_family = "wasm"))]
+use crate::LazyProvingKey;
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -182,8 +190,13 @@ pub struct Stack<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of function name to proving key.
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
+    /// The mapping of function name to lazy proving key, which is loaded on first use.
+    #[cfg(not(target_family = "wasm"))]
+    lazy_proving_keys: Arc<RwLock<IndexMap<Identifier<N>, LazyProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of function name to the constraint report of its most recent synthesis.
+    constraint_reports: Arc<RwLock<IndexMap<Identifier<N>, ConstraintReport<N>>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -312,6 +325,10 @@ impl<N: Network> Stack<N> {
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
+        #[cfg(not(target_family = "wasm"))]
+        if self.lazy_proving_keys.read().contains_key(function_name) {
+            return true;
+        }
         self.proving_keys.read().contains_key(function_name)
     }
 
@@ -324,6 +341,9 @@ impl<N: Network> Stack<N> {
     /// Returns the proving key for the given function name.
     #[inline]
     pub fn get_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
+        // Load the proving key, if it is lazy.
+        #[cfg(not(target_family = "wasm"))]
+        self.load_lazy_proving_key(function_name)?;
         // Return the proving key, if it exists.
         match self.proving_keys.read().get(function_name) {
             Some(proving_key) => Ok(proving_key.clone()),
@@ -355,6 +375,22 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Inserts the given lazy proving key for the given function name, which is loaded on first use.
+    #[cfg(not(target_family = "wasm"))]
+    #[inline]
+    pub fn insert_lazy_proving_key(&self, function_name: &Identifier<N>, proving_key: LazyProvingKey<N>) -> Result<()> {
+        // Ensure the function name exists in the program.
+        ensure!(
+            self.program.contains_function(function_name),
+            "Function '{function_name}' does not exist in program '{}'.",
+            self.program.id()
+        );
+        // Insert the lazy proving key, replacing any loaded proving key.
+        self.proving_keys.write().remove(function_name);
+        self.lazy_proving_keys.write().insert(*function_name, proving_key);
+        Ok(())
+    }
+
     /// Inserts the given verifying key for the given function name.
     #[inline]
     pub fn insert_verifying_key(&self, function_name: &Identifier<N>, verifying_key: VerifyingKey<N>) -> Result<()> {
@@ -372,6 +408,8 @@ impl<N: Network> Stack<N> {
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
+        #[cfg(not(target_family = "wasm"))]
+        self.lazy_proving_keys.write().remove(function_name);
         self.proving_keys.write().remove(function_name);
     }
 
@@ -380,6 +418,20 @@ impl<N: Network> Stack<N> {
     pub fn remove_verifying_key(&self, function_name: &Identifier<N>) {
         self.verifying_keys.write().remove(function_name);
     }
+
+    /// Deserializes the lazy proving key for the given function name, if it exists,
+    /// and moves it into the loaded proving keys, releasing the mapping.
+    #[cfg(not(target_family = "wasm"))]
+    fn load_lazy_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
+        // Note: The lock is held while loading, so concurrent callers deserialize the proving key once.
+        let mut lazy_proving_keys = self.lazy_proving_keys.write();
+        if let Some(lazy_proving_key) = lazy_proving_keys.get(function_name) {
+            let proving_key = lazy_proving_key.get()?.clone();
+            self.proving_keys.write().insert(*function_name, proving_key);
+            lazy_proving_keys.remove(function_name);
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> PartialEq for Stack<N> {
@@ -102,6 +102,13 @@ impl<N: Network> RegisterTypes<N> {
                             function.name()
                         );
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                        bail!(
+                            "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                            stack.program_id(),
+                            function.name()
+                        );
+                    }
                     RegisterType::Record(..) => {
                         bail!(
                             "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -180,7 +187,7 @@ impl<N: Network> RegisterTypes<N> {
     fn check_input(&mut self, stack: &Stack<N>, register: &Register<N>, register_type: &RegisterType<N>) -> Result<()> {
         // Ensure the register type is defined in the program.
         match register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+            RegisterType::Plaintext(PlaintextType::Literal(..)) | RegisterType::Plaintext(PlaintextType::Array(..)) => (),
             RegisterType::Plaintext(PlaintextType::Interface(interface_name)) => {
                 // Ensure the interface is defined in the program.
                 if !stack.program().contains_interface(interface_name) {
@@ -226,7 +233,7 @@ impl<N: Network> RegisterTypes<N> {
 
         // Ensure the register type is defined in the program.
         match register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+            RegisterType::Plaintext(PlaintextType::Literal(..)) | RegisterType::Plaintext(PlaintextType::Array(..)) => (),
             RegisterType::Plaintext(PlaintextType::Interface(interface_name)) => {
                 // Ensure the interface is defined in the program.
                 if !stack.program().contains_interface(interface_name) {
@@ -313,6 +320,24 @@ impl<N: Network> RegisterTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Array(opcode) => {
+                // Ensure the instruction belongs to the defined set.
+                if !["array.get", "array.len"].contains(&opcode) {
+                    bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
+                }
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "array.get" => ensure!(
+                        matches!(instruction, Instruction::ArrayGet(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "array.len" => ensure!(
+                        matches!(instruction, Instruction::ArrayLen(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
             Opcode::Assert(opcode) => {
                 // Ensure the instruction belongs to the defined set.
                 if !["assert.eq", "assert.neq"].contains(&opcode) {
@@ -412,6 +437,10 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the operand types match the interface.
                         self.matches_interface(stack, instruction.operands(), &interface)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(record_name) => {
                         // Ensure the record type is defined in the program.
                         if !stack.program().contains_record(record_name) {
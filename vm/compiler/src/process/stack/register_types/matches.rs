@@ -89,6 +89,30 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Checks that the given operands match the element type of the array, and are within its capacity.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of elements does not exceed the capacity.
+        let num_elements = operands.len();
+        ensure!(
+            num_elements <= **array_type.capacity() as usize,
+            "'{array_type}' cannot exceed {} elements, found {num_elements} elements",
+            array_type.capacity()
+        );
+
+        // Ensure the operand types match the element type.
+        let element_type = RegisterType::Plaintext(PlaintextType::Literal(array_type.element_type()));
+        for operand in operands {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == element_type,
+                "Array '{array_type}' expects elements of type {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` and `gates` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -21,6 +21,7 @@ use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Progr
 use console::{
     network::prelude::*,
     program::{
+        ArrayType,
         EntryType,
         Identifier,
         Interface,
@@ -115,6 +116,8 @@ impl<N: Network> RegisterTypes<N> {
             register_type = match &register_type {
                 // Ensure the plaintext type is not a literal, as the register references a member.
                 RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+                // Ensure the plaintext type is not an array, as the register references a member.
+                RegisterType::Plaintext(PlaintextType::Array(..)) => bail!("'{register}' references an array."),
                 // Traverse the member path to output the register type.
                 RegisterType::Plaintext(PlaintextType::Interface(interface_name)) => {
                     // Retrieve the member type from the interface.
@@ -25,6 +25,7 @@ use console::{
 };
 
 use indexmap::IndexMap;
+use rand::{rngs::StdRng, SeedableRng};
 
 #[derive(Clone)]
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
@@ -44,6 +45,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The RNG for the requests and proofs of function calls, which is seeded from the RNG of the caller.
+    rng: Option<StdRng>,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -59,6 +62,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            rng: None,
         }
     }
 
@@ -116,6 +120,21 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.tvk_circuit = Some(tvk_circuit);
     }
 
+    /// Sets the RNG for the requests and proofs of function calls, from the given seed.
+    #[inline]
+    pub fn set_rng_seed(&mut self, seed: [u8; 32]) {
+        self.rng = Some(StdRng::from_seed(seed));
+    }
+
+    /// Returns a new seed for the RNG of a function call.
+    #[inline]
+    pub fn sample_rng_seed(&mut self) -> Result<[u8; 32]> {
+        match &mut self.rng {
+            Some(rng) => Ok(rng.gen()),
+            None => bail!("The RNG for function calls is not set in the registers."),
+        }
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The constraint counts of a function circuit, for each section of the circuit, in order of synthesis:
+///  - `request`: the request signature, the serial numbers, and the input commitments and ciphertexts,
+///  - `function`: the instructions of the function,
+///  - `response`: the output commitments, and the encryption of the output records,
+///  - `finalize`: the checksum of the finalize inputs, if the function has a finalize command,
+///  - `fee`: the balance of the input and output records.
+///
+/// The `function` section is further broken down by instruction, in program order. The metrics of a `call`
+/// instruction include the entire circuit of the called closure or function.
+///
+/// Note: Merkle paths for state inclusion are proven outside of the function circuit, so they are not included.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstraintReport<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The metrics of each section of the circuit, in order of synthesis.
+    sections: Vec<(&'static str, circuit::CircuitMetrics)>,
+    /// The metrics of each instruction of the function, in program order.
+    instructions: Vec<(String, circuit::CircuitMetrics)>,
+}
+
+impl<N: Network> ConstraintReport<N> {
+    /// Initializes a new constraint report.
+    pub(crate) fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        sections: Vec<(&'static str, circuit::CircuitMetrics)>,
+        instructions: Vec<(String, circuit::CircuitMetrics)>,
+    ) -> Self {
+        Self { program_id, function_name, sections, instructions }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the metrics of each section of the circuit, in order of synthesis.
+    pub fn sections(&self) -> &[(&'static str, circuit::CircuitMetrics)] {
+        &self.sections
+    }
+
+    /// Returns the metrics of the given section of the circuit, if it exists.
+    pub fn get(&self, section: &str) -> Option<&circuit::CircuitMetrics> {
+        self.sections.iter().find(|(name, _)| *name == section).map(|(_, metrics)| metrics)
+    }
+
+    /// Returns the metrics of each instruction of the function, in program order.
+    pub fn instructions(&self) -> &[(String, circuit::CircuitMetrics)] {
+        &self.instructions
+    }
+
+    /// Returns the indices of the `n` instructions with the most constraints, in decreasing order of constraints.
+    pub fn most_expensive_instructions(&self, n: usize) -> Vec<usize> {
+        let mut indices = (0..self.instructions.len()).collect::<Vec<_>>();
+        // Note: The sort is stable, so instructions with the same number of constraints remain in program order.
+        indices.sort_by_key(|index| core::cmp::Reverse(self.instructions[*index].1.num_constraints()));
+        indices.truncate(n);
+        indices
+    }
+
+    /// Returns the metrics of the entire circuit.
+    pub fn total(&self) -> circuit::CircuitMetrics {
+        self.sections.iter().fold(circuit::CircuitMetrics::default(), |total, (_, metrics)| total + *metrics)
+    }
+}
+
+impl<N: Network> Display for ConstraintReport<N> {
+    /// Prints the constraint report, with one line per section, and one line per instruction of the function.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}/{}", self.program_id, self.function_name)?;
+        for (section, metrics) in &self.sections {
+            writeln!(f, "  {section:10} {metrics}")?;
+            // Break down the function section by instruction.
+            if *section == "function" {
+                for (index, (instruction, metrics)) in self.instructions.iter().enumerate() {
+                    writeln!(f, "    {index:>4}: {instruction}\n          {metrics}")?;
+                }
+            }
+        }
+        write!(f, "  {:10} {}", "total", self.total())
+    }
+}
+
+impl<N: Network> Serialize for ConstraintReport<N> {
+    /// Serializes the constraint report into a structured form, such as JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        /// A wrapper to serialize the metrics as a struct.
+        struct SerializeMetrics<'a>(&'a circuit::CircuitMetrics);
+
+        impl Serialize for SerializeMetrics<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut metrics = serializer.serialize_struct("Metrics", 5)?;
+                metrics.serialize_field("constants", &self.0.num_constants())?;
+                metrics.serialize_field("public", &self.0.num_public())?;
+                metrics.serialize_field("private", &self.0.num_private())?;
+                metrics.serialize_field("constraints", &self.0.num_constraints())?;
+                metrics.serialize_field("gates", &self.0.num_gates())?;
+                metrics.end()
+            }
+        }
+
+        /// A wrapper to serialize an instruction and its metrics as a struct.
+        struct SerializeInstruction<'a>(usize, &'a str, &'a circuit::CircuitMetrics);
+
+        impl Serialize for SerializeInstruction<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut instruction = serializer.serialize_struct("Instruction", 3)?;
+                instruction.serialize_field("index", &self.0)?;
+                instruction.serialize_field("instruction", self.1)?;
+                instruction.serialize_field("metrics", &SerializeMetrics(self.2))?;
+                instruction.end()
+            }
+        }
+
+        let sections = self.sections.iter().map(|(section, metrics)| (*section, SerializeMetrics(metrics)));
+        let instructions = self
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(index, (instruction, metrics))| SerializeInstruction(index, instruction, metrics));
+
+        let mut report = serializer.serialize_struct("ConstraintReport", 5)?;
+        report.serialize_field("program_id", &self.program_id.to_string())?;
+        report.serialize_field("function_name", &self.function_name.to_string())?;
+        report.serialize_field("sections", &sections.collect::<IndexMap<_, _>>())?;
+        report.serialize_field("instructions", &instructions.collect::<Vec<_>>())?;
+        report.serialize_field("total", &SerializeMetrics(&self.total()))?;
+        report.end()
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the constraint report for the given function name.
+    ///
+    /// If the function has not been synthesized yet, its circuit is synthesized on sampled inputs,
+    /// without synthesizing its proving key.
+    #[inline]
+    pub fn constraint_report<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<ConstraintReport<N>> {
+        // If the function has not been synthesized yet, synthesize its circuit.
+        if !self.constraint_reports.read().contains_key(function_name) {
+            // Sample a request, with a burner private key.
+            let (burner_private_key, request) = self.sample_burner_request(function_name, rng)?;
+            // Initialize the call stack.
+            let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, Default::default());
+            // Synthesize the circuit.
+            let _response = self.execute_function::<A, R>(call_stack, rng)?;
+        }
+
+        // Retrieve the constraint report.
+        self.constraint_reports
+            .read()
+            .get(function_name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Constraint report for '{}/{function_name}' does not exist", self.program_id()))
+    }
+
+    /// Inserts the constraint report for the given function name.
+    #[inline]
+    pub(crate) fn insert_constraint_report(&self, function_name: &Identifier<N>, report: ConstraintReport<N>) {
+        self.constraint_reports.write().insert(*function_name, report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_constraint_report() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize a program with a record output.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program report.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    mul.w r0 r0 into r1;
+    cast self.caller 0u64 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )?;
+        let process = Process::<CurrentNetwork>::load()?;
+        let stack = Stack::new(&process, &program)?;
+        let function_name = Identifier::from_str("mint")?;
+
+        // Ensure the report covers each section of the circuit, in order.
+        let report = stack.constraint_report::<CurrentAleo, _>(&function_name, rng)?;
+        let sections = report.sections().iter().map(|(section, _)| *section).collect::<Vec<_>>();
+        assert_eq!(vec!["request", "function", "response", "finalize", "fee"], sections);
+        assert!(report.get("request").unwrap().num_constraints() > 0);
+        assert!(report.get("function").unwrap().num_constraints() > 0);
+        assert!(report.get("response").unwrap().num_constraints() > 0);
+        assert_eq!(0, report.get("finalize").unwrap().num_constraints());
+        assert!(report.get("unknown").is_none());
+
+        // Ensure the total matches the synthesized circuit.
+        let (burner_private_key, request) = stack.sample_burner_request(&function_name, rng)?;
+        let assignments = Assignments::<CurrentNetwork>::default();
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+        stack.execute_function::<CurrentAleo, _>(call_stack, rng)?;
+        let assignment = assignments.read()[0].clone();
+        assert_eq!(assignment.num_constraints(), report.total().num_constraints());
+        assert_eq!(assignment.num_private(), report.total().num_private());
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_report_instructions() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize a program, where the second instruction is the most expensive.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program profile.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as u64.private;
+    add r0 r0 into r2;
+    hash.psd2 r0 into r3;
+    mul.w r1 r1 into r4;
+    output r3 as field.private;
+    output r4 as u64.private;",
+        )?;
+        let process = Process::<CurrentNetwork>::load()?;
+        let stack = Stack::new(&process, &program)?;
+        let function_name = Identifier::from_str("compute")?;
+
+        // Ensure the report contains each instruction, in program order.
+        let report = stack.constraint_report::<CurrentAleo, _>(&function_name, rng)?;
+        let instructions = report.instructions().iter().map(|(instruction, _)| instruction.as_str());
+        let instructions = instructions.collect::<Vec<_>>();
+        assert_eq!(vec!["add r0 r0 into r2;", "hash.psd2 r0 into r3;", "mul.w r1 r1 into r4;"], instructions);
+
+        // Ensure the costs are attributed to each instruction.
+        assert_eq!(0, report.instructions()[0].1.num_constraints());
+        assert!(report.instructions()[1].1.num_constraints() > report.instructions()[2].1.num_constraints());
+        assert!(report.instructions()[2].1.num_constraints() > 0);
+        assert_eq!(vec![1, 2], report.most_expensive_instructions(2));
+
+        // Ensure the instructions do not exceed the function section.
+        let total = report
+            .instructions()
+            .iter()
+            .fold(circuit::CircuitMetrics::default(), |total, (_, metrics)| total + *metrics);
+        assert!(total.num_constraints() <= report.get("function").unwrap().num_constraints());
+
+        // Ensure the structured report contains each instruction.
+        let json = serde_json::to_value(&report)?;
+        assert_eq!(json["program_id"], "profile.aleo");
+        assert_eq!(json["function_name"], "compute");
+        assert_eq!(json["instructions"][1]["instruction"], "hash.psd2 r0 into r3;");
+        assert_eq!(json["instructions"][1]["metrics"]["constraints"], report.instructions()[1].1.num_constraints());
+        assert_eq!(json["sections"]["function"]["constraints"], report.get("function").unwrap().num_constraints());
+        assert_eq!(json["total"]["constraints"], report.total().num_constraints());
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+#[cfg(feature = "parallel")]
+use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The environment variable for the number of threads that synthesize independent circuits.
+pub const SYNTHESIS_THREADS_VARIABLE: &str = "SNARKVM_SYNTHESIS_THREADS";
+
+/// The number of threads that synthesize independent circuits, or `0` if it is not set.
+static SYNTHESIS_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// A handler that runs each synthesis thread, e.g. on a web worker.
+#[cfg(feature = "parallel")]
+pub type SynthesisSpawnHandler = Box<dyn Fn(rayon::ThreadBuilder) -> std::io::Result<()> + Send + Sync>;
+
+/// The handler that runs each synthesis thread, if one is set.
+#[cfg(feature = "parallel")]
+static SYNTHESIS_SPAWN_HANDLER: OnceCell<SynthesisSpawnHandler> = OnceCell::new();
+
+/// Sets the number of threads that synthesize independent circuits, where `0` restores the default.
+pub fn set_synthesis_threads(num_threads: usize) {
+    SYNTHESIS_THREADS.store(num_threads, Ordering::SeqCst);
+}
+
+/// Sets the handler that runs each synthesis thread, in place of `std::thread::spawn`.
+///
+/// This is the hook for targets without native threads, such as `wasm32-unknown-unknown`, where the embedder
+/// runs each `rayon::ThreadBuilder` on a web worker. On these targets, without a handler, circuits are
+/// synthesized on the calling thread, and the number of threads should be set with `set_synthesis_threads`,
+/// e.g. to `navigator.hardwareConcurrency`. The handler may only be set once.
+#[cfg(feature = "parallel")]
+pub fn set_synthesis_spawn_handler(
+    handler: impl Fn(rayon::ThreadBuilder) -> std::io::Result<()> + Send + Sync + 'static,
+) -> Result<()> {
+    SYNTHESIS_SPAWN_HANDLER
+        .set(Box::new(handler))
+        .map_err(|_| anyhow!("The synthesis spawn handler is already set"))
+}
+
+/// Returns the number of threads that synthesize independent circuits.
+///
+/// This is the number set by `set_synthesis_threads`, if any, or else the `SNARKVM_SYNTHESIS_THREADS`
+/// environment variable, if it is a nonzero integer, or else the number of threads in the current pool.
+pub fn synthesis_threads() -> usize {
+    match SYNTHESIS_THREADS.load(Ordering::SeqCst) {
+        0 => std::env::var(SYNTHESIS_THREADS_VARIABLE)
+            .ok()
+            .and_then(|num_threads| num_threads.trim().parse::<usize>().ok())
+            .filter(|num_threads| *num_threads > 0)
+            .unwrap_or_else(default_threads),
+        num_threads => num_threads,
+    }
+}
+
+/// Returns the default number of threads that synthesize independent circuits.
+fn default_threads() -> usize {
+    // Note: On WebAssembly, the global pool may not be able to spawn threads, so it is not queried.
+    #[cfg(all(feature = "parallel", not(target_family = "wasm")))]
+    return rayon::current_num_threads();
+    #[cfg(any(not(feature = "parallel"), target_family = "wasm"))]
+    return 1;
+}
+
+/// Runs the given synthesis on a pool with `synthesis_threads` threads.
+///
+/// Each thread has its own circuit environment, so independent circuits may be synthesized
+/// concurrently, as long as each circuit is synthesized on a single thread.
+pub(crate) fn synthesize_in_pool<T: Send>(f: impl FnOnce() -> T + Send) -> Result<T> {
+    #[cfg(feature = "parallel")]
+    {
+        let num_threads = synthesis_threads();
+        match SYNTHESIS_SPAWN_HANDLER.get() {
+            // Run the threads of the pool with the spawn handler.
+            Some(handler) => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .spawn_handler(handler)
+                    .build()?;
+                return Ok(pool.install(f));
+            }
+            // Without a spawn handler, WebAssembly targets synthesize on the calling thread.
+            None if cfg!(target_family = "wasm") => return Ok(f()),
+            None => (),
+        }
+        // Reuse the current pool, if it has the requested number of threads.
+        if num_threads == rayon::current_num_threads() {
+            return Ok(f());
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|i| format!("synthesis-{i}"))
+            .build()?;
+        Ok(pool.install(f))
+    }
+    #[cfg(not(feature = "parallel"))]
+    Ok(f())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthesis_threads() -> Result<()> {
+        // Ensure the explicit number of threads takes precedence.
+        set_synthesis_threads(3);
+        assert_eq!(3, synthesis_threads());
+        assert_eq!(3, synthesize_in_pool(synthesis_threads)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!(3, synthesize_in_pool(rayon::current_num_threads)?);
+
+        // Ensure the default is restored.
+        set_synthesis_threads(0);
+        assert!(synthesis_threads() > 0);
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_synthesis_spawn_handler() -> Result<()> {
+        static NUM_SPAWNED: AtomicUsize = AtomicUsize::new(0);
+
+        // Set a spawn handler that counts the threads it runs.
+        set_synthesis_spawn_handler(|thread| {
+            NUM_SPAWNED.fetch_add(1, Ordering::SeqCst);
+            std::thread::spawn(move || thread.run());
+            Ok(())
+        })?;
+        assert!(set_synthesis_spawn_handler(|_| Ok(())).is_err());
+
+        // Ensure the pool is run by the spawn handler.
+        assert!(synthesize_in_pool(rayon::current_num_threads)? > 0);
+        assert!(NUM_SPAWNED.load(Ordering::SeqCst) > 0);
+        Ok(())
+    }
+}
@@ -86,6 +86,7 @@ impl<N: Network> Decrement<N> {
         let start = match store.get_value(stack.program_id(), &self.mapping, &key)? {
             Some(Value::Plaintext(Plaintext::Literal(literal, _))) => literal,
             Some(Value::Plaintext(Plaintext::Interface(..))) => bail!("Cannot 'decrement' by an 'interface'"),
+            Some(Value::Plaintext(Plaintext::Array(..))) => bail!("Cannot 'decrement' by an 'array'"),
             Some(Value::Record(..)) => bail!("Cannot 'decrement' by a 'record'"),
             // If the key does not exist, set the starting value to 0.
             // Infer the starting type from the decrement type.
@@ -105,6 +106,8 @@ impl<N: Network> Decrement<N> {
                 Literal::U64(..) => Literal::U64(Zero::zero()),
                 Literal::U128(..) => Literal::U128(Zero::zero()),
                 Literal::Scalar(..) => Literal::Scalar(Zero::zero()),
+                Literal::I256(..) => Literal::I256(Zero::zero()),
+                Literal::U256(..) => Literal::U256(Zero::zero()),
                 Literal::String(..) => bail!("Cannot 'decrement' by a 'string'"),
             },
         };
@@ -124,6 +127,8 @@ impl<N: Network> Decrement<N> {
             (Literal::U64(a), Literal::U64(b)) => Literal::U64(a.sub(b)),
             (Literal::U128(a), Literal::U128(b)) => Literal::U128(a.sub(b)),
             (Literal::Scalar(a), Literal::Scalar(b)) => Literal::Scalar(a.sub(b)),
+            (Literal::I256(a), Literal::I256(b)) => Literal::I256(a.sub(b)),
+            (Literal::U256(a), Literal::U256(b)) => Literal::U256(a.sub(b)),
             (a, b) => bail!("Cannot 'decrement' '{a}' by '{b}'"),
         };
 
@@ -69,6 +69,10 @@ pub enum Instruction<N: Network> {
     AddWrapped(AddWrapped<N>),
     /// Performs a bitwise `and` operation on `first` and `second`, storing the outcome in `destination`.
     And(And<N>),
+    /// Loads the element at index `second` of the array `first`, storing the outcome in `destination`.
+    ArrayGet(ArrayGet<N>),
+    /// Loads the number of elements in the array `first`, storing the outcome in `destination`.
+    ArrayLen(ArrayLen<N>),
     /// Asserts `first` and `second` are equal.
     AssertEq(AssertEq<N>),
     /// Asserts `first` and `second` are **not** equal.
@@ -95,6 +99,10 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Shifts `first` left by `third` bits, shifting in the upper bits of `second`, storing the outcome in `destination`.
+    FunnelShl(FunnelShl<N>),
+    /// Shifts `second` right by `third` bits, shifting in the lower bits of `first`, storing the outcome in `destination`.
+    FunnelShr(FunnelShr<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -151,6 +159,10 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Rotates `first` left by `second` bits, storing the outcome in `destination`.
+    Rotl(Rotl<N>),
+    /// Rotates `first` right by `second` bits, storing the outcome in `destination`.
+    Rotr(Rotr<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, continuing past the boundary of the type, storing the outcome in `destination`.
@@ -207,6 +219,8 @@ macro_rules! instruction {
             Add,
             AddWrapped,
             And,
+            ArrayGet,
+            ArrayLen,
             AssertEq,
             AssertNeq,
             Call,
@@ -220,6 +234,8 @@ macro_rules! instruction {
             Div,
             DivWrapped,
             Double,
+            FunnelShl,
+            FunnelShr,
             GreaterThan,
             GreaterThanOrEqual,
             HashBHP256,
@@ -248,6 +264,8 @@ macro_rules! instruction {
             PowWrapped,
             Rem,
             RemWrapped,
+            Rotl,
+            Rotr,
             Shl,
             ShlWrapped,
             Shr,
@@ -404,7 +422,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            62,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -19,6 +19,8 @@ use console::network::prelude::*;
 /// The `Opcode` enum stores the mnemonic for the instruction.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Opcode {
+    /// The opcode is for an array operation (i.e. `array.get`).
+    Array(&'static str),
     /// The opcode is for a assert operation (i.e. `assert`).
     Assert(&'static str),
     /// The opcode is for a call operation (i.e. `call`).
@@ -45,6 +47,7 @@ impl Deref for Opcode {
     /// Returns the opcode as a string.
     fn deref(&self) -> &Self::Target {
         match self {
+            Opcode::Array(opcode) => opcode,
             Opcode::Assert(opcode) => opcode,
             Opcode::Call => &"call",
             Opcode::Cast => &"cast",
@@ -70,6 +73,7 @@ impl Display for Opcode {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // write!(f, "{}", *self)
         match self {
+            Self::Array(opcode) => write!(f, "{opcode}"),
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Call => write!(f, "{}", self.deref()),
             Self::Cast => write!(f, "{}", self.deref()),
@@ -0,0 +1,468 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U32,
+};
+
+/// Loads the element at index `second` of the array `first`, storing the outcome in `destination`.
+pub type ArrayGet<N> = ArrayInstruction<N, { Variant::ArrayGet as u8 }>;
+/// Loads the number of elements in the array `first` as a `u32`, storing the outcome in `destination`.
+pub type ArrayLen<N> = ArrayInstruction<N, { Variant::ArrayLen as u8 }>;
+
+enum Variant {
+    ArrayGet,
+    ArrayLen,
+}
+
+/// Computes an operation on an array, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> ArrayInstruction<N, VARIANT> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Array("array.get"),
+            1 => Opcode::Array("array.len"),
+            _ => panic!("Invalid 'array' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands of the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            0 => 2,
+            1 => 1,
+            _ => panic!("Invalid 'array' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is the expected number of inputs.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ArrayInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Load the elements of the array.
+        let elements = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(_, elements, _)) => elements,
+            _ => bail!("Instruction '{}' expects the first operand to be an array", Self::opcode()),
+        };
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => match registers.load_literal(stack, &self.operands[1])? {
+                Literal::U32(index) => match elements.get(*index as usize) {
+                    Some(element) => element.clone(),
+                    None => bail!("Index {index} is out of bounds for an array of {} elements", elements.len()),
+                },
+                _ => bail!("Instruction '{}' expects the index to be a 'u32'", Self::opcode()),
+            },
+            1 => Literal::U32(U32::new(elements.len() as u32)),
+            _ => bail!("Invalid 'array' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Load the array.
+        let array = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(array @ circuit::Plaintext::Array(..)) => array,
+            _ => bail!("Instruction '{}' expects the first operand to be an array", Self::opcode()),
+        };
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => match registers.load_literal_circuit(stack, &self.operands[1])? {
+                circuit::Literal::U32(index) => array.array_get(&index),
+                _ => bail!("Instruction '{}' expects the index to be a 'u32'", Self::opcode()),
+            },
+            1 => circuit::Literal::U32(array.array_len()),
+            _ => bail!("Invalid 'array' variant: {VARIANT}"),
+        };
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Ensure the first operand is an array.
+        let array_type = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => array_type,
+            _ => bail!("Instruction '{}' expects an array, found '{}'", Self::opcode(), input_types[0]),
+        };
+
+        match VARIANT {
+            0 => {
+                // Ensure the index is a 'u32'.
+                if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)) {
+                    bail!("Instruction '{}' expects a 'u32' index, found '{}'", Self::opcode(), input_types[1])
+                }
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(array_type.element_type()))])
+            }
+            1 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))]),
+            _ => bail!("Invalid 'array' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for ArrayInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (mut string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        for _ in 0..Self::num_operands() {
+            // Parse the whitespace from the string.
+            let (next, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            let (next, operand) = Operand::parse(next)?;
+            string = next;
+            operands.push(operand);
+        }
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for ArrayInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for ArrayInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for ArrayInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            eprintln!("The number of operands must be {}, found {}", Self::num_operands(), self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for ArrayInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for ArrayInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+    fn sample_stack(mode: circuit::Mode) -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        // Initialize the program.
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as [u64; 4u32].{mode};
+                input r1 as u32.{mode};
+                array.get r0 r1 into r2;
+                array.len r0 into r3;
+        "
+        ))?;
+
+        // Initialize the stack.
+        Stack::new(&Process::load()?, &program)
+    }
+
+    /// Samples the registers. Note: Do not replicate this for real program use, it is insecure.
+    fn sample_registers(
+        stack: &Stack<CurrentNetwork>,
+        array: &str,
+        index: u32,
+        mode: Option<circuit::Mode>,
+    ) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+        use crate::{Authorization, CallStack};
+        use console::program::Identifier;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+
+        // Initialize the console values.
+        let value_a = Value::Plaintext(Plaintext::from_str(array)?);
+        let value_b = Value::Plaintext(Plaintext::from(Literal::U32(U32::new(index))));
+
+        // Store the values in the console registers.
+        registers.store(stack, &Register::Locator(0), value_a.clone())?;
+        registers.store(stack, &Register::Locator(1), value_b.clone())?;
+
+        if let Some(mode) = mode {
+            use circuit::Inject;
+
+            // Store the values in the circuit registers.
+            registers.store_circuit(stack, &Register::Locator(0), circuit::Value::new(mode, value_a))?;
+            registers.store_circuit(stack, &Register::Locator(1), circuit::Value::new(mode, value_b))?;
+        }
+
+        Ok(registers)
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, array_get) = ArrayGet::<CurrentNetwork>::parse("array.get r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(array_get.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(array_get.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!("array.get r0 r1 into r2", array_get.to_string());
+
+        let (string, array_len) = ArrayLen::<CurrentNetwork>::parse("array.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(array_len.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(array_len.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!("array.len r0 into r1", array_len.to_string());
+
+        // Ensure the wrong number of operands fails to parse.
+        assert!(ArrayGet::<CurrentNetwork>::from_str("array.get r0 into r1").is_err());
+        assert!(ArrayLen::<CurrentNetwork>::from_str("array.len r0 r1 into r2").is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        for string in ["array.get r0 r1 into r2", "array.get r0 3u32 into r1"] {
+            let expected = ArrayGet::<CurrentNetwork>::from_str(string).unwrap();
+            let candidate = ArrayGet::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+        }
+        let expected = ArrayLen::<CurrentNetwork>::from_str("array.len r0 into r1").unwrap();
+        let candidate = ArrayLen::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_array() {
+        use circuit::{Eject, Environment};
+
+        let array = "[5u64, 6u64, 7u64] as [u64; 4u32]";
+        let array_get = ArrayGet::<CurrentNetwork>::from_str("array.get r0 r1 into r2").unwrap();
+        let array_len = ArrayLen::<CurrentNetwork>::from_str("array.len r0 into r3").unwrap();
+        let (r2, r3) = (Operand::Register(Register::Locator(2)), Operand::Register(Register::Locator(3)));
+        let length = Literal::U32(U32::new(3));
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let stack = sample_stack(mode).unwrap();
+
+            for (index, expected) in [5u64, 6, 7].into_iter().enumerate() {
+                let expected = Literal::U64(console::types::U64::new(expected));
+
+                // Ensure the console outputs are correct.
+                let mut registers = sample_registers(&stack, array, index as u32, None).unwrap();
+                array_get.evaluate(&stack, &mut registers).unwrap();
+                array_len.evaluate(&stack, &mut registers).unwrap();
+                assert_eq!(expected, registers.load_literal(&stack, &r2).unwrap());
+                assert_eq!(length, registers.load_literal(&stack, &r3).unwrap());
+
+                // Ensure the circuit outputs are correct.
+                let mut registers = sample_registers(&stack, array, index as u32, Some(mode)).unwrap();
+                array_get.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+                array_len.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+                assert_eq!(expected, registers.load_literal_circuit(&stack, &r2).unwrap().eject_value());
+                assert_eq!(length, registers.load_literal_circuit(&stack, &r3).unwrap().eject_value());
+                assert!(CurrentAleo::is_satisfied());
+                CurrentAleo::reset();
+            }
+        }
+
+        // Ensure an index beyond the number of elements fails.
+        let stack = sample_stack(circuit::Mode::Private).unwrap();
+        for index in [3, 4, u32::MAX] {
+            let mut registers = sample_registers(&stack, array, index, None).unwrap();
+            assert!(array_get.evaluate(&stack, &mut registers).is_err());
+
+            let mut registers = sample_registers(&stack, array, index, Some(circuit::Mode::Private)).unwrap();
+            array_get.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+            assert!(!CurrentAleo::is_satisfied());
+            CurrentAleo::reset();
+        }
+    }
+
+    #[test]
+    fn test_array_fails() {
+        use crate::{Process, Program};
+
+        // Ensure the operands must be an array and a 'u32' index.
+        for (input_a, input_b) in [("u64", "u32"), ("[u64; 4u32]", "u64"), ("[u64; 4u32]", "field")] {
+            let program = Program::<CurrentNetwork>::from_str(&format!(
+                "program testing.aleo;
+                function run:
+                    input r0 as {input_a}.private;
+                    input r1 as {input_b}.private;
+                    array.get r0 r1 into r2;
+            "
+            ))
+            .unwrap();
+            assert!(Stack::new(&Process::load().unwrap(), &program).is_err());
+        }
+    }
+}
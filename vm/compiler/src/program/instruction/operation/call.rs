@@ -20,6 +20,8 @@ use console::{
     program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
 };
 
+use rand::{rngs::StdRng, SeedableRng};
+
 /// The operator references a function name or closure name.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum CallOperator<N: Network> {
@@ -288,8 +290,8 @@ impl<N: Network> Call<N> {
                 // Eject the circuit inputs.
                 let inputs = inputs.eject_value();
 
-                // Initialize an RNG.
-                let rng = &mut rand::thread_rng();
+                // Initialize an RNG, seeded from the RNG of the caller.
+                let rng = &mut StdRng::from_seed(registers.sample_rng_seed()?);
 
                 match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
@@ -32,7 +32,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::Field,
+    types::{Field, U32},
 };
 
 use indexmap::IndexMap;
@@ -119,6 +119,21 @@ impl<N: Network> Cast<N> {
                 // Store the interface.
                 registers.store(stack, &self.destination, Value::Plaintext(interface))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Retrieve the elements, ensuring each operand is a literal.
+                let elements = inputs
+                    .into_iter()
+                    .map(|input| match input {
+                        Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
+                        _ => bail!("Casting to an array requires literal operands"),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Construct the array, ensuring the elements match the array type.
+                let array = Plaintext::new_array(array_type, elements)?;
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -250,6 +265,29 @@ impl<N: Network> Cast<N> {
                 // Store the interface.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(interface))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Retrieve the elements, ensuring each operand is a literal.
+                let mut elements = inputs
+                    .into_iter()
+                    .map(|input| match input {
+                        circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
+                        _ => bail!("Casting to an array requires literal operands"),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Ensure the elements match the array type.
+                Plaintext::new_array(array_type, elements.eject_value())?;
+
+                // Initialize the number of elements, as a constant.
+                let length = circuit::U32::constant(U32::new(elements.len() as u32));
+                // Fill the unused capacity with the padding, as constants.
+                elements.resize(**array_type.capacity() as usize, circuit::Literal::constant(array_type.padding()));
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(array_type, length, elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -380,6 +418,23 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of elements does not exceed the capacity.
+                ensure!(
+                    input_types.len() <= **array_type.capacity() as usize,
+                    "Array '{array_type}' cannot exceed {} elements, found {} elements",
+                    array_type.capacity(),
+                    input_types.len()
+                );
+                // Ensure the input types match the element type.
+                let element_type = RegisterType::Plaintext(PlaintextType::Literal(array_type.element_type()));
+                for input_type in input_types {
+                    ensure!(
+                        *input_type == element_type,
+                        "Array '{array_type}' element type mismatch: expected '{element_type}', found '{input_type}'"
+                    )
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(&record_name)?;
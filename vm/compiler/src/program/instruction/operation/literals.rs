@@ -152,7 +152,8 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
             .copied()
             .map(|input_type| match input_type {
                 RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Ok(literal_type),
-                RegisterType::Plaintext(PlaintextType::Interface(..)) => {
+                RegisterType::Plaintext(PlaintextType::Interface(..))
+                | RegisterType::Plaintext(PlaintextType::Array(..)) => {
                     bail!("Expected literal type, found '{input_type}'")
                 }
                 RegisterType::Record(..) => bail!("Expected literal type, found '{input_type}'"),
@@ -95,6 +95,9 @@ macro_rules! operation {
             mod [<test _ $operate>] {
                 use super::$name;
                 use console::{network::prelude::*, types::*};
+                // Note: The `String` literal is backed by `StringType`, so the cases may refer to it as `String`.
+                #[allow(unused_imports)]
+                use console::types::StringType as String;
 
                 // Prepare the environment.
                 type CurrentNetwork = console::network::Testnet3;
@@ -150,6 +153,9 @@ macro_rules! operation {
             mod [<test _ $operate>] {
                 use super::$name;
                 use console::{network::prelude::*, types::*};
+                // Note: The `String` literal is backed by `StringType`, so the cases may refer to it as `String`.
+                #[allow(unused_imports)]
+                use console::types::StringType as String;
 
                 // Prepare the environment.
                 type CurrentNetwork = console::network::Testnet3;
@@ -358,6 +364,8 @@ mod tests {
                 console::program::Literal::U128(console::types::U128::rand($rng)),
                 console::program::Literal::Scalar(console::types::Scalar::rand($rng)),
                 console::program::Literal::String(console::types::StringType::rand($rng)),
+                console::program::Literal::I256(console::types::I256::rand($rng)),
+                console::program::Literal::U256(console::types::U256::rand($rng)),
             ]
         };
     }
@@ -742,6 +750,21 @@ mod tests {
                                 // This indicator is later used in the for-loops below.
                                 is_division_operator |= true;
                             };
+                            // Note: 256-bit integers are not backed by a primitive, so they are checked by value.
+                            ("ensure 256-bit overflows halt") => {
+                                match *<$operation as $crate::Operation<_, _, _, 2>>::OPCODE {
+                                    "add" => should_succeed &= a.checked_add(&b).is_some(),
+                                    "div" => should_succeed &= a.checked_div(&b).is_some(),
+                                    "mul" => should_succeed &= a.checked_mul(&b).is_some(),
+                                    "sub" => should_succeed &= a.checked_sub(&b).is_some(),
+                                    _ => panic!("Unsupported test enforcement for '{}'", <$operation as $crate::Operation<_, _, _, 2>>::OPCODE),
+                                }
+                            };
+                            ("ensure 256-bit divide by zero halts") => {
+                                should_succeed &= !b.is_zero();
+                                // This indicator is later used in the for-loops below.
+                                is_division_operator |= true;
+                            };
                         }
                         // Check the conditions.
                         $( $( check_condition!($condition); )+ )?
@@ -822,6 +845,9 @@ mod tests {
         //   2. "ensure divide by zero halts"
         //     - If the sampled divisor is zero, ensure it halts.
         //     - If the sampled divisor is **not** zero, ensure it succeeds.
+        //   3. "ensure unequal lengths halt"
+        //     - If the sampled second and third strings differ in length, ensure it halts.
+        //     - If the sampled second and third strings have the same length, ensure it succeeds.
         ($operator:tt::$operate:tt == $operation:tt::$execute:tt for ($input_a:ident, $input_b:ident, $input_c:ident) => $output:ident $( ($($condition:tt),+) )?) => {
             paste::paste! {
                 #[test]
@@ -855,6 +881,21 @@ mod tests {
                         // Initialize an indicator whether the operation should succeed or not.
                         #[allow(unused_mut)]
                         let mut should_succeed = true;
+                        // This indicator bit is used to check that a case panics on halt,
+                        // instead of checking that the circuit is not satisfied (i.e. for `Public|Private`).
+                        #[allow(unused_mut)]
+                        let mut should_panic_on_halt = false;
+                        /// A helper macro to check the conditions.
+                        #[allow(unused_macros)]
+                        macro_rules! check_condition {
+                            ("ensure unequal lengths halt") => {
+                                should_succeed &= b.len() == c.len();
+                                // The lengths of the strings are known when synthesizing the circuit, so the execution halts in every mode.
+                                should_panic_on_halt |= true;
+                            };
+                        }
+                        // Check the conditions.
+                        $( $( check_condition!($condition); )+ )?
 
                         // If `should_succeed` is `true`, compute the expected output.
                         let expected = match should_succeed {
@@ -895,7 +936,7 @@ mod tests {
                                         assert!(result_a.is_err(), "Failure case (on iteration {i}) did not halt (console): {a} {b} {c}");
 
                                         // Halt the execution.
-                                        if (mode_a.is_constant() && mode_b.is_constant() && mode_c.is_constant()) {
+                                        if (mode_a.is_constant() && mode_b.is_constant() && mode_c.is_constant()) || should_panic_on_halt {
                                             // Attempt to execute a failure case.
                                             let result_b = std::panic::catch_unwind(|| <$operation as $crate::Operation<_, _, _, 3>>::$execute::<CurrentAleo>(&[first, second, third]).unwrap());
                                             // Ensure the execution halted.
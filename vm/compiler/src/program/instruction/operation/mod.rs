@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod array;
+pub use array::*;
+
 mod assert;
 pub use assert::*;
 
@@ -99,6 +102,8 @@ crate::operation!(
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (I256, I256) => I256 ("ensure 256-bit overflows halt"),
+        (U256, U256) => U256 ("ensure 256-bit overflows halt"),
         (Scalar, Scalar) => Scalar,
     }
 );
@@ -118,6 +123,8 @@ crate::operation!(
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (I256, I256) => I256,
+        (U256, U256) => U256,
     }
 );
 
@@ -156,6 +163,8 @@ crate::operation!(
         (U32, U32) => U32 ("ensure overflows halt", "ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure overflows halt", "ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (I256, I256) => I256 ("ensure 256-bit overflows halt", "ensure 256-bit divide by zero halts"),
+        (U256, U256) => U256 ("ensure 256-bit overflows halt", "ensure 256-bit divide by zero halts"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -175,6 +184,8 @@ crate::operation!(
         (U32, U32) => U32 ("ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure divide by zero halts"),
+        (I256, I256) => I256 ("ensure 256-bit divide by zero halts"),
+        (U256, U256) => U256 ("ensure 256-bit divide by zero halts"),
     }
 );
 
@@ -188,6 +199,82 @@ crate::operation!(
     }
 );
 
+/// Shifts `first` left by `third` bits, shifting in the upper bits of `second`, storing the outcome in `destination`.
+pub type FunnelShl<N> = TernaryLiteral<N, FunnelShlOperation<N>>;
+
+crate::operation!(
+    pub struct FunnelShlOperation<console::prelude::FunnelShl, circuit::prelude::FunnelShl, funnel_shl, "fshl"> {
+        (I8, I8, U8) => I8,
+        (I8, I8, U16) => I8,
+        (I8, I8, U32) => I8,
+        (I16, I16, U8) => I16,
+        (I16, I16, U16) => I16,
+        (I16, I16, U32) => I16,
+        (I32, I32, U8) => I32,
+        (I32, I32, U16) => I32,
+        (I32, I32, U32) => I32,
+        (I64, I64, U8) => I64,
+        (I64, I64, U16) => I64,
+        (I64, I64, U32) => I64,
+        (I128, I128, U8) => I128,
+        (I128, I128, U16) => I128,
+        (I128, I128, U32) => I128,
+        (U8, U8, U8) => U8,
+        (U8, U8, U16) => U8,
+        (U8, U8, U32) => U8,
+        (U16, U16, U8) => U16,
+        (U16, U16, U16) => U16,
+        (U16, U16, U32) => U16,
+        (U32, U32, U8) => U32,
+        (U32, U32, U16) => U32,
+        (U32, U32, U32) => U32,
+        (U64, U64, U8) => U64,
+        (U64, U64, U16) => U64,
+        (U64, U64, U32) => U64,
+        (U128, U128, U8) => U128,
+        (U128, U128, U16) => U128,
+        (U128, U128, U32) => U128,
+    }
+);
+
+/// Shifts `second` right by `third` bits, shifting in the lower bits of `first`, storing the outcome in `destination`.
+pub type FunnelShr<N> = TernaryLiteral<N, FunnelShrOperation<N>>;
+
+crate::operation!(
+    pub struct FunnelShrOperation<console::prelude::FunnelShr, circuit::prelude::FunnelShr, funnel_shr, "fshr"> {
+        (I8, I8, U8) => I8,
+        (I8, I8, U16) => I8,
+        (I8, I8, U32) => I8,
+        (I16, I16, U8) => I16,
+        (I16, I16, U16) => I16,
+        (I16, I16, U32) => I16,
+        (I32, I32, U8) => I32,
+        (I32, I32, U16) => I32,
+        (I32, I32, U32) => I32,
+        (I64, I64, U8) => I64,
+        (I64, I64, U16) => I64,
+        (I64, I64, U32) => I64,
+        (I128, I128, U8) => I128,
+        (I128, I128, U16) => I128,
+        (I128, I128, U32) => I128,
+        (U8, U8, U8) => U8,
+        (U8, U8, U16) => U8,
+        (U8, U8, U32) => U8,
+        (U16, U16, U8) => U16,
+        (U16, U16, U16) => U16,
+        (U16, U16, U32) => U16,
+        (U32, U32, U8) => U32,
+        (U32, U32, U16) => U32,
+        (U32, U32, U32) => U32,
+        (U64, U64, U8) => U64,
+        (U64, U64, U16) => U64,
+        (U64, U64, U32) => U64,
+        (U128, U128, U8) => U128,
+        (U128, U128, U16) => U128,
+        (U128, U128, U32) => U128,
+    }
+);
+
 /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
 pub type GreaterThan<N> = BinaryLiteral<N, GreaterThanOperation<N>>;
 
@@ -205,7 +292,10 @@ crate::operation!(
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (I256, I256) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (String, String) => Boolean,
     }
 );
 
@@ -226,7 +316,10 @@ crate::operation!(
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (I256, I256) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (String, String) => Boolean,
     }
 );
 
@@ -256,7 +349,10 @@ crate::operation!(
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (I256, I256) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (String, String) => Boolean,
     }
 );
 
@@ -277,7 +373,10 @@ crate::operation!(
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (I256, I256) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (String, String) => Boolean,
     }
 );
 
@@ -312,6 +411,8 @@ crate::operation!(
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (I256, I256) => I256 ("ensure 256-bit overflows halt"),
+        (U256, U256) => U256 ("ensure 256-bit overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -331,6 +432,8 @@ crate::operation!(
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (I256, I256) => I256,
+        (U256, U256) => U256,
     }
 );
 
@@ -518,6 +621,82 @@ crate::operation!(
     }
 );
 
+/// Rotates `first` left by `second` bits, storing the outcome in `destination`.
+pub type Rotl<N> = BinaryLiteral<N, RotlOperation<N>>;
+
+crate::operation!(
+    pub struct RotlOperation<console::prelude::Rotl, circuit::prelude::Rotl, rotl, "rotl"> {
+        (I8, U8) => I8,
+        (I8, U16) => I8,
+        (I8, U32) => I8,
+        (I16, U8) => I16,
+        (I16, U16) => I16,
+        (I16, U32) => I16,
+        (I32, U8) => I32,
+        (I32, U16) => I32,
+        (I32, U32) => I32,
+        (I64, U8) => I64,
+        (I64, U16) => I64,
+        (I64, U32) => I64,
+        (I128, U8) => I128,
+        (I128, U16) => I128,
+        (I128, U32) => I128,
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
+/// Rotates `first` right by `second` bits, storing the outcome in `destination`.
+pub type Rotr<N> = BinaryLiteral<N, RotrOperation<N>>;
+
+crate::operation!(
+    pub struct RotrOperation<console::prelude::Rotr, circuit::prelude::Rotr, rotr, "rotr"> {
+        (I8, U8) => I8,
+        (I8, U16) => I8,
+        (I8, U32) => I8,
+        (I16, U8) => I16,
+        (I16, U16) => I16,
+        (I16, U32) => I16,
+        (I32, U8) => I32,
+        (I32, U16) => I32,
+        (I32, U32) => I32,
+        (I64, U8) => I64,
+        (I64, U16) => I64,
+        (I64, U32) => I64,
+        (I128, U8) => I128,
+        (I128, U16) => I128,
+        (I128, U32) => I128,
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
 /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
 pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
@@ -705,6 +884,8 @@ crate::operation!(
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (I256, I256) => I256 ("ensure 256-bit overflows halt"),
+        (U256, U256) => U256 ("ensure 256-bit overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -724,6 +905,8 @@ crate::operation!(
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (I256, I256) => I256,
+        (U256, U256) => U256,
     }
 );
 
@@ -747,7 +930,7 @@ crate::operation!(
         (Boolean, U64, U64) => U64,
         (Boolean, U128, U128) => U128,
         (Boolean, Scalar, Scalar) => Scalar,
-        // (Boolean, StringType, StringType) => StringType,
+        (Boolean, String, String) => String ("ensure unequal lengths halt"),
     }
 );
 
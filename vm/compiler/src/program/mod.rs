@@ -367,7 +367,7 @@ impl<N: Network> Program<N> {
             ensure!(!Self::is_reserved_keyword(identifier), "'{identifier}' is a reserved keyword.");
             // Ensure the member type is already defined in the program.
             match plaintext_type {
-                PlaintextType::Literal(..) => continue,
+                PlaintextType::Literal(..) | PlaintextType::Array(..) => continue,
                 PlaintextType::Interface(member_identifier) => {
                     // Ensure the member interface name exists in the program.
                     if !self.interfaces.contains_key(member_identifier) {
@@ -421,7 +421,7 @@ impl<N: Network> Program<N> {
                 EntryType::Constant(plaintext_type)
                 | EntryType::Public(plaintext_type)
                 | EntryType::Private(plaintext_type) => match plaintext_type {
-                    PlaintextType::Literal(..) => continue,
+                    PlaintextType::Literal(..) | PlaintextType::Array(..) => continue,
                     PlaintextType::Interface(identifier) => {
                         if !self.interfaces.contains_key(identifier) {
                             bail!("Interface '{identifier}' in record '{record_name}' is not defined.")
@@ -41,6 +41,8 @@ pub use proof::Proof;
 
 mod proving_key;
 pub use proving_key::ProvingKey;
+#[cfg(not(target_family = "wasm"))]
+pub use proving_key::LazyProvingKey;
 
 mod universal_srs;
 pub use universal_srs::UniversalSRS;
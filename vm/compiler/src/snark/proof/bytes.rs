@@ -61,4 +61,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_are_compressed() -> Result<()> {
+        // Sample the proof.
+        let expected = proof::tests::sample_proof();
+
+        // Ensure the byte representation is the version, followed by the compressed encoding of the proof.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(2 + expected.serialized_size(Compress::Yes), expected_bytes.len());
+        assert!(expected_bytes.len() < expected.serialized_size(Compress::No));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::path::{Path, PathBuf};
+
+/// A proving key that is read from a file, and deserialized on first use.
+///
+/// The file is only read when the proving key is first used, so a process that never proves
+/// the function does not pay the cost of loading the proving key, and a loaded proving key
+/// does not keep a second copy of its bytes in memory.
+#[derive(Clone)]
+pub struct LazyProvingKey<N: Network> {
+    /// The path to the proving key.
+    path: PathBuf,
+    /// The number of bytes in the proving key.
+    num_bytes: usize,
+    /// The proving key, once deserialized.
+    proving_key: Arc<OnceCell<ProvingKey<N>>>,
+}
+
+impl<N: Network> LazyProvingKey<N> {
+    /// Opens the proving key at the given path, without reading or deserializing it.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        // Ensure the proving key exists, and retrieve its size.
+        let num_bytes = usize::try_from(std::fs::metadata(path.as_ref())?.len())?;
+        Ok(Self { path: path.as_ref().to_path_buf(), num_bytes, proving_key: Default::default() })
+    }
+
+    /// Returns the proving key, reading and deserializing it from the file on first use.
+    pub fn get(&self) -> Result<&ProvingKey<N>> {
+        self.proving_key.get_or_try_init(|| {
+            // Read the proving key, and ensure the file was not modified since it was opened.
+            let bytes = std::fs::read(&self.path)?;
+            ensure!(bytes.len() == self.num_bytes, "The proving key at '{}' was modified", self.path.display());
+            // Note: The bytes are dropped once the proving key is deserialized.
+            ProvingKey::from_bytes_le(&bytes)
+        })
+    }
+
+    /// Returns `true` if the proving key has been deserialized.
+    pub fn is_loaded(&self) -> bool {
+        self.proving_key.get().is_some()
+    }
+
+    /// Returns the number of bytes in the proving key.
+    pub fn num_bytes(&self) -> usize {
+        self.num_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lazy_proving_key() -> Result<()> {
+        // Sample a proving key.
+        let (_, expected, _) = crate::process::test_helpers::sample_key();
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Write the proving key to a file.
+        let path = std::env::temp_dir().join(format!("lazy_proving_key_{}.prover", std::process::id()));
+        std::fs::write(&path, &expected_bytes)?;
+
+        // Ensure the proving key is only deserialized on first use.
+        let candidate = LazyProvingKey::<CurrentNetwork>::open(&path)?;
+        assert!(!candidate.is_loaded());
+        assert_eq!(expected_bytes.len(), candidate.num_bytes());
+        assert_eq!(expected_bytes, candidate.get()?.to_bytes_le()?);
+        assert!(candidate.is_loaded());
+
+        // Ensure a truncated proving key fails to load.
+        std::fs::write(&path, &expected_bytes[..expected_bytes.len() / 2])?;
+        assert!(LazyProvingKey::<CurrentNetwork>::open(&path)?.get().is_err());
+
+        // Ensure a proving key that is modified after it is opened fails to load.
+        let candidate = LazyProvingKey::<CurrentNetwork>::open(&path)?;
+        std::fs::write(&path, &expected_bytes)?;
+        assert!(candidate.get().is_err());
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -20,6 +20,11 @@ mod bytes;
 mod parse;
 mod serialize;
 
+#[cfg(not(target_family = "wasm"))]
+mod lazy;
+#[cfg(not(target_family = "wasm"))]
+pub use lazy::LazyProvingKey;
+
 #[derive(Clone)]
 pub struct ProvingKey<N: Network> {
     /// The proving key for the function.
@@ -20,6 +20,9 @@ mod bytes;
 mod parse;
 mod serialize;
 
+/// A verifying key, along with the public inputs and the proof to verify against it.
+pub type VerifyingInstance<'a, N> = (&'a VerifyingKey<N>, &'a [<N as Environment>::Field], &'a Proof<N>);
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct VerifyingKey<N: Network> {
     /// The verifying key for the function.
@@ -83,6 +86,39 @@ impl<N: Network> VerifyingKey<N> {
     }
 }
 
+impl<N: Network> VerifyingKey<N> {
+    /// Returns `true` if every proof is valid for its verifying key and public inputs,
+    /// where the pairing checks of the proofs are batched into a single pairing product.
+    pub fn batch_verify(instances: &[VerifyingInstance<N>]) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the instances.
+        let instances = instances
+            .iter()
+            .map(|(verifying_key, inputs, proof)| (&***verifying_key, std::slice::from_ref(inputs), &***proof))
+            .collect::<Vec<_>>();
+
+        // Verify the proofs.
+        match Marlin::<N>::batch_verify(N::marlin_fs_parameters(), &instances) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                {
+                    let elapsed = timer.elapsed().as_millis();
+                    println!("{}", format!(" • Verified {} proofs (in {} ms)", instances.len(), elapsed).dimmed());
+                }
+
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+}
+
 impl<N: Network> Deref for VerifyingKey<N> {
     type Target = marlin::CircuitVerifyingKey<N::PairingCurve, marlin::MarlinHidingMode>;
 
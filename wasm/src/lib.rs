@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "compiler")]
+pub use snarkvm_compiler as compiler;
+
 #[cfg(feature = "console")]
 pub use snarkvm_console::*;
 